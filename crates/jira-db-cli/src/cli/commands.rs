@@ -1,4 +1,6 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "jira-db")]
@@ -64,6 +66,14 @@ pub enum Commands {
         #[arg(short, long)]
         assignee: Option<String>,
 
+        #[command(flatten)]
+        filters: SearchFilterArgs,
+
+        /// Sort keys in priority order, e.g. "updated:desc,key"
+        /// (relevance, key, summary, created, updated, due, resolved)
+        #[arg(long)]
+        sort: Option<String>,
+
         /// Maximum number of results
         #[arg(short, long, default_value = "20")]
         limit: usize,
@@ -71,6 +81,52 @@ pub enum Commands {
         /// Offset for pagination
         #[arg(short, long, default_value = "0")]
         offset: usize,
+
+        /// Fuse the keyword ranking with embedding similarity (reciprocal rank fusion).
+        /// Projects without embeddings use the keyword ranking alone
+        #[arg(long)]
+        hybrid: bool,
+
+        /// Weight of the keyword ranking in hybrid search (default: settings or 1.0)
+        #[arg(long, requires = "hybrid")]
+        lexical_weight: Option<f64>,
+
+        /// Weight of the embedding ranking in hybrid search (default: settings or 1.0)
+        #[arg(long, requires = "hybrid")]
+        semantic_weight: Option<f64>,
+
+        /// Summarize all matches by status, type, priority, assignee, labels,
+        /// components and fix versions
+        #[arg(long)]
+        facets: bool,
+    },
+
+    /// Run a JQL query against the local database
+    Jql {
+        /// JQL query (e.g., "project = PROJ AND status WAS Done AFTER -7d ORDER BY updated DESC")
+        query: String,
+
+        /// Project key
+        #[arg(short, long, required_unless_present = "all_projects")]
+        project: Option<String>,
+
+        /// Query all synced projects
+        #[arg(long, conflicts_with = "project")]
+        all_projects: bool,
+
+        /// Maximum number of results
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+
+        /// Print the generated SQL and its parameters
+        #[arg(long)]
+        show_sql: bool,
+    },
+
+    /// Manage and run saved searches (shared with the MCP server and web API)
+    Saved {
+        #[command(subcommand)]
+        action: SavedAction,
     },
 
     /// List metadata (statuses, priorities, etc.)
@@ -79,7 +135,7 @@ pub enum Commands {
         #[arg(short, long)]
         project: String,
 
-        /// Type of metadata to show (status, priority, issue-type, label, component, version)
+        /// Type of metadata to show (status, priority, issue-type, label, component, version, field-option)
         #[arg(short, long)]
         r#type: Option<String>,
     },
@@ -163,6 +219,103 @@ pub enum Commands {
         endpoint: Option<String>,
     },
 
+    /// Find likely duplicate issues from their embeddings
+    Duplicates {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// Minimum cosine similarity of a reported pair (0-1)
+        #[arg(short, long, default_value = "0.9")]
+        threshold: f64,
+
+        /// Only check issues created within this age (e.g., 30d, 2w, 12h) or since a date
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Nearest neighbors examined per issue
+        #[arg(long, default_value = "10")]
+        neighbors: usize,
+
+        /// Output format: table, json or html
+        #[arg(short, long, default_value = "table")]
+        format: String,
+
+        /// Output file for json or html
+        /// (html defaults to reports/duplicates_<PROJECT>_YYYYMMDD_HHMMSS.html)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Find issues similar to an issue from its embedding ("more like this")
+    Similar {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Only return issues of the same project
+        #[arg(long)]
+        same_project: bool,
+
+        /// Leave out resolved issues
+        #[arg(long)]
+        exclude_resolved: bool,
+
+        /// Only return issues created on or after this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        created_from: Option<String>,
+
+        /// Only return issues created on or before this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        created_to: Option<String>,
+
+        /// Maximum number of results
+        #[arg(short, long, default_value = "10")]
+        limit: usize,
+
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// Group a project's issues into topics from their embeddings and suggest labels
+    ///
+    /// Replaces the project's stored topics, also when --since, --until or
+    /// --issue-type narrow the clustered issues.
+    Topics {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// Number of topics (default: about sqrt(issues / 2), between 2 and 20)
+        #[arg(short = 'k', long)]
+        topics: Option<usize>,
+
+        /// Only cluster issues created within this age (e.g., 90d, 12w) or since a date
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only cluster issues created on or before this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Only cluster issues of this type (e.g., Bug)
+        #[arg(long)]
+        issue_type: Option<String>,
+
+        /// Name topics with Claude (requires ANTHROPIC_API_KEY)
+        #[arg(long)]
+        llm: bool,
+
+        /// Output format: table, json or html
+        #[arg(short, long, default_value = "table")]
+        format: String,
+
+        /// Output file for json or html
+        /// (html defaults to reports/topics_<PROJECT>_YYYYMMDD_HHMMSS.html)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
     /// Manage issue snapshots (historical versions)
     Snapshots {
         #[command(subcommand)]
@@ -180,6 +333,164 @@ pub enum Commands {
         #[command(subcommand)]
         action: DebugAction,
     },
+
+    /// Edit issues in JIRA
+    Issue {
+        #[command(subcommand)]
+        action: IssueAction,
+    },
+
+    /// Show synced workflows and check history against them
+    Workflow {
+        #[command(subcommand)]
+        action: WorkflowAction,
+    },
+
+    /// Inspect the recorded history of raw JIRA API payloads
+    Raw {
+        #[command(subcommand)]
+        action: RawAction,
+    },
+
+    /// Edit many issues selected by a SQL predicate
+    Bulk {
+        #[command(subcommand)]
+        action: BulkAction,
+    },
+
+    /// Queue writes offline (applied to the local database immediately)
+    Queue {
+        #[command(subcommand)]
+        action: QueueAction,
+    },
+
+    /// Push queued writes to JIRA
+    Push {
+        /// Specific project key to push (pushes all enabled projects if not specified)
+        #[arg(short, long)]
+        project: Option<String>,
+    },
+
+    /// Import a project from a JIRA export file (works without API access)
+    Import {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// "Export all fields" CSV, JSON with REST-shaped issues (search response or issues
+        /// array), or the entities.xml of a site backup
+        #[arg(short, long)]
+        file: String,
+    },
+
+    /// Export a table, view or query result to Parquet, CSV, JSON Lines or Excel
+    Export {
+        /// Project key
+        #[arg(short, long, required_unless_present = "all_projects")]
+        project: Option<String>,
+
+        /// Export from all synced projects (adds a project_key column)
+        #[arg(long, conflicts_with = "project")]
+        all_projects: bool,
+
+        /// Table or view to export (e.g., issues_readable)
+        #[arg(long, required_unless_present = "sql", conflicts_with = "sql")]
+        table: Option<String>,
+
+        /// SELECT query whose result is exported
+        #[arg(long)]
+        sql: Option<String>,
+
+        /// Output format: parquet, csv, jsonl or xlsx (inferred from --out if omitted)
+        #[arg(short, long)]
+        format: Option<String>,
+
+        /// Output file path
+        #[arg(short, long)]
+        out: String,
+    },
+
+    /// Manage the local database files
+    Db {
+        #[command(subcommand)]
+        action: DbAction,
+    },
+}
+
+/// Structured filters for `search`
+#[derive(Args)]
+pub struct SearchFilterArgs {
+    /// Filter by issue type
+    #[arg(long = "type")]
+    pub issue_type: Option<String>,
+
+    /// Filter by priority
+    #[arg(long)]
+    pub priority: Option<String>,
+
+    /// Filter by label (repeatable)
+    #[arg(long = "label")]
+    pub labels: Vec<String>,
+
+    /// Match any or all of the given labels
+    #[arg(long, default_value = "any", value_parser = ["any", "all"])]
+    pub labels_match: String,
+
+    /// Filter by component (repeatable, matches any)
+    #[arg(long = "component")]
+    pub components: Vec<String>,
+
+    /// Filter by fix version (repeatable, matches any)
+    #[arg(long = "fix-version")]
+    pub fix_versions: Vec<String>,
+
+    /// Filter by sprint name
+    #[arg(long)]
+    pub sprint: Option<String>,
+
+    /// Filter by parent issue or epic key
+    #[arg(long)]
+    pub parent: Option<String>,
+
+    /// Filter by resolution ("Unresolved" for issues without one)
+    #[arg(long)]
+    pub resolution: Option<String>,
+
+    /// Filter by status category (To Do, In Progress, Done)
+    #[arg(long)]
+    pub status_category: Option<String>,
+
+    /// Created on or after (YYYY-MM-DD or RFC 3339)
+    #[arg(long)]
+    pub created_from: Option<String>,
+
+    /// Created on or before (YYYY-MM-DD or RFC 3339)
+    #[arg(long)]
+    pub created_to: Option<String>,
+
+    /// Updated on or after
+    #[arg(long)]
+    pub updated_from: Option<String>,
+
+    /// Updated on or before
+    #[arg(long)]
+    pub updated_to: Option<String>,
+
+    /// Due on or after
+    #[arg(long)]
+    pub due_from: Option<String>,
+
+    /// Due on or before
+    #[arg(long)]
+    pub due_to: Option<String>,
+
+    /// Resolved on or after
+    #[arg(long)]
+    pub resolved_from: Option<String>,
+
+    /// Resolved on or before
+    #[arg(long)]
+    pub resolved_to: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -326,6 +637,335 @@ pub enum FieldsAction {
     },
 }
 
+#[derive(Subcommand)]
+pub enum IssueAction {
+    /// Edit issue fields (validated against synced fields and metadata)
+    Edit {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Set a field value: FIELD=VALUE (field ID or name; empty value clears the field)
+        #[arg(long = "set", value_name = "FIELD=VALUE")]
+        set: Vec<String>,
+
+        /// Add a label
+        #[arg(long = "add-label", value_name = "LABEL")]
+        add_labels: Vec<String>,
+
+        /// Remove a label
+        #[arg(long = "remove-label", value_name = "LABEL")]
+        remove_labels: Vec<String>,
+
+        /// Assignee account ID ("none" to unassign)
+        #[arg(long)]
+        assignee: Option<String>,
+
+        /// Show the request without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Move issues to a status by name, chaining transitions as needed
+    Move {
+        /// Issue keys (e.g., PROJ-1 PROJ-2)
+        #[arg(required = true)]
+        issue_keys: Vec<String>,
+
+        /// Target status name (e.g., "Done")
+        #[arg(short, long)]
+        to: String,
+
+        /// Show the planned transitions without executing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WorkflowAction {
+    /// Print the workflow of an issue type as a Mermaid state diagram
+    Show {
+        /// Project key
+        project: String,
+
+        /// Issue type name (e.g., Bug)
+        issue_type: String,
+    },
+
+    /// List status changes in the change history that the workflow does not allow
+    Validate {
+        /// Project key
+        project: String,
+
+        /// Only check issues of this type
+        #[arg(long)]
+        issue_type: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum RawAction {
+    /// List the recorded payload versions of an issue
+    Versions {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+    },
+
+    /// Print a JSON diff between two payload versions of an issue
+    Diff {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Sync run ID to compare from (default: the version before --to)
+        #[arg(long, value_name = "RUN")]
+        from: Option<i64>,
+
+        /// Sync run ID to compare to (default: the latest version)
+        #[arg(long, value_name = "RUN")]
+        to: Option<i64>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BulkAction {
+    /// Apply the same field edit to every issue matching a SQL predicate
+    Edit {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// SQL predicate on the issues table (e.g., "status = 'Open' AND priority = 'Low'")
+        #[arg(long = "where", value_name = "PREDICATE")]
+        predicate: Option<String>,
+
+        /// Set a field value: FIELD=VALUE (field ID or name; empty value clears the field)
+        #[arg(long = "set", value_name = "FIELD=VALUE")]
+        set: Vec<String>,
+
+        /// Add a label
+        #[arg(long = "add-label", value_name = "LABEL")]
+        add_labels: Vec<String>,
+
+        /// Remove a label
+        #[arg(long = "remove-label", value_name = "LABEL")]
+        remove_labels: Vec<String>,
+
+        /// Assignee account ID ("none" to unassign)
+        #[arg(long)]
+        assignee: Option<String>,
+
+        /// Show the per-issue diff without sending anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Maximum number of issues to select
+        #[arg(long, default_value = "1000")]
+        limit: usize,
+
+        /// Maximum requests per second (updating and re-fetching an issue count separately)
+        #[arg(long, default_value = "5")]
+        rate: f64,
+
+        /// Report file (defaults to ./bulk-edit-<PROJECT>-<TIMESTAMP>.json)
+        #[arg(long)]
+        report: Option<PathBuf>,
+
+        /// Resume an interrupted bulk edit from its checkpoint
+        #[arg(long)]
+        resume: bool,
+    },
+
+    /// Discard the checkpoint of an interrupted bulk edit
+    Abort {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum QueueAction {
+    /// Queue a status transition
+    Transition {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Target status name (e.g., "In Progress")
+        #[arg(short, long)]
+        to: String,
+    },
+
+    /// Queue a comment
+    Comment {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Comment text
+        #[arg(short, long)]
+        body: String,
+    },
+
+    /// Queue a due date change
+    DueDate {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Due date (YYYY-MM-DD)
+        date: String,
+    },
+
+    /// Queue a field edit (validated against the synced fields and metadata)
+    Edit {
+        /// Issue key (e.g., PROJ-123)
+        issue_key: String,
+
+        /// Set a field value: FIELD=VALUE (field ID or name; empty value clears the field)
+        #[arg(long = "set", value_name = "FIELD=VALUE")]
+        set: Vec<String>,
+
+        /// Add a label
+        #[arg(long = "add-label", value_name = "LABEL")]
+        add_labels: Vec<String>,
+
+        /// Remove a label
+        #[arg(long = "remove-label", value_name = "LABEL")]
+        remove_labels: Vec<String>,
+
+        /// Assignee account ID ("none" to unassign)
+        #[arg(long)]
+        assignee: Option<String>,
+    },
+
+    /// Queue the creation of an issue
+    Create {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// Issue summary
+        #[arg(short, long)]
+        summary: String,
+
+        /// Issue description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Issue type (e.g., Task, Bug, Story)
+        #[arg(short = 't', long, default_value = "Task")]
+        issue_type: String,
+    },
+
+    /// List queued operations
+    List {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// Filter by status (pending, applied, rejected, discarded)
+        #[arg(short, long)]
+        status: Option<String>,
+    },
+
+    /// Re-queue a rejected operation (skips conflict detection on the next push)
+    Retry {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// Operation ID
+        id: i64,
+    },
+
+    /// Discard a pending or rejected operation
+    Discard {
+        /// Project key
+        #[arg(short, long)]
+        project: String,
+
+        /// Operation ID
+        id: i64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SavedAction {
+    /// List saved searches
+    List,
+
+    /// Show a saved search and its parameters
+    Show {
+        /// Saved search name
+        name: String,
+    },
+
+    /// Save a search (replaces an existing one with the same name)
+    Add {
+        /// Saved search name (letters, digits, '-' and '_')
+        name: String,
+
+        /// Read-only SQL; reference parameters as {{name}} without quotes
+        #[arg(
+            long,
+            required_unless_present_any = ["jql", "filter"],
+            conflicts_with_all = ["jql", "filter"]
+        )]
+        sql: Option<String>,
+
+        /// JQL query (e.g., "assignee = {{who}} AND status != Done")
+        #[arg(long, conflicts_with = "filter")]
+        jql: Option<String>,
+
+        /// Structured filters as JSON (e.g., '{"status": "Open", "assignee": "{{who}}"}')
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Parameter as NAME[?][:TYPE][=DEFAULT]; TYPE is string, number, date or boolean,
+        /// and '?' makes it optional (repeatable)
+        #[arg(long = "param")]
+        params: Vec<String>,
+
+        /// Description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Project to run against (all projects if not specified)
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Owner (defaults to the JIRA username of the active endpoint)
+        #[arg(long)]
+        owner: Option<String>,
+    },
+
+    /// Delete a saved search
+    Delete {
+        /// Saved search name
+        name: String,
+    },
+
+    /// Run a saved search
+    Run {
+        /// Saved search name
+        name: String,
+
+        /// Parameter value as NAME=VALUE (repeatable)
+        #[arg(long = "param")]
+        params: Vec<String>,
+
+        /// Project key (overrides the saved project)
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Maximum number of results
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+
+        /// Print the executed SQL
+        #[arg(long)]
+        show_sql: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum DebugAction {
     /// Create test issues in JIRA
@@ -381,3 +1021,53 @@ pub enum DebugAction {
     /// Show current debug mode status
     Status,
 }
+
+#[derive(Subcommand)]
+pub enum DbAction {
+    /// Apply pending schema migrations to the project databases
+    Migrate {
+        /// Specific project key (all project databases if not specified)
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Show applied and pending migrations without changing anything
+        #[arg(long, conflicts_with = "dry_run")]
+        status: bool,
+
+        /// Run pending migrations in a transaction that is rolled back
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Inspect and maintain the project database files
+    Maintain {
+        /// Specific project key (all project databases if not specified)
+        #[arg(short, long, global = true)]
+        project: Option<String>,
+
+        #[command(subcommand)]
+        action: MaintainAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum MaintainAction {
+    /// Show file, WAL, and per-table sizes and row counts
+    Size,
+
+    /// Flush the write-ahead log into the database file
+    Checkpoint,
+
+    /// Refresh statistics and checkpoint so freed space can be reused
+    Vacuum,
+
+    /// Drop and re-create all indexes (including the embeddings HNSW index)
+    RebuildIndexes,
+
+    /// Check referential consistency between issues and derived tables
+    Check {
+        /// Delete the inconsistent rows that can be repaired
+        #[arg(long)]
+        repair: bool,
+    },
+}
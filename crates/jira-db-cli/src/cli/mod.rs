@@ -1,6 +1,7 @@
 mod commands;
 
 pub use commands::{
-    Cli, Commands, ConfigAction, DebugAction, EndpointAction, FieldsAction, ProjectAction,
-    SnapshotsAction,
+    BulkAction, Cli, Commands, ConfigAction, DbAction, DebugAction, EndpointAction, FieldsAction,
+    IssueAction, MaintainAction, ProjectAction, QueueAction, RawAction, SavedAction,
+    SearchFilterArgs, SnapshotsAction, WorkflowAction,
 };
@@ -8,26 +8,31 @@ use log::{error, info, warn};
 
 use jira_db_core::application::services::JiraService;
 use jira_db_core::application::use_cases::{
-    CreateTestTicketUseCase, GenerateReportUseCase, GenerateSnapshotsUseCase,
+    CreateTestTicketUseCase, ExecuteSqlUseCase, GenerateReportUseCase, GenerateSnapshotsUseCase,
     GetChangeHistoryUseCase, GetProjectMetadataUseCase, SearchIssuesUseCase,
     SyncProjectListUseCase, SyncProjectUseCase,
 };
 use jira_db_core::chrono::{Duration, Utc};
+use jira_db_core::domain::entities::Issue;
 use jira_db_core::domain::error::{DomainError, DomainResult};
-use jira_db_core::domain::repositories::SearchParams;
+use jira_db_core::domain::repositories::{
+    DateRange, FacetField, SearchFacet, SearchParams, SearchSort, merge_facets,
+};
 use jira_db_core::indicatif::{ProgressBar, ProgressStyle};
 use jira_db_core::infrastructure::config::{ProjectConfig, Settings, SyncCheckpoint};
 use jira_db_core::infrastructure::database::{
     DatabaseFactory, DuckDbChangeHistoryRepository, DuckDbIssueRepository,
     DuckDbIssueSnapshotRepository, DuckDbMetadataRepository, DuckDbProjectRepository,
-    DuckDbSyncHistoryRepository, RawDataRepository,
+    DuckDbSyncHistoryRepository, DuckDbTopicRepository, FullTextSearchRepository,
+    RawDataRepository, SchemaKind,
 };
 use jira_db_core::infrastructure::external::jira::JiraApiClient;
 use jira_db_core::report::{generate_interactive_report, generate_static_report};
 
 use cli::{
-    Cli, Commands, ConfigAction, DebugAction, EndpointAction, FieldsAction, ProjectAction,
-    SnapshotsAction,
+    BulkAction, Cli, Commands, ConfigAction, DbAction, DebugAction, EndpointAction, FieldsAction,
+    IssueAction, MaintainAction, ProjectAction, QueueAction, RawAction, SavedAction,
+    SearchFilterArgs, SnapshotsAction, WorkflowAction,
 };
 
 #[tokio::main]
@@ -59,17 +64,13 @@ async fn run() -> DomainResult<()> {
     // Create database factory for per-project databases
     let db_factory = Arc::new(DatabaseFactory::new(&settings));
 
-    // Create JIRA service (DIP: implements application service trait)
-    let jira_config = settings.get_jira_config().ok_or_else(|| {
-        DomainError::Validation("No JIRA endpoint configured. Run 'jira-db init' first.".into())
-    })?;
-    let jira_service = Arc::new(JiraApiClient::new(&jira_config)?);
-
-    // Route commands
+    // Route commands. Only commands that call JIRA need an endpoint, so local ones
+    // (search, sql, report, import, ...) work before any endpoint is configured.
     match cli.command {
         Commands::Init { .. } => unreachable!(), // Already handled above
         Commands::Project { action } => match action {
             ProjectAction::Init => {
+                let jira_service = active_jira_client(&settings)?;
                 handle_project_init(&settings_path, jira_service, db_factory).await?
             }
             ProjectAction::List { verbose } => handle_project_list(&settings_path, verbose)?,
@@ -81,27 +82,66 @@ async fn run() -> DomainResult<()> {
             }
         },
         Commands::Sync { project, force: _ } => {
-            handle_sync(&settings_path, db_factory, jira_service, project).await?;
+            handle_sync(&settings_path, db_factory, project).await?;
         }
         Commands::Search {
             query,
             project,
             status,
             assignee,
+            filters,
+            sort,
             limit,
             offset,
+            hybrid,
+            lexical_weight,
+            semantic_weight,
+            facets,
+        } => {
+            let params = SearchParams {
+                query: Some(query),
+                project_key: project,
+                status,
+                assignee,
+                sort: SearchSort::parse_list(sort.as_deref().unwrap_or_default())?,
+                limit: Some(limit),
+                offset: Some(offset),
+                ..search_filters(filters)?
+            };
+            if hybrid {
+                handle_hybrid_search(
+                    &settings_path,
+                    db_factory,
+                    params,
+                    lexical_weight,
+                    semantic_weight,
+                    facets,
+                )
+                .await?;
+            } else {
+                handle_search(&settings_path, db_factory, params, facets)?;
+            }
+        }
+        Commands::Jql {
+            query,
+            project,
+            all_projects: _,
+            limit,
+            show_sql,
         } => {
-            handle_search(
+            // Without --project, clap guarantees --all-projects was given
+            handle_jql(
                 &settings_path,
-                db_factory,
+                &db_factory,
                 &query,
                 project,
-                status,
-                assignee,
                 limit,
-                offset,
+                show_sql,
             )?;
         }
+        Commands::Saved { action } => {
+            handle_saved_command(&settings_path, &db_factory, action)?;
+        }
         Commands::Metadata { project, r#type } => {
             handle_metadata(&settings_path, db_factory, &project, r#type)?;
         }
@@ -121,7 +161,7 @@ async fn run() -> DomainResult<()> {
         } => {
             handle_test_ticket(
                 &settings_path,
-                jira_service,
+                active_jira_client(&settings)?,
                 &project,
                 &summary,
                 description.as_deref(),
@@ -134,9 +174,7 @@ async fn run() -> DomainResult<()> {
             ConfigAction::Show => handle_config_show(&settings_path)?,
             ConfigAction::Set { key, value } => handle_config_set(&settings_path, &key, &value)?,
         },
-        Commands::Endpoint { action } => {
-            handle_endpoint_command(&settings_path, jira_service, action).await?
-        }
+        Commands::Endpoint { action } => handle_endpoint_command(&settings_path, action).await?,
         Commands::Report {
             project,
             interactive,
@@ -157,6 +195,69 @@ async fn run() -> DomainResult<()> {
             )
             .await?;
         }
+        Commands::Duplicates {
+            project,
+            threshold,
+            since,
+            neighbors,
+            format,
+            output,
+        } => {
+            handle_duplicates(
+                &settings_path,
+                &db_factory,
+                &project,
+                threshold,
+                since.as_deref(),
+                neighbors,
+                &format,
+                output,
+            )?;
+        }
+        Commands::Similar {
+            issue_key,
+            same_project,
+            exclude_resolved,
+            created_from,
+            created_to,
+            limit,
+            format,
+        } => {
+            handle_similar(
+                &db_factory,
+                &issue_key,
+                same_project,
+                exclude_resolved,
+                created_from.as_deref(),
+                created_to.as_deref(),
+                limit,
+                &format,
+            )?;
+        }
+        Commands::Topics {
+            project,
+            topics,
+            since,
+            until,
+            issue_type,
+            llm,
+            format,
+            output,
+        } => {
+            handle_topics(
+                &settings_path,
+                &db_factory,
+                &project,
+                topics,
+                since.as_deref(),
+                until.as_deref(),
+                issue_type,
+                llm,
+                &format,
+                output,
+            )
+            .await?;
+        }
         Commands::Snapshots { action } => match action {
             SnapshotsAction::Generate { project } => {
                 handle_snapshots_generate(&settings_path, db_factory, &project)?;
@@ -166,16 +267,59 @@ async fn run() -> DomainResult<()> {
             }
         },
         Commands::Fields { action } => {
-            handle_fields_command(&settings_path, db_factory, jira_service, action).await?;
+            handle_fields_command(&settings_path, db_factory, action).await?;
         }
         Commands::Debug { action } => {
-            handle_debug_command(&settings, jira_service, action).await?;
+            handle_debug_command(&settings, action).await?;
+        }
+        Commands::Issue { action } => {
+            handle_issue_command(&settings_path, db_factory, action).await?;
+        }
+        Commands::Workflow { action } => {
+            handle_workflow_command(&settings_path, db_factory, action)?;
+        }
+        Commands::Raw { action } => {
+            handle_raw_command(db_factory, action)?;
+        }
+        Commands::Bulk { action } => {
+            handle_bulk_command(&settings_path, db_factory, action).await?;
+        }
+        Commands::Queue { action } => {
+            handle_queue_command(&settings_path, db_factory, action)?;
+        }
+        Commands::Push { project } => {
+            handle_push(&settings_path, db_factory, project).await?;
+        }
+        Commands::Import { project, file } => {
+            handle_import(&settings_path, &db_factory, &project, &file)?;
+        }
+        Commands::Export {
+            project,
+            all_projects: _,
+            table,
+            sql,
+            format,
+            out,
+        } => {
+            // Without --project, clap guarantees --all-projects was given
+            handle_export(&db_factory, project, table, sql, format, out)?;
+        }
+        Commands::Db { action } => {
+            handle_db_command(db_factory, action)?;
         }
     }
 
     Ok(())
 }
 
+/// Client for the active endpoint, for commands that call JIRA
+fn active_jira_client(settings: &Settings) -> DomainResult<Arc<JiraApiClient>> {
+    let jira_config = settings.get_jira_config().ok_or_else(|| {
+        DomainError::Validation("No JIRA endpoint configured. Run 'jira-db init' first.".into())
+    })?;
+    Ok(Arc::new(JiraApiClient::new(&jira_config)?))
+}
+
 async fn handle_init_command(
     settings_path: &std::path::Path,
     interactive: bool,
@@ -237,10 +381,14 @@ async fn handle_init_command(
             database: DatabaseConfig {
                 path: None,
                 database_dir: std::path::PathBuf::from(db_dir),
+                read_pool_size: jira_db_core::DEFAULT_READ_POOL_SIZE,
+                read_replicas: false,
             },
             embeddings: None,
             log: None,
             sync: None,
+            search: None,
+            saved_searches: Vec::new(),
             debug_mode: false,
         };
 
@@ -315,6 +463,7 @@ async fn handle_project_init(
             endpoint: settings.active_endpoint.clone(), // Assign to current active endpoint
             sync_checkpoint: None,
             snapshot_checkpoint: None,
+            bulk_edit_checkpoint: None,
         };
         settings.upsert_project(project_config);
     }
@@ -421,10 +570,10 @@ fn handle_project_disable(settings_path: &std::path::Path, project_key: &str) ->
 async fn handle_sync(
     settings_path: &std::path::Path,
     db_factory: Arc<DatabaseFactory>,
-    jira_service: Arc<JiraApiClient>,
     project_key: Option<String>,
 ) -> DomainResult<()> {
-    let settings = Settings::load(settings_path)?;
+    let mut settings = Settings::load(settings_path)?;
+    settings.migrate_legacy_config();
     settings.validate()?;
 
     if settings.projects.is_empty() {
@@ -443,6 +592,12 @@ async fn handle_sync(
             .find_project(&key)
             .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", key)))?;
 
+        // Each project syncs against its own endpoint (falls back to the active one)
+        let jira_config = settings.get_jira_config_for_project(&key).ok_or_else(|| {
+            DomainError::Validation(format!("No JIRA endpoint configured for project {}", key))
+        })?;
+        let jira_service = Arc::new(JiraApiClient::new(&jira_config)?);
+
         let project_id = project.id.clone();
         let last_synced = project.last_synced;
         let snapshot_checkpoint = project.snapshot_checkpoint.clone();
@@ -592,6 +747,8 @@ async fn handle_sync(
                 p.snapshot_checkpoint = None;
             }
             settings.save(&settings_path)?;
+            refresh_search_index(&db_factory, &key, &settings);
+            publish_read_replica(&db_factory, &key);
         } else {
             println!(
                 "Sync failed for project {}: {}",
@@ -614,226 +771,402 @@ async fn handle_sync(
             settings.save(&settings_path)?;
         }
     } else {
-        // Collect project info including last_synced for incremental sync
-        let enabled_projects: Vec<_> = settings
-            .sync_enabled_projects()
-            .iter()
-            .map(|p| {
-                (
-                    p.key.clone(),
-                    p.id.clone(),
-                    p.sync_checkpoint.clone(),
-                    p.snapshot_checkpoint.clone(),
-                    p.last_synced,
-                )
-            })
-            .collect();
+        let enabled_projects = settings.sync_enabled_projects();
 
         if enabled_projects.is_empty() {
             warn!("No projects enabled for sync");
             return Ok(());
         }
 
-        info!("Syncing {} projects", enabled_projects.len());
+        // Group projects by endpoint so each JIRA instance gets its own client
+        let endpoint_groups: Vec<(String, Option<_>, Vec<_>)> = settings
+            .group_projects_by_endpoint(&enabled_projects)
+            .into_iter()
+            .map(|(endpoint_name, projects)| {
+                let jira_config = endpoint_name
+                    .as_deref()
+                    .and_then(|name| settings.get_endpoint(name))
+                    .map(|e| e.to_jira_config());
+                // Collect project info including last_synced for incremental sync
+                let projects: Vec<_> = projects
+                    .iter()
+                    .map(|p| {
+                        (
+                            p.key.clone(),
+                            p.id.clone(),
+                            p.sync_checkpoint.clone(),
+                            p.snapshot_checkpoint.clone(),
+                            p.last_synced,
+                        )
+                    })
+                    .collect();
+                (
+                    endpoint_name.unwrap_or_else(|| "default".to_string()),
+                    jira_config,
+                    projects,
+                )
+            })
+            .collect();
+
+        info!(
+            "Syncing {} projects from {} endpoint(s)",
+            enabled_projects.len(),
+            endpoint_groups.len()
+        );
 
-        for (key, id, existing_checkpoint, snapshot_checkpoint, last_synced) in enabled_projects {
-            // Check if we have a snapshot checkpoint - if so, skip issue sync
-            if snapshot_checkpoint.is_some() {
-                println!("Resuming snapshot generation for {} from checkpoint", key);
-            }
+        let mut endpoint_summaries: Vec<EndpointSyncSummary> = Vec::new();
 
-            // Determine the checkpoint to use (same logic as single project)
-            let checkpoint = if snapshot_checkpoint.is_some() {
-                // If we have a snapshot checkpoint, we don't need issue sync checkpoint
-                None
-            } else if let Some(cp) = existing_checkpoint.clone() {
-                // Resuming from interrupted sync
-                Some(cp)
-            } else if sync_settings.incremental_sync_enabled {
-                if let Some(last_sync_time) = last_synced {
-                    // Create incremental sync checkpoint with safety margin
-                    let margin_minutes = sync_settings.incremental_sync_margin_minutes as i64;
-                    let incremental_start = last_sync_time - Duration::minutes(margin_minutes);
-                    println!(
-                        "Incremental sync for {}: fetching issues updated since {} (margin: {} min)",
-                        key,
-                        incremental_start.format("%Y-%m-%d %H:%M:%S"),
-                        margin_minutes
+        for (endpoint_name, jira_config, projects) in endpoint_groups {
+            let mut summary = EndpointSyncSummary {
+                endpoint_name: endpoint_name.clone(),
+                projects: projects.len(),
+                succeeded: 0,
+                failed: 0,
+                issues_synced: 0,
+                error: None,
+            };
+
+            let jira_service = match jira_config.map(|c| JiraApiClient::new(&c)) {
+                Some(Ok(client)) => Arc::new(client),
+                Some(Err(e)) => {
+                    warn!(
+                        "Failed to create JIRA client for endpoint '{}': {}",
+                        endpoint_name, e
                     );
-                    Some(SyncCheckpoint {
-                        last_issue_updated_at: incremental_start,
-                        last_issue_key: String::new(),
-                        items_processed: 0,
-                        total_items: 0,
-                    })
-                } else {
-                    println!("Full sync for {} (first time)", key);
-                    None
+                    summary.failed = projects.len();
+                    summary.error = Some(e.to_string());
+                    endpoint_summaries.push(summary);
+                    continue;
+                }
+                None => {
+                    warn!("JIRA endpoint '{}' not found", endpoint_name);
+                    summary.failed = projects.len();
+                    summary.error = Some(format!("Endpoint '{}' not found", endpoint_name));
+                    endpoint_summaries.push(summary);
+                    continue;
                 }
-            } else {
-                println!("Full sync for {} (incremental sync disabled)", key);
-                None
             };
 
-            // Get connection for this specific project
-            let conn = db_factory.get_connection(&key)?;
-            let raw_conn = db_factory.get_raw_connection(&key)?;
-
-            let issue_repository = Arc::new(DuckDbIssueRepository::new(conn.clone()));
-            let change_history_repository =
-                Arc::new(DuckDbChangeHistoryRepository::new(conn.clone()));
-            let metadata_repository = Arc::new(DuckDbMetadataRepository::new(conn.clone()));
-            let sync_history_repository = Arc::new(DuckDbSyncHistoryRepository::new(conn.clone()));
-            let snapshot_repository = Arc::new(DuckDbIssueSnapshotRepository::new(conn));
-            let raw_repository = Arc::new(RawDataRepository::new(raw_conn));
-
-            let use_case = SyncProjectUseCase::new(
-                issue_repository,
-                change_history_repository,
-                metadata_repository,
-                sync_history_repository,
-                snapshot_repository,
-                jira_service.clone(),
-            )
-            .with_raw_repository(raw_repository);
+            println!(
+                "Syncing {} project(s) from endpoint '{}'",
+                projects.len(),
+                endpoint_name
+            );
 
-            // Show resuming message if we have a checkpoint from interrupted sync
-            if existing_checkpoint.is_some() {
-                if let Some(ref cp) = checkpoint {
-                    println!(
-                        "Resuming sync for {} from checkpoint ({}/{} issues processed)",
-                        key, cp.items_processed, cp.total_items
-                    );
+            for (key, id, existing_checkpoint, snapshot_checkpoint, last_synced) in projects {
+                // Check if we have a snapshot checkpoint - if so, skip issue sync
+                if snapshot_checkpoint.is_some() {
+                    println!("Resuming snapshot generation for {} from checkpoint", key);
                 }
-            }
 
-            let settings_path_clone = settings_path.clone();
-            let settings_path_clone2 = settings_path.clone();
-            let key_clone = key.clone();
-            let key_clone2 = key.clone();
-
-            match use_case
-                .execute_resumable_with_snapshot_checkpoint(
-                    &key,
-                    &id,
-                    checkpoint,
-                    snapshot_checkpoint,
-                    move |new_checkpoint| {
-                        // Save checkpoint to settings
-                        if let Ok(mut s) = Settings::load(&settings_path_clone) {
-                            if let Some(p) = s.find_project_mut(&key_clone) {
-                                p.sync_checkpoint = Some(new_checkpoint.clone());
-                            }
-                            let _ = s.save(&settings_path_clone);
-                        }
-                    },
-                    move |snapshot_cp| {
-                        // Save snapshot checkpoint continuously for resume support
-                        if let Ok(mut s) = Settings::load(&settings_path_clone2) {
-                            if let Some(p) = s.find_project_mut(&key_clone2) {
-                                p.snapshot_checkpoint = Some(snapshot_cp.clone());
-                            }
-                            let _ = s.save(&settings_path_clone2);
-                        }
-                    },
-                )
-                .await
-            {
-                Ok(result) => {
-                    if result.sync_result.success {
+                // Determine the checkpoint to use (same logic as single project)
+                let checkpoint = if snapshot_checkpoint.is_some() {
+                    // If we have a snapshot checkpoint, we don't need issue sync checkpoint
+                    None
+                } else if let Some(cp) = existing_checkpoint.clone() {
+                    // Resuming from interrupted sync
+                    Some(cp)
+                } else if sync_settings.incremental_sync_enabled {
+                    if let Some(last_sync_time) = last_synced {
+                        // Create incremental sync checkpoint with safety margin
+                        let margin_minutes = sync_settings.incremental_sync_margin_minutes as i64;
+                        let incremental_start = last_sync_time - Duration::minutes(margin_minutes);
                         println!(
-                            "Synced {} issues for project {}",
-                            result.sync_result.issues_synced, key
+                            "Incremental sync for {}: fetching issues updated since {} (margin: {} min)",
+                            key,
+                            incremental_start.format("%Y-%m-%d %H:%M:%S"),
+                            margin_minutes
                         );
-                        let mut settings = Settings::load(&settings_path)?;
-                        if let Some(p) = settings.find_project_mut(&key) {
-                            // Use the last issue's updated_at for reliable incremental sync
-                            if let Some(last_updated) = result.sync_result.last_issue_updated_at {
-                                p.last_synced = Some(last_updated);
-                            } else if p.last_synced.is_none() {
-                                // First sync with no issues: set to current time
-                                p.last_synced = Some(Utc::now());
-                            }
-                            // Clear both checkpoints on success
-                            p.sync_checkpoint = None;
-                            p.snapshot_checkpoint = None;
-                        }
-                        settings.save(&settings_path)?;
+                        Some(SyncCheckpoint {
+                            last_issue_updated_at: incremental_start,
+                            last_issue_key: String::new(),
+                            items_processed: 0,
+                            total_items: 0,
+                        })
                     } else {
-                        warn!(
-                            "Sync failed for project {}: {}",
-                            key,
-                            result.sync_result.error_message.unwrap_or_default()
+                        println!("Full sync for {} (first time)", key);
+                        None
+                    }
+                } else {
+                    println!("Full sync for {} (incremental sync disabled)", key);
+                    None
+                };
+
+                // Get connection for this specific project
+                let conn = db_factory.get_connection(&key)?;
+                let raw_conn = db_factory.get_raw_connection(&key)?;
+
+                let issue_repository = Arc::new(DuckDbIssueRepository::new(conn.clone()));
+                let change_history_repository =
+                    Arc::new(DuckDbChangeHistoryRepository::new(conn.clone()));
+                let metadata_repository = Arc::new(DuckDbMetadataRepository::new(conn.clone()));
+                let sync_history_repository =
+                    Arc::new(DuckDbSyncHistoryRepository::new(conn.clone()));
+                let snapshot_repository = Arc::new(DuckDbIssueSnapshotRepository::new(conn));
+                let raw_repository = Arc::new(RawDataRepository::new(raw_conn));
+
+                let use_case = SyncProjectUseCase::new(
+                    issue_repository,
+                    change_history_repository,
+                    metadata_repository,
+                    sync_history_repository,
+                    snapshot_repository,
+                    jira_service.clone(),
+                )
+                .with_raw_repository(raw_repository);
+
+                // Show resuming message if we have a checkpoint from interrupted sync
+                if existing_checkpoint.is_some() {
+                    if let Some(ref cp) = checkpoint {
+                        println!(
+                            "Resuming sync for {} from checkpoint ({}/{} issues processed)",
+                            key, cp.items_processed, cp.total_items
                         );
-                        let mut settings = Settings::load(&settings_path)?;
-                        if let Some(p) = settings.find_project_mut(&key) {
-                            p.sync_checkpoint = result.checkpoint;
-                            // Save snapshot checkpoint if available
-                            if result.snapshot_checkpoint.is_some() {
-                                p.snapshot_checkpoint = result.snapshot_checkpoint;
+                    }
+                }
+
+                let settings_path_clone = settings_path.clone();
+                let settings_path_clone2 = settings_path.clone();
+                let key_clone = key.clone();
+                let key_clone2 = key.clone();
+
+                match use_case
+                    .execute_resumable_with_snapshot_checkpoint(
+                        &key,
+                        &id,
+                        checkpoint,
+                        snapshot_checkpoint,
+                        move |new_checkpoint| {
+                            // Save checkpoint to settings
+                            if let Ok(mut s) = Settings::load(&settings_path_clone) {
+                                if let Some(p) = s.find_project_mut(&key_clone) {
+                                    p.sync_checkpoint = Some(new_checkpoint.clone());
+                                }
+                                let _ = s.save(&settings_path_clone);
+                            }
+                        },
+                        move |snapshot_cp| {
+                            // Save snapshot checkpoint continuously for resume support
+                            if let Ok(mut s) = Settings::load(&settings_path_clone2) {
+                                if let Some(p) = s.find_project_mut(&key_clone2) {
+                                    p.snapshot_checkpoint = Some(snapshot_cp.clone());
+                                }
+                                let _ = s.save(&settings_path_clone2);
+                            }
+                        },
+                    )
+                    .await
+                {
+                    Ok(result) => {
+                        if result.sync_result.success {
+                            println!(
+                                "Synced {} issues for project {}",
+                                result.sync_result.issues_synced, key
+                            );
+                            summary.succeeded += 1;
+                            summary.issues_synced += result.sync_result.issues_synced;
+                            let mut settings = Settings::load(&settings_path)?;
+                            if let Some(p) = settings.find_project_mut(&key) {
+                                // Use the last issue's updated_at for reliable incremental sync
+                                if let Some(last_updated) = result.sync_result.last_issue_updated_at
+                                {
+                                    p.last_synced = Some(last_updated);
+                                } else if p.last_synced.is_none() {
+                                    // First sync with no issues: set to current time
+                                    p.last_synced = Some(Utc::now());
+                                }
+                                // Clear both checkpoints on success
+                                p.sync_checkpoint = None;
+                                p.snapshot_checkpoint = None;
+                            }
+                            settings.save(&settings_path)?;
+                            refresh_search_index(&db_factory, &key, &settings);
+                            publish_read_replica(&db_factory, &key);
+                        } else {
+                            warn!(
+                                "Sync failed for project {}: {}",
+                                key,
+                                result.sync_result.error_message.unwrap_or_default()
+                            );
+                            summary.failed += 1;
+                            let mut settings = Settings::load(&settings_path)?;
+                            if let Some(p) = settings.find_project_mut(&key) {
+                                p.sync_checkpoint = result.checkpoint;
+                                // Save snapshot checkpoint if available
+                                if result.snapshot_checkpoint.is_some() {
+                                    p.snapshot_checkpoint = result.snapshot_checkpoint;
+                                }
                             }
+                            settings.save(&settings_path)?;
                         }
-                        settings.save(&settings_path)?;
                     }
-                }
-                Err(e) => {
-                    warn!("Failed to sync project {}: {}", key, e);
+                    Err(e) => {
+                        warn!("Failed to sync project {}: {}", key, e);
+                        summary.failed += 1;
+                    }
                 }
             }
+
+            endpoint_summaries.push(summary);
         }
+
+        print_endpoint_sync_summary(&endpoint_summaries);
     }
 
     Ok(())
 }
 
-fn handle_search(
-    settings_path: &std::path::Path,
-    db_factory: Arc<DatabaseFactory>,
-    query: &str,
-    project: Option<String>,
-    status: Option<String>,
-    assignee: Option<String>,
-    limit: usize,
-    offset: usize,
-) -> DomainResult<()> {
-    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+/// Rebuild the full-text search index of a project after its issues changed
+fn refresh_search_index(db_factory: &DatabaseFactory, project_key: &str, settings: &Settings) {
+    let search_fields = settings.get_sync_settings().search_fields;
+    let result = db_factory
+        .get_connection(project_key)
+        .and_then(|conn| FullTextSearchRepository::new(conn).rebuild(&search_fields));
+    match result {
+        Ok(count) => info!("Indexed {} issues for search in {}", count, project_key),
+        Err(e) => warn!("Failed to refresh search index for {}: {}", project_key, e),
+    }
+}
 
-    let settings = Settings::load(settings_path)?;
+/// Publish the project's read replica for servers when enabled in the settings
+///
+/// A failure only means servers keep reading the previous replica, so it is not fatal.
+fn publish_read_replica(db_factory: &DatabaseFactory, project_key: &str) {
+    match db_factory.publish_read_replica(project_key) {
+        Ok(true) => info!("Published read replica for {}", project_key),
+        Ok(false) => {}
+        Err(e) => warn!("Failed to publish read replica for {}: {}", project_key, e),
+    }
+}
 
-    let params = SearchParams {
-        query: Some(query.to_string()),
-        project_key: project.clone(),
-        status,
-        assignee,
-        issue_type: None,
-        priority: None,
-        team: None,
-        limit: Some(limit),
-        offset: Some(offset),
-    };
+/// Per-endpoint outcome of a multi-project sync
+struct EndpointSyncSummary {
+    endpoint_name: String,
+    projects: usize,
+    succeeded: usize,
+    failed: usize,
+    issues_synced: usize,
+    error: Option<String>,
+}
+
+fn print_endpoint_sync_summary(summaries: &[EndpointSyncSummary]) {
+    use comfy_table::{Cell, Color, Table, presets::UTF8_FULL};
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        "Endpoint",
+        "Projects",
+        "Succeeded",
+        "Failed",
+        "Issues",
+        "Error",
+    ]);
+
+    for summary in summaries {
+        table.add_row(vec![
+            Cell::new(&summary.endpoint_name),
+            Cell::new(summary.projects),
+            Cell::new(summary.succeeded).fg(Color::Green),
+            if summary.failed > 0 {
+                Cell::new(summary.failed).fg(Color::Red)
+            } else {
+                Cell::new(summary.failed)
+            },
+            Cell::new(summary.issues_synced),
+            Cell::new(summary.error.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("\nSync summary by endpoint:");
+    println!("{table}");
+}
 
-    // Determine which projects to search
-    let projects_to_search: Vec<String> = if let Some(ref key) = project {
-        // Verify project exists
-        if settings.find_project(key).is_none() {
-            return Err(DomainError::NotFound(format!("Project not found: {}", key)));
+/// Projects a search runs over: the given project, or every sync-enabled one
+fn projects_to_search(settings: &Settings, project: Option<&str>) -> DomainResult<Vec<String>> {
+    match project {
+        Some(key) => {
+            // Verify project exists
+            if settings.find_project(key).is_none() {
+                return Err(DomainError::NotFound(format!("Project not found: {}", key)));
+            }
+            Ok(vec![key.to_string()])
         }
-        vec![key.clone()]
-    } else {
         // Search all enabled projects
-        settings
+        None => Ok(settings
             .sync_enabled_projects()
             .iter()
             .map(|p| p.key.clone())
-            .collect()
-    };
+            .collect()),
+    }
+}
+
+/// Structured search filters from the command line
+fn search_filters(filters: SearchFilterArgs) -> DomainResult<SearchParams> {
+    Ok(SearchParams {
+        issue_type: filters.issue_type,
+        priority: filters.priority,
+        labels: filters.labels,
+        labels_match: filters.labels_match.parse()?,
+        components: filters.components,
+        fix_versions: filters.fix_versions,
+        sprint: filters.sprint,
+        parent_key: filters.parent,
+        resolution: filters.resolution,
+        status_category: filters.status_category,
+        created: DateRange::parse(
+            filters.created_from.as_deref(),
+            filters.created_to.as_deref(),
+        )?,
+        updated: DateRange::parse(
+            filters.updated_from.as_deref(),
+            filters.updated_to.as_deref(),
+        )?,
+        due: DateRange::parse(filters.due_from.as_deref(), filters.due_to.as_deref())?,
+        resolved: DateRange::parse(
+            filters.resolved_from.as_deref(),
+            filters.resolved_to.as_deref(),
+        )?,
+        ..Default::default()
+    })
+}
+
+/// Order hits merged from several projects by the requested sort keys
+fn compare_by_sort(sort: &[SearchSort], a: &Issue, b: &Issue) -> std::cmp::Ordering {
+    sort.iter()
+        .map(|key| key.compare(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.key.cmp(&b.key))
+}
+
+fn handle_search(
+    settings_path: &std::path::Path,
+    db_factory: Arc<DatabaseFactory>,
+    params: SearchParams,
+    facets: bool,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+
+    let settings = Settings::load(settings_path)?;
 
+    let projects_to_search = projects_to_search(&settings, params.project_key.as_deref())?;
     if projects_to_search.is_empty() {
         println!("No projects to search. Enable sync for a project first.");
         return Ok(());
     }
 
-    let mut all_issues = Vec::new();
+    // Take the first `offset + limit` hits of every project, then page the merged list
+    let offset = params.offset.unwrap_or(0);
+    let limit = params.limit.unwrap_or(20);
+    let project_params = SearchParams {
+        limit: Some(offset + limit),
+        offset: None,
+        ..params.clone()
+    };
+
+    let mut all_hits = Vec::new();
+    let mut all_facets = Vec::new();
+    let mut total = 0;
 
     for project_key in &projects_to_search {
         let conn = match db_factory.get_connection(project_key) {
@@ -844,92 +1177,592 @@ fn handle_search(
         let issue_repository = Arc::new(DuckDbIssueRepository::new(conn));
         let use_case = SearchIssuesUseCase::new(issue_repository);
 
-        match use_case.execute(params.clone()) {
-            Ok(issues) => all_issues.extend(issues),
+        match use_case.execute_ranked(project_params.clone()) {
+            Ok(hits) => all_hits.extend(hits),
             Err(_) => continue, // Skip on errors
         }
+        total += use_case.count(&params).unwrap_or(0);
+        if facets {
+            merge_facets(
+                &mut all_facets,
+                use_case
+                    .facets(&params, &FacetField::ALL)
+                    .unwrap_or_default(),
+            );
+        }
     }
 
-    if all_issues.is_empty() {
+    if all_hits.is_empty() {
         println!("No issues found matching your search criteria.");
         return Ok(());
     }
 
+    if params.sorts_by_relevance() {
+        // Best BM25 matches first when the projects have a search index
+        all_hits.sort_by(|a, b| {
+            b.score
+                .unwrap_or(f64::MIN)
+                .total_cmp(&a.score.unwrap_or(f64::MIN))
+        });
+    } else {
+        all_hits.sort_by(|a, b| compare_by_sort(&params.sort, &a.issue, &b.issue));
+    }
+    let all_hits: Vec<_> = all_hits.into_iter().skip(offset).take(limit).collect();
+
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
-    table.set_header(vec!["Key", "Summary", "Status", "Assignee", "Priority"]);
+    table.set_header(vec![
+        "Key", "Summary", "Status", "Assignee", "Score", "Match",
+    ]);
 
-    for issue in &all_issues {
+    for hit in &all_hits {
+        let issue = &hit.issue;
         table.add_row(vec![
             Cell::new(&issue.key),
             Cell::new(truncate(&issue.summary, 50)),
             Cell::new(issue.status.as_deref().unwrap_or("-")),
             Cell::new(issue.assignee.as_deref().unwrap_or("-")),
-            Cell::new(issue.priority.as_deref().unwrap_or("-")),
+            Cell::new(
+                hit.score
+                    .map(|score| format!("{:.2}", score))
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            Cell::new(hit.snippet.as_deref().unwrap_or("-")),
         ]);
     }
 
     println!("{table}");
-    println!("\nShowing {} issues", all_issues.len());
+    println!("\nShowing {} of {} issues", all_hits.len(), total);
+    print_facets(all_facets);
 
     Ok(())
 }
 
-fn handle_metadata(
+/// Print the value counts of every match, most frequent first
+fn print_facets(facets: Vec<SearchFacet>) {
+    if facets.is_empty() {
+        return;
+    }
+
+    println!("\nFacets:");
+    for mut facet in facets {
+        facet.truncate(SearchFacet::DEFAULT_SIZE);
+        let name = match facet.field {
+            FacetField::Status => "Status",
+            FacetField::IssueType => "Type",
+            FacetField::Priority => "Priority",
+            FacetField::Assignee => "Assignee",
+            FacetField::Labels => "Labels",
+            FacetField::Components => "Components",
+            FacetField::FixVersions => "Fix versions",
+        };
+        let none = match facet.field {
+            FacetField::Assignee => "Unassigned",
+            _ => "(none)",
+        };
+        let values: Vec<String> = facet
+            .values
+            .iter()
+            .map(|v| format!("{} {}", v.value.as_deref().unwrap_or(none), v.count))
+            .collect();
+        let values = if values.is_empty() {
+            "-".to_string()
+        } else {
+            values.join(", ")
+        };
+        println!("  {:<13}{}", name, values);
+    }
+}
+
+async fn handle_hybrid_search(
     settings_path: &std::path::Path,
     db_factory: Arc<DatabaseFactory>,
-    project_key: &str,
-    metadata_type: Option<String>,
+    params: SearchParams,
+    lexical_weight: Option<f64>,
+    semantic_weight: Option<f64>,
+    facets: bool,
 ) -> DomainResult<()> {
-    let settings = Settings::load(settings_path)?;
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::HybridSearchUseCase;
+    use jira_db_core::infrastructure::database::EmbeddingsRepository;
+    use jira_db_core::infrastructure::external::embeddings::{
+        EmbeddingProvider, ProviderConfig, create_provider,
+    };
 
-    let project = settings
-        .find_project(project_key)
-        .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", project_key)))?;
+    let settings = Settings::load(settings_path)?;
 
-    let conn = db_factory.get_connection(project_key)?;
-    let metadata_repository = Arc::new(DuckDbMetadataRepository::new(conn));
+    let projects_to_search = projects_to_search(&settings, params.project_key.as_deref())?;
+    if projects_to_search.is_empty() {
+        println!("No projects to search. Enable sync for a project first.");
+        return Ok(());
+    }
 
-    let use_case = GetProjectMetadataUseCase::new(metadata_repository);
+    // Embed the query once with the configured provider; without one, or when
+    // sorting by a field, rank by keywords only
+    let embeddings = settings.embeddings.clone().unwrap_or_default();
+    let provider = ProviderConfig::from_settings(&embeddings).and_then(create_provider);
+    let query = params.query.clone().unwrap_or_default();
+    let query_embedding = match provider {
+        Ok(_) if !params.sorts_by_relevance() => None,
+        Ok(provider) => match provider.embed(&query).await {
+            Ok(embedding) => Some(embedding),
+            Err(e) => {
+                warn!("Failed to embed query, using keyword ranking only: {}", e);
+                None
+            }
+        },
+        Err(e) => {
+            warn!("No embedding provider, using keyword ranking only: {}", e);
+            None
+        }
+    };
 
-    let metadata = if let Some(t) = &metadata_type {
-        use_case.execute_by_type(&project.id, t)?
-    } else {
-        use_case.execute(&project.id)?
+    let offset = params.offset.unwrap_or(0);
+    let limit = params.limit.unwrap_or(20);
+    let project_params = SearchParams {
+        limit: Some(offset + limit),
+        offset: None,
+        ..params.clone()
     };
 
-    println!("Metadata for project: {}\n", project_key);
+    let facet_fields: &[FacetField] = if facets { &FacetField::ALL } else { &[] };
+    let mut all_hits = Vec::new();
+    let mut all_facets = Vec::new();
+    let mut total = 0;
 
-    if !metadata.statuses.is_empty() {
-        println!("Statuses ({}):", metadata.statuses.len());
-        for s in &metadata.statuses {
-            println!("  - {} ({})", s.name, s.category.as_deref().unwrap_or("-"));
+    for project_key in &projects_to_search {
+        let conn = match db_factory.get_connection(project_key) {
+            Ok(c) => c,
+            Err(_) => continue, // Skip projects without database
+        };
+
+        let use_case = HybridSearchUseCase::new(
+            Arc::new(DuckDbIssueRepository::new(conn.clone())),
+            Arc::new(EmbeddingsRepository::new(conn)),
+        )
+        .with_settings(settings.get_search_settings())
+        .with_weights(lexical_weight, semantic_weight)
+        .with_facets(facet_fields);
+
+        match use_case.execute_with_embedding(project_params.clone(), query_embedding.as_deref()) {
+            Ok(result) => {
+                all_hits.extend(result.hits);
+                merge_facets(&mut all_facets, result.facets);
+                total += result.total;
+            }
+            Err(_) => continue, // Skip on errors
         }
-        println!();
     }
 
-    if !metadata.priorities.is_empty() {
-        println!("Priorities ({}):", metadata.priorities.len());
-        for p in &metadata.priorities {
-            println!("  - {}", p.name);
-        }
-        println!();
+    if all_hits.is_empty() {
+        println!("No issues found matching your search criteria.");
+        return Ok(());
     }
 
-    if !metadata.issue_types.is_empty() {
-        println!("Issue Types ({}):", metadata.issue_types.len());
-        for t in &metadata.issue_types {
-            println!(
-                "  - {}{}",
-                t.name,
-                if t.subtask { " (subtask)" } else { "" }
-            );
-        }
-        println!();
+    if params.sorts_by_relevance() {
+        all_hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+    } else {
+        all_hits.sort_by(|a, b| compare_by_sort(&params.sort, &a.issue, &b.issue));
     }
+    let all_hits: Vec<_> = all_hits.into_iter().skip(offset).take(limit).collect();
 
-    if !metadata.labels.is_empty() {
-        println!("Labels ({}):", metadata.labels.len());
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        "Key", "Summary", "Status", "Assignee", "Score", "Keyword", "Semantic", "Match",
+    ]);
+
+    for hit in &all_hits {
+        let issue = &hit.issue;
+        let keyword = match (hit.lexical_rank, hit.lexical_score) {
+            (Some(rank), Some(score)) => format!("#{} ({:.2})", rank, score),
+            (Some(rank), None) => format!("#{}", rank),
+            _ => "-".to_string(),
+        };
+        let semantic = match (hit.semantic_rank, hit.semantic_distance) {
+            (Some(rank), Some(distance)) => format!("#{} ({:.3})", rank, distance),
+            _ => "-".to_string(),
+        };
+        table.add_row(vec![
+            Cell::new(&issue.key),
+            Cell::new(truncate(&issue.summary, 50)),
+            Cell::new(issue.status.as_deref().unwrap_or("-")),
+            Cell::new(issue.assignee.as_deref().unwrap_or("-")),
+            Cell::new(format!("{:.4}", hit.score)),
+            Cell::new(keyword),
+            Cell::new(semantic),
+            Cell::new(hit.snippet.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{table}");
+    println!("\nShowing {} of {} issues", all_hits.len(), total);
+    print_facets(all_facets);
+
+    Ok(())
+}
+
+fn handle_jql(
+    settings_path: &std::path::Path,
+    db_factory: &DatabaseFactory,
+    query: &str,
+    project: Option<String>,
+    limit: usize,
+    show_sql: bool,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::ExecuteJqlUseCase;
+
+    let settings = Settings::load(settings_path)?;
+
+    let (use_case, jira_config) = match &project {
+        Some(project_key) => (
+            ExecuteJqlUseCase::new(db_factory.get_connection(project_key)?),
+            settings.get_jira_config_for_project(project_key),
+        ),
+        None => (
+            ExecuteJqlUseCase::all_projects(db_factory)?,
+            settings.get_jira_config(),
+        ),
+    };
+    // currentUser() refers to the account the endpoint is configured with
+    let use_case = match jira_config {
+        Some(config) => use_case.with_current_user(config.username),
+        None => use_case,
+    };
+
+    let result = use_case.execute(query, Some(limit))?;
+
+    if show_sql {
+        println!("{}\n", result.sql);
+        for (i, param) in result.params.iter().enumerate() {
+            println!("  ${} = {}", i + 1, param);
+        }
+        println!();
+    }
+
+    if result.result.rows.is_empty() {
+        println!("No issues match the query.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(&result.result.columns);
+    for row in &result.result.rows {
+        table.add_row(row.iter().map(|value| {
+            Cell::new(match value.as_str() {
+                Some(text) => truncate(text, 50),
+                None if value.is_null() => "-".to_string(),
+                None => value.to_string(),
+            })
+        }));
+    }
+
+    println!("{table}");
+    println!("\nShowing {} issues", result.result.row_count);
+
+    Ok(())
+}
+
+fn handle_saved_command(
+    settings_path: &std::path::Path,
+    db_factory: &DatabaseFactory,
+    action: SavedAction,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::RunSavedSearchUseCase;
+    use jira_db_core::domain::entities::{SavedSearch, SavedSearchQuery};
+
+    let mut settings = Settings::load(settings_path)?;
+    let find = |settings: &Settings, name: &str| {
+        settings
+            .find_saved_search(name)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("Saved search not found: {}", name)))
+    };
+
+    match action {
+        SavedAction::List => {
+            if settings.saved_searches.is_empty() {
+                println!("No saved searches. Add one with 'jira-db saved add'.");
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec![
+                "Name",
+                "Type",
+                "Project",
+                "Parameters",
+                "Owner",
+                "Description",
+            ]);
+            for search in &settings.saved_searches {
+                let params: Vec<&str> = search.parameters.iter().map(|p| p.name.as_str()).collect();
+                table.add_row(vec![
+                    Cell::new(&search.name),
+                    Cell::new(search.query.kind()),
+                    Cell::new(search.project.as_deref().unwrap_or("(all)")),
+                    Cell::new(params.join(", ")),
+                    Cell::new(search.owner.as_deref().unwrap_or("-")),
+                    Cell::new(truncate(search.description.as_deref().unwrap_or("-"), 50)),
+                ]);
+            }
+            println!("{table}");
+        }
+        SavedAction::Show { name } => {
+            let search = find(&settings, &name)?;
+            println!("Name:        {}", search.name);
+            println!("Type:        {}", search.query.kind());
+            println!(
+                "Project:     {}",
+                search.project.as_deref().unwrap_or("(all)")
+            );
+            if let Some(description) = &search.description {
+                println!("Description: {}", description);
+            }
+            if let Some(owner) = &search.owner {
+                println!("Owner:       {}", owner);
+            }
+            println!(
+                "Updated:     {}",
+                search.updated_at.format("%Y-%m-%d %H:%M:%S")
+            );
+            match &search.query {
+                SavedSearchQuery::Sql { sql } => println!("\n{}", sql),
+                SavedSearchQuery::Jql { jql } => println!("\n{}", jql),
+                SavedSearchQuery::Filter { filter } => println!(
+                    "\n{}",
+                    serde_json::to_string_pretty(filter).unwrap_or_default()
+                ),
+            }
+            if !search.parameters.is_empty() {
+                println!("\nParameters:");
+                for param in &search.parameters {
+                    let mut line = format!("  {} ({})", param.name, param.param_type.as_str());
+                    if param.required {
+                        line.push_str(" required");
+                    }
+                    if let Some(default) = &param.default {
+                        line.push_str(&format!(" default={}", default));
+                    }
+                    if let Some(description) = &param.description {
+                        line.push_str(&format!(" - {}", description));
+                    }
+                    println!("{}", line);
+                }
+            }
+        }
+        SavedAction::Add {
+            name,
+            sql,
+            jql,
+            filter,
+            params,
+            description,
+            project,
+            owner,
+        } => {
+            let query = match (sql, jql, filter) {
+                (Some(sql), _, _) => {
+                    ExecuteSqlUseCase::validate_read_only(&sql)?;
+                    SavedSearchQuery::Sql { sql }
+                }
+                (_, Some(jql), _) => SavedSearchQuery::Jql { jql },
+                (_, _, Some(filter)) => SavedSearchQuery::Filter {
+                    filter: serde_json::from_str(&filter).map_err(|e| {
+                        DomainError::Validation(format!("Invalid filter JSON: {}", e))
+                    })?,
+                },
+                // clap requires one of --sql, --jql or --filter
+                (None, None, None) => unreachable!(),
+            };
+
+            let mut search = SavedSearch::new(&name, query);
+            search.description = description;
+            search.project = project;
+            search.owner = owner.or_else(|| settings.get_jira_config().map(|c| c.username));
+            search.parameters = params
+                .iter()
+                .map(String::as_str)
+                .map(parse_parameter_spec)
+                .collect::<DomainResult<_>>()?;
+
+            let replaced = settings.find_saved_search(&name).is_some();
+            settings.upsert_saved_search(search)?;
+            settings.save(settings_path)?;
+            if replaced {
+                println!("✅ Updated saved search '{}'", name);
+            } else {
+                println!("✅ Saved search '{}'", name);
+            }
+        }
+        SavedAction::Delete { name } => {
+            if !settings.remove_saved_search(&name) {
+                return Err(DomainError::NotFound(format!(
+                    "Saved search not found: {}",
+                    name
+                )));
+            }
+            settings.save(settings_path)?;
+            println!("✅ Deleted saved search '{}'", name);
+        }
+        SavedAction::Run {
+            name,
+            params,
+            project,
+            limit,
+            show_sql,
+        } => {
+            let search = find(&settings, &name)?;
+            let args = params
+                .iter()
+                .map(|arg| {
+                    arg.split_once('=')
+                        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+                        .ok_or_else(|| {
+                            DomainError::Validation(format!(
+                                "Invalid parameter '{}': expected NAME=VALUE",
+                                arg
+                            ))
+                        })
+                })
+                .collect::<DomainResult<std::collections::HashMap<_, _>>>()?;
+
+            let project = project.or_else(|| search.project.clone());
+            let jira_config = match &project {
+                Some(project_key) => settings.get_jira_config_for_project(project_key),
+                None => settings.get_jira_config(),
+            };
+            let use_case = RunSavedSearchUseCase::connect(db_factory, &search, project.as_deref())?;
+            // currentUser() refers to the account the endpoint is configured with
+            let use_case = match jira_config {
+                Some(config) => use_case.with_current_user(config.username),
+                None => use_case,
+            };
+
+            let result = use_case.execute(&search, &args, Some(limit))?;
+
+            if let Some(sql) = result.sql.as_ref().filter(|_| show_sql) {
+                println!("{}\n", sql);
+            }
+
+            if result.result.rows.is_empty() {
+                println!("No results.");
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(&result.result.columns);
+            for row in &result.result.rows {
+                table.add_row(row.iter().map(|value| {
+                    Cell::new(match value.as_str() {
+                        Some(text) => truncate(text, 50),
+                        None if value.is_null() => "-".to_string(),
+                        None => value.to_string(),
+                    })
+                }));
+            }
+
+            println!("{table}");
+            match result.total {
+                Some(total) => {
+                    println!("\nShowing {} of {} issues", result.result.row_count, total)
+                }
+                None => println!("\nShowing {} rows", result.result.row_count),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse `NAME[?][:TYPE][=DEFAULT]` into a saved search parameter
+fn parse_parameter_spec(
+    spec: &str,
+) -> DomainResult<jira_db_core::domain::entities::SavedSearchParameter> {
+    use jira_db_core::domain::entities::{SavedSearchParameter, SavedSearchParameterType};
+
+    let (head, default) = match spec.split_once('=') {
+        Some((head, default)) => (head, Some(default.to_string())),
+        None => (spec, None),
+    };
+    let (name, param_type) = match head.split_once(':') {
+        Some((name, param_type)) => (name, param_type.parse()?),
+        None => (head, SavedSearchParameterType::default()),
+    };
+    let (name, optional) = match name.trim().strip_suffix('?') {
+        Some(name) => (name, true),
+        None => (name.trim(), false),
+    };
+
+    Ok(SavedSearchParameter {
+        name: name.to_string(),
+        param_type,
+        description: None,
+        required: !optional && default.is_none(),
+        default,
+    })
+}
+
+fn handle_metadata(
+    settings_path: &std::path::Path,
+    db_factory: Arc<DatabaseFactory>,
+    project_key: &str,
+    metadata_type: Option<String>,
+) -> DomainResult<()> {
+    let settings = Settings::load(settings_path)?;
+
+    let project = settings
+        .find_project(project_key)
+        .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", project_key)))?;
+
+    let conn = db_factory.get_connection(project_key)?;
+    let metadata_repository = Arc::new(DuckDbMetadataRepository::new(conn));
+
+    let use_case = GetProjectMetadataUseCase::new(metadata_repository);
+
+    let metadata = if let Some(t) = &metadata_type {
+        use_case.execute_by_type(&project.id, t)?
+    } else {
+        use_case.execute(&project.id)?
+    };
+
+    println!("Metadata for project: {}\n", project_key);
+
+    if !metadata.statuses.is_empty() {
+        println!("Statuses ({}):", metadata.statuses.len());
+        for s in &metadata.statuses {
+            println!("  - {} ({})", s.name, s.category.as_deref().unwrap_or("-"));
+        }
+        println!();
+    }
+
+    if !metadata.priorities.is_empty() {
+        println!("Priorities ({}):", metadata.priorities.len());
+        for p in &metadata.priorities {
+            println!("  - {}", p.name);
+        }
+        println!();
+    }
+
+    if !metadata.issue_types.is_empty() {
+        println!("Issue Types ({}):", metadata.issue_types.len());
+        for t in &metadata.issue_types {
+            println!(
+                "  - {}{}",
+                t.name,
+                if t.subtask { " (subtask)" } else { "" }
+            );
+        }
+        println!();
+    }
+
+    if !metadata.labels.is_empty() {
+        println!("Labels ({}):", metadata.labels.len());
         for l in &metadata.labels {
             println!("  - {}", l.name);
         }
@@ -953,6 +1786,32 @@ fn handle_metadata(
                 if v.released { " (released)" } else { "" }
             );
         }
+        println!();
+    }
+
+    if !metadata.field_options.is_empty() {
+        println!("Field options ({}):", metadata.field_options.len());
+        let mut current_field = "";
+        for o in &metadata.field_options {
+            if o.field_id != current_field {
+                current_field = &o.field_id;
+                println!(
+                    "  {} ({})",
+                    o.field_name.as_deref().unwrap_or(&o.field_id),
+                    o.field_id
+                );
+            }
+            println!(
+                "    {}- {}{}",
+                if o.parent_option_id.is_some() {
+                    "  "
+                } else {
+                    ""
+                },
+                o.value,
+                if o.disabled { " (disabled)" } else { "" }
+            );
+        }
     }
 
     Ok(())
@@ -997,19 +1856,27 @@ fn handle_history(
 
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
-    table.set_header(vec!["Date", "Field", "From", "To", "Author"]);
+    table.set_header(vec!["Date", "Field", "From", "To", "Author", "Source"]);
 
     for (i, item) in history.iter().enumerate() {
         if i >= limit {
             break;
         }
 
+        // Payload-diff rows have no author; the change was only seen at sync time
+        let author = if item.is_sync_diff() {
+            "(detected at sync)"
+        } else {
+            item.author_display_name.as_deref().unwrap_or("-")
+        };
+
         table.add_row(vec![
             Cell::new(item.changed_at.format("%Y-%m-%d %H:%M").to_string()),
             Cell::new(&item.field),
             Cell::new(truncate(item.from_string.as_deref().unwrap_or("-"), 30)),
             Cell::new(truncate(item.to_string.as_deref().unwrap_or("-"), 30)),
-            Cell::new(item.author_display_name.as_deref().unwrap_or("-")),
+            Cell::new(author),
+            Cell::new(&item.source),
         ]);
     }
 
@@ -1246,9 +2113,10 @@ fn handle_report(
         };
 
         let issue_repository = Arc::new(DuckDbIssueRepository::new(conn.clone()));
-        let change_history_repository = Arc::new(DuckDbChangeHistoryRepository::new(conn));
+        let change_history_repository = Arc::new(DuckDbChangeHistoryRepository::new(conn.clone()));
 
-        let use_case = GenerateReportUseCase::new(issue_repository, change_history_repository);
+        let use_case = GenerateReportUseCase::new(issue_repository, change_history_repository)
+            .with_topic_repository(Arc::new(DuckDbTopicRepository::new(conn)));
 
         let project_tuple = vec![(id.as_str(), key.as_str(), name.as_str())];
         match use_case.execute(&project_tuple) {
@@ -1330,53 +2198,388 @@ fn handle_report(
     Ok(())
 }
 
-async fn handle_embeddings_command(
-    settings: &Settings,
-    db_factory: Arc<DatabaseFactory>,
-    project: Option<String>,
-    force: bool,
-    batch_size: usize,
-    cli_provider: Option<String>,
-    cli_model: Option<String>,
-    cli_endpoint: Option<String>,
+#[allow(clippy::too_many_arguments)]
+fn handle_duplicates(
+    settings_path: &std::path::Path,
+    db_factory: &DatabaseFactory,
+    project_key: &str,
+    threshold: f64,
+    since: Option<&str>,
+    neighbors: usize,
+    format: &str,
+    output: Option<PathBuf>,
 ) -> DomainResult<()> {
-    use jira_db_core::application::use_cases::{
-        EmbeddingGenerationConfig, GenerateEmbeddingsUseCase,
-    };
-    use jira_db_core::infrastructure::database::EmbeddingsRepository;
-    use jira_db_core::infrastructure::external::embeddings::{
-        EmbeddingProviderType, ProviderConfig, create_provider,
-    };
-
-    // Project is required for embeddings
-    let project_key = project.ok_or_else(|| {
-        DomainError::Validation(
-            "Project key is required for embeddings. Use --project <KEY>".into(),
-        )
-    })?;
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::{FindDuplicatesUseCase, parse_since};
+    use jira_db_core::report::generate_duplicates_report;
+    use std::fs;
 
-    // Verify project exists
-    if settings.find_project(&project_key).is_none() {
-        return Err(DomainError::NotFound(format!(
-            "Project not found: {}",
-            project_key
+    if !matches!(format, "table" | "json" | "html") {
+        return Err(DomainError::Validation(format!(
+            "Invalid format '{}': expected table, json or html",
+            format
         )));
     }
 
-    // Determine provider type (CLI > settings > default)
-    let provider_str = cli_provider
-        .or_else(|| settings.embeddings.as_ref().map(|e| e.provider.clone()))
-        .unwrap_or_else(|| "openai".to_string());
+    let settings = Settings::load(settings_path)?;
+    let project = settings
+        .find_project(project_key)
+        .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", project_key)))?;
 
-    let provider_type: EmbeddingProviderType = provider_str.parse()?;
+    let mut use_case = FindDuplicatesUseCase::new(db_factory.get_connection(project_key)?)
+        .with_threshold(threshold)
+        .with_neighbors(neighbors);
+    if let Some(since) = since {
+        use_case = use_case.with_since(parse_since(since, Utc::now())?);
+    }
+    let report = use_case.execute(&project.key, &project.id)?;
 
-    // Get model (CLI > settings > provider default)
-    let model = cli_model.or_else(|| settings.embeddings.as_ref().map(|e| e.model.clone()));
+    if report.scanned_issues == 0 {
+        println!(
+            "No embedded issues found. Run 'jira-db embeddings --project {}' first.",
+            project.key
+        );
+        return Ok(());
+    }
 
-    // Get endpoint (CLI > settings)
-    let endpoint = cli_endpoint.or_else(|| {
-        settings
-            .embeddings
+    let write_file = |path: &std::path::Path, content: String| -> DomainResult<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| {
+                DomainError::Repository(format!("Failed to create directory: {}", e))
+            })?;
+        }
+        fs::write(path, content)
+            .map_err(|e| DomainError::Repository(format!("Failed to write report file: {}", e)))?;
+        println!("Report written to {}", path.display());
+        Ok(())
+    };
+
+    match format {
+        "json" => {
+            let json = serde_json::to_string_pretty(&report).map_err(|e| {
+                DomainError::Repository(format!("Failed to serialize report: {}", e))
+            })?;
+            match output {
+                Some(path) => write_file(&path, json)?,
+                None => println!("{}", json),
+            }
+        }
+        "html" => {
+            let path = output.unwrap_or_else(|| {
+                PathBuf::from("reports").join(format!(
+                    "duplicates_{}_{}.html",
+                    project.key,
+                    Utc::now().format("%Y%m%d_%H%M%S")
+                ))
+            });
+            write_file(&path, generate_duplicates_report(&report))?;
+        }
+        _ => {
+            if report.clusters.is_empty() {
+                println!(
+                    "No duplicate candidates above {:.2} among {} issues.",
+                    report.threshold, report.scanned_issues
+                );
+            } else {
+                let mut table = Table::new();
+                table.load_preset(UTF8_FULL);
+                table.set_header(vec![
+                    "Cluster",
+                    "Similarity",
+                    "Issue",
+                    "Summary",
+                    "Issue",
+                    "Summary",
+                ]);
+                for (index, cluster) in report.clusters.iter().enumerate() {
+                    let summary = |key: &str| {
+                        cluster
+                            .issues
+                            .iter()
+                            .find(|issue| issue.key == key)
+                            .map(|issue| truncate(&issue.summary, 40))
+                            .unwrap_or_default()
+                    };
+                    for pair in &cluster.pairs {
+                        table.add_row(vec![
+                            Cell::new(index + 1),
+                            Cell::new(format!("{:.3}", pair.similarity)),
+                            Cell::new(&pair.left),
+                            Cell::new(summary(&pair.left)),
+                            Cell::new(&pair.right),
+                            Cell::new(summary(&pair.right)),
+                        ]);
+                    }
+                }
+                println!("{table}");
+            }
+            println!(
+                "\n{} clusters from {} issues scanned (threshold {:.2}); {} pairs already linked as duplicates were skipped",
+                report.clusters.len(),
+                report.scanned_issues,
+                report.threshold,
+                report.linked_pairs
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_similar(
+    db_factory: &DatabaseFactory,
+    issue_key: &str,
+    same_project: bool,
+    exclude_resolved: bool,
+    created_from: Option<&str>,
+    created_to: Option<&str>,
+    limit: usize,
+    format: &str,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::FindSimilarIssuesUseCase;
+    use jira_db_core::domain::repositories::DateRange;
+
+    if !matches!(format, "table" | "json") {
+        return Err(DomainError::Validation(format!(
+            "Invalid format '{}': expected table or json",
+            format
+        )));
+    }
+
+    let result = FindSimilarIssuesUseCase::connect(db_factory, issue_key, same_project)?
+        .with_exclude_resolved(exclude_resolved)
+        .with_created(DateRange::parse(created_from, created_to)?)
+        .execute(issue_key, limit)?;
+
+    if format == "json" {
+        let json = serde_json::to_string_pretty(&result)
+            .map_err(|e| DomainError::Repository(format!("Failed to serialize result: {}", e)))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    println!(
+        "Issues similar to {}: {}",
+        result.source.key, result.source.summary
+    );
+    if result.similar.is_empty() {
+        println!("No similar issues found.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec!["Key", "Similarity", "Status", "Type", "Summary"]);
+    for similar in &result.similar {
+        let issue = &similar.issue;
+        table.add_row(vec![
+            Cell::new(&issue.key),
+            Cell::new(format!("{:.3}", similar.similarity)),
+            Cell::new(issue.status.as_deref().unwrap_or("-")),
+            Cell::new(issue.issue_type.as_deref().unwrap_or("-")),
+            Cell::new(truncate(&issue.summary, 60)),
+        ]);
+    }
+    println!("{table}");
+    println!("\nFound {} similar issues", result.similar.len());
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn handle_topics(
+    settings_path: &std::path::Path,
+    db_factory: &DatabaseFactory,
+    project_key: &str,
+    topics: Option<usize>,
+    since: Option<&str>,
+    until: Option<&str>,
+    issue_type: Option<String>,
+    llm: bool,
+    format: &str,
+    output: Option<PathBuf>,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::{ClusterTopicsUseCase, parse_since};
+    use jira_db_core::domain::repositories::DateRange;
+    use jira_db_core::infrastructure::external::claude::{ClaudeClient, ClaudeConfig};
+    use jira_db_core::report::generate_topics_report;
+    use std::fs;
+
+    if !matches!(format, "table" | "json" | "html") {
+        return Err(DomainError::Validation(format!(
+            "Invalid format '{}': expected table, json or html",
+            format
+        )));
+    }
+
+    let settings = Settings::load(settings_path)?;
+    let project = settings
+        .find_project(project_key)
+        .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", project_key)))?;
+
+    let created = DateRange {
+        from: since
+            .map(|since| parse_since(since, Utc::now()))
+            .transpose()?,
+        to: DateRange::parse(None, until)?.to,
+    };
+    let mut use_case =
+        ClusterTopicsUseCase::new(db_factory.get_connection(project_key)?).with_created(created);
+    if let Some(topics) = topics {
+        use_case = use_case.with_topics(topics);
+    }
+    if let Some(issue_type) = issue_type {
+        use_case = use_case.with_issue_type(issue_type);
+    }
+    if llm {
+        let api_key = std::env::var("ANTHROPIC_API_KEY")
+            .ok()
+            .filter(|key| !key.is_empty())
+            .ok_or_else(|| {
+                DomainError::Configuration(
+                    "ANTHROPIC_API_KEY is required to name topics with --llm".into(),
+                )
+            })?;
+        use_case =
+            use_case.with_llm_naming(ClaudeClient::new(ClaudeConfig::new(api_key).with_haiku())?);
+    }
+    let report = use_case.execute(&project.key, &project.id).await?;
+
+    let write_file = |path: &std::path::Path, content: String| -> DomainResult<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| {
+                DomainError::Repository(format!("Failed to create directory: {}", e))
+            })?;
+        }
+        fs::write(path, content)
+            .map_err(|e| DomainError::Repository(format!("Failed to write report file: {}", e)))?;
+        println!("Report written to {}", path.display());
+        Ok(())
+    };
+
+    match format {
+        "json" => {
+            let json = serde_json::to_string_pretty(&report).map_err(|e| {
+                DomainError::Repository(format!("Failed to serialize report: {}", e))
+            })?;
+            match output {
+                Some(path) => write_file(&path, json)?,
+                None => println!("{}", json),
+            }
+        }
+        "html" => {
+            let path = output.unwrap_or_else(|| {
+                PathBuf::from("reports").join(format!(
+                    "topics_{}_{}.html",
+                    project.key,
+                    Utc::now().format("%Y%m%d_%H%M%S")
+                ))
+            });
+            write_file(&path, generate_topics_report(&report))?;
+        }
+        _ => {
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec!["#", "Topic", "Issues", "Representative Issues"]);
+            for topic in &report.topics {
+                let representatives: Vec<String> = topic
+                    .representatives
+                    .iter()
+                    .take(3)
+                    .map(|issue| format!("{} {}", issue.key, truncate(&issue.summary, 40)))
+                    .collect();
+                table.add_row(vec![
+                    Cell::new(topic.cluster_id),
+                    Cell::new(&topic.name),
+                    Cell::new(topic.size),
+                    Cell::new(representatives.join("\n")),
+                ]);
+            }
+            println!("{table}");
+
+            if !report.suggestions.is_empty() {
+                let mut table = Table::new();
+                table.load_preset(UTF8_FULL);
+                table.set_header(vec!["Key", "Field", "Suggested", "Summary"]);
+                for suggestion in &report.suggestions {
+                    let values: Vec<String> = suggestion
+                        .values
+                        .iter()
+                        .map(|value| format!("{} ({:.2})", value.value, value.score))
+                        .collect();
+                    table.add_row(vec![
+                        Cell::new(&suggestion.issue_key),
+                        Cell::new(&suggestion.field),
+                        Cell::new(values.join(", ")),
+                        Cell::new(truncate(&suggestion.summary, 50)),
+                    ]);
+                }
+                println!("\nLabel suggestions for issues without labels or components:");
+                println!("{table}");
+            }
+
+            println!(
+                "\n{} topics from {} issues; {} label suggestions. Assignments stored in issue_topic_assignments",
+                report.topics.len(),
+                report.scanned_issues,
+                report.suggestions.len()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_embeddings_command(
+    settings: &Settings,
+    db_factory: Arc<DatabaseFactory>,
+    project: Option<String>,
+    force: bool,
+    batch_size: usize,
+    cli_provider: Option<String>,
+    cli_model: Option<String>,
+    cli_endpoint: Option<String>,
+) -> DomainResult<()> {
+    use jira_db_core::application::use_cases::{
+        EmbeddingGenerationConfig, GenerateEmbeddingsUseCase,
+    };
+    use jira_db_core::infrastructure::database::EmbeddingsRepository;
+    use jira_db_core::infrastructure::external::embeddings::{
+        EmbeddingProviderType, ProviderConfig, create_provider,
+    };
+
+    // Project is required for embeddings
+    let project_key = project.ok_or_else(|| {
+        DomainError::Validation(
+            "Project key is required for embeddings. Use --project <KEY>".into(),
+        )
+    })?;
+
+    // Verify project exists
+    if settings.find_project(&project_key).is_none() {
+        return Err(DomainError::NotFound(format!(
+            "Project not found: {}",
+            project_key
+        )));
+    }
+
+    // Determine provider type (CLI > settings > default)
+    let provider_str = cli_provider
+        .or_else(|| settings.embeddings.as_ref().map(|e| e.provider.clone()))
+        .unwrap_or_else(|| "openai".to_string());
+
+    let provider_type: EmbeddingProviderType = provider_str.parse()?;
+
+    // Get model (CLI > settings > provider default)
+    let model = cli_model.or_else(|| settings.embeddings.as_ref().map(|e| e.model.clone()));
+
+    // Get endpoint (CLI > settings)
+    let endpoint = cli_endpoint.or_else(|| {
+        settings
+            .embeddings
             .as_ref()
             .and_then(|e| e.endpoint.clone())
     });
@@ -1614,7 +2817,6 @@ fn handle_snapshots_show(
 async fn handle_fields_command(
     settings_path: &std::path::Path,
     db_factory: Arc<DatabaseFactory>,
-    jira_service: Arc<JiraApiClient>,
     action: FieldsAction,
 ) -> DomainResult<()> {
     use comfy_table::{Cell, Color, Table, presets::UTF8_FULL};
@@ -1652,6 +2854,7 @@ async fn handle_fields_command(
     match action {
         FieldsAction::Sync => {
             println!("Fetching field definitions from JIRA...");
+            let jira_service = active_jira_client(&settings)?;
             let use_case =
                 SyncFieldsUseCase::new(jira_service, field_repo.clone(), expanded_repo.clone());
 
@@ -1733,11 +2936,7 @@ async fn handle_fields_command(
     Ok(())
 }
 
-async fn handle_debug_command(
-    settings: &Settings,
-    jira_service: Arc<JiraApiClient>,
-    action: DebugAction,
-) -> DomainResult<()> {
+async fn handle_debug_command(settings: &Settings, action: DebugAction) -> DomainResult<()> {
     use comfy_table::{Cell, Table, presets::UTF8_FULL};
     use jira_db_core::application::use_cases::{CreateTestTicketUseCase, TransitionIssueUseCase};
 
@@ -1758,6 +2957,7 @@ async fn handle_debug_command(
     }
 
     settings.validate()?;
+    let jira_service = active_jira_client(settings)?;
 
     match action {
         DebugAction::Status => {
@@ -1912,9 +3112,1335 @@ async fn handle_debug_command(
     Ok(())
 }
 
+async fn handle_issue_command(
+    settings_path: &std::path::Path,
+    db_factory: Arc<DatabaseFactory>,
+    action: IssueAction,
+) -> DomainResult<()> {
+    use jira_db_core::application::use_cases::UpdateIssueFieldsUseCase;
+    use jira_db_core::infrastructure::database::DuckDbFieldRepository;
+
+    match action {
+        IssueAction::Edit {
+            issue_key,
+            set,
+            add_labels,
+            remove_labels,
+            assignee,
+            dry_run,
+        } => {
+            let edits = collect_field_edits(&set, add_labels, remove_labels, assignee)?;
+
+            let project_key = project_key_from_issue_key(&issue_key)?;
+            let mut settings = Settings::load(settings_path)?;
+            settings.migrate_legacy_config();
+            if settings.find_project(project_key).is_none() {
+                return Err(DomainError::NotFound(format!(
+                    "Project not found: {}",
+                    project_key
+                )));
+            }
+
+            let jira_config = settings
+                .get_jira_config_for_project(project_key)
+                .ok_or_else(|| {
+                    DomainError::Validation(format!(
+                        "No JIRA endpoint configured for project {}",
+                        project_key
+                    ))
+                })?;
+
+            let conn = db_factory.get_connection(project_key)?;
+            let use_case = UpdateIssueFieldsUseCase::new(
+                Arc::new(DuckDbIssueRepository::new(conn.clone())),
+                Arc::new(DuckDbMetadataRepository::new(conn.clone())),
+                Arc::new(DuckDbFieldRepository::new(conn)),
+                Arc::new(JiraApiClient::new(&jira_config)?),
+            );
+
+            if dry_run {
+                let update = use_case.prepare(&issue_key, &edits)?;
+                println!(
+                    "Dry run: the following update would be sent for {}:\n",
+                    issue_key
+                );
+                println!("{:#}", update.to_request_body());
+                return Ok(());
+            }
+
+            let issue = use_case.execute(&issue_key, &edits).await?;
+
+            println!("Updated {}", issue.key);
+            println!("  Summary:  {}", issue.summary);
+            println!("  Priority: {}", issue.priority.as_deref().unwrap_or("-"));
+            println!("  Assignee: {}", issue.assignee.as_deref().unwrap_or("-"));
+            println!(
+                "  Labels:   {}",
+                issue
+                    .labels
+                    .as_ref()
+                    .filter(|l| !l.is_empty())
+                    .map(|l| l.join(", "))
+                    .unwrap_or_else(|| "-".to_string())
+            );
+        }
+        IssueAction::Move {
+            issue_keys,
+            to,
+            dry_run,
+        } => {
+            use comfy_table::{Cell, Color, Table, presets::UTF8_FULL};
+            use jira_db_core::application::use_cases::MoveIssueUseCase;
+            use jira_db_core::domain::entities::WorkflowGraph;
+            use jira_db_core::domain::repositories::{ChangeHistoryRepository, MetadataRepository};
+
+            // Group keys by project, keeping the given order
+            let mut by_project: Vec<(String, Vec<String>)> = Vec::new();
+            for key in issue_keys {
+                let project_key = project_key_from_issue_key(&key)?.to_string();
+                match by_project.iter_mut().find(|(p, _)| *p == project_key) {
+                    Some((_, keys)) => keys.push(key),
+                    None => by_project.push((project_key, vec![key])),
+                }
+            }
+
+            let mut settings = Settings::load(settings_path)?;
+            settings.migrate_legacy_config();
+
+            let mut results = Vec::new();
+            for (project_key, keys) in &by_project {
+                let jira_config = settings
+                    .get_jira_config_for_project(project_key)
+                    .ok_or_else(|| {
+                        DomainError::Validation(format!(
+                            "No JIRA endpoint configured for project {}",
+                            project_key
+                        ))
+                    })?;
+
+                let project_id = &settings
+                    .find_project(project_key)
+                    .ok_or_else(|| {
+                        DomainError::NotFound(format!("Project not found: {}", project_key))
+                    })?
+                    .id;
+                let conn = db_factory.get_connection(project_key)?;
+                // Synced workflows and status changes seen in the change history guide multi-step moves
+                let mut graph = WorkflowGraph::from_transitions(
+                    DuckDbChangeHistoryRepository::new(conn.clone()).find_status_transitions()?,
+                );
+                for workflow in DuckDbMetadataRepository::new(conn.clone())
+                    .find_workflows_by_project(project_id)?
+                {
+                    graph.extend(&workflow.graph());
+                }
+
+                let use_case = MoveIssueUseCase::new(
+                    Arc::new(DuckDbIssueRepository::new(conn)),
+                    Arc::new(JiraApiClient::new(&jira_config)?),
+                );
+                results.extend(use_case.execute_many(keys, &to, &mut graph, dry_run).await);
+            }
+
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec!["Key", "Path", "Transitions", "Result"]);
+            for result in &results {
+                let transitions = result
+                    .steps
+                    .iter()
+                    .map(|s| {
+                        s.transition
+                            .as_ref()
+                            .map(|t| format!("{} ({})", t.name, t.id))
+                            .unwrap_or_else(|| "?".to_string())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let outcome = match (&result.error, dry_run) {
+                    (Some(error), _) => Cell::new(error).fg(Color::Red),
+                    (None, true) => Cell::new("planned").fg(Color::Yellow),
+                    (None, false) if result.steps.is_empty() => Cell::new("already there"),
+                    (None, false) => Cell::new("moved").fg(Color::Green),
+                };
+                table.add_row(vec![
+                    Cell::new(&result.issue_key),
+                    Cell::new(result.path().join(" → ")),
+                    Cell::new(transitions),
+                    outcome,
+                ]);
+            }
+            println!("{table}");
+
+            let failed = results.iter().filter(|r| !r.success).count();
+            if dry_run {
+                println!(
+                    "\nDry run: nothing was changed. '?' marks hops planned from change history."
+                );
+            } else {
+                println!(
+                    "\nMoved {} of {} issue(s) to '{}'",
+                    results.len() - failed,
+                    results.len(),
+                    to
+                );
+            }
+            if failed > 0 {
+                return Err(DomainError::ExternalService(format!(
+                    "{} issue(s) could not be moved",
+                    failed
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_workflow_command(
+    settings_path: &std::path::Path,
+    db_factory: Arc<DatabaseFactory>,
+    action: WorkflowAction,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::{
+        GetWorkflowUseCase, ValidateWorkflowTransitionsUseCase,
+    };
+
+    let settings = Settings::load(settings_path)?;
+    let project_key = match &action {
+        WorkflowAction::Show { project, .. } | WorkflowAction::Validate { project, .. } => project,
+    };
+    let project = settings
+        .find_project(project_key)
+        .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", project_key)))?;
+    let conn = db_factory.get_connection(project_key)?;
+
+    match action {
+        WorkflowAction::Show { issue_type, .. } => {
+            let use_case = GetWorkflowUseCase::new(Arc::new(DuckDbMetadataRepository::new(conn)));
+            let workflow = use_case.for_issue_type(&project.id, &issue_type)?;
+
+            println!("%% Workflow: {}", workflow.name);
+            if let Some(scheme) = &workflow.scheme_name {
+                println!("%% Scheme: {}", scheme);
+            }
+            println!("{}", workflow.to_mermaid());
+        }
+        WorkflowAction::Validate { issue_type, .. } => {
+            let use_case = ValidateWorkflowTransitionsUseCase::new(
+                Arc::new(DuckDbIssueRepository::new(conn.clone())),
+                Arc::new(DuckDbChangeHistoryRepository::new(conn.clone())),
+                Arc::new(DuckDbMetadataRepository::new(conn)),
+            );
+            let violations = use_case.execute(&project.id, issue_type.as_deref())?;
+
+            if violations.is_empty() {
+                println!("All status changes follow the workflow.");
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec![
+                "Key",
+                "Type",
+                "Workflow",
+                "From",
+                "To",
+                "Changed At",
+                "Author",
+            ]);
+            for v in &violations {
+                table.add_row(vec![
+                    Cell::new(&v.issue_key),
+                    Cell::new(&v.issue_type),
+                    Cell::new(&v.workflow),
+                    Cell::new(&v.from_status),
+                    Cell::new(&v.to_status),
+                    Cell::new(v.changed_at.format("%Y-%m-%d %H:%M").to_string()),
+                    Cell::new(v.author.as_deref().unwrap_or("-")),
+                ]);
+            }
+            println!("{table}");
+            println!(
+                "\n{} status change(s) not defined by the workflow (illegal or admin-forced)",
+                violations.len()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_raw_command(db_factory: Arc<DatabaseFactory>, action: RawAction) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::RawDiffUseCase;
+
+    let issue_key = match &action {
+        RawAction::Versions { issue_key } | RawAction::Diff { issue_key, .. } => issue_key,
+    };
+    let project_key = project_key_from_issue_key(issue_key)?;
+    let raw_conn = db_factory.get_raw_connection(project_key)?;
+    let use_case = RawDiffUseCase::new(Arc::new(RawDataRepository::new(raw_conn)));
+
+    match action {
+        RawAction::Versions { issue_key } => {
+            let versions = use_case.versions(&issue_key)?;
+            if versions.is_empty() {
+                println!("No raw payload versions recorded for {}", issue_key);
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec!["Version", "Sync Run", "Synced At", "Hash"]);
+            for v in &versions {
+                table.add_row(vec![
+                    Cell::new(v.version),
+                    Cell::new(
+                        v.sync_run_id
+                            .map(|id| id.to_string())
+                            .unwrap_or_else(|| "-".to_string()),
+                    ),
+                    Cell::new(v.synced_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+                    Cell::new(truncate(&v.content_hash, 12)),
+                ]);
+            }
+            println!("{table}");
+        }
+        RawAction::Diff {
+            issue_key,
+            from,
+            to,
+        } => {
+            let diff = use_case.execute(&issue_key, from, to)?;
+            println!("{:#}", diff.to_json());
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_db_command(db_factory: Arc<DatabaseFactory>, action: DbAction) -> DomainResult<()> {
+    match action {
+        DbAction::Migrate {
+            project,
+            status,
+            dry_run,
+        } => handle_db_migrate(&db_factory, project, status, dry_run),
+        DbAction::Maintain { project, action } => handle_db_maintain(&db_factory, project, action),
+    }
+}
+
+/// Existing database files per project (all project databases if no project is given)
+fn project_database_files(
+    db_factory: &DatabaseFactory,
+    project: Option<String>,
+) -> DomainResult<Vec<(String, Vec<(SchemaKind, PathBuf)>)>> {
+    let projects = match project {
+        Some(key) => vec![key],
+        None => db_factory.list_project_databases()?,
+    };
+    if projects.is_empty() {
+        println!(
+            "No project databases found in {}",
+            db_factory.database_dir().display()
+        );
+    }
+
+    let mut files = Vec::new();
+    for project_key in projects {
+        let databases: Vec<_> = [
+            (SchemaKind::Main, db_factory.get_database_path(&project_key)),
+            (
+                SchemaKind::Raw,
+                db_factory.get_raw_database_path(&project_key),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, path)| path.exists())
+        .collect();
+        if databases.is_empty() {
+            println!("No database found for project {}", project_key);
+            continue;
+        }
+        files.push((project_key, databases));
+    }
+    Ok(files)
+}
+
+fn handle_import(
+    settings_path: &std::path::Path,
+    db_factory: &DatabaseFactory,
+    project_key: &str,
+    file: &str,
+) -> DomainResult<()> {
+    use jira_db_core::application::use_cases::{IMPORT_SYNC_TYPE, ImportProjectUseCase};
+    use jira_db_core::domain::entities::Project;
+    use jira_db_core::domain::repositories::ProjectRepository;
+    use jira_db_core::infrastructure::database::{
+        DuckDbFieldRepository, DuckDbIssuesExpandedRepository,
+    };
+    use jira_db_core::infrastructure::external::jira::JiraExport;
+
+    println!("Reading {}...", file);
+    let export = JiraExport::read(std::path::Path::new(file))?;
+
+    // Projects known only from the file are registered with what the file says about them
+    let mut settings = Settings::load(settings_path)?;
+    let project = match settings.find_project(project_key) {
+        Some(project) => project.clone(),
+        None => {
+            let prefix = format!("{}-", project_key);
+            let project_json = export
+                .issues
+                .iter()
+                .find(|issue| {
+                    issue["key"]
+                        .as_str()
+                        .is_some_and(|k| k.starts_with(&prefix))
+                })
+                .map(|issue| issue["fields"]["project"].clone())
+                .unwrap_or_default();
+            let project = ProjectConfig {
+                id: project_json["id"]
+                    .as_str()
+                    .unwrap_or(project_key)
+                    .to_string(),
+                key: project_key.to_string(),
+                name: project_json["name"]
+                    .as_str()
+                    .unwrap_or(project_key)
+                    .to_string(),
+                sync_enabled: true,
+                last_synced: None,
+                endpoint: settings.active_endpoint.clone(),
+                sync_checkpoint: None,
+                snapshot_checkpoint: None,
+                bulk_edit_checkpoint: None,
+            };
+            settings.upsert_project(project.clone());
+            settings.save(settings_path)?;
+            println!("Registered project {} ({})", project.key, project.name);
+            project
+        }
+    };
+
+    let conn = db_factory.get_connection(project_key)?;
+    let raw_conn = db_factory.get_raw_connection(project_key)?;
+
+    let project_repository = DuckDbProjectRepository::new(conn.clone());
+    if project_repository.find_by_key(project_key)?.is_none() {
+        project_repository.insert(&Project::new(
+            project.id.clone(),
+            project.key.clone(),
+            project.name.clone(),
+            None,
+        ))?;
+    }
+
+    let use_case = ImportProjectUseCase::new(
+        Arc::new(DuckDbIssueRepository::new(conn.clone())),
+        Arc::new(DuckDbChangeHistoryRepository::new(conn.clone())),
+        Arc::new(DuckDbMetadataRepository::new(conn.clone())),
+        Arc::new(DuckDbSyncHistoryRepository::new(conn.clone())),
+    )
+    .with_raw_repository(Arc::new(RawDataRepository::new(raw_conn)))
+    .with_field_repositories(
+        Arc::new(DuckDbFieldRepository::new(conn.clone())),
+        Arc::new(DuckDbIssuesExpandedRepository::new(conn.clone())),
+    )
+    .with_search_index(
+        Arc::new(FullTextSearchRepository::new(conn)),
+        settings.get_sync_settings().search_fields,
+    );
+
+    let result = use_case.execute(project_key, &project.id, &export)?;
+    publish_read_replica(db_factory, project_key);
+
+    println!(
+        "Imported {} issues into {} (recorded as '{}' in sync_history)",
+        result.issues_imported, project_key, IMPORT_SYNC_TYPE
+    );
+    if result.issues_skipped > 0 {
+        println!(
+            "  Skipped {} issues of other projects",
+            result.issues_skipped
+        );
+    }
+    println!(
+        "  Change history: {} from changelogs, {} derived from the previous import",
+        result.history_items, result.derived_changes
+    );
+    println!(
+        "  Fields: {} stored, {} issues expanded",
+        result.fields_imported, result.issues_expanded
+    );
+    if !result.placeholder_fields.is_empty() {
+        println!(
+            "  Placeholder fields for unmatched columns: {}",
+            result.placeholder_fields.join(", ")
+        );
+    }
+    println!(
+        "Run 'jira-db snapshots generate --project {}' to build issue snapshots.",
+        project_key
+    );
+
+    Ok(())
+}
+
+fn handle_export(
+    db_factory: &DatabaseFactory,
+    project: Option<String>,
+    table: Option<String>,
+    sql: Option<String>,
+    format: Option<String>,
+    out: String,
+) -> DomainResult<()> {
+    use jira_db_core::application::use_cases::{ExportDataUseCase, ExportFormat, ExportSource};
+
+    let out = PathBuf::from(out);
+    let format = match format {
+        Some(format) => format.parse::<ExportFormat>()?,
+        None => ExportFormat::from_extension(&out).ok_or_else(|| {
+            DomainError::Validation(
+                "Cannot infer the export format from --out. Use --format parquet|csv|jsonl|xlsx"
+                    .into(),
+            )
+        })?,
+    };
+    let source = match (table, sql) {
+        (Some(table), _) => ExportSource::Table(table),
+        (None, Some(sql)) => ExportSource::Query(sql),
+        (None, None) => {
+            return Err(DomainError::Validation(
+                "Either --table or --sql is required".into(),
+            ));
+        }
+    };
+
+    let conn = match project {
+        Some(project_key) => db_factory.get_connection(&project_key)?,
+        None => {
+            let projects = db_factory.list_project_databases()?;
+            println!(
+                "Exporting from {} project(s): {}",
+                projects.len(),
+                projects.join(", ")
+            );
+            db_factory.get_federated_connection(&projects)?
+        }
+    };
+
+    let result = ExportDataUseCase::new(conn).execute(&source, format, &out)?;
+    println!(
+        "Exported {} rows to {} ({})",
+        result.row_count,
+        result.path.display(),
+        result.format
+    );
+
+    Ok(())
+}
+
+fn handle_db_migrate(
+    db_factory: &DatabaseFactory,
+    project: Option<String>,
+    status: bool,
+    dry_run: bool,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::infrastructure::database::Database;
+
+    for (_, databases) in project_database_files(db_factory, project)? {
+        for (kind, path) in databases {
+            let db = Database::open_existing(&path)?;
+
+            if status {
+                let statuses = db.migration_status(kind)?;
+                let current = statuses
+                    .iter()
+                    .filter(|s| !s.is_pending())
+                    .map(|s| s.version)
+                    .max()
+                    .unwrap_or(0);
+                println!(
+                    "{} (schema version {} of {})",
+                    path.display(),
+                    current,
+                    kind.latest_version()
+                );
+
+                let mut table = Table::new();
+                table.load_preset(UTF8_FULL);
+                table.set_header(vec!["Version", "Description", "Applied At", "Applied By"]);
+                for s in &statuses {
+                    table.add_row(vec![
+                        Cell::new(s.version),
+                        Cell::new(&s.description),
+                        Cell::new(
+                            s.applied_at
+                                .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                                .unwrap_or_else(|| "pending".to_string()),
+                        ),
+                        Cell::new(s.applied_by.as_deref().unwrap_or("-")),
+                    ]);
+                }
+                println!("{table}\n");
+                continue;
+            }
+
+            let migrations = if dry_run {
+                db.dry_run_migrations(kind)?
+            } else {
+                db.migrate(kind)?
+            };
+            if migrations.is_empty() {
+                println!("{}: up to date", path.display());
+                continue;
+            }
+
+            let verb = if dry_run { "Would apply" } else { "Applied" };
+            println!(
+                "{}: {} {} migration(s)",
+                path.display(),
+                verb,
+                migrations.len()
+            );
+            for m in &migrations {
+                println!("  {:>4}  {}", m.version, m.description);
+            }
+            if !dry_run {
+                db.checkpoint()?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_db_maintain(
+    db_factory: &DatabaseFactory,
+    project: Option<String>,
+    action: MaintainAction,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Table, presets::UTF8_FULL};
+    use jira_db_core::infrastructure::database::DatabaseMaintenance;
+
+    for (project_key, databases) in project_database_files(db_factory, project)? {
+        for (kind, path) in databases {
+            // Referential checks only concern the processed data
+            if matches!(action, MaintainAction::Check { .. }) && kind == SchemaKind::Raw {
+                continue;
+            }
+
+            // Opening replays a WAL left behind by a crash and applies pending migrations
+            let conn = match kind {
+                SchemaKind::Main => db_factory.get_connection(&project_key)?,
+                SchemaKind::Raw => db_factory.get_raw_connection(&project_key)?,
+            };
+            let maintenance = DatabaseMaintenance::new(conn);
+
+            match &action {
+                MaintainAction::Size => {
+                    let usage = maintenance.block_usage()?;
+                    println!(
+                        "{}: file {}, WAL {}, {} of {} blocks used",
+                        path.display(),
+                        format_bytes(file_size(&path)),
+                        format_bytes(file_size(&wal_path(&path))),
+                        usage.used_blocks,
+                        usage.total_blocks
+                    );
+
+                    let mut table = Table::new();
+                    table.load_preset(UTF8_FULL);
+                    table.set_header(vec!["Table", "Rows", "Size (approx.)"]);
+                    for size in maintenance.table_sizes()? {
+                        table.add_row(vec![
+                            Cell::new(&size.table_name),
+                            Cell::new(size.row_count),
+                            Cell::new(format_bytes(size.estimated_bytes)),
+                        ]);
+                    }
+                    println!("{table}\n");
+                }
+                MaintainAction::Checkpoint => {
+                    maintenance.checkpoint()?;
+                    println!(
+                        "{}: checkpointed (WAL {})",
+                        path.display(),
+                        format_bytes(file_size(&wal_path(&path)))
+                    );
+                }
+                MaintainAction::Vacuum => {
+                    let before = maintenance.block_usage()?;
+                    maintenance.vacuum()?;
+                    let after = maintenance.block_usage()?;
+                    println!(
+                        "{}: vacuumed ({} -> {} blocks used, {} free for reuse)",
+                        path.display(),
+                        before.used_blocks,
+                        after.used_blocks,
+                        after.free_blocks
+                    );
+                }
+                MaintainAction::RebuildIndexes => {
+                    let rebuilt = maintenance.rebuild_indexes()?;
+                    println!("{}: rebuilt {} index(es)", path.display(), rebuilt.len());
+                    for name in &rebuilt {
+                        println!("  {}", name);
+                    }
+                }
+                MaintainAction::Check { repair } => {
+                    let findings = maintenance.check()?;
+                    let mut table = Table::new();
+                    table.load_preset(UTF8_FULL);
+                    table.set_header(vec!["Check", "Rows", "Repairable", "Description"]);
+                    for f in &findings {
+                        table.add_row(vec![
+                            Cell::new(&f.check),
+                            Cell::new(f.count),
+                            Cell::new(if f.repairable { "yes" } else { "no" }),
+                            Cell::new(&f.description),
+                        ]);
+                    }
+                    println!("{}\n{table}", path.display());
+
+                    let problems: usize = findings.iter().map(|f| f.count).sum();
+                    if problems == 0 {
+                        println!("No inconsistencies found\n");
+                    } else if *repair {
+                        for (check, deleted) in maintenance.repair()? {
+                            println!("Repaired {}: deleted {} rows", check, deleted);
+                        }
+                        println!();
+                    } else {
+                        println!("Run with --repair to delete the repairable rows\n");
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// DuckDB keeps the write-ahead log next to the database as "<file>.wal"
+fn wal_path(path: &std::path::Path) -> PathBuf {
+    let mut wal = path.as_os_str().to_os_string();
+    wal.push(".wal");
+    PathBuf::from(wal)
+}
+
+fn file_size(path: &std::path::Path) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Build field edits from the shared --set/--add-label/--remove-label/--assignee options
+fn collect_field_edits(
+    set: &[String],
+    add_labels: Vec<String>,
+    remove_labels: Vec<String>,
+    assignee: Option<String>,
+) -> DomainResult<Vec<jira_db_core::application::dto::FieldEdit>> {
+    use jira_db_core::application::dto::FieldEdit;
+
+    let mut edits = Vec::new();
+    for arg in set {
+        edits.push(FieldEdit::parse_set(arg).ok_or_else(|| {
+            DomainError::Validation(format!("Invalid --set '{}': expected FIELD=VALUE", arg))
+        })?);
+    }
+    edits.extend(add_labels.into_iter().map(|value| FieldEdit::Add {
+        field: "labels".to_string(),
+        value,
+    }));
+    edits.extend(remove_labels.into_iter().map(|value| FieldEdit::Remove {
+        field: "labels".to_string(),
+        value,
+    }));
+    if let Some(value) = assignee {
+        edits.push(FieldEdit::Set {
+            field: "assignee".to_string(),
+            value,
+        });
+    }
+    Ok(edits)
+}
+
+async fn handle_bulk_command(
+    settings_path: &std::path::Path,
+    db_factory: Arc<DatabaseFactory>,
+    action: BulkAction,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Color, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::BulkEditUseCase;
+    use jira_db_core::infrastructure::database::DuckDbFieldRepository;
+
+    let mut settings = Settings::load(settings_path)?;
+    settings.migrate_legacy_config();
+
+    match action {
+        BulkAction::Abort { project } => {
+            let project_config = settings
+                .find_project_mut(&project)
+                .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", project)))?;
+            match project_config.bulk_edit_checkpoint.take() {
+                Some(checkpoint) => {
+                    settings.save(settings_path)?;
+                    println!(
+                        "Discarded bulk edit checkpoint for {} ({}/{} issues processed)",
+                        project,
+                        checkpoint.items_processed,
+                        checkpoint.issue_keys.len()
+                    );
+                }
+                None => println!("No bulk edit in progress for {}", project),
+            }
+        }
+        BulkAction::Edit {
+            project,
+            predicate,
+            set,
+            add_labels,
+            remove_labels,
+            assignee,
+            dry_run,
+            limit,
+            rate,
+            report,
+            resume,
+        } => {
+            let existing_checkpoint = settings
+                .find_project(&project)
+                .ok_or_else(|| DomainError::NotFound(format!("Project not found: {}", project)))?
+                .bulk_edit_checkpoint
+                .clone();
+
+            let jira_config = settings
+                .get_jira_config_for_project(&project)
+                .ok_or_else(|| {
+                    DomainError::Validation(format!(
+                        "No JIRA endpoint configured for project {}",
+                        project
+                    ))
+                })?;
+
+            if rate.is_nan() || rate <= 0.0 {
+                return Err(DomainError::Validation(
+                    "--rate must be greater than 0".to_string(),
+                ));
+            }
+
+            let conn = db_factory.get_connection(&project)?;
+            let use_case = BulkEditUseCase::new(
+                Arc::new(DuckDbIssueRepository::new(conn.clone())),
+                Arc::new(DuckDbMetadataRepository::new(conn.clone())),
+                Arc::new(DuckDbFieldRepository::new(conn.clone())),
+                Arc::new(JiraApiClient::new(&jira_config)?),
+                conn,
+            );
+
+            let checkpoint = if resume {
+                let checkpoint = existing_checkpoint.ok_or_else(|| {
+                    DomainError::Validation(format!("No bulk edit to resume for {}", project))
+                })?;
+                println!(
+                    "Resuming bulk edit ({}/{} issues processed)",
+                    checkpoint.items_processed,
+                    checkpoint.issue_keys.len()
+                );
+                println!("  Where: {}", checkpoint.predicate);
+                checkpoint
+            } else {
+                if let Some(checkpoint) = existing_checkpoint {
+                    return Err(DomainError::Validation(format!(
+                        "An interrupted bulk edit exists for {} ({}/{} issues processed). \
+                         Use --resume to continue or 'jira-db bulk abort -p {}' to discard it",
+                        project,
+                        checkpoint.items_processed,
+                        checkpoint.issue_keys.len(),
+                        project
+                    )));
+                }
+
+                let predicate = predicate.ok_or_else(|| {
+                    DomainError::Validation("--where is required (unless --resume)".to_string())
+                })?;
+                let edits = collect_field_edits(&set, add_labels, remove_labels, assignee)?;
+                if edits.is_empty() {
+                    return Err(DomainError::Validation(
+                        "Nothing to update: use --set, --add-label, --remove-label or --assignee"
+                            .to_string(),
+                    ));
+                }
+
+                let keys = use_case.select_issue_keys(&predicate, limit)?;
+                if keys.is_empty() {
+                    println!("No issues match: {}", predicate);
+                    return Ok(());
+                }
+                if keys.len() == limit {
+                    warn!(
+                        "Selection reached --limit {}; some matching issues may be excluded",
+                        limit
+                    );
+                }
+
+                let preview = use_case.preview(&keys, &edits)?;
+
+                let mut table = Table::new();
+                table.load_preset(UTF8_FULL);
+                table.set_header(vec!["Key", "Summary", "Field", "Before", "After"]);
+                for issue in preview.issues.iter().filter(|i| !i.changes.is_empty()) {
+                    for (i, change) in issue.changes.iter().enumerate() {
+                        let (key, summary) = if i == 0 {
+                            (issue.issue_key.as_str(), truncate(&issue.summary, 40))
+                        } else {
+                            ("", String::new())
+                        };
+                        table.add_row(vec![
+                            Cell::new(key),
+                            Cell::new(summary),
+                            Cell::new(&change.field),
+                            Cell::new(if change.before.is_empty() {
+                                "-"
+                            } else {
+                                &change.before
+                            })
+                            .fg(Color::Red),
+                            Cell::new(if change.after.is_empty() {
+                                "-"
+                            } else {
+                                &change.after
+                            })
+                            .fg(Color::Green),
+                        ]);
+                    }
+                }
+
+                let changed = preview.changed_issue_keys().len();
+                if changed > 0 {
+                    println!("{table}");
+                }
+                println!(
+                    "\n{} issue(s) selected, {} would change, {} already up to date",
+                    keys.len(),
+                    changed,
+                    keys.len() - changed
+                );
+
+                if dry_run {
+                    println!("\nDry run: the following update would be sent to each issue:\n");
+                    println!("{:#}", preview.update.to_request_body());
+                    return Ok(());
+                }
+                if changed == 0 {
+                    return Ok(());
+                }
+
+                let checkpoint = preview.to_checkpoint(&predicate);
+                if let Some(p) = settings.find_project_mut(&project) {
+                    p.bulk_edit_checkpoint = Some(checkpoint.clone());
+                }
+                settings.save(settings_path)?;
+                checkpoint
+            };
+
+            let total = checkpoint.issue_keys.len() as u64;
+            let pb = ProgressBar::new(total);
+            pb.set_style(
+                ProgressStyle::default_bar()
+                    .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+                    .unwrap()
+                    .progress_chars("#>-"),
+            );
+            pb.set_position(checkpoint.items_processed as u64);
+
+            let path = settings_path.to_path_buf();
+            let key = project.clone();
+            let progress = pb.clone();
+            let delay = std::time::Duration::from_secs_f64(1.0 / rate);
+
+            let result = use_case
+                .execute(checkpoint, delay, move |cp| {
+                    progress.set_position(cp.items_processed as u64);
+                    progress.set_message(format!("{} failed", cp.failures.len()));
+                    if let Ok(mut s) = Settings::load(&path) {
+                        if let Some(p) = s.find_project_mut(&key) {
+                            p.bulk_edit_checkpoint = Some(cp.clone());
+                        }
+                        let _ = s.save(&path);
+                    }
+                })
+                .await?;
+            pb.finish_and_clear();
+
+            // Completed: clear the checkpoint
+            let mut settings = Settings::load(settings_path)?;
+            if let Some(p) = settings.find_project_mut(&project) {
+                p.bulk_edit_checkpoint = None;
+            }
+            settings.save(settings_path)?;
+
+            let report_path = report.unwrap_or_else(|| {
+                PathBuf::from(format!(
+                    "bulk-edit-{}-{}.json",
+                    project,
+                    Utc::now().format("%Y%m%d%H%M%S")
+                ))
+            });
+            result.save(&report_path)?;
+
+            println!(
+                "Bulk edit finished: {} succeeded, {} failed (of {})",
+                result.succeeded, result.failed, result.total
+            );
+            for failure in result.results.iter().filter(|r| !r.success) {
+                println!(
+                    "  {} {}",
+                    failure.issue_key,
+                    failure.error.as_deref().unwrap_or("")
+                );
+            }
+            println!("Report written to {}", report_path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Extract project key from issue key (e.g., "PROJ-123" -> "PROJ")
+fn project_key_from_issue_key(issue_key: &str) -> DomainResult<&str> {
+    issue_key
+        .rsplit_once('-')
+        .map(|(project, _)| project)
+        .filter(|project| !project.is_empty())
+        .ok_or_else(|| DomainError::Validation(format!("Invalid issue key: {}", issue_key)))
+}
+
+fn handle_queue_command(
+    settings_path: &std::path::Path,
+    db_factory: Arc<DatabaseFactory>,
+    action: QueueAction,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Color, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::{
+        QueuePendingOperationUseCase, prepare_field_update,
+    };
+    use jira_db_core::domain::entities::{PendingOperationKind, PendingOperationStatus};
+    use jira_db_core::infrastructure::database::{
+        DuckDbFieldRepository, DuckDbPendingOperationRepository,
+    };
+
+    let settings = Settings::load(settings_path)?;
+
+    let (project_key, issue_key, kind) = match &action {
+        QueueAction::Transition { issue_key, to } => (
+            project_key_from_issue_key(issue_key)?,
+            Some(issue_key.as_str()),
+            Some(PendingOperationKind::Transition {
+                to_status: to.clone(),
+            }),
+        ),
+        QueueAction::Comment { issue_key, body } => (
+            project_key_from_issue_key(issue_key)?,
+            Some(issue_key.as_str()),
+            Some(PendingOperationKind::Comment { body: body.clone() }),
+        ),
+        QueueAction::DueDate { issue_key, date } => (
+            project_key_from_issue_key(issue_key)?,
+            Some(issue_key.as_str()),
+            Some(PendingOperationKind::UpdateDueDate {
+                due_date: date.clone(),
+            }),
+        ),
+        QueueAction::Edit { issue_key, .. } => (
+            project_key_from_issue_key(issue_key)?,
+            Some(issue_key.as_str()),
+            None,
+        ),
+        QueueAction::Create {
+            project,
+            summary,
+            description,
+            issue_type,
+        } => (
+            project.as_str(),
+            Some(project.as_str()),
+            Some(PendingOperationKind::CreateIssue {
+                summary: summary.clone(),
+                description: description.clone(),
+                issue_type: issue_type.clone(),
+            }),
+        ),
+        QueueAction::List { project, .. }
+        | QueueAction::Retry { project, .. }
+        | QueueAction::Discard { project, .. } => (project.as_str(), None, None),
+    };
+
+    if settings.find_project(project_key).is_none() {
+        return Err(DomainError::NotFound(format!(
+            "Project not found: {}",
+            project_key
+        )));
+    }
+
+    let conn = db_factory.get_connection(project_key)?;
+    let issue_repository = Arc::new(DuckDbIssueRepository::new(conn.clone()));
+
+    // Field edits are validated and converted now, so the push only replays the payload
+    let kind = match &action {
+        QueueAction::Edit {
+            issue_key,
+            set,
+            add_labels,
+            remove_labels,
+            assignee,
+        } => {
+            let edits = collect_field_edits(
+                set,
+                add_labels.clone(),
+                remove_labels.clone(),
+                assignee.clone(),
+            )?;
+            let update = prepare_field_update(
+                issue_repository.as_ref(),
+                &DuckDbMetadataRepository::new(conn.clone()),
+                &DuckDbFieldRepository::new(conn.clone()),
+                issue_key,
+                &edits,
+            )?;
+            Some(PendingOperationKind::UpdateFields {
+                body: update.to_request_body(),
+            })
+        }
+        _ => kind,
+    };
+
+    let use_case = QueuePendingOperationUseCase::new(
+        issue_repository,
+        Arc::new(DuckDbPendingOperationRepository::new(conn)),
+    );
+
+    if let (Some(issue_key), Some(kind)) = (issue_key, kind) {
+        let operation = use_case.enqueue(issue_key, kind)?;
+        println!(
+            "Queued #{} for {}: {}",
+            operation.id,
+            operation.issue_key,
+            operation.kind.describe()
+        );
+        println!("Run 'jira-db push' to send queued operations to JIRA.");
+        return Ok(());
+    }
+
+    match &action {
+        QueueAction::List { status, .. } => {
+            let status = status
+                .as_deref()
+                .map(|s| {
+                    PendingOperationStatus::parse(s).ok_or_else(|| {
+                        DomainError::Validation(format!(
+                            "Invalid status: {}. Use pending, applied, rejected or discarded",
+                            s
+                        ))
+                    })
+                })
+                .transpose()?;
+            let operations = use_case.list(status)?;
+
+            if operations.is_empty() {
+                println!("No queued operations for project {}", project_key);
+                return Ok(());
+            }
+
+            let mut table = Table::new();
+            table.load_preset(UTF8_FULL);
+            table.set_header(vec![
+                "ID",
+                "Issue",
+                "Operation",
+                "Status",
+                "Queued",
+                "Error",
+            ]);
+
+            for op in &operations {
+                let status_color = match op.status {
+                    PendingOperationStatus::Pending => Color::Yellow,
+                    PendingOperationStatus::Applied => Color::Green,
+                    PendingOperationStatus::Rejected => Color::Red,
+                    PendingOperationStatus::Discarded => Color::DarkGrey,
+                };
+                table.add_row(vec![
+                    Cell::new(op.id),
+                    Cell::new(&op.issue_key),
+                    Cell::new(truncate(&op.kind.describe(), 40)),
+                    Cell::new(op.status.as_str()).fg(status_color),
+                    Cell::new(op.created_at.format("%Y-%m-%d %H:%M").to_string()),
+                    Cell::new(truncate(op.error_message.as_deref().unwrap_or("-"), 50)),
+                ]);
+            }
+
+            println!("{table}");
+        }
+        QueueAction::Retry { id, .. } => {
+            use_case.retry(*id)?;
+            println!(
+                "Operation #{} re-queued. It will be pushed without conflict detection.",
+                id
+            );
+        }
+        QueueAction::Discard { id, .. } => {
+            use_case.discard(*id)?;
+            println!(
+                "Operation #{} discarded. Its local change has been undone.",
+                id
+            );
+        }
+        _ => unreachable!(), // Write operations handled above
+    }
+
+    Ok(())
+}
+
+async fn handle_push(
+    settings_path: &std::path::Path,
+    db_factory: Arc<DatabaseFactory>,
+    project_key: Option<String>,
+) -> DomainResult<()> {
+    use comfy_table::{Cell, Color, Table, presets::UTF8_FULL};
+    use jira_db_core::application::use_cases::{PushOutcome, PushPendingOperationsUseCase};
+    use jira_db_core::infrastructure::database::DuckDbPendingOperationRepository;
+
+    let mut settings = Settings::load(settings_path)?;
+    settings.migrate_legacy_config();
+
+    let project_keys: Vec<String> = match project_key {
+        Some(key) => {
+            if settings.find_project(&key).is_none() {
+                return Err(DomainError::NotFound(format!("Project not found: {}", key)));
+            }
+            vec![key]
+        }
+        None => settings
+            .sync_enabled_projects()
+            .iter()
+            .map(|p| p.key.clone())
+            .collect(),
+    };
+
+    let mut rejected = Vec::new();
+    let (mut applied_total, mut skipped_total) = (0, 0);
+
+    for key in &project_keys {
+        let conn = db_factory.get_connection(key)?;
+        let pending_repository = Arc::new(DuckDbPendingOperationRepository::new(conn.clone()));
+
+        // Each project pushes to its own endpoint (falls back to the active one)
+        let jira_config = settings.get_jira_config_for_project(key).ok_or_else(|| {
+            DomainError::Validation(format!("No JIRA endpoint configured for project {}", key))
+        })?;
+        let use_case = PushPendingOperationsUseCase::new(
+            Arc::new(DuckDbIssueRepository::new(conn.clone())),
+            pending_repository,
+            Arc::new(JiraApiClient::new(&jira_config)?),
+        )
+        .with_search_index(
+            Arc::new(FullTextSearchRepository::new(conn)),
+            settings.get_sync_settings().search_fields,
+        );
+
+        let result = use_case.execute().await?;
+        if result.results.is_empty() {
+            continue;
+        }
+
+        println!(
+            "{}: {} applied, {} rejected, {} skipped",
+            key,
+            result.applied_count(),
+            result.rejected_count(),
+            result.skipped_count()
+        );
+        applied_total += result.applied_count();
+        skipped_total += result.skipped_count();
+
+        for r in result.results {
+            let reason = match r.outcome {
+                PushOutcome::Conflict { remote_updated_at } => format!(
+                    "Conflict: updated in JIRA at {}",
+                    remote_updated_at
+                        .map(|d| d.format("%Y-%m-%d %H:%M").to_string())
+                        .unwrap_or_else(|| "-".to_string())
+                ),
+                PushOutcome::Rejected(message) => message,
+                PushOutcome::Applied | PushOutcome::Skipped(_) => continue,
+            };
+            rejected.push((key.clone(), r.operation, reason));
+        }
+    }
+
+    if applied_total == 0 && rejected.is_empty() && skipped_total == 0 {
+        println!("No pending operations to push.");
+        return Ok(());
+    }
+
+    if skipped_total > 0 {
+        println!(
+            "\n{} operation(s) could not be sent and remain pending.",
+            skipped_total
+        );
+    }
+
+    if !rejected.is_empty() {
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec!["Project", "ID", "Issue", "Operation", "Reason"]);
+
+        for (project, op, reason) in &rejected {
+            table.add_row(vec![
+                Cell::new(project),
+                Cell::new(op.id),
+                Cell::new(&op.issue_key),
+                Cell::new(truncate(&op.kind.describe(), 40)),
+                Cell::new(truncate(reason, 60)).fg(Color::Red),
+            ]);
+        }
+
+        println!("\nRejected operations:");
+        println!("{table}");
+        println!("\nFor each rejected operation, either:");
+        println!(
+            "  jira-db queue retry --project <KEY> <ID>     Push again, ignoring the conflict"
+        );
+        println!("  jira-db queue discard --project <KEY> <ID>   Drop it and keep JIRA's version");
+    }
+
+    Ok(())
+}
+
 async fn handle_endpoint_command(
     settings_path: &std::path::Path,
-    _jira_service: Arc<JiraApiClient>,
     action: EndpointAction,
 ) -> DomainResult<()> {
     use comfy_table::{Cell, Color, Table, presets::UTF8_FULL};
@@ -0,0 +1,93 @@
+use serde_json::{Map, Value};
+
+/// A requested change to an issue field, before validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldEdit {
+    /// Replace the field value (an empty value clears the field)
+    Set { field: String, value: String },
+    /// Add a value to an array field (labels, components, ...)
+    Add { field: String, value: String },
+    /// Remove a value from an array field
+    Remove { field: String, value: String },
+}
+
+impl FieldEdit {
+    /// Parse a `field=value` argument into a `Set` edit
+    pub fn parse_set(arg: &str) -> Option<Self> {
+        let (field, value) = arg.split_once('=')?;
+        let field = field.trim();
+        if field.is_empty() {
+            return None;
+        }
+        Some(Self::Set {
+            field: field.to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+
+    pub fn field(&self) -> &str {
+        match self {
+            Self::Set { field, .. } | Self::Add { field, .. } | Self::Remove { field, .. } => field,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Set { value, .. } | Self::Add { value, .. } | Self::Remove { value, .. } => value,
+        }
+    }
+}
+
+/// Validated payload for JIRA's edit issue API (PUT /rest/api/3/issue/{key})
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueFieldUpdate {
+    /// Values for the `fields` object, keyed by field ID
+    pub fields: Map<String, Value>,
+    /// Operations for the `update` object, keyed by field ID (e.g., `[{"add": "x"}]`)
+    pub update: Map<String, Value>,
+}
+
+impl IssueFieldUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.update.is_empty()
+    }
+
+    /// Append an add/remove operation for a field
+    pub fn push_operation(&mut self, field_id: &str, operation: &str, value: Value) {
+        let entry = self
+            .update
+            .entry(field_id.to_string())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        if let Value::Array(operations) = entry {
+            let mut op = Map::new();
+            op.insert(operation.to_string(), value);
+            operations.push(Value::Object(op));
+        }
+    }
+
+    /// Rebuild an update from a request body produced by `to_request_body`
+    pub fn from_request_body(body: &Value) -> Self {
+        let object = |key: &str| {
+            body.get(key)
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default()
+        };
+        Self {
+            fields: object("fields"),
+            update: object("update"),
+        }
+    }
+
+    /// Build the JSON request body
+    pub fn to_request_body(&self) -> Value {
+        let mut body = Map::new();
+        if !self.fields.is_empty() {
+            body.insert("fields".to_string(), Value::Object(self.fields.clone()));
+        }
+        if !self.update.is_empty() {
+            body.insert("update".to_string(), Value::Object(self.update.clone()));
+        }
+        Value::Object(body)
+    }
+}
@@ -1,7 +1,9 @@
 mod created_issue;
+mod issue_field_update;
 mod sync_result;
 mod transition;
 
 pub use created_issue::CreatedIssueDto;
+pub use issue_field_update::{FieldEdit, IssueFieldUpdate};
 pub use sync_result::SyncResult;
 pub use transition::TransitionDto;
@@ -9,6 +9,8 @@ pub struct TransitionDto {
     pub to_status: String,
     /// Target status category (e.g., "new", "indeterminate", "done")
     pub to_status_category: Option<String>,
+    /// Fields that must be filled on the transition screen (no default value)
+    pub required_fields: Vec<String>,
 }
 
 impl TransitionDto {
@@ -23,6 +25,7 @@ impl TransitionDto {
             name,
             to_status,
             to_status_category,
+            required_fields: Vec::new(),
         }
     }
 }
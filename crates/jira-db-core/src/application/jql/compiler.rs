@@ -0,0 +1,1026 @@
+//! Compile parsed JQL into parameterized DuckDB SQL over `issues_expanded`
+//!
+//! Every value from the query is passed as a positional parameter; only field
+//! expressions chosen by the compiler itself are written into the SQL text.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+
+use super::parser::{Clause, CompareOp, Condition, Expr, HistoryPredicate, JqlQuery, Operand};
+use crate::domain::entities::JiraField;
+use crate::domain::error::{DomainError, DomainResult};
+
+/// Columns returned when the caller does not choose its own
+pub const DEFAULT_COLUMNS: &str = "e.issue_key AS key, e.summary, e.status, e.assignee, \
+     e.priority, e.issue_type, e.created_date, e.updated_date";
+
+/// Values that JQL functions depend on
+#[derive(Debug, Clone)]
+pub struct JqlContext {
+    /// Reference time for relative dates and `startOfDay()`-style functions
+    pub now: DateTime<Utc>,
+    /// User that `currentUser()` stands for (email, account ID or display name)
+    pub current_user: Option<String>,
+}
+
+impl Default for JqlContext {
+    fn default() -> Self {
+        Self {
+            now: Utc::now(),
+            current_user: None,
+        }
+    }
+}
+
+impl JqlContext {
+    pub fn with_current_user(mut self, user: impl Into<String>) -> Self {
+        self.current_user = Some(user.into());
+        self
+    }
+}
+
+/// Fields a query may refer to: the JIRA field definitions and the columns of `issues_expanded`
+#[derive(Debug, Clone, Default)]
+pub struct FieldCatalog {
+    fields: Vec<JiraField>,
+    columns: HashSet<String>,
+}
+
+impl FieldCatalog {
+    pub fn new(fields: Vec<JiraField>, columns: HashSet<String>) -> Self {
+        Self {
+            fields,
+            columns: columns.into_iter().map(|c| c.to_lowercase()).collect(),
+        }
+    }
+
+    /// Resolve a JQL field name: `cf[N]`, a system field, or a field ID / name from `jira_fields`
+    fn resolve(&self, name: &str) -> DomainResult<FieldDef> {
+        let lower = name.to_lowercase();
+        if let Some(number) = lower.strip_prefix("cf[").and_then(|r| r.strip_suffix(']')) {
+            return self.custom_field(&format!("customfield_{}", number), name);
+        }
+        if let Some(def) = system_field(&lower) {
+            return Ok(def);
+        }
+
+        let field = self
+            .fields
+            .iter()
+            .find(|f| f.id.eq_ignore_ascii_case(name))
+            .or_else(|| {
+                self.fields
+                    .iter()
+                    .find(|f| f.name.eq_ignore_ascii_case(name))
+            });
+        match field {
+            Some(f) if !f.custom => system_field(&f.id.to_lowercase()).ok_or_else(|| {
+                DomainError::Validation(format!("JQL: field '{}' is not searchable", name))
+            }),
+            Some(f) => self.custom_field(&f.id, name),
+            None => Err(DomainError::Validation(format!(
+                "JQL: unknown field '{}'",
+                name
+            ))),
+        }
+    }
+
+    fn custom_field(&self, id: &str, name: &str) -> DomainResult<FieldDef> {
+        let field = self.fields.iter().find(|f| f.id.eq_ignore_ascii_case(id));
+        let column = field
+            .map(|f| f.get_safe_column_name())
+            .unwrap_or_else(|| id.to_lowercase());
+        if !self.columns.contains(&column) {
+            return Err(DomainError::Validation(format!(
+                "JQL: field '{}' has no column in issues_expanded (sync the project to expand it)",
+                name
+            )));
+        }
+
+        let column = format!("e.\"{}\"", column);
+        let (kind, expr) = match field.and_then(|f| f.schema_type.as_deref()) {
+            Some("number") => (Kind::Number, format!("TRY_CAST({} AS DOUBLE)", column)),
+            Some("date") | Some("datetime") => {
+                (Kind::Date, format!("TRY_CAST({} AS TIMESTAMPTZ)", column))
+            }
+            Some("array") => (
+                Kind::List(CUSTOM_LIST_PATHS),
+                format!("TRY_CAST({} AS JSON)", column),
+            ),
+            Some("user") => (
+                Kind::User {
+                    field_id: id.to_lowercase(),
+                },
+                column,
+            ),
+            _ => (Kind::Text, column),
+        };
+
+        Ok(FieldDef {
+            kind,
+            expr,
+            history: field.map(|f| f.name.clone()),
+        })
+    }
+}
+
+/// JSON paths tried for the elements of a custom array field (options, versions, plain strings)
+const CUSTOM_LIST_PATHS: &[&str] = &["$[*].value", "$[*].name", "$[*]"];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Text,
+    /// The `text` pseudo-field, which only supports `~`
+    FullText,
+    /// Issue key, ordered by project then number
+    Key,
+    Project,
+    /// Display name column, also matched against email and account ID in `raw_data`
+    User {
+        field_id: String,
+    },
+    /// JSON array column and the paths of its element values
+    List(&'static [&'static str]),
+    Number,
+    Date,
+}
+
+#[derive(Debug, Clone)]
+struct FieldDef {
+    kind: Kind,
+    /// SQL expression for the current value
+    expr: String,
+    /// Field name in `issue_change_history`, for `WAS` / `CHANGED`
+    history: Option<String>,
+}
+
+fn system_field(name: &str) -> Option<FieldDef> {
+    let (kind, expr, history) = match name {
+        "project" => (
+            Kind::Project,
+            "upper(split_part(e.issue_key, '-', 1))",
+            Some("project"),
+        ),
+        "key" | "issuekey" | "id" => (Kind::Key, "e.issue_key", Some("Key")),
+        "summary" => (Kind::Text, "e.summary", Some("summary")),
+        "description" => (Kind::Text, "e.description", Some("description")),
+        "environment" => (Kind::Text, "e.environment", Some("environment")),
+        "text" => (
+            Kind::FullText,
+            "concat_ws(' ', e.summary, e.description, e.environment)",
+            None,
+        ),
+        "status" => (Kind::Text, "e.status", Some("status")),
+        "priority" => (Kind::Text, "e.priority", Some("priority")),
+        "resolution" => (Kind::Text, "e.resolution", Some("resolution")),
+        "issuetype" | "type" => (Kind::Text, "e.issue_type", Some("issuetype")),
+        "sprint" => (Kind::Text, "e.sprint", Some("Sprint")),
+        "parent" => (Kind::Text, "e.parent_key", Some("Parent")),
+        "assignee" | "reporter" | "creator" => {
+            return Some(FieldDef {
+                kind: Kind::User {
+                    field_id: name.to_string(),
+                },
+                expr: format!("e.{}", name),
+                history: Some(name.to_string()),
+            });
+        }
+        "labels" => (Kind::List(&["$[*]"]), "e.labels", Some("labels")),
+        "component" | "components" => (
+            Kind::List(&["$[*].name"]),
+            "e.components",
+            Some("Component"),
+        ),
+        "fixversion" | "fixversions" => (
+            Kind::List(&["$[*].name"]),
+            "e.fix_versions",
+            Some("Fix Version"),
+        ),
+        "affectedversion" | "affectedversions" | "versions" => (
+            Kind::List(&["$[*].name"]),
+            "e.affected_versions",
+            Some("Version"),
+        ),
+        "created" | "createddate" => (Kind::Date, "e.created_date", None),
+        "updated" | "updateddate" => (Kind::Date, "e.updated_date", None),
+        "resolved" | "resolutiondate" => (Kind::Date, "e.resolved_date", None),
+        "due" | "duedate" => (Kind::Date, "e.due_date", Some("duedate")),
+        _ => return None,
+    };
+
+    Some(FieldDef {
+        kind,
+        expr: expr.to_string(),
+        history: history.map(str::to_string),
+    })
+}
+
+/// A compiled query: SQL fragments plus the positional parameters of the filter
+#[derive(Debug, Clone)]
+pub struct CompiledJql {
+    /// `WHERE` condition, without the keyword
+    pub filter_sql: String,
+    /// `ORDER BY` list, without the keyword
+    pub order_sql: String,
+    /// Values for the `?` placeholders in `filter_sql`, in order
+    pub params: Vec<String>,
+}
+
+impl CompiledJql {
+    /// Full statement selecting `columns` (over `e` = issues_expanded and `i` = issues)
+    pub fn to_sql(&self, columns: &str) -> String {
+        format!(
+            "SELECT {}\nFROM issues_expanded e\nLEFT JOIN issues i ON i.id = e.id\n\
+             WHERE (i.is_deleted IS NULL OR i.is_deleted = false) AND ({})\nORDER BY {}",
+            columns, self.filter_sql, self.order_sql
+        )
+    }
+}
+
+/// Compile a parsed query against the fields known in the database
+pub fn compile(
+    query: &JqlQuery,
+    catalog: &FieldCatalog,
+    context: &JqlContext,
+) -> DomainResult<CompiledJql> {
+    let mut compiler = Compiler {
+        catalog,
+        context,
+        params: Vec::new(),
+    };
+
+    let filter_sql = match &query.filter {
+        Some(expr) => compiler.expr(expr)?,
+        None => "true".to_string(),
+    };
+
+    let mut order = Vec::new();
+    for item in &query.order_by {
+        let def = catalog.resolve(&item.field)?;
+        let direction = if item.descending { "DESC" } else { "ASC" };
+        let exprs = match &def.kind {
+            Kind::FullText => {
+                return Err(DomainError::Validation(format!(
+                    "JQL: cannot order by '{}'",
+                    item.field
+                )));
+            }
+            Kind::Key => vec![
+                "upper(split_part(e.issue_key, '-', 1))".to_string(),
+                "TRY_CAST(split_part(e.issue_key, '-', 2) AS BIGINT)".to_string(),
+            ],
+            Kind::List(_) => vec![format!("CAST({} AS VARCHAR)", def.expr)],
+            _ => vec![def.expr.clone()],
+        };
+        order.extend(
+            exprs
+                .into_iter()
+                .map(|e| format!("{} {} NULLS LAST", e, direction)),
+        );
+    }
+    if order.is_empty() {
+        order.push("e.created_date DESC NULLS LAST".to_string());
+    }
+
+    Ok(CompiledJql {
+        filter_sql,
+        order_sql: order.join(", "),
+        params: compiler.params,
+    })
+}
+
+struct Compiler<'a> {
+    catalog: &'a FieldCatalog,
+    context: &'a JqlContext,
+    params: Vec<String>,
+}
+
+impl Compiler<'_> {
+    /// Queue a parameter and return its placeholder
+    ///
+    /// Fragments must be assembled in the order they were built so the
+    /// placeholders line up with `params`.
+    fn bind(&mut self, value: impl Into<String>) -> &'static str {
+        self.params.push(value.into());
+        "?"
+    }
+
+    fn expr(&mut self, expr: &Expr) -> DomainResult<String> {
+        Ok(match expr {
+            Expr::And(left, right) => {
+                let left = self.expr(left)?;
+                format!("({} AND {})", left, self.expr(right)?)
+            }
+            Expr::Or(left, right) => {
+                let left = self.expr(left)?;
+                format!("({} OR {})", left, self.expr(right)?)
+            }
+            Expr::Not(inner) => format!("NOT COALESCE({}, false)", self.expr(inner)?),
+            Expr::Clause(clause) => self.clause(clause)?,
+        })
+    }
+
+    fn clause(&mut self, clause: &Clause) -> DomainResult<String> {
+        let field = clause.field.as_str();
+        let def = self.catalog.resolve(field)?;
+        if def.kind == Kind::FullText && !matches!(clause.condition, Condition::Contains { .. }) {
+            return Err(DomainError::Validation(format!(
+                "JQL: '{}' only supports ~ and !~",
+                field
+            )));
+        }
+
+        match &clause.condition {
+            Condition::Compare(CompareOp::Eq, operand) => match self.value(&def, field, operand)? {
+                Some(value) => Ok(self.member(&def, &value)),
+                None => Ok(empty(&def)),
+            },
+            Condition::Compare(CompareOp::NotEq, operand) => {
+                match self.value(&def, field, operand)? {
+                    Some(value) => Ok(format!(
+                        "(NOT {} AND NOT COALESCE({}, false))",
+                        empty(&def),
+                        self.member(&def, &value)
+                    )),
+                    None => Ok(format!("NOT {}", empty(&def))),
+                }
+            }
+            Condition::Compare(op, operand) => self.ordered(&def, field, *op, operand),
+            Condition::Contains { negated, value } => self.contains(&def, field, *negated, value),
+            Condition::In { negated, values } => {
+                let mut matches = Vec::new();
+                let mut has_empty = false;
+                for operand in values {
+                    match self.value(&def, field, operand)? {
+                        Some(value) => matches.push(self.member(&def, &value)),
+                        None => has_empty = true,
+                    }
+                }
+                if has_empty {
+                    matches.push(empty(&def));
+                }
+                let any = format!("({})", matches.join(" OR "));
+                if !*negated {
+                    Ok(any)
+                } else if has_empty {
+                    Ok(format!("NOT COALESCE({}, false)", any))
+                } else {
+                    Ok(format!(
+                        "(NOT {} AND NOT COALESCE({}, false))",
+                        empty(&def),
+                        any
+                    ))
+                }
+            }
+            Condition::IsEmpty { negated } => Ok(if *negated {
+                format!("NOT {}", empty(&def))
+            } else {
+                empty(&def)
+            }),
+            Condition::Was {
+                negated,
+                values,
+                predicates,
+            } => {
+                let history = self.history(&def, field, Some(values), predicates)?;
+                // Without predicates the value held since creation counts too
+                let was = if predicates.is_empty() {
+                    let mut current = Vec::new();
+                    for operand in values {
+                        current.push(match self.value(&def, field, operand)? {
+                            Some(value) => self.member(&def, &value),
+                            None => empty(&def),
+                        });
+                    }
+                    format!("({} OR {})", history, current.join(" OR "))
+                } else {
+                    history
+                };
+                Ok(if *negated {
+                    format!("NOT COALESCE({}, false)", was)
+                } else {
+                    was
+                })
+            }
+            Condition::Changed { predicates } => self.history(&def, field, None, predicates),
+        }
+    }
+
+    /// Condition that the field currently holds `value` (case-insensitive)
+    fn member(&mut self, def: &FieldDef, value: &str) -> String {
+        let expr = &def.expr;
+        match &def.kind {
+            Kind::Key => format!("upper({}) = upper({})", expr, self.bind(value)),
+            Kind::Project => {
+                let key = self.bind(value);
+                format!(
+                    "({} = upper({}) OR e.project_id = {})",
+                    expr,
+                    key,
+                    self.bind(value)
+                )
+            }
+            Kind::User { field_id } => {
+                let raw = format!("i.raw_data->'fields'->'{}'", field_id);
+                let name = self.bind(value);
+                let email = self.bind(value);
+                format!(
+                    "(lower({}) = lower({}) OR lower({}->>'emailAddress') = lower({}) OR {}->>'accountId' = {})",
+                    expr,
+                    name,
+                    raw,
+                    email,
+                    raw,
+                    self.bind(value)
+                )
+            }
+            Kind::List(paths) => format!(
+                "EXISTS (SELECT 1 FROM (SELECT unnest({}) AS v) t WHERE lower(t.v) = lower({}))",
+                list_values(expr, paths),
+                self.bind(value)
+            ),
+            Kind::Number => format!("{} = CAST({} AS DOUBLE)", expr, self.bind(value)),
+            Kind::Date => format!("{} = CAST({} AS TIMESTAMPTZ)", expr, self.bind(value)),
+            Kind::Text | Kind::FullText => {
+                format!("lower({}) = lower({})", expr, self.bind(value))
+            }
+        }
+    }
+
+    fn ordered(
+        &mut self,
+        def: &FieldDef,
+        field: &str,
+        op: CompareOp,
+        operand: &Operand,
+    ) -> DomainResult<String> {
+        let sql_op = match op {
+            CompareOp::Gt => ">",
+            CompareOp::Gte => ">=",
+            CompareOp::Lt => "<",
+            CompareOp::Lte => "<=",
+            CompareOp::Eq | CompareOp::NotEq => unreachable!("handled as membership"),
+        };
+        let Some(value) = self.value(def, field, operand)? else {
+            return Err(DomainError::Validation(format!(
+                "JQL: cannot compare '{}' {} EMPTY",
+                field, sql_op
+            )));
+        };
+
+        match def.kind {
+            Kind::Number => Ok(format!(
+                "{} {} CAST({} AS DOUBLE)",
+                def.expr,
+                sql_op,
+                self.bind(value)
+            )),
+            Kind::Date => Ok(format!(
+                "{} {} CAST({} AS TIMESTAMPTZ)",
+                def.expr,
+                sql_op,
+                self.bind(value)
+            )),
+            Kind::Key => {
+                let project = self.bind(value.clone());
+                Ok(format!(
+                    "(upper(split_part(e.issue_key, '-', 1)) = upper(split_part({}, '-', 1)) \
+                     AND TRY_CAST(split_part(e.issue_key, '-', 2) AS BIGINT) {} \
+                     TRY_CAST(split_part({}, '-', 2) AS BIGINT))",
+                    project,
+                    sql_op,
+                    self.bind(value)
+                ))
+            }
+            _ => Err(DomainError::Validation(format!(
+                "JQL: operator {} is not supported for '{}'",
+                sql_op, field
+            ))),
+        }
+    }
+
+    fn contains(
+        &mut self,
+        def: &FieldDef,
+        field: &str,
+        negated: bool,
+        operand: &Operand,
+    ) -> DomainResult<String> {
+        if !matches!(def.kind, Kind::Text | Kind::FullText) {
+            return Err(DomainError::Validation(format!(
+                "JQL: '{}' does not support ~",
+                field
+            )));
+        }
+        let Operand::Value(text) = operand else {
+            return Err(DomainError::Validation(format!(
+                "JQL: ~ on '{}' needs a text value",
+                field
+            )));
+        };
+
+        let condition = format!(
+            "COALESCE({}, '') ILIKE {} ESCAPE '\\'",
+            def.expr,
+            self.bind(like_pattern(text))
+        );
+        Ok(if negated {
+            format!("NOT ({})", condition)
+        } else {
+            condition
+        })
+    }
+
+    /// `EXISTS` over `issue_change_history` for `WAS` (with `values`) and `CHANGED`
+    fn history(
+        &mut self,
+        def: &FieldDef,
+        field: &str,
+        values: Option<&[Operand]>,
+        predicates: &[HistoryPredicate],
+    ) -> DomainResult<String> {
+        let Some(history_field) = &def.history else {
+            return Err(DomainError::Validation(format!(
+                "JQL: WAS and CHANGED are not supported for '{}'",
+                field
+            )));
+        };
+
+        let mut conditions = vec![
+            "h.issue_id = e.id".to_string(),
+            format!(
+                "lower(h.field) = lower({})",
+                self.bind(history_field.as_str())
+            ),
+        ];
+
+        if let Some(values) = values {
+            let mut any = Vec::new();
+            for operand in values {
+                let value = self.history_value(operand)?;
+                let from = self.changed_side("from", value.as_deref());
+                let to = self.changed_side("to", value.as_deref());
+                any.push(format!("{} OR {}", from, to));
+            }
+            conditions.push(format!("({})", any.join(" OR ")));
+        }
+
+        for predicate in predicates {
+            let condition = match predicate {
+                HistoryPredicate::From(operand) => {
+                    let value = self.history_value(operand)?;
+                    self.changed_side("from", value.as_deref())
+                }
+                HistoryPredicate::To(operand) => {
+                    let value = self.history_value(operand)?;
+                    self.changed_side("to", value.as_deref())
+                }
+                HistoryPredicate::By(operand) => {
+                    let Some(user) = self.history_value(operand)? else {
+                        return Err(DomainError::Validation("JQL: BY needs a user".to_string()));
+                    };
+                    let name = self.bind(user.clone());
+                    format!(
+                        "(lower(h.author_display_name) = lower({}) OR h.author_account_id = {})",
+                        name,
+                        self.bind(user)
+                    )
+                }
+                HistoryPredicate::After(operand) => {
+                    let at = self.date(operand)?;
+                    format!("h.changed_at > {}", self.bind_instant(at))
+                }
+                HistoryPredicate::Before(operand) => {
+                    let at = self.date(operand)?;
+                    format!("h.changed_at < {}", self.bind_instant(at))
+                }
+                HistoryPredicate::On(operand) => {
+                    let day = start_of_day(self.date(operand)?);
+                    let start = self.bind_instant(day);
+                    format!(
+                        "h.changed_at >= {} AND h.changed_at < {}",
+                        start,
+                        self.bind_instant(day + TimeDelta::days(1))
+                    )
+                }
+                HistoryPredicate::During(from, to) => {
+                    let (from, to) = (self.date(from)?, self.date(to)?);
+                    let start = self.bind_instant(from);
+                    format!(
+                        "h.changed_at BETWEEN {} AND {}",
+                        start,
+                        self.bind_instant(to)
+                    )
+                }
+            };
+            conditions.push(condition);
+        }
+
+        Ok(format!(
+            "EXISTS (SELECT 1 FROM issue_change_history h WHERE {})",
+            conditions.join(" AND ")
+        ))
+    }
+
+    /// Match one side (`from` / `to`) of a change against a display string or raw value
+    fn changed_side(&mut self, side: &str, value: Option<&str>) -> String {
+        match value {
+            Some(value) => {
+                let display = self.bind(value);
+                format!(
+                    "(lower(h.{side}_string) = lower({}) OR h.{side}_value = {})",
+                    display,
+                    self.bind(value)
+                )
+            }
+            None => format!("(h.{side}_string IS NULL AND h.{side}_value IS NULL)"),
+        }
+    }
+
+    fn bind_instant(&mut self, at: DateTime<Utc>) -> String {
+        format!("CAST({} AS TIMESTAMPTZ)", self.bind(at.to_rfc3339()))
+    }
+
+    /// Operand as a parameter for the field's current value; `None` for `EMPTY`
+    fn value(
+        &self,
+        def: &FieldDef,
+        field: &str,
+        operand: &Operand,
+    ) -> DomainResult<Option<String>> {
+        match def.kind {
+            Kind::Date => {
+                if *operand == Operand::Empty {
+                    return Ok(None);
+                }
+                let at = self.date(operand)?;
+                Ok(Some(at.to_rfc3339()))
+            }
+            Kind::Number => match operand {
+                Operand::Value(v) if v.parse::<f64>().is_err() => Err(DomainError::Validation(
+                    format!("JQL: '{}' is not a number (field '{}')", v, field),
+                )),
+                _ => self.history_value(operand),
+            },
+            _ => self.history_value(operand),
+        }
+    }
+
+    /// Operand as plain text: values as written, `currentUser()` as the configured user
+    fn history_value(&self, operand: &Operand) -> DomainResult<Option<String>> {
+        match operand {
+            Operand::Empty => Ok(None),
+            Operand::Value(v) => Ok(Some(v.clone())),
+            Operand::Function { name, .. } if name.eq_ignore_ascii_case("currentUser") => {
+                self.context.current_user.clone().map(Some).ok_or_else(|| {
+                    DomainError::Validation(
+                        "JQL: currentUser() needs a configured JIRA username".to_string(),
+                    )
+                })
+            }
+            Operand::Function { name, .. } => Err(DomainError::Validation(format!(
+                "JQL: {}() cannot be used here",
+                name
+            ))),
+        }
+    }
+
+    /// Operand as a point in time: absolute date, relative offset or date function
+    fn date(&self, operand: &Operand) -> DomainResult<DateTime<Utc>> {
+        match operand {
+            Operand::Value(value) => parse_date(value, self.context.now),
+            Operand::Function { name, args } => date_function(name, args, self.context.now),
+            Operand::Empty => Err(DomainError::Validation(
+                "JQL: EMPTY is not a date".to_string(),
+            )),
+        }
+    }
+}
+
+fn empty(def: &FieldDef) -> String {
+    match def.kind {
+        Kind::List(_) => format!(
+            "({} IS NULL OR json_array_length({}) = 0)",
+            def.expr, def.expr
+        ),
+        Kind::Number | Kind::Date => format!("({} IS NULL)", def.expr),
+        _ => format!("(NULLIF(trim({}), '') IS NULL)", def.expr),
+    }
+}
+
+/// Element values of a JSON array column as a `VARCHAR[]`
+fn list_values(expr: &str, paths: &[&str]) -> String {
+    paths
+        .iter()
+        .map(|path| format!("json_extract_string({}, '{}')", expr, path))
+        .reduce(|acc, next| format!("list_concat({}, {})", acc, next))
+        .unwrap_or_default()
+}
+
+/// `~` pattern: substring match, with `*` as a wildcard and SQL wildcards escaped
+fn like_pattern(text: &str) -> String {
+    let mut pattern = String::from("%");
+    for c in text.chars() {
+        match c {
+            '\\' | '%' | '_' => {
+                pattern.push('\\');
+                pattern.push(c);
+            }
+            '*' => pattern.push('%'),
+            _ => pattern.push(c),
+        }
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// Absolute (`2024-01-31`, `2024/01/31 14:00`, RFC 3339) or relative (`-7d`, `-1w 2d`) date, in UTC
+fn parse_date(value: &str, now: DateTime<Utc>) -> DomainResult<DateTime<Utc>> {
+    let value = value.trim();
+    if let Some(at) = shift(now, value, None) {
+        return Ok(at);
+    }
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M", "%Y/%m/%d %H:%M", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(at) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(at.and_utc());
+        }
+    }
+    for format in ["%Y-%m-%d", "%Y/%m/%d"] {
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            return Ok(date.and_time(Default::default()).and_utc());
+        }
+    }
+    Err(DomainError::Validation(format!(
+        "JQL: invalid date '{}'",
+        value
+    )))
+}
+
+/// `now()`, `startOfDay()`, `endOfWeek(-1)`, `startOfMonth("+1d")`, ...
+///
+/// Weeks start on Monday. A bare number argument counts in the function's own unit.
+fn date_function(name: &str, args: &[String], now: DateTime<Utc>) -> DomainResult<DateTime<Utc>> {
+    let lower = name.to_lowercase();
+    if lower == "now" {
+        return Ok(now);
+    }
+
+    let today = start_of_day(now);
+    let period = lower
+        .strip_prefix("start")
+        .or_else(|| lower.strip_prefix("end"))
+        .unwrap_or_default();
+    let (start, unit) = match period {
+        "ofday" => (today, 'd'),
+        "ofweek" => (
+            today - TimeDelta::days(i64::from(now.weekday().num_days_from_monday())),
+            'w',
+        ),
+        "ofmonth" => (today - TimeDelta::days(i64::from(now.day0())), 'M'),
+        "ofyear" => (today - TimeDelta::days(i64::from(now.ordinal0())), 'y'),
+        _ => {
+            return Err(DomainError::Validation(format!(
+                "JQL: unsupported function {}()",
+                name
+            )));
+        }
+    };
+
+    let invalid = |arg: &str| {
+        DomainError::Validation(format!("JQL: invalid offset '{}' for {}()", arg, name))
+    };
+    let start = match args.first() {
+        Some(arg) => shift(start, arg, Some(unit)).ok_or_else(|| invalid(arg))?,
+        None => start,
+    };
+
+    if lower.starts_with("end") {
+        let next = shift(start, "1", Some(unit)).ok_or_else(|| invalid("1"))?;
+        Ok(next - TimeDelta::milliseconds(1))
+    } else {
+        Ok(start)
+    }
+}
+
+fn start_of_day(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.date_naive().and_time(Default::default()).and_utc()
+}
+
+/// Apply a relative offset such as `-7d`, `+1w2d` or `-1w 3h`
+///
+/// Units are `y`, `M`, `w`, `d`, `h` and `m`; a trailing bare number uses `default_unit`.
+/// Returns `None` when `spec` is not an offset.
+fn shift(base: DateTime<Utc>, spec: &str, default_unit: Option<char>) -> Option<DateTime<Utc>> {
+    let spec: String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    let (negative, body) = match spec.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, spec.strip_prefix('+').unwrap_or(&spec)),
+    };
+    if body.is_empty() {
+        return None;
+    }
+
+    let mut parts = Vec::new();
+    let mut amount = String::new();
+    for c in body.chars() {
+        if c.is_ascii_digit() {
+            amount.push(c);
+            continue;
+        }
+        if amount.is_empty() {
+            return None;
+        }
+        parts.push((amount.parse::<i64>().ok()?, c));
+        amount.clear();
+    }
+    if !amount.is_empty() {
+        parts.push((amount.parse::<i64>().ok()?, default_unit?));
+    }
+
+    let mut at = base;
+    for (amount, unit) in parts {
+        let amount = if negative { -amount } else { amount };
+        at = match unit {
+            'y' => add_months(at, amount.checked_mul(12)?)?,
+            'M' => add_months(at, amount)?,
+            'w' => at.checked_add_signed(TimeDelta::try_weeks(amount)?)?,
+            'd' => at.checked_add_signed(TimeDelta::try_days(amount)?)?,
+            'h' => at.checked_add_signed(TimeDelta::try_hours(amount)?)?,
+            'm' => at.checked_add_signed(TimeDelta::try_minutes(amount)?)?,
+            _ => return None,
+        };
+    }
+    Some(at)
+}
+
+fn add_months(at: DateTime<Utc>, months: i64) -> Option<DateTime<Utc>> {
+    let delta = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if months >= 0 {
+        at.checked_add_months(delta)
+    } else {
+        at.checked_sub_months(delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parser::parse;
+    use super::*;
+    use chrono::TimeZone;
+
+    fn field(id: &str, name: &str, schema_type: &str) -> JiraField {
+        JiraField {
+            id: id.to_string(),
+            key: id.to_string(),
+            name: name.to_string(),
+            custom: id.starts_with("customfield_"),
+            searchable: true,
+            navigable: true,
+            orderable: true,
+            schema_type: Some(schema_type.to_string()),
+            schema_items: None,
+            schema_system: None,
+            schema_custom: None,
+            schema_custom_id: None,
+        }
+    }
+
+    fn catalog() -> FieldCatalog {
+        FieldCatalog::new(
+            vec![
+                field("customfield_10016", "Story Points", "number"),
+                field("customfield_10020", "Team", "option"),
+                field("fixVersions", "Fix versions", "array"),
+            ],
+            ["customfield_10016", "customfield_10020"]
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    fn context() -> JqlContext {
+        // Wednesday
+        JqlContext {
+            now: Utc.with_ymd_and_hms(2024, 5, 15, 10, 30, 0).unwrap(),
+            current_user: None,
+        }
+    }
+
+    fn compile_str(jql: &str, context: &JqlContext) -> DomainResult<CompiledJql> {
+        compile(&parse(jql)?, &catalog(), context)
+    }
+
+    #[test]
+    fn test_compile_binds_values_as_params() {
+        let compiled = compile_str(
+            "project = PROJ AND status IN (Open, 'In Progress') AND summary ~ \"50%\" ORDER BY key DESC",
+            &context(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            compiled.params,
+            vec!["PROJ", "PROJ", "Open", "In Progress", "%50\\%%"]
+        );
+        assert_eq!(
+            compiled.filter_sql.matches('?').count(),
+            compiled.params.len()
+        );
+        assert!(!compiled.filter_sql.contains("PROJ"));
+        assert!(
+            compiled
+                .order_sql
+                .starts_with("upper(split_part(e.issue_key")
+        );
+        assert!(compiled.order_sql.contains("AS BIGINT) DESC NULLS LAST"));
+    }
+
+    #[test]
+    fn test_compile_resolves_custom_fields_by_name() {
+        let compiled =
+            compile_str("\"Story Points\" >= 3 AND cf[10020] = Platform", &context()).unwrap();
+        assert!(
+            compiled
+                .filter_sql
+                .contains("TRY_CAST(e.\"customfield_10016\" AS DOUBLE) >= CAST(? AS DOUBLE)")
+        );
+        assert!(
+            compiled
+                .filter_sql
+                .contains("lower(e.\"customfield_10020\") = lower(?)")
+        );
+
+        // System fields found through jira_fields by display name
+        let compiled = compile_str("\"Fix versions\" = '1.0'", &context()).unwrap();
+        assert!(compiled.filter_sql.contains("e.fix_versions"));
+
+        assert!(compile_str("\"Story Points\" = many", &context()).is_err());
+        assert!(compile_str("nosuchfield = 1", &context()).is_err());
+    }
+
+    #[test]
+    fn test_compile_history_and_current_user() {
+        assert!(compile_str("assignee = currentUser()", &context()).is_err());
+
+        let context = context().with_current_user("me@example.com");
+        let compiled = compile_str(
+            "status WAS Done BY currentUser() AND status CHANGED ON 2024-05-01",
+            &context,
+        )
+        .unwrap();
+        assert_eq!(
+            compiled.filter_sql.matches("issue_change_history").count(),
+            2
+        );
+        assert_eq!(
+            compiled.params,
+            vec![
+                "status",
+                "Done",
+                "Done",
+                "Done",
+                "Done",
+                "me@example.com",
+                "me@example.com",
+                "status",
+                "2024-05-01T00:00:00+00:00",
+                "2024-05-02T00:00:00+00:00",
+            ]
+        );
+        assert!(compile_str("created WAS 2024-01-01", &context).is_err());
+    }
+
+    #[test]
+    fn test_relative_dates_and_functions() {
+        let now = context().now;
+        let at = |jql: &str| {
+            let compiled = compile_str(jql, &context()).unwrap();
+            compiled.params[0].clone()
+        };
+
+        assert_eq!(at("created >= -7d"), "2024-05-08T10:30:00+00:00");
+        assert_eq!(at("created >= '-1w 2d'"), "2024-05-06T10:30:00+00:00");
+        assert_eq!(at("created >= startOfWeek()"), "2024-05-13T00:00:00+00:00");
+        assert_eq!(
+            at("created >= startOfWeek(-1)"),
+            "2024-05-06T00:00:00+00:00"
+        );
+        assert_eq!(
+            at("created <= endOfMonth()"),
+            "2024-05-31T23:59:59.999+00:00"
+        );
+        assert_eq!(at("created >= startOfYear()"), "2024-01-01T00:00:00+00:00");
+        assert_eq!(at("due < '2024/06/01 12:00'"), "2024-06-01T12:00:00+00:00");
+        assert_eq!(
+            date_function("startOfDay", &["+1d".to_string()], now).unwrap(),
+            Utc.with_ymd_and_hms(2024, 5, 16, 0, 0, 0).unwrap()
+        );
+        assert!(compile_str("created > yesterday", &context()).is_err());
+        assert!(compile_str("created > startOfDecade()", &context()).is_err());
+    }
+}
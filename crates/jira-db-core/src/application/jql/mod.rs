@@ -0,0 +1,14 @@
+//! Local JQL engine
+//!
+//! Parses JQL and compiles it into parameterized DuckDB SQL over `issues_expanded`,
+//! so saved JIRA filters can be run against the synced data without calling JIRA.
+//! Custom fields are resolved by name through `jira_fields`, and `WAS` / `CHANGED`
+//! are answered from `issue_change_history`.
+
+mod compiler;
+mod parser;
+
+pub use compiler::{CompiledJql, DEFAULT_COLUMNS, FieldCatalog, JqlContext, compile};
+pub use parser::{
+    Clause, CompareOp, Condition, Expr, HistoryPredicate, JqlQuery, Operand, OrderBy, parse,
+};
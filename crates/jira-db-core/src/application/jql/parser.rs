@@ -0,0 +1,624 @@
+//! JQL tokenizer and parser
+
+use crate::domain::error::{DomainError, DomainResult};
+
+/// A parsed JQL query
+#[derive(Debug, Clone, PartialEq)]
+pub struct JqlQuery {
+    pub filter: Option<Expr>,
+    pub order_by: Vec<OrderBy>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBy {
+    pub field: String,
+    pub descending: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Clause(Clause),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clause {
+    pub field: String,
+    pub condition: Condition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    NotEq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// `=`, `!=`, `>`, `>=`, `<`, `<=`
+    Compare(CompareOp, Operand),
+    /// `~` / `!~`
+    Contains { negated: bool, value: Operand },
+    /// `IN` / `NOT IN`
+    In { negated: bool, values: Vec<Operand> },
+    /// `IS EMPTY` / `IS NOT EMPTY`
+    IsEmpty { negated: bool },
+    /// `WAS`, `WAS NOT`, `WAS IN`, `WAS NOT IN`
+    Was {
+        negated: bool,
+        values: Vec<Operand>,
+        predicates: Vec<HistoryPredicate>,
+    },
+    /// `CHANGED`
+    Changed { predicates: Vec<HistoryPredicate> },
+}
+
+/// `FROM`, `TO`, `BY`, `AFTER`, `BEFORE`, `ON` and `DURING` of `WAS` / `CHANGED`
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryPredicate {
+    From(Operand),
+    To(Operand),
+    By(Operand),
+    After(Operand),
+    Before(Operand),
+    On(Operand),
+    During(Operand, Operand),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Value(String),
+    Empty,
+    Function { name: String, args: Vec<String> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Unquoted word: field names, keywords, values like `PROJ-1` or `-7d`
+    Word(String),
+    /// Quoted string
+    Str(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(w) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Token::Word(w) => w.clone(),
+            Token::Str(s) => format!("\"{}\"", s),
+            Token::Op(op) => op.to_string(),
+            Token::LParen => "(".to_string(),
+            Token::RParen => ")".to_string(),
+            Token::Comma => ",".to_string(),
+        }
+    }
+}
+
+/// Words with a meaning of their own, which must be quoted to be used as values
+const RESERVED: &[&str] = &[
+    "and", "or", "not", "in", "is", "was", "changed", "order", "by", "empty", "null",
+];
+
+fn tokenize(input: &str) -> DomainResult<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '"' | '\'' => {
+                let quote = c;
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => {
+                            return Err(DomainError::Validation(
+                                "JQL: unterminated string".to_string(),
+                            ));
+                        }
+                        Some('\\') if i + 1 < chars.len() => {
+                            value.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some(&ch) if ch == quote => {
+                            i += 1;
+                            break;
+                        }
+                        Some(&ch) => {
+                            value.push(ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            '!' | '>' | '<' | '=' | '~' => {
+                let next = chars.get(i + 1).copied();
+                let op = match (c, next) {
+                    ('!', Some('=')) => "!=",
+                    ('!', Some('~')) => "!~",
+                    ('!', _) => "!",
+                    ('>', Some('=')) => ">=",
+                    ('<', Some('=')) => "<=",
+                    ('>', _) => ">",
+                    ('<', _) => "<",
+                    ('=', _) => "=",
+                    _ => "~",
+                };
+                tokens.push(Token::Op(op));
+                i += op.len();
+            }
+            _ => {
+                let start = i;
+                while i < chars.len() {
+                    let ch = chars[i];
+                    if ch.is_whitespace() || "()\",'=!<>~".contains(ch) {
+                        break;
+                    }
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Parse a JQL query
+pub fn parse(input: &str) -> DomainResult<JqlQuery> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+    };
+
+    let filter = if parser.peek().is_none() || parser.peek_keyword("order") {
+        None
+    } else {
+        Some(parser.parse_or()?)
+    };
+
+    let mut order_by = Vec::new();
+    if parser.eat_keyword("order") {
+        parser.expect_keyword("by")?;
+        loop {
+            let field = parser.expect_name()?;
+            let descending = if parser.eat_keyword("desc") {
+                true
+            } else {
+                parser.eat_keyword("asc");
+                false
+            };
+            order_by.push(OrderBy { field, descending });
+            if !parser.eat(&Token::Comma) {
+                break;
+            }
+        }
+    }
+
+    if let Some(token) = parser.peek() {
+        return Err(DomainError::Validation(format!(
+            "JQL: unexpected '{}'",
+            token.describe()
+        )));
+    }
+
+    Ok(JqlQuery { filter, order_by })
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        self.peek().is_some_and(|t| t.is_keyword(keyword))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek_keyword(keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> DomainResult<()> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&keyword.to_uppercase()))
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> DomainError {
+        match self.peek() {
+            Some(token) => DomainError::Validation(format!(
+                "JQL: expected {} but found '{}'",
+                expected,
+                token.describe()
+            )),
+            None => {
+                DomainError::Validation(format!("JQL: expected {} but the query ended", expected))
+            }
+        }
+    }
+
+    fn parse_or(&mut self) -> DomainResult<Expr> {
+        let mut left = self.parse_and()?;
+        while self.eat_keyword("or") {
+            let right = self.parse_and()?;
+            left = Expr::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> DomainResult<Expr> {
+        let mut left = self.parse_not()?;
+        while self.eat_keyword("and") {
+            let right = self.parse_not()?;
+            left = Expr::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> DomainResult<Expr> {
+        if self.eat_keyword("not") || self.eat(&Token::Op("!")) {
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+        if self.eat(&Token::LParen) {
+            let expr = self.parse_or()?;
+            if !self.eat(&Token::RParen) {
+                return Err(self.unexpected("')'"));
+            }
+            return Ok(expr);
+        }
+        Ok(Expr::Clause(self.parse_clause()?))
+    }
+
+    /// Field name: a word (possibly `cf[10010]`) or a quoted custom field name
+    fn expect_name(&mut self) -> DomainResult<String> {
+        match self.next() {
+            Some(Token::Word(w)) if !RESERVED.contains(&w.to_lowercase().as_str()) => Ok(w),
+            Some(Token::Str(s)) => Ok(s),
+            _ => {
+                self.pos -= 1;
+                Err(self.unexpected("a field name"))
+            }
+        }
+    }
+
+    fn parse_clause(&mut self) -> DomainResult<Clause> {
+        let field = self.expect_name()?;
+
+        let condition = match self.next() {
+            Some(Token::Op(op)) => match op {
+                "~" | "!~" => Condition::Contains {
+                    negated: op == "!~",
+                    value: self.parse_operand()?,
+                },
+                "=" => Condition::Compare(CompareOp::Eq, self.parse_operand()?),
+                "!=" => Condition::Compare(CompareOp::NotEq, self.parse_operand()?),
+                ">" => Condition::Compare(CompareOp::Gt, self.parse_operand()?),
+                ">=" => Condition::Compare(CompareOp::Gte, self.parse_operand()?),
+                "<" => Condition::Compare(CompareOp::Lt, self.parse_operand()?),
+                "<=" => Condition::Compare(CompareOp::Lte, self.parse_operand()?),
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.unexpected(&format!("an operator after '{}'", field)));
+                }
+            },
+            Some(token) if token.is_keyword("in") => Condition::In {
+                negated: false,
+                values: self.parse_list()?,
+            },
+            Some(token) if token.is_keyword("not") => {
+                self.expect_keyword("in")?;
+                Condition::In {
+                    negated: true,
+                    values: self.parse_list()?,
+                }
+            }
+            Some(token) if token.is_keyword("is") => {
+                let negated = self.eat_keyword("not");
+                if !(self.eat_keyword("empty") || self.eat_keyword("null")) {
+                    return Err(self.unexpected("EMPTY"));
+                }
+                Condition::IsEmpty { negated }
+            }
+            Some(token) if token.is_keyword("was") => {
+                let negated = self.eat_keyword("not");
+                let values = if self.eat_keyword("in") {
+                    self.parse_list()?
+                } else {
+                    vec![self.parse_operand()?]
+                };
+                Condition::Was {
+                    negated,
+                    values,
+                    predicates: self.parse_predicates()?,
+                }
+            }
+            Some(token) if token.is_keyword("changed") => Condition::Changed {
+                predicates: self.parse_predicates()?,
+            },
+            _ => {
+                self.pos -= 1;
+                return Err(self.unexpected(&format!("an operator after '{}'", field)));
+            }
+        };
+
+        Ok(Clause { field, condition })
+    }
+
+    fn parse_operand(&mut self) -> DomainResult<Operand> {
+        match self.next() {
+            Some(Token::Str(s)) => Ok(Operand::Value(s)),
+            Some(Token::Word(w)) => {
+                if w.eq_ignore_ascii_case("empty") || w.eq_ignore_ascii_case("null") {
+                    return Ok(Operand::Empty);
+                }
+                if self.eat(&Token::LParen) {
+                    let mut args = Vec::new();
+                    while !self.eat(&Token::RParen) {
+                        match self.next() {
+                            Some(Token::Word(a)) | Some(Token::Str(a)) => args.push(a),
+                            Some(Token::Comma) => {}
+                            _ => {
+                                self.pos -= 1;
+                                return Err(self.unexpected("')'"));
+                            }
+                        }
+                    }
+                    return Ok(Operand::Function { name: w, args });
+                }
+                if RESERVED.contains(&w.to_lowercase().as_str()) {
+                    self.pos -= 1;
+                    return Err(self.unexpected("a value"));
+                }
+                Ok(Operand::Value(w))
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.unexpected("a value"))
+            }
+        }
+    }
+
+    fn parse_list(&mut self) -> DomainResult<Vec<Operand>> {
+        if !self.eat(&Token::LParen) {
+            return Err(self.unexpected("'('"));
+        }
+        let mut values = vec![self.parse_operand()?];
+        while self.eat(&Token::Comma) {
+            values.push(self.parse_operand()?);
+        }
+        if !self.eat(&Token::RParen) {
+            return Err(self.unexpected("')'"));
+        }
+        Ok(values)
+    }
+
+    fn parse_predicates(&mut self) -> DomainResult<Vec<HistoryPredicate>> {
+        let mut predicates = Vec::new();
+        loop {
+            let predicate = if self.eat_keyword("from") {
+                HistoryPredicate::From(self.parse_operand()?)
+            } else if self.eat_keyword("to") {
+                HistoryPredicate::To(self.parse_operand()?)
+            } else if self.eat_keyword("by") {
+                HistoryPredicate::By(self.parse_operand()?)
+            } else if self.eat_keyword("after") {
+                HistoryPredicate::After(self.parse_operand()?)
+            } else if self.eat_keyword("before") {
+                HistoryPredicate::Before(self.parse_operand()?)
+            } else if self.eat_keyword("on") {
+                HistoryPredicate::On(self.parse_operand()?)
+            } else if self.eat_keyword("during") {
+                let range = self.parse_list()?;
+                let [start, end]: [Operand; 2] = range.try_into().map_err(|_| {
+                    DomainError::Validation("JQL: DURING takes two dates".to_string())
+                })?;
+                HistoryPredicate::During(start, end)
+            } else {
+                break;
+            };
+            predicates.push(predicate);
+        }
+        Ok(predicates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clause(field: &str, condition: Condition) -> Expr {
+        Expr::Clause(Clause {
+            field: field.to_string(),
+            condition,
+        })
+    }
+
+    fn value(v: &str) -> Operand {
+        Operand::Value(v.to_string())
+    }
+
+    #[test]
+    fn test_parse_precedence_and_order_by() {
+        let query = parse(
+            r#"project = PROJ AND (status = "In Progress" OR assignee IS EMPTY) ORDER BY created DESC, key"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            query.filter.unwrap(),
+            Expr::And(
+                Box::new(clause(
+                    "project",
+                    Condition::Compare(CompareOp::Eq, value("PROJ"))
+                )),
+                Box::new(Expr::Or(
+                    Box::new(clause(
+                        "status",
+                        Condition::Compare(CompareOp::Eq, value("In Progress"))
+                    )),
+                    Box::new(clause("assignee", Condition::IsEmpty { negated: false })),
+                )),
+            )
+        );
+        assert_eq!(
+            query.order_by,
+            vec![
+                OrderBy {
+                    field: "created".to_string(),
+                    descending: true
+                },
+                OrderBy {
+                    field: "key".to_string(),
+                    descending: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_operators() {
+        let query =
+            parse("updated >= -7d and labels not in (a, 'b c') and summary !~ crash").unwrap();
+        let Some(Expr::And(left, right)) = query.filter else {
+            panic!("expected AND");
+        };
+        assert_eq!(
+            *right,
+            clause(
+                "summary",
+                Condition::Contains {
+                    negated: true,
+                    value: value("crash")
+                }
+            )
+        );
+        let Expr::And(first, second) = *left else {
+            panic!("expected AND");
+        };
+        assert_eq!(
+            *first,
+            clause("updated", Condition::Compare(CompareOp::Gte, value("-7d")))
+        );
+        assert_eq!(
+            *second,
+            clause(
+                "labels",
+                Condition::In {
+                    negated: true,
+                    values: vec![value("a"), value("b c")]
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_history_and_functions() {
+        let query = parse(
+            "status WAS NOT IN (Open, Done) BY currentUser() AFTER startOfWeek(-1) \
+             AND assignee CHANGED FROM EMPTY DURING ('2024-01-01', '2024-02-01')",
+        )
+        .unwrap();
+        let Some(Expr::And(was, changed)) = query.filter else {
+            panic!("expected AND");
+        };
+        assert_eq!(
+            *was,
+            clause(
+                "status",
+                Condition::Was {
+                    negated: true,
+                    values: vec![value("Open"), value("Done")],
+                    predicates: vec![
+                        HistoryPredicate::By(Operand::Function {
+                            name: "currentUser".to_string(),
+                            args: vec![]
+                        }),
+                        HistoryPredicate::After(Operand::Function {
+                            name: "startOfWeek".to_string(),
+                            args: vec!["-1".to_string()]
+                        }),
+                    ],
+                }
+            )
+        );
+        assert_eq!(
+            *changed,
+            clause(
+                "assignee",
+                Condition::Changed {
+                    predicates: vec![
+                        HistoryPredicate::From(Operand::Empty),
+                        HistoryPredicate::During(value("2024-01-01"), value("2024-02-01")),
+                    ]
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("status =").is_err());
+        assert!(parse("status = Open AND").is_err());
+        assert!(parse("(status = Open").is_err());
+        assert!(parse("summary ~ \"unterminated").is_err());
+        assert!(parse("status Open").is_err());
+        assert!(parse("").unwrap().filter.is_none());
+        assert_eq!(parse("ORDER BY key").unwrap().order_by.len(), 1);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod dto;
+pub mod jql;
 pub mod services;
 pub mod use_cases;
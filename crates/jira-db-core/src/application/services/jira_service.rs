@@ -1,6 +1,7 @@
-use crate::application::dto::{CreatedIssueDto, TransitionDto};
+use crate::application::dto::{CreatedIssueDto, IssueFieldUpdate, TransitionDto};
 use crate::domain::entities::{
-    Component, FixVersion, Issue, IssueType, JiraField, Label, Priority, Project, Status,
+    Component, FieldOption, FixVersion, Issue, IssueType, JiraField, Label, Priority, Project,
+    Status, Workflow,
 };
 use crate::domain::error::DomainResult;
 use async_trait::async_trait;
@@ -50,6 +51,9 @@ pub trait JiraService: Send + Sync {
         max_results: usize,
     ) -> DomainResult<FetchProgress>;
 
+    /// Fetch a single issue by key (with changelog)
+    async fn fetch_issue(&self, issue_key: &str) -> DomainResult<Issue>;
+
     /// Test connection to JIRA
     async fn test_connection(&self) -> DomainResult<()>;
 
@@ -123,4 +127,25 @@ pub trait JiraService: Send + Sync {
     /// * `issue_key` - The issue key to update
     /// * `due_date` - The due date in YYYY-MM-DD format
     async fn update_issue_due_date(&self, issue_key: &str, due_date: &str) -> DomainResult<()>;
+
+    /// Add a plain-text comment to an issue
+    async fn add_comment(&self, issue_key: &str, body: &str) -> DomainResult<()>;
+
+    /// Edit issue fields (values must already be in JIRA's API format)
+    async fn update_issue_fields(
+        &self,
+        issue_key: &str,
+        update: &IssueFieldUpdate,
+    ) -> DomainResult<()>;
+
+    /// Fetch the workflows of a project's workflow scheme, with statuses and transitions
+    async fn fetch_project_workflows(&self, project_id: &str) -> DomainResult<Vec<Workflow>>;
+
+    /// Fetch the allowed values of select, multi-select and cascading select custom fields
+    /// used by the project's create screens
+    async fn fetch_field_options(
+        &self,
+        project_key: &str,
+        project_id: &str,
+    ) -> DomainResult<Vec<FieldOption>>;
 }
@@ -0,0 +1,512 @@
+//! Bulk edit use case
+//!
+//! Selects issues with a SQL predicate over the local `issues` table, previews
+//! the changes per issue and applies one validated edit to all of them in JIRA.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use duckdb::Connection;
+use log::{info, warn};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{ExecuteSqlUseCase, UpdateIssueFieldsUseCase};
+use crate::application::dto::{FieldEdit, IssueFieldUpdate};
+use crate::application::services::JiraService;
+use crate::domain::entities::Issue;
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{IssueRepository, MetadataRepository};
+use crate::infrastructure::config::{BulkEditCheckpoint, BulkEditFailure};
+use crate::infrastructure::database::DuckDbFieldRepository;
+
+/// Change of a single field on a single issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Preview of the changes a bulk edit makes to one issue
+#[derive(Debug, Clone)]
+pub struct IssueChangePreview {
+    pub issue_key: String,
+    pub summary: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Preview of a whole bulk edit
+#[derive(Debug, Clone)]
+pub struct BulkEditPreview {
+    /// Validated update applied to every issue
+    pub update: IssueFieldUpdate,
+    pub issues: Vec<IssueChangePreview>,
+}
+
+impl BulkEditPreview {
+    /// Issues that would actually change
+    pub fn changed_issue_keys(&self) -> Vec<String> {
+        self.issues
+            .iter()
+            .filter(|i| !i.changes.is_empty())
+            .map(|i| i.issue_key.clone())
+            .collect()
+    }
+
+    /// Create a checkpoint covering the issues that would change
+    pub fn to_checkpoint(&self, predicate: &str) -> BulkEditCheckpoint {
+        BulkEditCheckpoint {
+            predicate: predicate.to_string(),
+            payload: self.update.to_request_body(),
+            issue_keys: self.changed_issue_keys(),
+            items_processed: 0,
+            failures: Vec::new(),
+            started_at: Utc::now(),
+        }
+    }
+}
+
+/// Result for one issue in the bulk edit report
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkEditIssueResult {
+    pub issue_key: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Final report of a bulk edit
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkEditReport {
+    pub predicate: String,
+    pub payload: Value,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkEditIssueResult>,
+}
+
+impl BulkEditReport {
+    /// Build the report from a (completed) checkpoint
+    pub fn from_checkpoint(checkpoint: &BulkEditCheckpoint) -> Self {
+        let results: Vec<BulkEditIssueResult> = checkpoint
+            .issue_keys
+            .iter()
+            .take(checkpoint.items_processed)
+            .map(|key| {
+                let failure = checkpoint.failures.iter().find(|f| &f.issue_key == key);
+                BulkEditIssueResult {
+                    issue_key: key.clone(),
+                    success: failure.is_none(),
+                    error: failure.map(|f| f.error.clone()),
+                }
+            })
+            .collect();
+        let failed = results.iter().filter(|r| !r.success).count();
+
+        Self {
+            predicate: checkpoint.predicate.clone(),
+            payload: checkpoint.payload.clone(),
+            started_at: checkpoint.started_at,
+            finished_at: Utc::now(),
+            total: checkpoint.issue_keys.len(),
+            succeeded: results.len() - failed,
+            failed,
+            results,
+        }
+    }
+
+    /// Write the report as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> DomainResult<()> {
+        let content = serde_json::to_string_pretty(self).map_err(|e| {
+            DomainError::Repository(format!("Failed to serialize bulk edit report: {}", e))
+        })?;
+        std::fs::write(path, content).map_err(|e| {
+            DomainError::Repository(format!("Failed to write report {}: {}", path.display(), e))
+        })
+    }
+}
+
+/// Use case for editing many issues at once
+pub struct BulkEditUseCase<I, M, J>
+where
+    I: IssueRepository,
+    M: MetadataRepository,
+    J: JiraService,
+{
+    issue_repository: Arc<I>,
+    jira_service: Arc<J>,
+    update_use_case: UpdateIssueFieldsUseCase<I, M, J>,
+    sql_use_case: ExecuteSqlUseCase,
+}
+
+impl<I, M, J> BulkEditUseCase<I, M, J>
+where
+    I: IssueRepository,
+    M: MetadataRepository,
+    J: JiraService,
+{
+    pub fn new(
+        issue_repository: Arc<I>,
+        metadata_repository: Arc<M>,
+        field_repository: Arc<DuckDbFieldRepository>,
+        jira_service: Arc<J>,
+        db_conn: Arc<Mutex<Connection>>,
+    ) -> Self {
+        Self {
+            update_use_case: UpdateIssueFieldsUseCase::new(
+                issue_repository.clone(),
+                metadata_repository,
+                field_repository,
+                jira_service.clone(),
+            ),
+            sql_use_case: ExecuteSqlUseCase::new(db_conn),
+            issue_repository,
+            jira_service,
+        }
+    }
+
+    /// Select issue keys matching a SQL predicate on the `issues` table
+    pub fn select_issue_keys(&self, predicate: &str, limit: usize) -> DomainResult<Vec<String>> {
+        let predicate = predicate.trim().trim_end_matches(';');
+        if predicate.is_empty() {
+            return Err(DomainError::Validation(
+                "A WHERE predicate is required".into(),
+            ));
+        }
+
+        let query = format!(
+            "SELECT key FROM issues WHERE {} ORDER BY created_date, key LIMIT {}",
+            predicate, limit
+        );
+        let result = self.sql_use_case.execute(&query, Some(limit))?;
+
+        Ok(result
+            .rows
+            .into_iter()
+            .filter_map(|row| row.into_iter().next())
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect())
+    }
+
+    /// Validate the edits and compute the per-issue diff
+    pub fn preview(
+        &self,
+        issue_keys: &[String],
+        edits: &[FieldEdit],
+    ) -> DomainResult<BulkEditPreview> {
+        let first_key = issue_keys
+            .first()
+            .ok_or_else(|| DomainError::Validation("No issues selected".into()))?;
+
+        // All selected issues live in the same project database, so one validation covers all
+        let update = self.update_use_case.prepare(first_key, edits)?;
+
+        let mut issues = Vec::with_capacity(issue_keys.len());
+        for key in issue_keys {
+            let issue = self
+                .issue_repository
+                .find_by_key(key)?
+                .ok_or_else(|| DomainError::NotFound(format!("Issue not found: {}", key)))?;
+            issues.push(IssueChangePreview {
+                issue_key: issue.key.clone(),
+                summary: issue.summary.clone(),
+                changes: diff_issue(&issue, &update),
+            });
+        }
+
+        Ok(BulkEditPreview { update, issues })
+    }
+
+    /// Apply the edit to the remaining issues of the checkpoint.
+    ///
+    /// `delay` is waited between requests (rate limiting). Each issue takes two requests,
+    /// the update and the re-fetch of the issue. `on_checkpoint` is called after every
+    /// issue so the caller can persist progress for resume.
+    pub async fn execute<F>(
+        &self,
+        mut checkpoint: BulkEditCheckpoint,
+        delay: Duration,
+        mut on_checkpoint: F,
+    ) -> DomainResult<BulkEditReport>
+    where
+        F: FnMut(&BulkEditCheckpoint),
+    {
+        let update = IssueFieldUpdate::from_request_body(&checkpoint.payload);
+        if update.is_empty() {
+            return Err(DomainError::Validation(
+                "Bulk edit checkpoint has an empty payload".into(),
+            ));
+        }
+
+        info!(
+            "Bulk edit: {} of {} issues remaining",
+            checkpoint
+                .issue_keys
+                .len()
+                .saturating_sub(checkpoint.items_processed),
+            checkpoint.issue_keys.len()
+        );
+
+        while checkpoint.items_processed < checkpoint.issue_keys.len() {
+            let key = checkpoint.issue_keys[checkpoint.items_processed].clone();
+
+            if let Err(e) = self.apply_one(&key, &update, delay).await {
+                warn!("Bulk edit failed for {}: {}", key, e);
+                checkpoint.failures.push(BulkEditFailure {
+                    issue_key: key,
+                    error: e.to_string(),
+                });
+            }
+
+            checkpoint.items_processed += 1;
+            on_checkpoint(&checkpoint);
+
+            if checkpoint.items_processed < checkpoint.issue_keys.len() && !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+
+        Ok(BulkEditReport::from_checkpoint(&checkpoint))
+    }
+
+    async fn apply_one(
+        &self,
+        issue_key: &str,
+        update: &IssueFieldUpdate,
+        delay: Duration,
+    ) -> DomainResult<()> {
+        self.jira_service
+            .update_issue_fields(issue_key, update)
+            .await?;
+
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+
+        // Refresh the local row; the edit itself already succeeded
+        match self.jira_service.fetch_issue(issue_key).await {
+            Ok(issue) => {
+                if let Err(e) = self.issue_repository.batch_insert(&[issue]) {
+                    warn!("Failed to refresh local issue {}: {}", issue_key, e);
+                }
+            }
+            Err(e) => warn!("Failed to re-fetch issue {}: {}", issue_key, e),
+        }
+
+        Ok(())
+    }
+}
+
+/// Compute the field changes an update makes to an issue, based on its raw JSON
+pub fn diff_issue(issue: &Issue, update: &IssueFieldUpdate) -> Vec<FieldChange> {
+    let raw: Value = issue
+        .raw_json
+        .as_deref()
+        .and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or(Value::Null);
+    let current = |field: &str| raw.get("fields").and_then(|f| f.get(field));
+
+    let mut changes = Vec::new();
+
+    for (field, value) in &update.fields {
+        let current = current(field);
+        if current.map(comparison_key).unwrap_or_default() == comparison_key(value) {
+            continue;
+        }
+        changes.push(FieldChange {
+            field: field.clone(),
+            before: current.map(display_value).unwrap_or_default(),
+            after: display_value(value),
+        });
+    }
+
+    for (field, operations) in &update.update {
+        let mut items: Vec<String> = match current(field) {
+            Some(Value::Array(values)) => values.iter().map(display_value).collect(),
+            _ => Vec::new(),
+        };
+        let before = items.join(", ");
+
+        for op in operations.as_array().into_iter().flatten() {
+            if let Some(value) = op.get("add") {
+                let item = display_value(value);
+                if !items.iter().any(|i| i.eq_ignore_ascii_case(&item)) {
+                    items.push(item);
+                }
+            } else if let Some(value) = op.get("remove") {
+                let item = display_value(value);
+                items.retain(|i| !i.eq_ignore_ascii_case(&item));
+            }
+        }
+
+        let after = items.join(", ");
+        if before != after {
+            changes.push(FieldChange {
+                field: field.clone(),
+                before,
+                after,
+            });
+        }
+    }
+
+    changes
+}
+
+/// What identifies a field value: users by account id (the API returns their display
+/// name too, an edit sends only the id), other values by their display form
+fn comparison_key(value: &Value) -> String {
+    match value.get("accountId").and_then(Value::as_str) {
+        Some(account_id) => account_id.to_string(),
+        None => display_value(value),
+    }
+}
+
+/// Human-readable form of a JIRA field value
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n
+            .as_f64()
+            .filter(|f| f.fract() == 0.0)
+            .map(|f| format!("{}", f as i64))
+            .unwrap_or_else(|| n.to_string()),
+        Value::Bool(b) => b.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(map) => {
+            if map.get("type").and_then(Value::as_str) == Some("doc") {
+                let mut text = Vec::new();
+                collect_adf_text(value, &mut text);
+                return text.join(" ");
+            }
+            ["name", "value", "displayName", "key", "accountId"]
+                .iter()
+                .find_map(|k| map.get(*k).and_then(Value::as_str))
+                .unwrap_or_default()
+                .to_string()
+        }
+    }
+}
+
+/// Collect text nodes from an Atlassian Document Format value
+fn collect_adf_text(value: &Value, out: &mut Vec<String>) {
+    if let Some(text) = value.get("text").and_then(Value::as_str) {
+        out.push(text.to_string());
+    }
+    if let Some(content) = value.get("content").and_then(Value::as_array) {
+        for child in content {
+            collect_adf_text(child, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue_with_raw(raw: Value) -> Issue {
+        let mut issue = Issue::new(
+            "1".to_string(),
+            "10000".to_string(),
+            "PROJ-1".to_string(),
+            "Test".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        issue.raw_json = Some(raw.to_string());
+        issue
+    }
+
+    #[test]
+    fn test_diff_issue() {
+        let issue = issue_with_raw(json!({
+            "fields": {
+                "priority": { "name": "Low" },
+                "labels": ["frontend", "ui"],
+                "customfield_10016": 3.0,
+                "assignee": { "accountId": "5b10a2844c20165700ede21g", "displayName": "Taro" }
+            }
+        }));
+
+        let update = IssueFieldUpdate::from_request_body(&json!({
+            "fields": {
+                "priority": { "name": "High" },
+                "customfield_10016": 3.0,
+                "assignee": { "accountId": "5b10a2844c20165700ede21g" }
+            },
+            "update": {
+                "labels": [{ "add": "backend" }, { "add": "Frontend" }, { "remove": "UI" }]
+            }
+        }));
+
+        let changes = diff_issue(&issue, &update);
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    field: "priority".to_string(),
+                    before: "Low".to_string(),
+                    after: "High".to_string(),
+                },
+                FieldChange {
+                    field: "labels".to_string(),
+                    before: "frontend, ui".to_string(),
+                    after: "frontend, backend".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report_from_checkpoint() {
+        let checkpoint = BulkEditCheckpoint {
+            predicate: "status = 'Open'".to_string(),
+            payload: json!({ "fields": { "priority": { "name": "High" } } }),
+            issue_keys: vec![
+                "PROJ-1".to_string(),
+                "PROJ-2".to_string(),
+                "PROJ-3".to_string(),
+            ],
+            items_processed: 2,
+            failures: vec![BulkEditFailure {
+                issue_key: "PROJ-2".to_string(),
+                error: "403 Forbidden".to_string(),
+            }],
+            started_at: Utc::now(),
+        };
+
+        let report = BulkEditReport::from_checkpoint(&checkpoint);
+        assert_eq!(report.total, 3);
+        assert_eq!(report.succeeded, 1);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.results.len(), 2);
+        assert!(report.results[0].success);
+        assert_eq!(report.results[1].error.as_deref(), Some("403 Forbidden"));
+    }
+}
@@ -0,0 +1,871 @@
+//! Group a project's issues into topics by clustering their embeddings
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{Issue, TopicAssignment, TopicCluster, TopicIssue};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{DateRange, IssueRepository, TopicRepository, key_order};
+use crate::infrastructure::database::{
+    DbConnection, DuckDbIssueRepository, DuckDbTopicRepository, EmbeddedIssue,
+    EmbeddingsRepository, is_cjk, tokenize,
+};
+use crate::infrastructure::external::claude::ClaudeClient;
+
+/// Upper bound of the topic count chosen from the number of issues
+const MAX_AUTO_TOPICS: usize = 20;
+
+/// k-means iterations before giving up on convergence
+const MAX_ITERATIONS: usize = 50;
+
+/// Seed of the k-means++ initialisation, so the same embeddings give the same topics
+const KMEANS_SEED: u64 = 0x6a69_7261_6462;
+
+/// Issues stored as representatives of a topic
+const REPRESENTATIVES: usize = 5;
+
+/// Most representative summaries used to name a topic
+const NAMING_SUMMARIES: usize = 10;
+
+/// Longest topic name taken from a summary
+const MAX_NAME_CHARS: usize = 60;
+
+/// Labeled neighbours voting on the labels of an unlabeled issue
+const SUGGESTION_NEIGHBORS: usize = 5;
+
+/// Share of the neighbours' similarity a value needs to be suggested
+const MIN_SUGGESTION_SCORE: f64 = 0.5;
+
+/// Values suggested per issue and field
+const MAX_SUGGESTED_VALUES: usize = 3;
+
+/// Words that say little about a topic
+const STOPWORDS: &[&str] = &[
+    "about", "add", "after", "all", "and", "are", "before", "can", "cannot", "does", "doesn",
+    "for", "from", "has", "have", "into", "not", "should", "that", "the", "this", "update", "when",
+    "while", "will", "with", "without",
+];
+
+const LLM_NAMING_SYSTEM: &str = "You name groups of JIRA issues. Each name is a short noun phrase that describes what the issues in the group have in common.";
+
+/// Label or component suggested for an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestedValue {
+    pub value: String,
+    /// Share of the neighbours' similarity carried by neighbours with the value (0 to 1)
+    pub score: f64,
+    /// Neighbours with the value, most similar first
+    pub neighbors: Vec<String>,
+}
+
+/// Labels or components for an issue that has none, from its nearest neighbours that have them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelSuggestion {
+    pub issue_key: String,
+    pub summary: String,
+    /// "labels" or "components"
+    pub field: String,
+    /// Best supported first
+    pub values: Vec<SuggestedValue>,
+}
+
+/// Topics of a project and label suggestions for its unlabeled issues
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicReport {
+    pub project_key: String,
+    pub generated_at: DateTime<Utc>,
+    /// Only issues created at or after this were clustered
+    pub created_from: Option<DateTime<Utc>>,
+    /// Only issues created before this were clustered
+    pub created_to: Option<DateTime<Utc>>,
+    pub issue_type: Option<String>,
+    /// Issues clustered
+    pub scanned_issues: usize,
+    /// Largest topic first
+    pub topics: Vec<TopicCluster>,
+    /// In issue key order
+    pub suggestions: Vec<LabelSuggestion>,
+}
+
+/// Embedded issue with its unit-length embedding
+struct PooledIssue<'a> {
+    issue: &'a Issue,
+    vector: Vec<f32>,
+}
+
+/// Clusters the embeddings of a project's issues into topics with spherical
+/// k-means, names each topic and stores the assignments. Also suggests labels
+/// and components for clustered issues that have none.
+pub struct ClusterTopicsUseCase {
+    issue_repository: DuckDbIssueRepository,
+    embeddings_repository: EmbeddingsRepository,
+    topic_repository: DuckDbTopicRepository,
+    topics: Option<usize>,
+    created: DateRange,
+    issue_type: Option<String>,
+    claude: Option<ClaudeClient>,
+}
+
+impl ClusterTopicsUseCase {
+    pub fn new(db_conn: DbConnection) -> Self {
+        Self {
+            issue_repository: DuckDbIssueRepository::new(db_conn.clone()),
+            embeddings_repository: EmbeddingsRepository::new(db_conn.clone()),
+            topic_repository: DuckDbTopicRepository::new(db_conn),
+            topics: None,
+            created: DateRange::default(),
+            issue_type: None,
+            claude: None,
+        }
+    }
+
+    /// Number of topics; by default about sqrt(issues / 2), between 2 and 20
+    pub fn with_topics(mut self, topics: usize) -> Self {
+        self.topics = Some(topics);
+        self
+    }
+
+    /// Only cluster issues created within `created`
+    pub fn with_created(mut self, created: DateRange) -> Self {
+        self.created = created;
+        self
+    }
+
+    /// Only cluster issues of this type
+    pub fn with_issue_type(mut self, issue_type: impl Into<String>) -> Self {
+        self.issue_type = Some(issue_type.into());
+        self
+    }
+
+    /// Let Claude name the topics from their representative summaries
+    pub fn with_llm_naming(mut self, claude: ClaudeClient) -> Self {
+        self.claude = Some(claude);
+        self
+    }
+
+    /// Cluster the project's embedded issues and replace its stored topics
+    ///
+    /// The stored topics are replaced even when the time window or issue type
+    /// narrowed the clustered issues.
+    pub async fn execute(&self, project_key: &str, project_id: &str) -> DomainResult<TopicReport> {
+        if self.topics.is_some_and(|topics| topics < 2) {
+            return Err(DomainError::Validation(
+                "At least 2 topics are needed".into(),
+            ));
+        }
+
+        let issues: HashMap<String, Issue> = self
+            .issue_repository
+            .find_by_project(project_id)?
+            .into_iter()
+            .map(|issue| (issue.id.clone(), issue))
+            .collect();
+        let embedded = self
+            .embeddings_repository
+            .embedded_issues(Some(project_id), None)?;
+        let pool = embedded_pool(embedded, &issues);
+
+        let members: Vec<usize> = pool
+            .iter()
+            .enumerate()
+            .filter(|(_, pooled)| self.matches(pooled.issue))
+            .map(|(index, _)| index)
+            .collect();
+        if members.len() < 2 {
+            return Err(DomainError::NotFound(format!(
+                "Fewer than 2 embedded issues to cluster in {}. Run 'jira-db embeddings' first",
+                project_key
+            )));
+        }
+
+        let topics = self
+            .topics
+            .unwrap_or_else(|| auto_topics(members.len()))
+            .min(members.len());
+        let vectors: Vec<&[f32]> = members
+            .iter()
+            .map(|&index| pool[index].vector.as_slice())
+            .collect();
+        let (labels, centroids) = kmeans(&vectors, topics);
+
+        let generated_at = Utc::now();
+        let (mut topics, assignments) =
+            build_topics(&pool, &members, &labels, &centroids, generated_at);
+        if let Some(claude) = &self.claude {
+            name_with_llm(claude, &mut topics).await;
+        }
+        self.topic_repository
+            .replace(project_id, &topics, &assignments)?;
+
+        // Neighbours are looked for within the topic, not across the whole project
+        let mut topic_members: Vec<Vec<usize>> = vec![Vec::new(); centroids.len()];
+        for (&index, &label) in members.iter().zip(&labels) {
+            topic_members[label].push(index);
+        }
+        let mut suggestions: Vec<LabelSuggestion> = members
+            .iter()
+            .zip(&labels)
+            .flat_map(|(&index, &label)| suggest(&pool, index, &topic_members[label]))
+            .collect();
+        suggestions.sort_by(|a, b| key_order(&a.issue_key).cmp(&key_order(&b.issue_key)));
+
+        Ok(TopicReport {
+            project_key: project_key.to_string(),
+            generated_at,
+            created_from: self.created.from,
+            created_to: self.created.to,
+            issue_type: self.issue_type.clone(),
+            scanned_issues: members.len(),
+            topics,
+            suggestions,
+        })
+    }
+
+    /// Whether the issue is in the time window and of the requested type
+    fn matches(&self, issue: &Issue) -> bool {
+        self.created.contains(issue.created_date)
+            && self.issue_type.as_deref().is_none_or(|wanted| {
+                issue
+                    .issue_type
+                    .as_deref()
+                    .is_some_and(|issue_type| issue_type.eq_ignore_ascii_case(wanted))
+            })
+    }
+}
+
+/// Topic count for `issues` issues when none is given
+fn auto_topics(issues: usize) -> usize {
+    ((issues as f64 / 2.0).sqrt().round() as usize).clamp(2, MAX_AUTO_TOPICS)
+}
+
+/// Embedded issues with unit-length vectors. Only the most common model and
+/// dimension are kept, since embeddings of different models cannot be compared.
+fn embedded_pool(
+    embedded: Vec<EmbeddedIssue>,
+    issues: &HashMap<String, Issue>,
+) -> Vec<PooledIssue<'_>> {
+    let mut spaces: HashMap<(&str, usize), usize> = HashMap::new();
+    for issue in &embedded {
+        *spaces
+            .entry((issue.model.as_str(), issue.embedding.len()))
+            .or_default() += 1;
+    }
+    let (model, dimension) = spaces
+        .into_iter()
+        .filter(|((_, dimension), _)| *dimension > 0)
+        .max_by_key(|&((model, dimension), count)| (count, dimension, std::cmp::Reverse(model)))
+        .map_or((String::new(), 0), |((model, dimension), _)| {
+            (model.to_string(), dimension)
+        });
+
+    embedded
+        .into_iter()
+        .filter(|embedded| embedded.model == model && embedded.embedding.len() == dimension)
+        .filter_map(|embedded| {
+            Some(PooledIssue {
+                issue: issues.get(&embedded.issue_id)?,
+                vector: normalize(embedded.embedding)?,
+            })
+        })
+        .collect()
+}
+
+fn normalize(mut vector: Vec<f32>) -> Option<Vec<f32>> {
+    let norm = vector
+        .iter()
+        .map(|x| f64::from(*x) * f64::from(*x))
+        .sum::<f64>()
+        .sqrt();
+    if norm == 0.0 || !norm.is_finite() {
+        return None;
+    }
+    for x in &mut vector {
+        *x = (f64::from(*x) / norm) as f32;
+    }
+    Some(vector)
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| f64::from(*x * *y)).sum()
+}
+
+/// SplitMix64, enough randomness for seeding without an RNG dependency
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Spherical k-means over unit vectors (`1 <= k <= vectors.len()`) with
+/// k-means++ seeding. Returns the cluster of each vector and the unit
+/// centroids; every cluster keeps at least one vector.
+fn kmeans(vectors: &[&[f32]], k: usize) -> (Vec<usize>, Vec<Vec<f32>>) {
+    let mut rng = SplitMix64(KMEANS_SEED);
+    let mut centroids = seed_centroids(vectors, k, &mut rng);
+    let mut labels = vec![usize::MAX; vectors.len()];
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (label, vector) in labels.iter_mut().zip(vectors) {
+            let nearest = nearest_centroid(vector, &centroids);
+            if *label != nearest {
+                *label = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        // An empty cluster takes the vector least similar to its centroid
+        // from a cluster that has more than one
+        let mut sizes = vec![0usize; k];
+        for &label in &labels {
+            sizes[label] += 1;
+        }
+        for cluster in 0..k {
+            if sizes[cluster] > 0 {
+                continue;
+            }
+            let farthest = (0..vectors.len())
+                .filter(|&index| sizes[labels[index]] > 1)
+                .min_by(|&a, &b| {
+                    dot(vectors[a], &centroids[labels[a]])
+                        .total_cmp(&dot(vectors[b], &centroids[labels[b]]))
+                });
+            if let Some(farthest) = farthest {
+                sizes[labels[farthest]] -= 1;
+                labels[farthest] = cluster;
+                sizes[cluster] = 1;
+            }
+        }
+
+        for (cluster, centroid) in centroids.iter_mut().enumerate() {
+            let mut sum = vec![0.0f32; centroid.len()];
+            for (vector, _) in vectors
+                .iter()
+                .zip(&labels)
+                .filter(|(_, label)| **label == cluster)
+            {
+                for (total, x) in sum.iter_mut().zip(vector.iter()) {
+                    *total += x;
+                }
+            }
+            if let Some(mean) = normalize(sum) {
+                *centroid = mean;
+            }
+        }
+    }
+
+    (labels, centroids)
+}
+
+/// k-means++: each further centroid is drawn with probability proportional to
+/// the squared cosine distance from the nearest centroid chosen so far
+fn seed_centroids(vectors: &[&[f32]], k: usize, rng: &mut SplitMix64) -> Vec<Vec<f32>> {
+    let first = (rng.next_u64() % vectors.len() as u64) as usize;
+    let mut centroids = vec![vectors[first].to_vec()];
+    let mut distances: Vec<f64> = vectors
+        .iter()
+        .map(|vector| (1.0 - dot(vector, &centroids[0])).max(0.0))
+        .collect();
+
+    while centroids.len() < k {
+        let total: f64 = distances.iter().map(|d| d * d).sum();
+        let next = if total > 0.0 {
+            let mut target = rng.next_f64() * total;
+            distances
+                .iter()
+                .position(|d| {
+                    target -= d * d;
+                    target < 0.0
+                })
+                .or_else(|| distances.iter().rposition(|d| *d > 0.0))
+                .unwrap_or(0)
+        } else {
+            // Every vector is already a centroid; empty clusters are re-seeded later
+            centroids.len() % vectors.len()
+        };
+        centroids.push(vectors[next].to_vec());
+
+        let latest = &centroids[centroids.len() - 1];
+        for (distance, vector) in distances.iter_mut().zip(vectors) {
+            *distance = distance.min((1.0 - dot(vector, latest)).max(0.0));
+        }
+    }
+    centroids
+}
+
+fn nearest_centroid(vector: &[f32], centroids: &[Vec<f32>]) -> usize {
+    let mut best = (0, f64::NEG_INFINITY);
+    for (index, centroid) in centroids.iter().enumerate() {
+        let similarity = dot(vector, centroid);
+        if similarity > best.1 {
+            best = (index, similarity);
+        }
+    }
+    best.0
+}
+
+/// Topics numbered from 1, largest first, with their representatives and
+/// heuristic names, and the assignment of every member
+fn build_topics(
+    pool: &[PooledIssue],
+    members: &[usize],
+    labels: &[usize],
+    centroids: &[Vec<f32>],
+    generated_at: DateTime<Utc>,
+) -> (Vec<TopicCluster>, Vec<TopicAssignment>) {
+    // Members of each cluster with their similarity to its centroid
+    let mut groups: Vec<Vec<(usize, f64)>> = vec![Vec::new(); centroids.len()];
+    for (&member, &label) in members.iter().zip(labels) {
+        let similarity = dot(&pool[member].vector, &centroids[label]);
+        groups[label].push((member, similarity));
+    }
+    groups.retain(|group| !group.is_empty());
+
+    let key = move |member: usize| key_order(&pool[member].issue.key);
+    for group in &mut groups {
+        group.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| key(a.0).cmp(&key(b.0))));
+    }
+    groups.sort_by(|a, b| {
+        b.len()
+            .cmp(&a.len())
+            .then_with(|| key(a[0].0).cmp(&key(b[0].0)))
+    });
+
+    let corpus = document_frequency(
+        members
+            .iter()
+            .map(|&member| pool[member].issue.summary.as_str()),
+    );
+
+    let mut topics = Vec::new();
+    let mut assignments = Vec::new();
+    for (index, group) in groups.iter().enumerate() {
+        let cluster_id = index as i32 + 1;
+        let summaries: Vec<&str> = group
+            .iter()
+            .take(NAMING_SUMMARIES)
+            .map(|&(member, _)| pool[member].issue.summary.as_str())
+            .collect();
+        let name = keyword_name(&summaries, &corpus, members.len())
+            .unwrap_or_else(|| truncate(summaries[0], MAX_NAME_CHARS));
+
+        topics.push(TopicCluster {
+            cluster_id,
+            name,
+            named_by: "summaries".to_string(),
+            size: group.len(),
+            representatives: group
+                .iter()
+                .take(REPRESENTATIVES)
+                .map(|&(member, similarity)| TopicIssue {
+                    key: pool[member].issue.key.clone(),
+                    summary: pool[member].issue.summary.clone(),
+                    similarity,
+                })
+                .collect(),
+            generated_at,
+        });
+        assignments.extend(group.iter().map(|&(member, similarity)| TopicAssignment {
+            issue_id: pool[member].issue.id.clone(),
+            issue_key: pool[member].issue.key.clone(),
+            cluster_id,
+            similarity,
+        }));
+    }
+    (topics, assignments)
+}
+
+/// Tokens of a summary that may describe a topic: lowercased words, and
+/// bigrams of CJK text (tokenized like the full-text search index)
+fn keywords(summary: &str) -> HashSet<String> {
+    tokenize(summary)
+        .into_iter()
+        .filter(|word| {
+            let min_chars = if is_cjk_token(word) { 2 } else { 3 };
+            word.chars().count() >= min_chars
+        })
+        .filter(|word| !word.chars().all(|c| c.is_ascii_digit()))
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
+fn is_cjk_token(word: &str) -> bool {
+    word.chars().any(is_cjk)
+}
+
+/// Join CJK bigrams that chain into a longer phrase shared by at least two of
+/// the summaries ("ログ", "グイ", "イン" -> "ログイン"), keeping the best score
+fn merge_bigrams(mut scored: Vec<(String, f64)>, summaries: &[&str]) -> Vec<(String, f64)> {
+    let shared = |phrase: &str| summaries.iter().filter(|s| s.contains(phrase)).count() >= 2;
+
+    let mut merged = Vec::new();
+    while !scored.is_empty() {
+        let (mut phrase, score) = scored.remove(0);
+        while is_cjk_token(&phrase) {
+            let extension = scored.iter().enumerate().find_map(|(index, (word, _))| {
+                let chars: Vec<char> = word.chars().collect();
+                if chars.len() != 2 || !is_cjk_token(word) {
+                    return None;
+                }
+                let appended = format!("{}{}", phrase, chars[1]);
+                let prepended = format!("{}{}", chars[0], phrase);
+                if phrase.ends_with(chars[0]) && shared(&appended) {
+                    Some((index, appended))
+                } else if phrase.starts_with(chars[1]) && shared(&prepended) {
+                    Some((index, prepended))
+                } else {
+                    None
+                }
+            });
+            match extension {
+                Some((index, longer)) => {
+                    scored.remove(index);
+                    phrase = longer;
+                }
+                None => break,
+            }
+        }
+        merged.push((phrase, score));
+    }
+    merged
+}
+
+/// Number of summaries each keyword appears in
+fn document_frequency<'a>(summaries: impl Iterator<Item = &'a str>) -> HashMap<String, usize> {
+    let mut frequency = HashMap::new();
+    for summary in summaries {
+        for word in keywords(summary) {
+            *frequency.entry(word).or_default() += 1;
+        }
+    }
+    frequency
+}
+
+/// Up to three keywords shared by the summaries and rare in the `corpus` of
+/// `documents` summaries (count x idf). `None` when no keyword appears in two
+/// of the summaries.
+fn keyword_name(
+    summaries: &[&str],
+    corpus: &HashMap<String, usize>,
+    documents: usize,
+) -> Option<String> {
+    let mut scored: Vec<(String, f64)> = document_frequency(summaries.iter().copied())
+        .into_iter()
+        .filter(|(_, count)| *count >= 2)
+        .filter_map(|(word, count)| {
+            let frequency = corpus.get(&word).copied().unwrap_or(count);
+            let idf = (documents as f64 / frequency.max(1) as f64).ln();
+            (idf > 0.0).then_some((word, count as f64 * idf))
+        })
+        .collect();
+    if scored.is_empty() {
+        return None;
+    }
+
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    Some(
+        merge_bigrams(scored, summaries)
+            .into_iter()
+            .take(3)
+            .map(|(word, _)| word)
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Ask Claude to name the topics; the heuristic names stay when that fails
+async fn name_with_llm(claude: &ClaudeClient, topics: &mut [TopicCluster]) {
+    let mut prompt = format!(
+        "Name each of these {} groups of JIRA issues from the summaries of its most representative issues.\n",
+        topics.len()
+    );
+    for (index, topic) in topics.iter().enumerate() {
+        prompt.push_str(&format!("\nGroup {}:\n", index + 1));
+        for issue in &topic.representatives {
+            prompt.push_str(&format!("- {}\n", issue.summary));
+        }
+    }
+    prompt.push_str(&format!(
+        "\nReturn a JSON array of {} strings: one name of 2 to 5 words per group, in the same order and in the language of the summaries.",
+        topics.len()
+    ));
+
+    match claude
+        .generate_json::<Vec<String>>(&prompt, Some(LLM_NAMING_SYSTEM))
+        .await
+    {
+        Ok(names) if names.len() == topics.len() => {
+            for (topic, name) in topics.iter_mut().zip(names) {
+                let name = name.trim();
+                if !name.is_empty() {
+                    topic.name = name.to_string();
+                    topic.named_by = "llm".to_string();
+                }
+            }
+        }
+        Ok(names) => warn!(
+            "Claude returned {} names for {} topics; keeping names from summaries",
+            names.len(),
+            topics.len()
+        ),
+        Err(e) => warn!("Failed to name topics with Claude: {}", e),
+    }
+}
+
+fn labels(issue: &Issue) -> &[String] {
+    issue.labels.as_deref().unwrap_or_default()
+}
+
+fn components(issue: &Issue) -> &[String] {
+    issue.components.as_deref().unwrap_or_default()
+}
+
+/// Labels and components for the issue at `target` where it has none, voted
+/// by its nearest neighbours among `candidates` (the members of its topic)
+/// that have them and weighted by similarity
+fn suggest(pool: &[PooledIssue], target: usize, candidates: &[usize]) -> Vec<LabelSuggestion> {
+    let issue = pool[target].issue;
+    let fields: [(&str, fn(&Issue) -> &[String]); 2] =
+        [("labels", labels), ("components", components)];
+
+    fields
+        .into_iter()
+        .filter(|(_, values)| values(issue).is_empty())
+        .filter_map(|(field, values)| {
+            let mut neighbors: Vec<(&Issue, f64)> = candidates
+                .iter()
+                .filter(|&&index| index != target && !values(pool[index].issue).is_empty())
+                .map(|&index| {
+                    let similarity = dot(&pool[target].vector, &pool[index].vector).max(0.0);
+                    (pool[index].issue, similarity)
+                })
+                .collect();
+            neighbors.sort_by(|a, b| {
+                b.1.total_cmp(&a.1)
+                    .then_with(|| key_order(&a.0.key).cmp(&key_order(&b.0.key)))
+            });
+            neighbors.truncate(SUGGESTION_NEIGHBORS);
+
+            let total: f64 = neighbors.iter().map(|(_, similarity)| similarity).sum();
+            if total <= 0.0 {
+                return None;
+            }
+
+            let mut votes: BTreeMap<&str, (f64, Vec<String>)> = BTreeMap::new();
+            for (neighbor, similarity) in &neighbors {
+                let distinct: BTreeSet<&str> =
+                    values(neighbor).iter().map(String::as_str).collect();
+                for value in distinct {
+                    let vote = votes.entry(value).or_default();
+                    vote.0 += similarity;
+                    vote.1.push(neighbor.key.clone());
+                }
+            }
+
+            let mut suggested: Vec<SuggestedValue> = votes
+                .into_iter()
+                .map(|(value, (weight, neighbors))| SuggestedValue {
+                    value: value.to_string(),
+                    score: weight / total,
+                    neighbors,
+                })
+                .filter(|suggested| suggested.score >= MIN_SUGGESTION_SCORE)
+                .collect();
+            suggested.sort_by(|a, b| {
+                b.score
+                    .total_cmp(&a.score)
+                    .then_with(|| a.value.cmp(&b.value))
+            });
+            suggested.truncate(MAX_SUGGESTED_VALUES);
+
+            (!suggested.is_empty()).then(|| LabelSuggestion {
+                issue_key: issue.key.clone(),
+                summary: issue.summary.clone(),
+                field: field.to_string(),
+                values: suggested,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::test_support::{store_embeddings, test_connection};
+
+    #[test]
+    fn test_kmeans_separates_groups() {
+        let vectors: Vec<Vec<f32>> = vec![
+            vec![1.0, 0.05, 0.0],
+            vec![0.0, 1.0, 0.1],
+            vec![0.98, 0.0, 0.1],
+            vec![0.1, 0.97, 0.0],
+            vec![0.95, 0.1, 0.05],
+            vec![0.05, 0.0, 1.0],
+        ]
+        .into_iter()
+        .map(|vector| normalize(vector).unwrap())
+        .collect();
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+
+        let (labels, centroids) = kmeans(&refs, 3);
+
+        assert_eq!(centroids.len(), 3);
+        assert_eq!(labels[0], labels[2]);
+        assert_eq!(labels[0], labels[4]);
+        assert_eq!(labels[1], labels[3]);
+        let distinct: HashSet<usize> = labels.iter().copied().collect();
+        assert_eq!(distinct.len(), 3);
+
+        // Identical vectors still fill every cluster
+        let same: &[f32] = &[1.0, 0.0];
+        let (labels, _) = kmeans(&[same, same, same], 2);
+        let distinct: HashSet<usize> = labels.iter().copied().collect();
+        assert_eq!(distinct.len(), 2);
+    }
+
+    #[test]
+    fn test_keyword_name_prefers_shared_distinctive_words() {
+        let all = [
+            "Login fails with SSO token",
+            "SSO login timeout on mobile",
+            "Login page shows SSO error",
+            "Export to CSV is slow",
+            "CSV export drops the header",
+        ];
+        let frequency = document_frequency(all.iter().copied());
+
+        assert_eq!(
+            keyword_name(&all[..3], &frequency, all.len()).as_deref(),
+            Some("login, sso")
+        );
+        assert_eq!(
+            keyword_name(&all[3..], &frequency, all.len()).as_deref(),
+            Some("csv, export")
+        );
+        assert_eq!(
+            keyword_name(&["ログインできない"], &frequency, all.len()),
+            None
+        );
+
+        // Japanese summaries are split into bigrams, which are joined again for the name
+        let japanese = [
+            "ログインできない",
+            "SSOでログインに失敗する",
+            "エクスポートが遅い",
+        ];
+        let frequency = document_frequency(japanese.iter().copied());
+        assert_eq!(
+            keyword_name(&japanese[..2], &frequency, japanese.len()).as_deref(),
+            Some("ログイン")
+        );
+        assert_eq!(truncate("abcdef", 4), "abc…");
+    }
+
+    #[tokio::test]
+    async fn test_execute_stores_topics_and_suggests_labels() {
+        let conn = test_connection(
+            r#"
+            INSERT INTO issues
+                (id, project_id, key, summary, issue_type, labels, components,
+                 created_date, updated_date, raw_data, is_deleted)
+            VALUES
+                ('1', '100', 'PROJ-1', 'Login fails with SSO', 'Bug', '["auth"]', '["Web"]',
+                 '2024-05-01 09:00:00+00', '2024-05-01 09:00:00+00', '{"fields": {}}', false),
+                ('2', '100', 'PROJ-2', 'SSO login timeout', 'Bug', '["auth"]', '[]',
+                 '2024-05-02 09:00:00+00', '2024-05-02 09:00:00+00', '{"fields": {}}', false),
+                ('3', '100', 'PROJ-3', 'Login broken after SSO change', 'Bug', '[]', '[]',
+                 '2024-05-03 09:00:00+00', '2024-05-03 09:00:00+00', '{"fields": {}}', false),
+                ('4', '100', 'PROJ-4', 'CSV export is slow', 'Bug', '["export"]', '[]',
+                 '2024-05-04 09:00:00+00', '2024-05-04 09:00:00+00', '{"fields": {}}', false),
+                ('5', '100', 'PROJ-5', 'CSV export drops header', 'Bug', '[]', '[]',
+                 '2024-05-05 09:00:00+00', '2024-05-05 09:00:00+00', '{"fields": {}}', false),
+                ('6', '100', 'PROJ-6', 'Add dark mode', 'Story', '[]', '[]',
+                 '2024-05-06 09:00:00+00', '2024-05-06 09:00:00+00', '{"fields": {}}', false),
+                ('7', '100', 'PROJ-7', 'Old login issue', 'Bug', '[]', '[]',
+                 '2023-01-01 09:00:00+00', '2023-01-01 09:00:00+00', '{"fields": {}}', false);
+            "#,
+        );
+        store_embeddings(
+            &conn,
+            "text-embedding-3-small",
+            &[
+                ("1", "PROJ-1", vec![1.0, 0.0, 0.0]),
+                ("2", "PROJ-2", vec![0.98, 0.1, 0.0]),
+                ("3", "PROJ-3", vec![0.97, 0.0, 0.1]),
+                ("4", "PROJ-4", vec![0.0, 1.0, 0.0]),
+                ("5", "PROJ-5", vec![0.1, 0.98, 0.0]),
+                ("6", "PROJ-6", vec![0.0, 0.0, 1.0]),
+                ("7", "PROJ-7", vec![1.0, 0.0, 0.0]),
+            ],
+        );
+
+        let report = ClusterTopicsUseCase::new(conn.clone())
+            .with_topics(2)
+            .with_issue_type("bug")
+            .with_created(DateRange::parse(Some("2024-01-01"), None).unwrap())
+            .execute("PROJ", "100")
+            .await
+            .unwrap();
+
+        assert_eq!(report.scanned_issues, 5);
+        assert_eq!(report.topics.len(), 2);
+        assert_eq!(report.topics[0].size, 3);
+        assert_eq!(report.topics[0].name, "login, sso");
+        assert_eq!(report.topics[0].named_by, "summaries");
+        assert_eq!(report.topics[1].name, "csv, export");
+
+        let suggestion = |key: &str, field: &str| {
+            report
+                .suggestions
+                .iter()
+                .find(|s| s.issue_key == key && s.field == field)
+                .map(|s| s.values[0].value.clone())
+        };
+        assert_eq!(suggestion("PROJ-3", "labels").as_deref(), Some("auth"));
+        assert_eq!(suggestion("PROJ-5", "labels").as_deref(), Some("export"));
+        assert_eq!(suggestion("PROJ-1", "labels"), None);
+
+        let repository = DuckDbTopicRepository::new(conn.clone());
+        let stored = repository.find_clusters("100").unwrap();
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0].representatives.len(), 3);
+        let assignments = repository.find_assignments("100").unwrap();
+        assert_eq!(assignments.len(), 5);
+        assert!(!assignments.iter().any(|a| a.issue_key == "PROJ-7"));
+
+        assert!(matches!(
+            ClusterTopicsUseCase::new(conn)
+                .with_topics(1)
+                .execute("PROJ", "100")
+                .await,
+            Err(DomainError::Validation(_))
+        ));
+    }
+}
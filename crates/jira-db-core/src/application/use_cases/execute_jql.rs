@@ -0,0 +1,166 @@
+//! Execute JQL query use case
+
+use serde::{Deserialize, Serialize};
+
+use super::execute_sql::{SqlResult, query_result};
+use crate::application::jql::{self, CompiledJql, DEFAULT_COLUMNS, FieldCatalog, JqlContext};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::infrastructure::database::{
+    DatabaseFactory, DbConnection, DuckDbFieldRepository, DuckDbIssuesExpandedRepository,
+};
+
+/// JQL execution result, with the SQL it was compiled to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JqlResult {
+    pub sql: String,
+    pub params: Vec<String>,
+    pub result: SqlResult,
+}
+
+/// Runs JQL against the local database instead of JIRA
+pub struct ExecuteJqlUseCase {
+    db_conn: DbConnection,
+    context: JqlContext,
+}
+
+impl ExecuteJqlUseCase {
+    pub fn new(db_conn: DbConnection) -> Self {
+        Self {
+            db_conn,
+            context: JqlContext::default(),
+        }
+    }
+
+    /// Query across every project database through the federated views
+    pub fn all_projects(db_factory: &DatabaseFactory) -> DomainResult<Self> {
+        Ok(Self::new(db_factory.get_all_projects_connection()?))
+    }
+
+    /// User that `currentUser()` refers to
+    pub fn with_current_user(mut self, user: impl Into<String>) -> Self {
+        self.context = self.context.with_current_user(user);
+        self
+    }
+
+    /// Parse and compile a query against the fields of this database
+    pub fn compile(&self, query: &str) -> DomainResult<CompiledJql> {
+        let parsed = jql::parse(query)?;
+        let fields = DuckDbFieldRepository::new(self.db_conn.clone()).find_all()?;
+        let columns =
+            DuckDbIssuesExpandedRepository::new(self.db_conn.clone()).get_existing_columns()?;
+        jql::compile(&parsed, &FieldCatalog::new(fields, columns), &self.context)
+    }
+
+    /// Execute a JQL query, returning at most `limit` issues (default 100)
+    pub fn execute(&self, query: &str, limit: Option<usize>) -> DomainResult<JqlResult> {
+        let compiled = self.compile(query)?;
+        let sql = format!(
+            "{}\nLIMIT {}",
+            compiled.to_sql(DEFAULT_COLUMNS),
+            limit.unwrap_or(100)
+        );
+
+        let conn = self.db_conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        let result = query_result(
+            &conn,
+            &sql,
+            duckdb::params_from_iter(compiled.params.iter()),
+        )?;
+
+        Ok(JqlResult {
+            sql,
+            params: compiled.params,
+            result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::test_support::test_connection;
+
+    fn create_test_db() -> DbConnection {
+        let conn = test_connection(
+            r#"
+            INSERT INTO jira_fields (id, key, name, custom, schema_type)
+            VALUES ('customfield_10016', 'customfield_10016', 'Story Points', true, 'number');
+            ALTER TABLE issues_expanded ADD COLUMN customfield_10016 DOUBLE;
+
+            INSERT INTO issues (id, project_id, key, summary, raw_data, is_deleted) VALUES
+                ('1', '100', 'PROJ-1', 'Login fails', '{"fields": {"assignee": {"emailAddress": "alice@example.com"}}}', false),
+                ('2', '100', 'PROJ-2', 'Add dark mode', '{"fields": {}}', false),
+                ('3', '100', 'PROJ-10', 'Crash on start', '{"fields": {}}', false),
+                ('4', '100', 'PROJ-11', 'Deleted issue', '{"fields": {}}', true);
+
+            INSERT INTO issues_expanded
+                (id, project_id, issue_key, summary, status, assignee, labels, created_date, customfield_10016)
+            VALUES
+                ('1', '100', 'PROJ-1', 'Login fails', 'Open', 'Alice', '["backend", "auth"]', '2024-05-14 09:00:00', 5),
+                ('2', '100', 'PROJ-2', 'Add dark mode', 'Done', NULL, '["frontend"]', '2024-04-01 09:00:00', 2),
+                ('3', '100', 'PROJ-10', 'Crash on start', 'In Progress', 'Bob', '[]', '2024-05-10 09:00:00', NULL),
+                ('4', '100', 'PROJ-11', 'Deleted issue', 'Open', NULL, '[]', '2024-05-15 09:00:00', NULL);
+
+            INSERT INTO issue_change_history
+                (issue_id, issue_key, history_id, author_display_name, field, from_string, to_string, changed_at)
+            VALUES
+                ('3', 'PROJ-10', 'h1', 'Bob', 'status', 'Done', 'In Progress', '2024-05-12 10:00:00+00');
+            "#,
+        );
+        conn
+    }
+
+    fn keys(use_case: &ExecuteJqlUseCase, query: &str) -> Vec<String> {
+        let result = use_case.execute(query, None).unwrap().result;
+        result
+            .rows
+            .iter()
+            .map(|row| row[0].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_execute_jql() {
+        let conn = create_test_db();
+        let use_case = ExecuteJqlUseCase::new(conn).with_current_user("alice@example.com");
+
+        assert_eq!(
+            keys(&use_case, "status != Done ORDER BY key"),
+            vec!["PROJ-1", "PROJ-10"]
+        );
+        assert_eq!(
+            keys(&use_case, "labels IN (frontend, auth) ORDER BY key DESC"),
+            vec!["PROJ-2", "PROJ-1"]
+        );
+        assert_eq!(
+            keys(
+                &use_case,
+                "\"Story Points\" > 3 OR assignee IS EMPTY ORDER BY key"
+            ),
+            vec!["PROJ-1", "PROJ-2"]
+        );
+        assert_eq!(keys(&use_case, "assignee = currentUser()"), vec!["PROJ-1"]);
+        assert_eq!(
+            keys(&use_case, "summary ~ 'crash' OR key > PROJ-9"),
+            vec!["PROJ-10"]
+        );
+        assert_eq!(
+            keys(&use_case, "status WAS Done BY Bob AFTER '2024-05-01'"),
+            vec!["PROJ-10"]
+        );
+        assert_eq!(
+            keys(&use_case, "status WAS Done ORDER BY key"),
+            vec!["PROJ-2", "PROJ-10"]
+        );
+        assert_eq!(
+            keys(&use_case, "NOT status CHANGED AND created >= 2024-05-01"),
+            vec!["PROJ-1"]
+        );
+
+        let result = use_case.execute("project = PROJ", Some(2)).unwrap();
+        assert_eq!(result.result.row_count, 2);
+        assert!(result.sql.ends_with("LIMIT 2"));
+    }
+}
@@ -47,6 +47,17 @@ fn is_whole_word(text: &str, keyword: &str) -> bool {
     false
 }
 
+/// Uppercased statement with comment lines (-- ...) skipped, for keyword checks
+fn statement_upper(query: &str) -> String {
+    query
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.starts_with("--") && !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+}
+
 /// SQL execution result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SqlResult {
@@ -65,20 +76,15 @@ impl ExecuteSqlUseCase {
         Self { db_conn }
     }
 
-    /// Execute a read-only SQL query
-    pub fn execute(&self, query: &str, limit: Option<usize>) -> DomainResult<SqlResult> {
+    /// Check that a query is a single read-only SELECT (or WITH ... SELECT).
+    ///
+    /// Returns the query with smart quotes normalized.
+    pub fn validate_read_only(query: &str) -> DomainResult<String> {
         // Normalize smart/curly quotes to standard ASCII quotes
         let query = normalize_quotes(query);
 
         // Security checks - only allow SELECT queries (including WITH...SELECT CTEs)
-        // Skip comment lines (-- ...) to find the actual SQL statement
-        let query_upper = query
-            .lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.starts_with("--") && !line.is_empty())
-            .collect::<Vec<_>>()
-            .join(" ")
-            .to_uppercase();
+        let query_upper = statement_upper(&query);
 
         let is_select = query_upper.starts_with("SELECT");
         let is_with_select = query_upper.starts_with("WITH") && query_upper.contains("SELECT");
@@ -104,6 +110,24 @@ impl ExecuteSqlUseCase {
             }
         }
 
+        Ok(query)
+    }
+
+    /// Execute a read-only SQL query
+    pub fn execute(&self, query: &str, limit: Option<usize>) -> DomainResult<SqlResult> {
+        self.execute_with_params(query, [], limit)
+    }
+
+    /// Execute a read-only SQL query with `?` placeholders bound to `params`
+    pub fn execute_with_params<P: duckdb::Params>(
+        &self,
+        query: &str,
+        params: P,
+        limit: Option<usize>,
+    ) -> DomainResult<SqlResult> {
+        let query = Self::validate_read_only(query)?;
+        let query_upper = statement_upper(&query);
+
         let conn = self
             .db_conn
             .lock()
@@ -120,112 +144,120 @@ impl ExecuteSqlUseCase {
             query.to_string()
         };
 
-        let mut stmt = conn
-            .prepare(&final_query)
-            .map_err(|e| DomainError::Repository(format!("SQL prepare error: {}", e)))?;
-
-        // Execute query first with query_map, collecting rows with dynamic column detection
-        let mut detected_column_count: Option<usize> = None;
-        let rows_result = stmt
-            .query_map([], |row| {
-                // Detect column count from first row
-                let column_count = row.as_ref().column_count();
-
-                let mut row_values: Vec<serde_json::Value> = Vec::new();
-                for i in 0..column_count {
-                    let value: serde_json::Value = match row.get_ref(i) {
-                        Ok(val) => match val {
-                            duckdb::types::ValueRef::Null => serde_json::Value::Null,
-                            duckdb::types::ValueRef::Boolean(b) => serde_json::Value::Bool(b),
-                            duckdb::types::ValueRef::TinyInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::SmallInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::Int(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::BigInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::HugeInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::UTinyInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::USmallInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::UInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::UBigInt(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::Float(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::Double(n) => serde_json::json!(n),
-                            duckdb::types::ValueRef::Text(s) => {
-                                serde_json::Value::String(String::from_utf8_lossy(s).to_string())
-                            }
-                            duckdb::types::ValueRef::Blob(b) => {
-                                serde_json::Value::String(format!("<blob:{} bytes>", b.len()))
-                            }
-                            duckdb::types::ValueRef::Timestamp(unit, val) => {
-                                // Convert timestamp to string representation
-                                let ts_str = match unit {
-                                    duckdb::types::TimeUnit::Second => {
-                                        chrono::DateTime::from_timestamp(val, 0)
-                                            .map(|dt| dt.to_rfc3339())
-                                            .unwrap_or_else(|| format!("{}s", val))
-                                    }
-                                    duckdb::types::TimeUnit::Millisecond => {
-                                        chrono::DateTime::from_timestamp_millis(val)
-                                            .map(|dt| dt.to_rfc3339())
-                                            .unwrap_or_else(|| format!("{}ms", val))
-                                    }
-                                    duckdb::types::TimeUnit::Microsecond => {
-                                        chrono::DateTime::from_timestamp_micros(val)
-                                            .map(|dt| dt.to_rfc3339())
-                                            .unwrap_or_else(|| format!("{}us", val))
-                                    }
-                                    duckdb::types::TimeUnit::Nanosecond => {
-                                        chrono::DateTime::from_timestamp_nanos(val).to_rfc3339()
-                                    }
-                                };
-                                serde_json::Value::String(ts_str)
-                            }
-                            duckdb::types::ValueRef::Date32(days) => {
-                                // Days since Unix epoch
-                                let date =
-                                    chrono::NaiveDate::from_num_days_from_ce_opt(days + 719163)
-                                        .map(|d| d.to_string())
-                                        .unwrap_or_else(|| format!("date:{}", days));
-                                serde_json::Value::String(date)
-                            }
-                            duckdb::types::ValueRef::Time64(unit, val) => {
-                                serde_json::Value::String(format!("time:{:?}:{}", unit, val))
-                            }
-                            _ => serde_json::Value::String(format!("{:?}", val)),
-                        },
-                        Err(_) => serde_json::Value::Null,
-                    };
-                    row_values.push(value);
-                }
-                Ok((column_count, row_values))
-            })
-            .map_err(|e| DomainError::Repository(format!("Query execution error: {}", e)))?;
-
-        let mut raw_rows: Vec<Vec<serde_json::Value>> = Vec::new();
-        for result in rows_result {
-            if let Ok((col_count, row_values)) = result {
-                if detected_column_count.is_none() {
-                    detected_column_count = Some(col_count);
-                }
-                raw_rows.push(row_values);
+        query_result(&conn, &final_query, params)
+    }
+}
+
+/// Run a prepared query and collect every row as JSON values
+pub(crate) fn query_result<P: duckdb::Params>(
+    conn: &Connection,
+    sql: &str,
+    params: P,
+) -> DomainResult<SqlResult> {
+    let mut stmt = conn
+        .prepare(sql)
+        .map_err(|e| DomainError::Repository(format!("SQL prepare error: {}", e)))?;
+
+    // Execute query first with query_map, collecting rows with dynamic column detection
+    let mut detected_column_count: Option<usize> = None;
+    let rows_result = stmt
+        .query_map(params, |row| {
+            // Detect column count from first row
+            let column_count = row.as_ref().column_count();
+
+            let mut row_values: Vec<serde_json::Value> = Vec::new();
+            for i in 0..column_count {
+                let value: serde_json::Value = match row.get_ref(i) {
+                    Ok(val) => match val {
+                        duckdb::types::ValueRef::Null => serde_json::Value::Null,
+                        duckdb::types::ValueRef::Boolean(b) => serde_json::Value::Bool(b),
+                        duckdb::types::ValueRef::TinyInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::SmallInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::Int(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::BigInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::HugeInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::UTinyInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::USmallInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::UInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::UBigInt(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::Float(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::Double(n) => serde_json::json!(n),
+                        duckdb::types::ValueRef::Text(s) => {
+                            serde_json::Value::String(String::from_utf8_lossy(s).to_string())
+                        }
+                        duckdb::types::ValueRef::Blob(b) => {
+                            serde_json::Value::String(format!("<blob:{} bytes>", b.len()))
+                        }
+                        duckdb::types::ValueRef::Timestamp(unit, val) => {
+                            // Convert timestamp to string representation
+                            let ts_str = match unit {
+                                duckdb::types::TimeUnit::Second => {
+                                    chrono::DateTime::from_timestamp(val, 0)
+                                        .map(|dt| dt.to_rfc3339())
+                                        .unwrap_or_else(|| format!("{}s", val))
+                                }
+                                duckdb::types::TimeUnit::Millisecond => {
+                                    chrono::DateTime::from_timestamp_millis(val)
+                                        .map(|dt| dt.to_rfc3339())
+                                        .unwrap_or_else(|| format!("{}ms", val))
+                                }
+                                duckdb::types::TimeUnit::Microsecond => {
+                                    chrono::DateTime::from_timestamp_micros(val)
+                                        .map(|dt| dt.to_rfc3339())
+                                        .unwrap_or_else(|| format!("{}us", val))
+                                }
+                                duckdb::types::TimeUnit::Nanosecond => {
+                                    chrono::DateTime::from_timestamp_nanos(val).to_rfc3339()
+                                }
+                            };
+                            serde_json::Value::String(ts_str)
+                        }
+                        duckdb::types::ValueRef::Date32(days) => {
+                            // Days since Unix epoch
+                            let date = chrono::NaiveDate::from_num_days_from_ce_opt(days + 719163)
+                                .map(|d| d.to_string())
+                                .unwrap_or_else(|| format!("date:{}", days));
+                            serde_json::Value::String(date)
+                        }
+                        duckdb::types::ValueRef::Time64(unit, val) => {
+                            serde_json::Value::String(format!("time:{:?}:{}", unit, val))
+                        }
+                        _ => serde_json::Value::String(format!("{:?}", val)),
+                    },
+                    Err(_) => serde_json::Value::Null,
+                };
+                row_values.push(value);
             }
-        }
-        let row_count = raw_rows.len();
-
-        // Get column names from statement (now query has been executed)
-        let column_count = detected_column_count.unwrap_or_else(|| stmt.column_count());
-        let column_names: Vec<String> = (0..column_count)
-            .map(|i| {
-                stmt.column_name(i)
-                    .map(|s| s.to_string())
-                    .unwrap_or_else(|_| format!("col_{}", i))
-            })
-            .collect();
-
-        Ok(SqlResult {
-            columns: column_names,
-            rows: raw_rows,
-            row_count,
+            Ok((column_count, row_values))
         })
+        .map_err(|e| DomainError::Repository(format!("Query execution error: {}", e)))?;
+
+    let mut raw_rows: Vec<Vec<serde_json::Value>> = Vec::new();
+    for result in rows_result {
+        if let Ok((col_count, row_values)) = result {
+            if detected_column_count.is_none() {
+                detected_column_count = Some(col_count);
+            }
+            raw_rows.push(row_values);
+        }
     }
+    let row_count = raw_rows.len();
+
+    // Get column names from statement (now query has been executed)
+    let column_count = detected_column_count.unwrap_or_else(|| stmt.column_count());
+    let column_names: Vec<String> = (0..column_count)
+        .map(|i| {
+            stmt.column_name(i)
+                .map(|s| s.to_string())
+                .unwrap_or_else(|_| format!("col_{}", i))
+        })
+        .collect();
+
+    Ok(SqlResult {
+        columns: column_names,
+        rows: raw_rows,
+        row_count,
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,319 @@
+//! Export a table, view or query result to a file using DuckDB COPY
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::execute_sql::ExecuteSqlUseCase;
+use crate::domain::error::{DomainError, DomainResult};
+use crate::infrastructure::database::DbConnection;
+
+/// Output file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Parquet,
+    Csv,
+    Jsonl,
+    Xlsx,
+}
+
+impl ExportFormat {
+    /// Guess the format from a file extension
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?;
+        ext.parse().ok()
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Parquet => "parquet",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Jsonl => "jsonl",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Parquet => "application/vnd.apache.parquet",
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Jsonl => "application/x-ndjson",
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
+
+    fn copy_options(&self) -> &'static str {
+        match self {
+            ExportFormat::Parquet => "FORMAT PARQUET, COMPRESSION ZSTD",
+            ExportFormat::Csv => "FORMAT CSV, HEADER",
+            ExportFormat::Jsonl => "FORMAT JSON",
+            ExportFormat::Xlsx => "FORMAT XLSX, HEADER true",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "parquet" => Ok(ExportFormat::Parquet),
+            "csv" => Ok(ExportFormat::Csv),
+            "jsonl" | "ndjson" => Ok(ExportFormat::Jsonl),
+            "xlsx" | "excel" => Ok(ExportFormat::Xlsx),
+            _ => Err(DomainError::Configuration(format!(
+                "Unknown export format: {}. Use parquet, csv, jsonl or xlsx",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+/// What to export
+#[derive(Debug, Clone)]
+pub enum ExportSource {
+    /// A table or view by name
+    Table(String),
+    /// A read-only SELECT query
+    Query(String),
+}
+
+impl ExportSource {
+    fn select_sql(&self) -> DomainResult<String> {
+        match self {
+            ExportSource::Table(name) => {
+                if name.is_empty() || name.contains('"') {
+                    return Err(DomainError::Validation(format!(
+                        "Invalid table name: {}",
+                        name
+                    )));
+                }
+                Ok(format!("SELECT * FROM \"{}\"", name))
+            }
+            ExportSource::Query(query) => {
+                let query = ExecuteSqlUseCase::validate_read_only(query)?;
+                let query = query.trim().trim_end_matches(';').trim_end();
+                check_embeddable(query)?;
+                Ok(query.to_string())
+            }
+        }
+    }
+}
+
+/// Reject queries that could escape the `COPY (...)` wrapper
+///
+/// Outside string literals and quoted identifiers the query may not contain
+/// statement separators, comments, dollar-quoted strings or unbalanced parentheses.
+fn check_embeddable(query: &str) -> DomainResult<()> {
+    let invalid = |reason: &str| {
+        Err(DomainError::Validation(format!(
+            "Export query must be a single SELECT statement: {}",
+            reason
+        )))
+    };
+
+    let mut chars = query.chars().peekable();
+    let mut depth = 0usize;
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                // Doubled quotes are escapes and simply reopen the literal
+                if !chars.by_ref().any(|next| next == c) {
+                    return invalid("unterminated quote");
+                }
+            }
+            '(' => depth += 1,
+            ')' => {
+                if depth == 0 {
+                    return invalid("unbalanced parentheses");
+                }
+                depth -= 1;
+            }
+            ';' => return invalid("multiple statements"),
+            '$' => return invalid("dollar-quoted strings are not supported"),
+            '-' if chars.peek() == Some(&'-') => return invalid("comments are not allowed"),
+            '/' if chars.peek() == Some(&'*') => return invalid("comments are not allowed"),
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return invalid("unbalanced parentheses");
+    }
+    Ok(())
+}
+
+/// Export result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportResult {
+    pub path: PathBuf,
+    pub format: ExportFormat,
+    pub row_count: usize,
+}
+
+/// Export use case
+///
+/// Works on a single project connection or on a `FederatedDatabase` connection,
+/// in which case every relation carries a `project_key` column.
+pub struct ExportDataUseCase {
+    db_conn: DbConnection,
+}
+
+impl ExportDataUseCase {
+    pub fn new(db_conn: DbConnection) -> Self {
+        Self { db_conn }
+    }
+
+    pub fn execute(
+        &self,
+        source: &ExportSource,
+        format: ExportFormat,
+        out: &Path,
+    ) -> DomainResult<ExportResult> {
+        let select = source.select_sql()?;
+
+        if let Some(parent) = out.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                DomainError::Repository(format!("Failed to create output directory: {}", e))
+            })?;
+        }
+
+        let conn = self.db_conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        if format == ExportFormat::Xlsx {
+            conn.execute_batch("INSTALL excel; LOAD excel;")
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to load excel extension: {}", e))
+                })?;
+        }
+
+        // Preparing the bare query proves it parses as exactly one statement
+        // before it is embedded in COPY
+        conn.prepare(&select)
+            .map_err(|e| DomainError::Validation(format!("Invalid export query: {}", e)))?;
+
+        let sql = format!(
+            "COPY ({}) TO '{}' ({})",
+            select,
+            out.to_string_lossy().replace('\'', "''"),
+            format.copy_options()
+        );
+        let row_count = conn
+            .execute(&sql, [])
+            .map_err(|e| DomainError::Repository(format!("Failed to export data: {}", e)))?;
+
+        Ok(ExportResult {
+            path: out.to_path_buf(),
+            format,
+            row_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duckdb::Connection;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_format_parsing() {
+        assert_eq!("CSV".parse::<ExportFormat>().unwrap(), ExportFormat::Csv);
+        assert_eq!("excel".parse::<ExportFormat>().unwrap(), ExportFormat::Xlsx);
+        assert_eq!(
+            ExportFormat::from_extension(Path::new("out/issues.parquet")),
+            Some(ExportFormat::Parquet)
+        );
+        assert_eq!(ExportFormat::from_extension(Path::new("issues")), None);
+        assert!("xml".parse::<ExportFormat>().is_err());
+    }
+
+    #[test]
+    fn test_export_query_to_csv() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE issues (key VARCHAR, summary VARCHAR);
+             INSERT INTO issues VALUES ('PROJ-1', 'first'), ('PROJ-2', 'second');",
+        )
+        .unwrap();
+        let use_case = ExportDataUseCase::new(Arc::new(Mutex::new(conn)));
+
+        let out = std::env::temp_dir().join(format!("jira-db-export-{}.csv", std::process::id()));
+        let result = use_case
+            .execute(
+                &ExportSource::Query("SELECT key FROM issues ORDER BY key;".to_string()),
+                ExportFormat::Csv,
+                &out,
+            )
+            .unwrap();
+
+        assert_eq!(result.row_count, 2);
+        let content = std::fs::read_to_string(&out).unwrap();
+        assert_eq!(
+            content.lines().collect::<Vec<_>>(),
+            vec!["key", "PROJ-1", "PROJ-2"]
+        );
+        let _ = std::fs::remove_file(&out);
+
+        let rejected = use_case.execute(
+            &ExportSource::Query("DELETE FROM issues".to_string()),
+            ExportFormat::Csv,
+            &out,
+        );
+        assert!(rejected.is_err());
+    }
+
+    #[test]
+    fn test_export_query_cannot_escape_copy() {
+        let conn = Connection::open_in_memory().unwrap();
+        let use_case = ExportDataUseCase::new(Arc::new(Mutex::new(conn)));
+
+        let target =
+            std::env::temp_dir().join(format!("jira-db-injected-{}.csv", std::process::id()));
+        let out = std::env::temp_dir().join(format!("jira-db-export-{}.txt", std::process::id()));
+        let target_sql = target.to_string_lossy().replace('\'', "''");
+        let payloads = [
+            format!("SELECT 1) TO '{}' (FORMAT CSV) --", target_sql),
+            format!("SELECT 1) TO '{}' (FORMAT CSV); SELECT (1", target_sql),
+            format!("SELECT 1) TO '{}' /* (FORMAT CSV) */ (", target_sql),
+            "SELECT 1; SELECT 2".to_string(),
+        ];
+        for payload in payloads {
+            let result = use_case.execute(
+                &ExportSource::Query(payload.clone()),
+                ExportFormat::Csv,
+                &out,
+            );
+            assert!(
+                matches!(result, Err(DomainError::Validation(_))),
+                "accepted: {}",
+                payload
+            );
+        }
+        assert!(!target.exists());
+        assert!(!out.exists());
+
+        // Parentheses, semicolons and dashes inside literals are fine
+        let result = use_case
+            .execute(
+                &ExportSource::Query("SELECT '(;--' AS \"a)b\"".to_string()),
+                ExportFormat::Csv,
+                &out,
+            )
+            .unwrap();
+        assert_eq!(result.row_count, 1);
+        let _ = std::fs::remove_file(&out);
+    }
+}
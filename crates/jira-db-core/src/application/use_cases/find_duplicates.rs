@@ -0,0 +1,531 @@
+//! Find likely duplicate issues from their embeddings
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::Issue;
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{DateRange, IssueRepository, SearchParams, key_order};
+use crate::infrastructure::database::{
+    DbConnection, DuckDbIssueRepository, EmbeddingsRepository, NeighborFilter,
+};
+
+/// Cosine similarity at or above which two issues are reported by default
+pub const DEFAULT_DUPLICATE_THRESHOLD: f64 = 0.9;
+
+/// Nearest neighbors examined per issue by default
+const DEFAULT_NEIGHBORS: usize = 10;
+
+/// Issue in a duplicate report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateIssue {
+    pub key: String,
+    pub summary: String,
+    pub status: Option<String>,
+    pub issue_type: Option<String>,
+    pub created_date: Option<DateTime<Utc>>,
+}
+
+impl From<Issue> for DuplicateIssue {
+    fn from(issue: Issue) -> Self {
+        Self {
+            key: issue.key,
+            summary: issue.summary,
+            status: issue.status,
+            issue_type: issue.issue_type,
+            created_date: issue.created_date,
+        }
+    }
+}
+
+/// Two issues whose embeddings are at least as similar as the threshold
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicatePair {
+    pub left: String,
+    pub right: String,
+    /// Cosine similarity of the embeddings (1 = same direction)
+    pub similarity: f64,
+}
+
+/// Issues connected by candidate pairs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCluster {
+    /// Issues in key order
+    pub issues: Vec<DuplicateIssue>,
+    /// Candidate pairs within the cluster, most similar first
+    pub pairs: Vec<DuplicatePair>,
+}
+
+impl DuplicateCluster {
+    pub fn max_similarity(&self) -> f64 {
+        self.pairs.first().map_or(0.0, |pair| pair.similarity)
+    }
+}
+
+/// Duplicate candidates of a project, for review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateReport {
+    pub project_key: String,
+    pub threshold: f64,
+    /// Only issues created at or after this were compared with the rest
+    pub since: Option<DateTime<Utc>>,
+    pub generated_at: DateTime<Utc>,
+    /// Issues whose neighbors were examined
+    pub scanned_issues: usize,
+    /// Candidate pairs left out because they are already linked as duplicates
+    pub linked_pairs: usize,
+    /// Most similar cluster first
+    pub clusters: Vec<DuplicateCluster>,
+}
+
+/// Likely duplicate of a given issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateMatch {
+    pub issue: DuplicateIssue,
+    pub similarity: f64,
+}
+
+/// Likely duplicates of one issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueDuplicates {
+    pub issue: DuplicateIssue,
+    /// Most similar first, without issues already linked as duplicates
+    pub duplicates: Vec<DuplicateMatch>,
+    /// Issues already linked to it as duplicates
+    pub linked: Vec<String>,
+}
+
+/// Finds issue pairs whose embeddings are nearly identical, looking up the
+/// nearest neighbors of each issue within the project through the HNSW index
+pub struct FindDuplicatesUseCase {
+    issue_repository: DuckDbIssueRepository,
+    embeddings_repository: EmbeddingsRepository,
+    threshold: f64,
+    neighbors: usize,
+    since: Option<DateTime<Utc>>,
+}
+
+impl FindDuplicatesUseCase {
+    pub fn new(db_conn: DbConnection) -> Self {
+        Self {
+            issue_repository: DuckDbIssueRepository::new(db_conn.clone()),
+            embeddings_repository: EmbeddingsRepository::new(db_conn),
+            threshold: DEFAULT_DUPLICATE_THRESHOLD,
+            neighbors: DEFAULT_NEIGHBORS,
+            since: None,
+        }
+    }
+
+    /// Minimum cosine similarity of a reported pair (0 < threshold <= 1)
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Nearest neighbors examined per issue
+    pub fn with_neighbors(mut self, neighbors: usize) -> Self {
+        self.neighbors = neighbors;
+        self
+    }
+
+    /// Only compare issues created at or after `since` with the rest of the project
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Find duplicate candidates among the project's embedded issues
+    pub fn execute(&self, project_key: &str, project_id: &str) -> DomainResult<DuplicateReport> {
+        self.validate()?;
+
+        let sources = self
+            .embeddings_repository
+            .embedded_issues(Some(project_id), self.since)?;
+        let scanned_issues = sources.len();
+        let linked = self.duplicate_links(project_id)?;
+        let filter = NeighborFilter {
+            project_id: Some(project_id.to_string()),
+            ..Default::default()
+        };
+
+        let mut pairs: HashMap<(String, String), f64> = HashMap::new();
+        let mut linked_pairs = HashSet::new();
+        for source in &sources {
+            for neighbor in
+                self.embeddings_repository
+                    .similar_to(source, &filter, self.neighbors)?
+            {
+                let similarity = 1.0 - neighbor.distance as f64;
+                if similarity < self.threshold {
+                    break;
+                }
+                let pair = ordered_pair(&source.issue_key, &neighbor.issue_key);
+                if linked.contains(&pair) {
+                    linked_pairs.insert(pair);
+                    continue;
+                }
+                let best = pairs.entry(pair).or_insert(similarity);
+                *best = best.max(similarity);
+            }
+        }
+
+        let pairs = pairs
+            .into_iter()
+            .map(|((left, right), similarity)| DuplicatePair {
+                left,
+                right,
+                similarity,
+            })
+            .collect();
+        let groups = cluster_pairs(pairs);
+        let mut issues = self.issues(
+            groups
+                .iter()
+                .flat_map(|(keys, _)| keys.iter().cloned())
+                .collect(),
+        )?;
+
+        let clusters = groups
+            .into_iter()
+            .map(|(keys, pairs)| DuplicateCluster {
+                issues: keys
+                    .iter()
+                    .filter_map(|key| issues.remove(key))
+                    .map(DuplicateIssue::from)
+                    .collect(),
+                pairs,
+            })
+            .collect();
+
+        Ok(DuplicateReport {
+            project_key: project_key.to_string(),
+            threshold: self.threshold,
+            since: self.since,
+            generated_at: Utc::now(),
+            scanned_issues,
+            linked_pairs: linked_pairs.len(),
+            clusters,
+        })
+    }
+
+    /// Up to `limit` likely duplicates of one issue, most similar first
+    pub fn for_issue(&self, issue_key: &str, limit: usize) -> DomainResult<IssueDuplicates> {
+        self.validate()?;
+
+        let source = self
+            .embeddings_repository
+            .find_by_key(issue_key)?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!(
+                    "No embedding for {}. Run 'jira-db embeddings' first",
+                    issue_key
+                ))
+            })?;
+        let issue = self
+            .issues(vec![issue_key.to_string()])?
+            .remove(issue_key)
+            .ok_or_else(|| DomainError::NotFound(format!("Issue not found: {}", issue_key)))?;
+
+        let mut linked: Vec<String> = self
+            .duplicate_links(&issue.project_id)?
+            .into_iter()
+            .filter_map(
+                |(left, right)| match (left == issue_key, right == issue_key) {
+                    (true, _) => Some(right),
+                    (_, true) => Some(left),
+                    _ => None,
+                },
+            )
+            .collect();
+        linked.sort_by(|a, b| key_order(a).cmp(&key_order(b)));
+
+        let filter = NeighborFilter {
+            project_id: Some(issue.project_id.clone()),
+            ..Default::default()
+        };
+        let matches: Vec<(String, f64)> = self
+            .embeddings_repository
+            .similar_to(&source, &filter, limit + linked.len())?
+            .into_iter()
+            .filter(|neighbor| !linked.contains(&neighbor.issue_key))
+            .map(|neighbor| (neighbor.issue_key, 1.0 - neighbor.distance as f64))
+            .filter(|(_, similarity)| *similarity >= self.threshold)
+            .take(limit)
+            .collect();
+
+        let mut issues = self.issues(matches.iter().map(|(key, _)| key.clone()).collect())?;
+        Ok(IssueDuplicates {
+            issue: issue.into(),
+            duplicates: matches
+                .into_iter()
+                .filter_map(|(key, similarity)| {
+                    issues.remove(&key).map(|issue| DuplicateMatch {
+                        issue: issue.into(),
+                        similarity,
+                    })
+                })
+                .collect(),
+            linked,
+        })
+    }
+
+    fn validate(&self) -> DomainResult<()> {
+        if !(self.threshold > 0.0 && self.threshold <= 1.0) {
+            return Err(DomainError::Validation(format!(
+                "Invalid threshold {}: expected a cosine similarity in (0, 1]",
+                self.threshold
+            )));
+        }
+        if self.neighbors == 0 {
+            return Err(DomainError::Validation(
+                "At least one neighbor must be examined per issue".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Issue pairs already linked as duplicates, in key order
+    fn duplicate_links(&self, project_id: &str) -> DomainResult<HashSet<(String, String)>> {
+        Ok(self
+            .issue_repository
+            .find_links(project_id)?
+            .into_iter()
+            .filter(|link| link.is_duplicate())
+            .map(|link| ordered_pair(&link.key, &link.linked_key))
+            .collect())
+    }
+
+    /// Non-deleted issues by key
+    fn issues(&self, keys: Vec<String>) -> DomainResult<HashMap<String, Issue>> {
+        if keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+        Ok(self
+            .issue_repository
+            .search(&SearchParams {
+                limit: Some(keys.len()),
+                keys,
+                ..Default::default()
+            })?
+            .into_iter()
+            .map(|issue| (issue.key.clone(), issue))
+            .collect())
+    }
+}
+
+/// Parse a `since` value: an age such as `30d`, `2w` or `12h`, or a date
+/// (`YYYY-MM-DD` or RFC 3339)
+pub fn parse_since(spec: &str, now: DateTime<Utc>) -> DomainResult<DateTime<Utc>> {
+    let spec = spec.trim();
+    let age = spec
+        .char_indices()
+        .last()
+        .and_then(|(index, unit)| {
+            let amount = i64::from(spec[..index].parse::<u32>().ok()?);
+            match unit {
+                'w' => TimeDelta::try_weeks(amount),
+                'd' => TimeDelta::try_days(amount),
+                'h' => TimeDelta::try_hours(amount),
+                _ => None,
+            }
+        })
+        .and_then(|age| now.checked_sub_signed(age));
+    if let Some(since) = age {
+        return Ok(since);
+    }
+
+    DateRange::parse(Some(spec), None)
+        .ok()
+        .and_then(|range| range.from)
+        .ok_or_else(|| {
+            DomainError::Validation(format!(
+                "Invalid since '{}': expected an age such as 30d, 2w or 12h, or a date",
+                spec
+            ))
+        })
+}
+
+/// Keys of a pair with the lower issue number first
+fn ordered_pair(a: &str, b: &str) -> (String, String) {
+    if key_order(a) <= key_order(b) {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+/// Group pairs into connected components. Each group holds its keys in key
+/// order and its pairs most similar first; groups are ordered by their most
+/// similar pair.
+fn cluster_pairs(pairs: Vec<DuplicatePair>) -> Vec<(Vec<String>, Vec<DuplicatePair>)> {
+    let mut adjacency: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for pair in &pairs {
+        adjacency.entry(&pair.left).or_default().push(&pair.right);
+        adjacency.entry(&pair.right).or_default().push(&pair.left);
+    }
+
+    let mut component: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<(Vec<String>, Vec<DuplicatePair>)> = Vec::new();
+    for &start in adjacency.keys() {
+        if component.contains_key(start) {
+            continue;
+        }
+        let index = groups.len();
+        let mut keys = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        component.insert(start.to_string(), index);
+        while let Some(key) = queue.pop_front() {
+            keys.push(key.to_string());
+            for &next in &adjacency[key] {
+                if !component.contains_key(next) {
+                    component.insert(next.to_string(), index);
+                    queue.push_back(next);
+                }
+            }
+        }
+        keys.sort_by(|a, b| key_order(a).cmp(&key_order(b)));
+        groups.push((keys, Vec::new()));
+    }
+
+    for pair in pairs {
+        let index = component[&pair.left];
+        groups[index].1.push(pair);
+    }
+    let by_similarity = |a: &DuplicatePair, b: &DuplicatePair| {
+        b.similarity
+            .total_cmp(&a.similarity)
+            .then_with(|| key_order(&a.left).cmp(&key_order(&b.left)))
+            .then_with(|| key_order(&a.right).cmp(&key_order(&b.right)))
+    };
+    for (_, pairs) in &mut groups {
+        pairs.sort_by(by_similarity);
+    }
+    groups.sort_by(|a, b| by_similarity(&a.1[0], &b.1[0]));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::test_support::{store_embeddings, test_connection};
+
+    fn pair(left: &str, right: &str, similarity: f64) -> DuplicatePair {
+        DuplicatePair {
+            left: left.into(),
+            right: right.into(),
+            similarity,
+        }
+    }
+
+    #[test]
+    fn test_parse_since_accepts_ages_and_dates() {
+        let now = DateTime::parse_from_rfc3339("2024-06-30T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            parse_since("30d", now).unwrap().to_rfc3339(),
+            "2024-05-31T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2w", now).unwrap().to_rfc3339(),
+            "2024-06-16T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2024-06-01", now).unwrap().to_rfc3339(),
+            "2024-06-01T00:00:00+00:00"
+        );
+        assert!(parse_since("30x", now).is_err());
+        assert!(parse_since("d", now).is_err());
+        assert!(parse_since("-5d", now).is_err());
+    }
+
+    #[test]
+    fn test_cluster_pairs_groups_connected_issues() {
+        let groups = cluster_pairs(vec![
+            pair("PROJ-2", "PROJ-10", 0.93),
+            pair("PROJ-5", "PROJ-6", 0.97),
+            pair("PROJ-1", "PROJ-2", 0.91),
+        ]);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, vec!["PROJ-5", "PROJ-6"]);
+        assert_eq!(groups[1].0, vec!["PROJ-1", "PROJ-2", "PROJ-10"]);
+        assert_eq!(groups[1].1[0].similarity, 0.93);
+        assert_eq!(groups[1].1[1].left, "PROJ-1");
+    }
+
+    #[test]
+    fn test_execute_skips_pairs_linked_as_duplicates() {
+        let conn = test_connection(
+            r#"
+            INSERT INTO issues
+                (id, project_id, key, summary, status, issue_type,
+                 created_date, updated_date, raw_data, is_deleted)
+            VALUES
+                ('1', '100', 'PROJ-1', 'Login fails', 'Open', 'Bug',
+                 '2024-05-01 09:00:00+00', '2024-05-01 09:00:00+00', '{"fields": {}}', false),
+                ('2', '100', 'PROJ-2', 'Cannot log in', 'Open', 'Bug',
+                 '2024-05-02 09:00:00+00', '2024-05-02 09:00:00+00', '{"fields": {}}', false),
+                ('3', '100', 'PROJ-3', 'Dark mode', 'Open', 'Story',
+                 '2024-05-03 09:00:00+00', '2024-05-03 09:00:00+00', '{"fields": {}}', false),
+                ('4', '100', 'PROJ-4', 'Login error', 'Done', 'Bug',
+                 '2024-05-04 09:00:00+00', '2024-05-04 09:00:00+00', '{"fields": {}}', false),
+                ('5', '100', 'PROJ-5', 'Night theme', 'Open', 'Story',
+                 '2024-05-05 09:00:00+00', '2024-05-05 09:00:00+00',
+                 '{"fields": {"issuelinks": [{"type": {"name": "Duplicate", "inward": "is duplicated by", "outward": "duplicates"}, "outwardIssue": {"key": "PROJ-3"}}]}}',
+                 false),
+                ('6', '100', 'PROJ-6', 'Login broken', 'Open', 'Bug',
+                 '2024-05-06 09:00:00+00', '2024-05-06 09:00:00+00', '{"fields": {}}', true);
+            "#,
+        );
+        store_embeddings(
+            &conn,
+            "text-embedding-3-small",
+            &[
+                ("1", "PROJ-1", vec![1.0, 0.0, 0.0]),
+                ("2", "PROJ-2", vec![0.99, 0.1, 0.0]),
+                ("3", "PROJ-3", vec![0.0, 1.0, 0.0]),
+                ("4", "PROJ-4", vec![0.98, 0.0, 0.2]),
+                ("5", "PROJ-5", vec![0.0, 0.99, 0.1]),
+                ("6", "PROJ-6", vec![0.97, 0.0, -0.2]),
+            ],
+        );
+
+        let report = FindDuplicatesUseCase::new(conn.clone())
+            .execute("PROJ", "100")
+            .unwrap();
+        assert_eq!(report.scanned_issues, 5);
+        assert_eq!(report.linked_pairs, 1);
+        assert_eq!(report.clusters.len(), 1);
+        let keys: Vec<&str> = report.clusters[0]
+            .issues
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["PROJ-1", "PROJ-2", "PROJ-4"]);
+        assert_eq!(report.clusters[0].pairs.len(), 3);
+        assert_eq!(report.clusters[0].pairs[0].right, "PROJ-2");
+
+        let since = DateTime::parse_from_rfc3339("2024-05-04T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let report = FindDuplicatesUseCase::new(conn.clone())
+            .with_since(since)
+            .execute("PROJ", "100")
+            .unwrap();
+        assert_eq!(report.scanned_issues, 2);
+        assert_eq!(report.clusters[0].pairs.len(), 2);
+
+        let use_case = FindDuplicatesUseCase::new(conn);
+        let duplicates = use_case.for_issue("PROJ-3", 5).unwrap();
+        assert!(duplicates.duplicates.is_empty());
+        assert_eq!(duplicates.linked, vec!["PROJ-5"]);
+
+        let duplicates = use_case.for_issue("PROJ-1", 1).unwrap();
+        assert_eq!(duplicates.duplicates.len(), 1);
+        assert_eq!(duplicates.duplicates[0].issue.key, "PROJ-2");
+    }
+}
@@ -4,9 +4,9 @@ use std::sync::Arc;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::Serialize;
 
-use crate::domain::entities::Issue;
+use crate::domain::entities::{Issue, TopicCluster};
 use crate::domain::error::DomainResult;
-use crate::domain::repositories::{ChangeHistoryRepository, IssueRepository};
+use crate::domain::repositories::{ChangeHistoryRepository, IssueRepository, TopicRepository};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ReportData {
@@ -27,6 +27,8 @@ pub struct ProjectReportData {
     pub component_counts: HashMap<String, usize>,
     pub sprint_counts: HashMap<String, usize>,
     pub timeline_data: Vec<TimelineDataPoint>,
+    /// Stored topic clusters, largest first (empty until topics are clustered)
+    pub topics: Vec<TopicCluster>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -70,6 +72,7 @@ where
 {
     issue_repository: Arc<I>,
     change_history_repository: Arc<C>,
+    topic_repository: Option<Arc<dyn TopicRepository>>,
 }
 
 impl<I, C> GenerateReportUseCase<I, C>
@@ -81,9 +84,16 @@ where
         Self {
             issue_repository,
             change_history_repository,
+            topic_repository: None,
         }
     }
 
+    /// Include the stored topic clusters of each project
+    pub fn with_topic_repository(mut self, topic_repository: Arc<dyn TopicRepository>) -> Self {
+        self.topic_repository = Some(topic_repository);
+        self
+    }
+
     pub fn execute(&self, project_keys: &[(&str, &str, &str)]) -> DomainResult<ReportData> {
         let mut projects = Vec::new();
         let mut total_issues = 0;
@@ -92,7 +102,10 @@ where
             let issues = self.issue_repository.find_by_project(project_id)?;
             total_issues += issues.len();
 
-            let project_data = self.build_project_data(project_key, project_name, &issues)?;
+            let mut project_data = self.build_project_data(project_key, project_name, &issues)?;
+            if let Some(topic_repository) = &self.topic_repository {
+                project_data.topics = topic_repository.find_clusters(project_id)?;
+            }
             projects.push(project_data);
         }
 
@@ -204,6 +217,7 @@ where
             component_counts,
             sprint_counts,
             timeline_data,
+            topics: Vec::new(),
         })
     }
 
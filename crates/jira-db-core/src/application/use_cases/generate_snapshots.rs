@@ -545,6 +545,9 @@ where
     }
 
     /// Generate snapshots for a single issue
+    ///
+    /// The history includes rows derived from payload diffs during sync, so fields JIRA does
+    /// not log in the changelog still produce new versions.
     fn generate_snapshots_for_issue(&self, issue: &Issue) -> DomainResult<Vec<IssueSnapshot>> {
         let history = self
             .change_history_repository
@@ -1,4 +1,6 @@
-use crate::domain::entities::{Component, FixVersion, IssueType, Label, Priority, Status};
+use crate::domain::entities::{
+    Component, FieldOption, FixVersion, IssueType, Label, Priority, Status,
+};
 use crate::domain::error::DomainResult;
 use crate::domain::repositories::MetadataRepository;
 use std::sync::Arc;
@@ -11,6 +13,7 @@ pub struct ProjectMetadata {
     pub labels: Vec<Label>,
     pub components: Vec<Component>,
     pub fix_versions: Vec<FixVersion>,
+    pub field_options: Vec<FieldOption>,
 }
 
 pub struct GetProjectMetadataUseCase<M>
@@ -50,6 +53,9 @@ where
             fix_versions: self
                 .metadata_repository
                 .find_fix_versions_by_project(project_id)?,
+            field_options: self
+                .metadata_repository
+                .find_field_options_by_project(project_id)?,
         })
     }
 
@@ -91,6 +97,11 @@ where
                     .metadata_repository
                     .find_fix_versions_by_project(project_id)?
             }
+            "field-option" => {
+                metadata.field_options = self
+                    .metadata_repository
+                    .find_field_options_by_project(project_id)?
+            }
             _ => return self.execute(project_id),
         }
 
@@ -0,0 +1,430 @@
+//! Hybrid keyword + semantic search use case
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::domain::entities::Issue;
+use crate::domain::error::DomainResult;
+use crate::domain::repositories::{
+    FacetField, IssueRepository, IssueSearchHit, SearchFacet, SearchParams, merge_facets,
+};
+use crate::infrastructure::config::SearchSettings;
+use crate::infrastructure::database::EmbeddingsRepository;
+use crate::infrastructure::external::embeddings::EmbeddingProvider;
+
+/// Number of results returned when the params carry no limit
+const DEFAULT_LIMIT: usize = 100;
+
+/// Minimum number of candidates taken from each ranking before fusion
+const MIN_CANDIDATES: usize = 50;
+
+/// Issue returned by a hybrid search, with the score of each signal
+#[derive(Debug, Clone)]
+pub struct HybridSearchHit {
+    pub issue: Issue,
+    /// Reciprocal rank fusion score (higher is better)
+    pub score: f64,
+    /// 1-based position in the keyword ranking
+    pub lexical_rank: Option<usize>,
+    /// BM25 relevance score (None when no full-text index was used)
+    pub lexical_score: Option<f64>,
+    /// 1-based position in the embedding similarity ranking
+    pub semantic_rank: Option<usize>,
+    /// Cosine distance to the query embedding (lower is more similar)
+    pub semantic_distance: Option<f64>,
+    /// Excerpt around the first keyword match, with matched terms wrapped in `**`
+    pub snippet: Option<String>,
+}
+
+/// One page of hybrid search hits
+#[derive(Debug, Clone)]
+pub struct HybridSearchResult {
+    pub hits: Vec<HybridSearchHit>,
+    /// Issues matching the search across all pages: every keyword match plus
+    /// the semantic candidates that did not match the keywords
+    pub total: usize,
+    /// Value counts over the same issues as `total`, for the requested fields
+    pub facets: Vec<SearchFacet>,
+}
+
+/// Runs keyword and semantic search and fuses both rankings with
+/// weighted reciprocal rank fusion: `score = Σ weight / (k + rank)`.
+///
+/// Falls back to the keyword ranking alone when the project has no
+/// embeddings, no provider is available to embed the query, or the
+/// results are sorted by a field rather than by relevance.
+pub struct HybridSearchUseCase<I>
+where
+    I: IssueRepository,
+{
+    issue_repository: Arc<I>,
+    embeddings_repository: Arc<EmbeddingsRepository>,
+    settings: SearchSettings,
+    facets: Vec<FacetField>,
+}
+
+impl<I> HybridSearchUseCase<I>
+where
+    I: IssueRepository,
+{
+    pub fn new(issue_repository: Arc<I>, embeddings_repository: Arc<EmbeddingsRepository>) -> Self {
+        Self {
+            issue_repository,
+            embeddings_repository,
+            settings: SearchSettings::default(),
+            facets: Vec::new(),
+        }
+    }
+
+    /// Use the fusion weights from the settings file
+    pub fn with_settings(mut self, settings: SearchSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Override the fusion weight of each signal
+    pub fn with_weights(mut self, lexical: Option<f64>, semantic: Option<f64>) -> Self {
+        if let Some(weight) = lexical {
+            self.settings.lexical_weight = weight;
+        }
+        if let Some(weight) = semantic {
+            self.settings.semantic_weight = weight;
+        }
+        self
+    }
+
+    /// Count the values of these fields over the full result set
+    pub fn with_facets(mut self, fields: &[FacetField]) -> Self {
+        self.facets = fields.to_vec();
+        self
+    }
+
+    /// Whether the semantic ranking can contribute (embeddings exist and are weighted)
+    pub fn semantic_available(&self) -> bool {
+        self.settings.semantic_weight > 0.0
+            && self
+                .embeddings_repository
+                .count()
+                .map(|count| count > 0)
+                .unwrap_or(false)
+    }
+
+    /// Embed the query with `provider` and run the hybrid search.
+    ///
+    /// A failure to embed the query is logged and the keyword ranking is used alone.
+    pub async fn execute(
+        &self,
+        params: SearchParams,
+        provider: Option<&dyn EmbeddingProvider>,
+    ) -> DomainResult<HybridSearchResult> {
+        let query = params.query.as_deref().unwrap_or_default().trim();
+        let query_embedding = match provider {
+            Some(provider)
+                if !query.is_empty()
+                    && params.sorts_by_relevance()
+                    && self.semantic_available() =>
+            {
+                match provider.embed(query).await {
+                    Ok(embedding) => Some(embedding),
+                    Err(e) => {
+                        log::warn!("Failed to embed search query, using keyword ranking: {}", e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        self.execute_with_embedding(params, query_embedding.as_deref())
+    }
+
+    /// Run the hybrid search with an already computed query embedding, so one
+    /// embedding can be reused across project databases
+    pub fn execute_with_embedding(
+        &self,
+        params: SearchParams,
+        query_embedding: Option<&[f32]>,
+    ) -> DomainResult<HybridSearchResult> {
+        let query_embedding =
+            query_embedding.filter(|_| params.sorts_by_relevance() && self.semantic_available());
+        let offset = params.offset.unwrap_or(0);
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+        let candidates = (offset + limit).max(MIN_CANDIDATES);
+        let run_lexical = self.settings.lexical_weight > 0.0 || query_embedding.is_none();
+
+        let lexical = if run_lexical {
+            self.issue_repository.search_ranked(&SearchParams {
+                limit: Some(candidates),
+                offset: None,
+                ..params.clone()
+            })?
+        } else {
+            Vec::new()
+        };
+
+        let semantic = match query_embedding {
+            Some(embedding) => self.semantic_candidates(embedding, &params, candidates)?,
+            None => Vec::new(),
+        };
+
+        // Semantic candidates outside the keyword matches widen the result set
+        let semantic_only = if run_lexical {
+            self.semantic_only_keys(&semantic, &params)?
+        } else {
+            semantic
+                .iter()
+                .map(|(issue, _)| issue.key.clone())
+                .collect()
+        };
+
+        let mut total = semantic_only.len();
+        let mut facets = Vec::new();
+        if run_lexical {
+            total += self.issue_repository.count_matching(&params)?;
+            facets = self.issue_repository.facet_counts(&params, &self.facets)?;
+        }
+        if !semantic_only.is_empty() && !self.facets.is_empty() {
+            let semantic_facets = self
+                .issue_repository
+                .facet_counts(&keys_only(&params, semantic_only), &self.facets)?;
+            merge_facets(&mut facets, semantic_facets);
+        }
+
+        let hits = fuse(lexical, semantic, &self.settings)
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect();
+        Ok(HybridSearchResult {
+            hits,
+            total,
+            facets,
+        })
+    }
+
+    /// Nearest issues to the query embedding that pass the search filters,
+    /// closest first
+    fn semantic_candidates(
+        &self,
+        embedding: &[f32],
+        params: &SearchParams,
+        candidates: usize,
+    ) -> DomainResult<Vec<(Issue, f64)>> {
+        let nearest: Vec<_> = self
+            .embeddings_repository
+            .semantic_search(embedding, None, candidates)?
+            .into_iter()
+            .filter(|r| params.keys.is_empty() || params.keys.contains(&r.issue_key))
+            .collect();
+        if nearest.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut issues: HashMap<String, Issue> = self
+            .issue_repository
+            .search(&keys_only(
+                params,
+                nearest.iter().map(|r| r.issue_key.clone()).collect(),
+            ))?
+            .into_iter()
+            .map(|issue| (issue.key.clone(), issue))
+            .collect();
+
+        Ok(nearest
+            .into_iter()
+            .filter_map(|result| {
+                issues
+                    .remove(&result.issue_key)
+                    .map(|issue| (issue, result.similarity_score as f64))
+            })
+            .collect())
+    }
+
+    /// Keys of the semantic candidates that do not match the keyword search
+    fn semantic_only_keys(
+        &self,
+        semantic: &[(Issue, f64)],
+        params: &SearchParams,
+    ) -> DomainResult<Vec<String>> {
+        if semantic.is_empty() {
+            return Ok(Vec::new());
+        }
+        let keyword_matches: HashSet<String> = self
+            .issue_repository
+            .search(&SearchParams {
+                keys: semantic
+                    .iter()
+                    .map(|(issue, _)| issue.key.clone())
+                    .collect(),
+                sort: Vec::new(),
+                limit: None,
+                offset: None,
+                ..params.clone()
+            })?
+            .into_iter()
+            .map(|issue| issue.key)
+            .collect();
+
+        Ok(semantic
+            .iter()
+            .map(|(issue, _)| issue.key.clone())
+            .filter(|key| !keyword_matches.contains(key))
+            .collect())
+    }
+}
+
+/// The search filters restricted to `keys`, without the text query
+fn keys_only(params: &SearchParams, keys: Vec<String>) -> SearchParams {
+    SearchParams {
+        query: None,
+        keys,
+        sort: Vec::new(),
+        limit: None,
+        offset: None,
+        ..params.clone()
+    }
+}
+
+/// Merge both rankings into one list, best first, with one hit per issue
+fn fuse(
+    lexical: Vec<IssueSearchHit>,
+    semantic: Vec<(Issue, f64)>,
+    settings: &SearchSettings,
+) -> Vec<HybridSearchHit> {
+    let rrf = |weight: f64, rank: usize| weight / (settings.rrf_k + rank as f64);
+
+    let mut hits: Vec<HybridSearchHit> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for (index, hit) in lexical.into_iter().enumerate() {
+        if positions.contains_key(&hit.issue.key) {
+            continue;
+        }
+        let rank = index + 1;
+        positions.insert(hit.issue.key.clone(), hits.len());
+        hits.push(HybridSearchHit {
+            score: rrf(settings.lexical_weight, rank),
+            lexical_rank: Some(rank),
+            lexical_score: hit.score,
+            semantic_rank: None,
+            semantic_distance: None,
+            snippet: hit.snippet,
+            issue: hit.issue,
+        });
+    }
+
+    for (index, (issue, distance)) in semantic.into_iter().enumerate() {
+        let rank = index + 1;
+        match positions.get(&issue.key) {
+            Some(&position) => {
+                let hit = &mut hits[position];
+                if hit.semantic_rank.is_none() {
+                    hit.score += rrf(settings.semantic_weight, rank);
+                    hit.semantic_rank = Some(rank);
+                    hit.semantic_distance = Some(distance);
+                }
+            }
+            None => {
+                positions.insert(issue.key.clone(), hits.len());
+                hits.push(HybridSearchHit {
+                    score: rrf(settings.semantic_weight, rank),
+                    lexical_rank: None,
+                    lexical_score: None,
+                    semantic_rank: Some(rank),
+                    semantic_distance: Some(distance),
+                    snippet: None,
+                    issue,
+                });
+            }
+        }
+    }
+
+    hits.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.issue.key.cmp(&b.issue.key))
+    });
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str, status: &str) -> Issue {
+        Issue::new(
+            key.to_string(),
+            "10000".to_string(),
+            key.to_string(),
+            format!("Summary of {}", key),
+            None,
+            Some(status.to_string()),
+            None,
+            Some("Alice Smith".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn lexical_hit(key: &str, score: f64) -> IssueSearchHit {
+        IssueSearchHit {
+            issue: issue(key, "Open"),
+            score: Some(score),
+            snippet: Some(format!("**{}**", key)),
+        }
+    }
+
+    fn keys(hits: &[HybridSearchHit]) -> Vec<&str> {
+        hits.iter().map(|hit| hit.issue.key.as_str()).collect()
+    }
+
+    #[test]
+    fn test_fuse_rewards_issues_found_by_both_signals() {
+        let lexical = vec![lexical_hit("PROJ-1", 9.0), lexical_hit("PROJ-2", 5.0)];
+        let semantic = vec![
+            (issue("PROJ-3", "Open"), 0.1),
+            (issue("PROJ-2", "Open"), 0.2),
+        ];
+
+        let hits = fuse(lexical, semantic, &SearchSettings::default());
+
+        assert_eq!(keys(&hits), vec!["PROJ-2", "PROJ-1", "PROJ-3"]);
+        let both = &hits[0];
+        assert_eq!(both.lexical_rank, Some(2));
+        assert_eq!(both.lexical_score, Some(5.0));
+        assert_eq!(both.semantic_rank, Some(2));
+        assert_eq!(both.semantic_distance, Some(0.2));
+        assert_eq!(both.snippet.as_deref(), Some("**PROJ-2**"));
+        assert!((both.score - 2.0 / 62.0).abs() < 1e-12);
+
+        let semantic_only = &hits[2];
+        assert_eq!(semantic_only.lexical_rank, None);
+        assert_eq!(semantic_only.snippet, None);
+    }
+
+    #[test]
+    fn test_fuse_applies_weights() {
+        let settings = SearchSettings {
+            lexical_weight: 0.5,
+            semantic_weight: 2.0,
+            ..SearchSettings::default()
+        };
+        let lexical = vec![lexical_hit("PROJ-1", 9.0)];
+        let semantic = vec![(issue("PROJ-2", "Open"), 0.1)];
+
+        let hits = fuse(lexical, semantic, &settings);
+
+        assert_eq!(keys(&hits), vec!["PROJ-2", "PROJ-1"]);
+    }
+}
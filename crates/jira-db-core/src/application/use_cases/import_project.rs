@@ -0,0 +1,458 @@
+//! Import a project from a JIRA export file (no API access needed)
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use log::{info, warn};
+use serde_json::Value;
+
+use crate::domain::entities::{
+    ChangeHistoryItem, Component, FixVersion, Issue, IssueType, JiraField, Label, Priority, Status,
+};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{
+    ChangeHistoryRepository, IssueRepository, MetadataRepository, SyncHistoryRepository,
+};
+use crate::infrastructure::database::{
+    DuckDbFieldRepository, DuckDbIssuesExpandedRepository, FullTextSearchRepository,
+    SharedRawDataRepository,
+};
+use crate::infrastructure::external::jira::{JiraApiClient, JiraExport};
+
+/// `sync_history.sync_type` recorded for imports
+pub const IMPORT_SYNC_TYPE: &str = "import";
+
+/// Result of an import
+#[derive(Debug, Clone, Default)]
+pub struct ImportResult {
+    pub issues_imported: usize,
+    /// Issues in the file that belong to other projects
+    pub issues_skipped: usize,
+    /// Change history rows taken from changelogs in the file (JSON only)
+    pub history_items: usize,
+    /// Change history rows derived by diffing against previously stored issues
+    pub derived_changes: usize,
+    pub fields_imported: usize,
+    pub issues_expanded: usize,
+    /// CSV custom field columns whose name matches no single field in `jira_fields`;
+    /// their values are stored under placeholder fields until a fields sync resolves them
+    pub placeholder_fields: Vec<String>,
+}
+
+pub struct ImportProjectUseCase<I, C, M, S>
+where
+    I: IssueRepository,
+    C: ChangeHistoryRepository,
+    M: MetadataRepository,
+    S: SyncHistoryRepository,
+{
+    issue_repository: Arc<I>,
+    change_history_repository: Arc<C>,
+    metadata_repository: Arc<M>,
+    sync_history_repository: Arc<S>,
+    raw_repository: Option<SharedRawDataRepository>,
+    field_repositories: Option<(
+        Arc<DuckDbFieldRepository>,
+        Arc<DuckDbIssuesExpandedRepository>,
+    )>,
+    search_index: Option<(Arc<FullTextSearchRepository>, Vec<String>)>,
+}
+
+impl<I, C, M, S> ImportProjectUseCase<I, C, M, S>
+where
+    I: IssueRepository,
+    C: ChangeHistoryRepository,
+    M: MetadataRepository,
+    S: SyncHistoryRepository,
+{
+    pub fn new(
+        issue_repository: Arc<I>,
+        change_history_repository: Arc<C>,
+        metadata_repository: Arc<M>,
+        sync_history_repository: Arc<S>,
+    ) -> Self {
+        Self {
+            issue_repository,
+            change_history_repository,
+            metadata_repository,
+            sync_history_repository,
+            raw_repository: None,
+            field_repositories: None,
+            search_index: None,
+        }
+    }
+
+    /// Set the raw data repository for storing the imported payloads
+    pub fn with_raw_repository(mut self, raw_repository: SharedRawDataRepository) -> Self {
+        self.raw_repository = Some(raw_repository);
+        self
+    }
+
+    /// Store field definitions and refresh `issues_expanded` after the import
+    pub fn with_field_repositories(
+        mut self,
+        field_repo: Arc<DuckDbFieldRepository>,
+        expanded_repo: Arc<DuckDbIssuesExpandedRepository>,
+    ) -> Self {
+        self.field_repositories = Some((field_repo, expanded_repo));
+        self
+    }
+
+    /// Rebuild the full-text search index over `search_fields` after the import
+    pub fn with_search_index(
+        mut self,
+        search_repo: Arc<FullTextSearchRepository>,
+        search_fields: Vec<String>,
+    ) -> Self {
+        self.search_index = Some((search_repo, search_fields));
+        self
+    }
+
+    /// Import the issues of `project_key` from an export
+    ///
+    /// Issues are stored under `project_id` whatever the file says, so an import and a later
+    /// API sync land in the same project. The run is recorded in `sync_history` as `import`.
+    pub fn execute(
+        &self,
+        project_key: &str,
+        project_id: &str,
+        export: &JiraExport,
+    ) -> DomainResult<ImportResult> {
+        let started_at = Utc::now();
+        let history_id =
+            self.sync_history_repository
+                .insert(project_id, IMPORT_SYNC_TYPE, started_at)?;
+
+        match self.import(project_key, project_id, export, history_id) {
+            Ok(result) => {
+                self.sync_history_repository.update_completed(
+                    history_id,
+                    result.issues_imported,
+                    Utc::now(),
+                )?;
+                Ok(result)
+            }
+            Err(e) => {
+                self.sync_history_repository.update_failed(
+                    history_id,
+                    &e.to_string(),
+                    Utc::now(),
+                )?;
+                Err(e)
+            }
+        }
+    }
+
+    fn import(
+        &self,
+        project_key: &str,
+        project_id: &str,
+        export: &JiraExport,
+        history_id: i64,
+    ) -> DomainResult<ImportResult> {
+        let mut result = ImportResult::default();
+        let key_prefix = format!("{}-", project_key);
+
+        // CSV columns name custom fields, the synced field definitions give their ids
+        let known_fields = match self.field_repositories {
+            Some((ref field_repo, _)) => field_repo.find_all()?,
+            None => Vec::new(),
+        };
+        let mut placeholders = BTreeMap::new();
+
+        let mut issues: Vec<Issue> = Vec::new();
+        let mut has_changelog: Vec<bool> = Vec::new();
+        for payload in &export.issues {
+            let key = payload["key"].as_str().unwrap_or_default();
+            if !key.starts_with(&key_prefix) {
+                result.issues_skipped += 1;
+                continue;
+            }
+
+            let mut payload = payload.clone();
+            payload["fields"]["project"]["id"] = Value::String(project_id.to_string());
+            payload["fields"]["project"]["key"] = Value::String(project_key.to_string());
+            for placeholder in JiraExport::map_custom_fields(&mut payload, &known_fields) {
+                placeholders.insert(placeholder.id.clone(), placeholder);
+            }
+
+            let issue = JiraApiClient::parse_issue(&payload).ok_or_else(|| {
+                DomainError::Validation(format!("Issue {} has no id or key", key))
+            })?;
+            has_changelog.push(payload["changelog"]["histories"].is_array());
+            issues.push(issue);
+        }
+
+        if issues.is_empty() {
+            return Err(DomainError::NotFound(format!(
+                "No issues of project {} in the import file",
+                project_key
+            )));
+        }
+        let placeholders: Vec<JiraField> = placeholders.into_values().collect();
+        if !placeholders.is_empty() {
+            info!(
+                "Custom field columns stored under placeholder fields: {}",
+                placeholders
+                    .iter()
+                    .map(|field| field.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            result.placeholder_fields = placeholders.iter().map(|f| f.name.clone()).collect();
+        }
+
+        // A CSV has no changelog, so diffing against the stored issue is the only history
+        // source when the same project is imported repeatedly
+        let imported_at = Utc::now();
+        let mut derived_history = Vec::new();
+        for issue in &issues {
+            let Some(raw_json) = &issue.raw_json else {
+                continue;
+            };
+            if let Some(previous_raw) = self
+                .issue_repository
+                .find_by_key(&issue.key)?
+                .and_then(|stored| stored.raw_json)
+            {
+                derived_history.extend(ChangeHistoryItem::derive_from_payload_diff(
+                    &issue.id,
+                    &issue.key,
+                    &previous_raw,
+                    raw_json,
+                    &format!("import-{}", history_id),
+                    imported_at,
+                ));
+            }
+        }
+
+        self.issue_repository.batch_insert(&issues)?;
+        result.issues_imported = issues.len();
+
+        if let Some(ref raw_repo) = self.raw_repository {
+            let raw_data_items: Vec<(String, String, String, String)> = issues
+                .iter()
+                .filter_map(|issue| {
+                    issue.raw_json.as_ref().map(|raw| {
+                        (
+                            issue.id.clone(),
+                            issue.key.clone(),
+                            project_id.to_string(),
+                            raw.clone(),
+                        )
+                    })
+                })
+                .collect();
+            raw_repo.batch_upsert_issue_raw_data(&raw_data_items)?;
+            raw_repo.batch_append_issue_raw_versions(&raw_data_items, Some(history_id))?;
+        }
+
+        // Only replace changelog rows when the file brings a changelog of its own
+        for (issue, has_changelog) in issues.iter().zip(&has_changelog) {
+            let (Some(raw_json), true) = (&issue.raw_json, *has_changelog) else {
+                continue;
+            };
+            self.change_history_repository
+                .delete_changelog_by_issue_id(&issue.id)?;
+            let history_items =
+                ChangeHistoryItem::extract_from_raw_json(&issue.id, &issue.key, raw_json);
+            if !history_items.is_empty() {
+                result.history_items += history_items.len();
+                self.change_history_repository
+                    .batch_insert(&history_items)?;
+            }
+        }
+
+        if !derived_history.is_empty() {
+            result.derived_changes = derived_history.len();
+            self.change_history_repository
+                .batch_insert(&derived_history)?;
+        }
+
+        self.import_metadata(project_id, &issues)?;
+
+        if let Some((ref field_repo, ref expanded_repo)) = self.field_repositories {
+            result.fields_imported = field_repo.upsert_fields(&export.fields)?;
+            field_repo.upsert_fields(&placeholders)?;
+            expanded_repo.add_field_columns(&field_repo.find_all()?)?;
+            result.issues_expanded = expanded_repo.expand_issues(Some(project_id))?;
+            expanded_repo.create_readable_view(&field_repo.find_all()?)?;
+        }
+
+        // The issues are stored either way; a stale index only affects search
+        if let Some((ref search_repo, ref search_fields)) = self.search_index {
+            match search_repo.rebuild(search_fields) {
+                Ok(count) => info!("Indexed {} issues for search", count),
+                Err(e) => warn!("Failed to refresh search index: {}", e),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Derive the project metadata from the values used by the imported issues
+    fn import_metadata(&self, project_id: &str, issues: &[Issue]) -> DomainResult<()> {
+        let mut statuses: BTreeMap<String, Status> = BTreeMap::new();
+        let mut priorities: BTreeMap<String, Priority> = BTreeMap::new();
+        let mut issue_types: BTreeMap<String, IssueType> = BTreeMap::new();
+        let mut labels: BTreeMap<String, Label> = BTreeMap::new();
+        let mut components: BTreeMap<String, Component> = BTreeMap::new();
+        let mut fix_versions: BTreeMap<String, FixVersion> = BTreeMap::new();
+
+        for issue in issues {
+            let fields = issue
+                .raw_json
+                .as_deref()
+                .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+                .map(|json| json["fields"].clone())
+                .unwrap_or(Value::Null);
+
+            if let Some(name) = &issue.status {
+                statuses.entry(name.clone()).or_insert_with(|| Status {
+                    name: name.clone(),
+                    description: None,
+                    category: fields["status"]["statusCategory"]["name"]
+                        .as_str()
+                        .map(|s| s.to_string()),
+                });
+            }
+            if let Some(name) = &issue.priority {
+                priorities.entry(name.clone()).or_insert_with(|| Priority {
+                    name: name.clone(),
+                    description: None,
+                    icon_url: None,
+                });
+            }
+            if let Some(name) = &issue.issue_type {
+                issue_types
+                    .entry(name.clone())
+                    .or_insert_with(|| IssueType {
+                        name: name.clone(),
+                        description: None,
+                        icon_url: None,
+                        subtask: fields["issuetype"]["subtask"].as_bool().unwrap_or(false),
+                    });
+            }
+            for name in issue.labels.iter().flatten() {
+                labels
+                    .entry(name.clone())
+                    .or_insert_with(|| Label { name: name.clone() });
+            }
+            for name in issue.components.iter().flatten() {
+                components.entry(name.clone()).or_insert_with(|| Component {
+                    name: name.clone(),
+                    description: None,
+                    lead: None,
+                });
+            }
+            for name in issue.fix_versions.iter().flatten() {
+                fix_versions
+                    .entry(name.clone())
+                    .or_insert_with(|| FixVersion {
+                        name: name.clone(),
+                        description: None,
+                        released: false,
+                        release_date: None,
+                    });
+            }
+        }
+
+        let values = |map: BTreeMap<String, _>| map.into_values().collect::<Vec<_>>();
+        self.metadata_repository
+            .upsert_statuses(project_id, &values(statuses))?;
+        self.metadata_repository
+            .upsert_priorities(project_id, &values(priorities))?;
+        self.metadata_repository
+            .upsert_issue_types(project_id, &values(issue_types))?;
+        self.metadata_repository
+            .upsert_labels(project_id, &values(labels))?;
+        self.metadata_repository
+            .upsert_components(project_id, &values(components))?;
+        self.metadata_repository
+            .upsert_fix_versions(project_id, &values(fix_versions))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::sync_tests::{
+        MockChangeHistoryRepository, MockIssueRepository, MockMetadataRepository,
+        MockSyncHistoryRepository,
+    };
+    use serde_json::json;
+
+    fn export_with_status(status: &str) -> JiraExport {
+        JiraExport {
+            issues: vec![
+                json!({
+                    "id": "10001",
+                    "key": "PROJ-1",
+                    "fields": {
+                        "summary": "Imported",
+                        "status": { "name": status },
+                        "labels": ["backend"],
+                        "project": { "key": "PROJ" }
+                    }
+                }),
+                json!({ "id": "20001", "key": "OTHER-1", "fields": { "summary": "Other" } }),
+            ],
+            fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_import_stores_issues_and_diffs_reimports() {
+        let issue_repo = Arc::new(MockIssueRepository::new());
+        let history_repo = Arc::new(MockChangeHistoryRepository::new());
+        let metadata_repo = Arc::new(MockMetadataRepository::new());
+        let use_case = ImportProjectUseCase::new(
+            Arc::clone(&issue_repo),
+            Arc::clone(&history_repo),
+            Arc::clone(&metadata_repo),
+            Arc::new(MockSyncHistoryRepository::new()),
+        );
+
+        let result = use_case
+            .execute("PROJ", "100", &export_with_status("Open"))
+            .unwrap();
+        assert_eq!(result.issues_imported, 1);
+        assert_eq!(result.issues_skipped, 1);
+        assert_eq!(result.derived_changes, 0);
+
+        let issues = issue_repo.get_issues();
+        assert_eq!(issues[0].project_id, "100");
+        assert_eq!(issues[0].labels, Some(vec!["backend".to_string()]));
+        let statuses = metadata_repo.find_statuses_by_project("100").unwrap();
+        assert_eq!(statuses[0].name, "Open");
+
+        // The next export shows the issue resolved: the change is derived from the diff
+        let result = use_case
+            .execute("PROJ", "100", &export_with_status("Done"))
+            .unwrap();
+        assert_eq!(result.derived_changes, 1);
+        let history = history_repo.get_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].field, "status");
+        assert!(history[0].is_sync_diff());
+    }
+
+    #[test]
+    fn test_import_without_project_issues_fails() {
+        let use_case = ImportProjectUseCase::new(
+            Arc::new(MockIssueRepository::new()),
+            Arc::new(MockChangeHistoryRepository::new()),
+            Arc::new(MockMetadataRepository::new()),
+            Arc::new(MockSyncHistoryRepository::new()),
+        );
+
+        assert!(
+            use_case
+                .execute("NONE", "1", &export_with_status("Open"))
+                .is_err()
+        );
+    }
+}
@@ -1,12 +1,24 @@
+mod bulk_edit;
+mod cluster_topics;
 mod create_test_ticket;
+mod execute_jql;
 mod execute_sql;
+mod export_data;
+mod find_duplicates;
 mod generate_ai_test_data;
 mod generate_embeddings;
 mod generate_report;
 mod generate_snapshots;
 mod get_change_history;
 mod get_project_metadata;
+mod hybrid_search;
+mod import_project;
+mod move_issue;
+mod pending_operations;
+mod raw_diff;
+mod saved_searches;
 mod search_issues;
+mod similar_issues;
 mod sync_fields;
 mod sync_logger;
 mod sync_project;
@@ -14,9 +26,22 @@ mod sync_project_list;
 #[cfg(test)]
 mod sync_tests;
 mod transition_issue;
+mod update_issue_fields;
+mod workflows;
 
+pub use bulk_edit::{
+    BulkEditIssueResult, BulkEditPreview, BulkEditReport, BulkEditUseCase, FieldChange,
+    IssueChangePreview, diff_issue,
+};
+pub use cluster_topics::{ClusterTopicsUseCase, LabelSuggestion, SuggestedValue, TopicReport};
 pub use create_test_ticket::CreateTestTicketUseCase;
+pub use execute_jql::{ExecuteJqlUseCase, JqlResult};
 pub use execute_sql::{ExecuteSqlUseCase, SqlResult};
+pub use export_data::{ExportDataUseCase, ExportFormat, ExportResult, ExportSource};
+pub use find_duplicates::{
+    DEFAULT_DUPLICATE_THRESHOLD, DuplicateCluster, DuplicateIssue, DuplicateMatch, DuplicatePair,
+    DuplicateReport, FindDuplicatesUseCase, IssueDuplicates, parse_since,
+};
 pub use generate_ai_test_data::{
     AiTestDataConfig, AiTestDataResult, CreatedIssueInfo, FailedIssueInfo,
     GenerateAiTestDataUseCase, GenerationStats,
@@ -32,8 +57,22 @@ pub use generate_snapshots::{
 };
 pub use get_change_history::GetChangeHistoryUseCase;
 pub use get_project_metadata::GetProjectMetadataUseCase;
+pub use hybrid_search::{HybridSearchHit, HybridSearchResult, HybridSearchUseCase};
+pub use import_project::{IMPORT_SYNC_TYPE, ImportProjectUseCase, ImportResult};
+pub use move_issue::{MoveIssueUseCase, MoveResult, MoveStep};
+pub use pending_operations::{
+    PushOperationResult, PushOutcome, PushPendingOperationsUseCase, PushResult,
+    QueuePendingOperationUseCase,
+};
+pub use raw_diff::{JsonChange, JsonChangeKind, RawDiffUseCase, RawIssueDiff, diff_json};
+pub use saved_searches::{RunSavedSearchUseCase, SavedSearchResult};
 pub use search_issues::SearchIssuesUseCase;
+pub use similar_issues::{FindSimilarIssuesUseCase, SimilarIssue, SimilarIssuesResult};
 pub use sync_fields::{SyncFieldsResult, SyncFieldsUseCase};
 pub use sync_project::{ResumableSyncResult, SyncProjectUseCase};
 pub use sync_project_list::SyncProjectListUseCase;
 pub use transition_issue::{TransitionIssueUseCase, TransitionResult};
+pub use update_issue_fields::{
+    AllowedFieldValues, FieldEditValidator, UpdateIssueFieldsUseCase, prepare_field_update,
+};
+pub use workflows::{GetWorkflowUseCase, ValidateWorkflowTransitionsUseCase};
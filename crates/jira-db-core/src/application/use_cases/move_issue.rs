@@ -0,0 +1,376 @@
+//! Move issue use case
+//!
+//! Moves an issue to a target status by name, chaining as few transitions as possible.
+//! Each hop uses the transitions JIRA currently offers; a workflow graph (e.g., learned from
+//! change history) guides which transition to take when the target is not directly reachable.
+
+use std::sync::Arc;
+
+use log::{info, warn};
+
+use crate::application::dto::TransitionDto;
+use crate::application::services::JiraService;
+use crate::domain::entities::{WorkflowGraph, same_status};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::IssueRepository;
+
+/// Upper bound on transitions per issue (guards against cycles in a stale graph)
+const MAX_HOPS: usize = 10;
+
+/// One transition on the way to the target status
+#[derive(Debug, Clone)]
+pub struct MoveStep {
+    /// Transition to execute (`None` for hops planned from the graph only)
+    pub transition: Option<TransitionDto>,
+    pub to_status: String,
+}
+
+/// Result of moving (or planning to move) one issue
+#[derive(Debug, Clone)]
+pub struct MoveResult {
+    pub issue_key: String,
+    pub from_status: Option<String>,
+    pub steps: Vec<MoveStep>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl MoveResult {
+    /// Status path including the starting status (e.g., ["To Do", "In Progress", "Done"])
+    pub fn path(&self) -> Vec<String> {
+        self.from_status
+            .iter()
+            .cloned()
+            .chain(self.steps.iter().map(|s| s.to_status.clone()))
+            .collect()
+    }
+}
+
+pub struct MoveIssueUseCase<I, J>
+where
+    I: IssueRepository,
+    J: JiraService,
+{
+    issue_repository: Arc<I>,
+    jira_service: Arc<J>,
+}
+
+impl<I, J> MoveIssueUseCase<I, J>
+where
+    I: IssueRepository,
+    J: JiraService,
+{
+    pub fn new(issue_repository: Arc<I>, jira_service: Arc<J>) -> Self {
+        Self {
+            issue_repository,
+            jira_service,
+        }
+    }
+
+    /// Move several issues; transitions discovered along the way refine `graph`
+    pub async fn execute_many(
+        &self,
+        issue_keys: &[String],
+        target_status: &str,
+        graph: &mut WorkflowGraph,
+        dry_run: bool,
+    ) -> Vec<MoveResult> {
+        let mut results = Vec::with_capacity(issue_keys.len());
+        for issue_key in issue_keys {
+            let result = if dry_run {
+                self.plan(issue_key, target_status, graph).await
+            } else {
+                self.execute(issue_key, target_status, graph).await
+            };
+            results.push(result);
+        }
+        results
+    }
+
+    /// Compute the path without changing anything in JIRA.
+    ///
+    /// Only the first hop is checked against live transitions; later hops come from the graph.
+    pub async fn plan(
+        &self,
+        issue_key: &str,
+        target_status: &str,
+        graph: &mut WorkflowGraph,
+    ) -> MoveResult {
+        let mut result = MoveResult {
+            issue_key: issue_key.to_string(),
+            from_status: None,
+            steps: Vec::new(),
+            success: false,
+            error: None,
+        };
+
+        let outcome = self.plan_steps(&mut result, target_status, graph).await;
+        match outcome {
+            Ok(()) => result.success = true,
+            Err(e) => result.error = Some(e.to_string()),
+        }
+        result
+    }
+
+    async fn plan_steps(
+        &self,
+        result: &mut MoveResult,
+        target_status: &str,
+        graph: &mut WorkflowGraph,
+    ) -> DomainResult<()> {
+        let issue_key = result.issue_key.clone();
+        let current = self.current_status(&issue_key).await?;
+        result.from_status = Some(current.clone());
+        if same_status(&current, target_status) {
+            return Ok(());
+        }
+
+        let transitions = self.jira_service.get_issue_transitions(&issue_key).await?;
+        graph.set_transitions_from(&current, transitions.iter().map(|t| t.to_status.as_str()));
+
+        let first = choose_transition(&current, target_status, &transitions, graph)?;
+        check_required_fields(&current, first)?;
+
+        let path = graph
+            .shortest_path(&first.to_status, target_status)
+            .unwrap_or_default();
+        result.steps.push(MoveStep {
+            transition: Some(first.clone()),
+            to_status: first.to_status.clone(),
+        });
+        result
+            .steps
+            .extend(path.into_iter().skip(1).map(|to_status| MoveStep {
+                transition: None,
+                to_status,
+            }));
+        Ok(())
+    }
+
+    /// Move one issue to the target status
+    pub async fn execute(
+        &self,
+        issue_key: &str,
+        target_status: &str,
+        graph: &mut WorkflowGraph,
+    ) -> MoveResult {
+        let mut result = MoveResult {
+            issue_key: issue_key.to_string(),
+            from_status: None,
+            steps: Vec::new(),
+            success: false,
+            error: None,
+        };
+
+        let outcome = self.execute_steps(&mut result, target_status, graph).await;
+        match outcome {
+            Ok(()) => result.success = true,
+            Err(e) => result.error = Some(e.to_string()),
+        }
+
+        if !result.steps.is_empty() {
+            self.refresh_local(issue_key).await;
+        }
+        result
+    }
+
+    async fn execute_steps(
+        &self,
+        result: &mut MoveResult,
+        target_status: &str,
+        graph: &mut WorkflowGraph,
+    ) -> DomainResult<()> {
+        let issue_key = result.issue_key.clone();
+        let mut current = self.current_status(&issue_key).await?;
+        result.from_status = Some(current.clone());
+
+        while !same_status(&current, target_status) {
+            if result.steps.len() >= MAX_HOPS {
+                return Err(DomainError::Validation(format!(
+                    "Target status '{}' not reached within {} transitions",
+                    target_status, MAX_HOPS
+                )));
+            }
+
+            let transitions = self.jira_service.get_issue_transitions(&issue_key).await?;
+            graph.set_transitions_from(&current, transitions.iter().map(|t| t.to_status.as_str()));
+
+            let transition = choose_transition(&current, target_status, &transitions, graph)?;
+            check_required_fields(&current, transition)?;
+
+            info!(
+                "{}: {} -> {} ({})",
+                issue_key, current, transition.to_status, transition.name
+            );
+            self.jira_service
+                .transition_issue(&issue_key, &transition.id)
+                .await?;
+
+            current = transition.to_status.clone();
+            result.steps.push(MoveStep {
+                transition: Some(transition.clone()),
+                to_status: current.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn current_status(&self, issue_key: &str) -> DomainResult<String> {
+        let issue = self.jira_service.fetch_issue(issue_key).await?;
+        issue
+            .status
+            .ok_or_else(|| DomainError::Validation(format!("Issue {} has no status", issue_key)))
+    }
+
+    /// Re-fetch the issue so the local database reflects the new status
+    async fn refresh_local(&self, issue_key: &str) {
+        match self.jira_service.fetch_issue(issue_key).await {
+            Ok(issue) => {
+                if let Err(e) = self.issue_repository.batch_insert(&[issue]) {
+                    warn!("Failed to refresh local issue {}: {}", issue_key, e);
+                }
+            }
+            Err(e) => warn!("Failed to re-fetch issue {}: {}", issue_key, e),
+        }
+    }
+}
+
+/// Pick the transition that starts the shortest known path to the target
+fn choose_transition<'a>(
+    current: &str,
+    target_status: &str,
+    transitions: &'a [TransitionDto],
+    graph: &WorkflowGraph,
+) -> DomainResult<&'a TransitionDto> {
+    if let Some(direct) = transitions
+        .iter()
+        .find(|t| same_status(&t.to_status, target_status))
+    {
+        return Ok(direct);
+    }
+
+    // `graph` already has the live transitions of `current`, so the next hop is one of them
+    let next_hop = graph
+        .shortest_path(current, target_status)
+        .and_then(|path| path.into_iter().nth(1));
+    if let Some(transition) = next_hop.and_then(|next| {
+        transitions
+            .iter()
+            .find(|t| same_status(&t.to_status, &next))
+    }) {
+        return Ok(transition);
+    }
+
+    let available = transitions
+        .iter()
+        .map(|t| format!("'{}' -> {}", t.name, t.to_status))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(DomainError::Validation(format!(
+        "No known path from '{}' to '{}' (available: {}). \
+         Sync the project so change history can guide multi-step moves",
+        current,
+        target_status,
+        if available.is_empty() {
+            "none"
+        } else {
+            &available
+        }
+    )))
+}
+
+/// Transitions with screen fields cannot be completed here
+fn check_required_fields(current: &str, transition: &TransitionDto) -> DomainResult<()> {
+    if transition.required_fields.is_empty() {
+        return Ok(());
+    }
+    Err(DomainError::Validation(format!(
+        "Transition '{}' ({} -> {}) requires fields: {}. Complete it in JIRA",
+        transition.name,
+        current,
+        transition.to_status,
+        transition.required_fields.join(", ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::sync_tests::{
+        MockIssueRepository, MockJiraService, create_test_issue,
+    };
+    use chrono::Utc;
+
+    fn transition(id: &str, name: &str, to: &str) -> TransitionDto {
+        TransitionDto::new(id.to_string(), name.to_string(), to.to_string(), None)
+    }
+
+    fn workflow() -> Vec<(&'static str, TransitionDto)> {
+        vec![
+            ("Open", transition("11", "Start", "In Progress")),
+            ("In Progress", transition("21", "Review", "In Review")),
+            ("In Progress", transition("22", "Stop", "Open")),
+            ("In Review", transition("31", "Approve", "Done")),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_move_uses_graph_for_multi_hop() {
+        let issue = create_test_issue("1", "PROJ-1", "10000", Utc::now());
+        let jira = Arc::new(MockJiraService::new(vec![vec![issue]]).with_transitions(workflow()));
+        let use_case = MoveIssueUseCase::new(Arc::new(MockIssueRepository::new()), jira.clone());
+
+        let mut graph = WorkflowGraph::from_transitions([
+            ("Open", "In Progress"),
+            ("In Progress", "In Review"),
+            ("In Review", "Done"),
+        ]);
+        let result = use_case.execute("PROJ-1", "done", &mut graph).await;
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(
+            result.path(),
+            vec!["Open", "In Progress", "In Review", "Done"]
+        );
+        let ids: Vec<String> = jira
+            .get_transition_calls()
+            .into_iter()
+            .map(|(_, id)| id)
+            .collect();
+        assert_eq!(ids, vec!["11", "21", "31"]);
+    }
+
+    #[tokio::test]
+    async fn test_move_without_path_fails_clearly() {
+        let issue = create_test_issue("1", "PROJ-1", "10000", Utc::now());
+        let jira = Arc::new(MockJiraService::new(vec![vec![issue]]).with_transitions(workflow()));
+        let use_case = MoveIssueUseCase::new(Arc::new(MockIssueRepository::new()), jira.clone());
+
+        let result = use_case
+            .execute("PROJ-1", "Done", &mut WorkflowGraph::new())
+            .await;
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("No known path"));
+        assert!(jira.get_transition_calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_move_rejects_required_screen_fields() {
+        let issue = create_test_issue("1", "PROJ-1", "10000", Utc::now());
+        let mut resolve = transition("41", "Resolve", "Done");
+        resolve.required_fields = vec!["Resolution".to_string()];
+        let jira = Arc::new(
+            MockJiraService::new(vec![vec![issue]]).with_transitions(vec![("Open", resolve)]),
+        );
+        let use_case = MoveIssueUseCase::new(Arc::new(MockIssueRepository::new()), jira.clone());
+
+        let result = use_case
+            .execute("PROJ-1", "Done", &mut WorkflowGraph::new())
+            .await;
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Resolution"));
+        assert!(jira.get_transition_calls().is_empty());
+    }
+}
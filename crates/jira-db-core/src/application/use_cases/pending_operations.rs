@@ -0,0 +1,1013 @@
+use crate::application::dto::IssueFieldUpdate;
+use crate::application::services::JiraService;
+use crate::domain::entities::{
+    Issue, PendingOperation, PendingOperationKind, PendingOperationStatus,
+};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{IssueRepository, PendingOperationRepository};
+use crate::infrastructure::database::FullTextSearchRepository;
+use crate::infrastructure::external::jira::JiraApiClient;
+use chrono::{DateTime, NaiveDate, Utc};
+use log::{info, warn};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Records writes in the local queue and applies them optimistically to the local DB
+pub struct QueuePendingOperationUseCase<I, P>
+where
+    I: IssueRepository,
+    P: PendingOperationRepository,
+{
+    issue_repository: Arc<I>,
+    pending_repository: Arc<P>,
+}
+
+impl<I, P> QueuePendingOperationUseCase<I, P>
+where
+    I: IssueRepository,
+    P: PendingOperationRepository,
+{
+    pub fn new(issue_repository: Arc<I>, pending_repository: Arc<P>) -> Self {
+        Self {
+            issue_repository,
+            pending_repository,
+        }
+    }
+
+    /// Queue an operation and apply it to the local issue row.
+    ///
+    /// For `CreateIssue`, `issue_key` is the project key; nothing is written locally
+    /// until the issue has been created in JIRA.
+    pub fn enqueue(
+        &self,
+        issue_key: &str,
+        kind: PendingOperationKind,
+    ) -> DomainResult<PendingOperation> {
+        Self::validate(&kind)?;
+
+        let base_updated_at = if matches!(kind, PendingOperationKind::CreateIssue { .. }) {
+            None
+        } else {
+            self.issue_repository
+                .find_by_key(issue_key)?
+                .ok_or_else(|| DomainError::NotFound(format!("Issue not found: {}", issue_key)))?
+                .updated_date
+        };
+
+        let id = self
+            .pending_repository
+            .insert(issue_key, &kind, base_updated_at)?;
+
+        // Optimistic local update (overwritten by the next sync or push)
+        apply_locally(self.issue_repository.as_ref(), issue_key, &kind)?;
+
+        self.pending_repository
+            .find_by_id(id)?
+            .ok_or_else(|| DomainError::Repository(format!("Pending operation {} not found", id)))
+    }
+
+    /// List queued operations, optionally filtered by status
+    pub fn list(
+        &self,
+        status: Option<PendingOperationStatus>,
+    ) -> DomainResult<Vec<PendingOperation>> {
+        self.pending_repository.find_by_status(status)
+    }
+
+    /// Put a rejected operation back in the queue, overriding conflict detection.
+    /// Its optimistic local change, undone when it was rejected, is applied again.
+    pub fn retry(&self, id: i64) -> DomainResult<()> {
+        let operation = self.find(id)?;
+        if operation.status != PendingOperationStatus::Rejected {
+            return Err(DomainError::Validation(format!(
+                "Only rejected operations can be retried (operation {} is {})",
+                id,
+                operation.status.as_str()
+            )));
+        }
+        self.pending_repository.requeue(id)?;
+        apply_locally(
+            self.issue_repository.as_ref(),
+            &operation.issue_key,
+            &operation.kind,
+        )
+    }
+
+    /// Drop a pending or rejected operation.
+    ///
+    /// The local issue row goes back to the last state synced from JIRA, with the
+    /// operations still queued for the same issue applied on top.
+    pub fn discard(&self, id: i64) -> DomainResult<()> {
+        let operation = self.find(id)?;
+        if operation.status == PendingOperationStatus::Applied {
+            return Err(DomainError::Validation(format!(
+                "Operation {} has already been applied",
+                id
+            )));
+        }
+        self.pending_repository
+            .update_status(id, PendingOperationStatus::Discarded, None, None)?;
+
+        if !changes_locally(&operation.kind) {
+            return Ok(());
+        }
+        if let Err(e) = restore_locally(self.issue_repository.as_ref(), &operation.issue_key) {
+            warn!(
+                "Failed to restore local issue {}: {}",
+                operation.issue_key, e
+            );
+            return Ok(());
+        }
+        for queued in self
+            .pending_repository
+            .find_by_status(Some(PendingOperationStatus::Pending))?
+            .iter()
+            .filter(|op| op.issue_key == operation.issue_key)
+        {
+            apply_locally(
+                self.issue_repository.as_ref(),
+                &queued.issue_key,
+                &queued.kind,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn find(&self, id: i64) -> DomainResult<PendingOperation> {
+        self.pending_repository
+            .find_by_id(id)?
+            .ok_or_else(|| DomainError::NotFound(format!("Pending operation not found: {}", id)))
+    }
+
+    fn validate(kind: &PendingOperationKind) -> DomainResult<()> {
+        match kind {
+            PendingOperationKind::Transition { to_status } if to_status.trim().is_empty() => Err(
+                DomainError::Validation("Target status must not be empty".into()),
+            ),
+            PendingOperationKind::Comment { body } if body.trim().is_empty() => {
+                Err(DomainError::Validation("Comment must not be empty".into()))
+            }
+            PendingOperationKind::UpdateDueDate { due_date }
+                if NaiveDate::parse_from_str(due_date, "%Y-%m-%d").is_err() =>
+            {
+                Err(DomainError::Validation(format!(
+                    "Invalid due date '{}': expected YYYY-MM-DD",
+                    due_date
+                )))
+            }
+            PendingOperationKind::UpdateFields { body }
+                if IssueFieldUpdate::from_request_body(body).is_empty() =>
+            {
+                Err(DomainError::Validation("No field changes given".into()))
+            }
+            PendingOperationKind::CreateIssue {
+                summary,
+                issue_type,
+                ..
+            } if summary.trim().is_empty() || issue_type.trim().is_empty() => Err(
+                DomainError::Validation("Summary and issue type must not be empty".into()),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Outcome of replaying a single pending operation
+#[derive(Debug, Clone)]
+pub enum PushOutcome {
+    /// Applied to JIRA
+    Applied,
+    /// The issue was updated in JIRA after the operation was queued
+    Conflict {
+        remote_updated_at: Option<DateTime<Utc>>,
+    },
+    /// JIRA refused the operation
+    Rejected(String),
+    /// Not attempted (e.g. JIRA unreachable); the operation stays pending
+    Skipped(String),
+}
+
+/// Result of replaying a single pending operation
+#[derive(Debug, Clone)]
+pub struct PushOperationResult {
+    pub operation: PendingOperation,
+    pub outcome: PushOutcome,
+}
+
+/// Result of a push run
+#[derive(Debug, Clone, Default)]
+pub struct PushResult {
+    pub results: Vec<PushOperationResult>,
+}
+
+impl PushResult {
+    pub fn applied_count(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, PushOutcome::Applied))
+            .count()
+    }
+
+    pub fn rejected_count(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| {
+                matches!(
+                    r.outcome,
+                    PushOutcome::Conflict { .. } | PushOutcome::Rejected(_)
+                )
+            })
+            .count()
+    }
+
+    pub fn skipped_count(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, PushOutcome::Skipped(_)))
+            .count()
+    }
+}
+
+/// Replays queued operations against JIRA with conflict detection on `updated_date`
+pub struct PushPendingOperationsUseCase<I, P, J>
+where
+    I: IssueRepository,
+    P: PendingOperationRepository,
+    J: JiraService,
+{
+    issue_repository: Arc<I>,
+    pending_repository: Arc<P>,
+    jira_service: Arc<J>,
+    search_index: Option<(Arc<FullTextSearchRepository>, Vec<String>)>,
+}
+
+impl<I, P, J> PushPendingOperationsUseCase<I, P, J>
+where
+    I: IssueRepository,
+    P: PendingOperationRepository,
+    J: JiraService,
+{
+    pub fn new(issue_repository: Arc<I>, pending_repository: Arc<P>, jira_service: Arc<J>) -> Self {
+        Self {
+            issue_repository,
+            pending_repository,
+            jira_service,
+            search_index: None,
+        }
+    }
+
+    /// Rebuild the full-text search index over `search_fields` after issues were refreshed
+    pub fn with_search_index(
+        mut self,
+        search_repo: Arc<FullTextSearchRepository>,
+        search_fields: Vec<String>,
+    ) -> Self {
+        self.search_index = Some((search_repo, search_fields));
+        self
+    }
+
+    /// Push all pending operations in the order they were queued
+    pub async fn execute(&self) -> DomainResult<PushResult> {
+        let operations = self
+            .pending_repository
+            .find_by_status(Some(PendingOperationStatus::Pending))?;
+
+        info!("Pushing {} pending operation(s)", operations.len());
+
+        // updated_date observed after our own writes, so consecutive operations
+        // on the same issue don't conflict with each other
+        let mut own_updates: HashMap<String, DateTime<Utc>> = HashMap::new();
+        let mut result = PushResult::default();
+
+        for (index, mut operation) in operations.iter().cloned().enumerate() {
+            if operation.base_updated_at.is_some() {
+                if let Some(updated_at) = own_updates.get(&operation.issue_key) {
+                    operation.base_updated_at = Some(*updated_at);
+                }
+            }
+
+            let outcome = self.push_one(&operation).await;
+
+            match &outcome {
+                PushOutcome::Applied => {
+                    self.pending_repository.update_status(
+                        operation.id,
+                        PendingOperationStatus::Applied,
+                        None,
+                        Some(Utc::now()),
+                    )?;
+                    // Created issues are stored under their new key by push_one
+                    if !matches!(operation.kind, PendingOperationKind::CreateIssue { .. }) {
+                        // Without a refresh, later operations keep their own base timestamp
+                        // and a change made in JIRA meanwhile is still reported as a conflict
+                        match self.refresh_local_issue(&operation.issue_key).await {
+                            Ok(Some(updated_at)) => {
+                                own_updates.insert(operation.issue_key.clone(), updated_at);
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                warn!(
+                                    "Failed to refresh local issue {}: {}",
+                                    operation.issue_key, e
+                                )
+                            }
+                        }
+                        self.reapply_later(&operations[index + 1..], &operation.issue_key);
+                    }
+                }
+                PushOutcome::Conflict { remote_updated_at } => {
+                    let message = format!(
+                        "Conflict: issue was updated in JIRA at {} after this operation was queued",
+                        remote_updated_at
+                            .map(|d| d.to_rfc3339())
+                            .unwrap_or_else(|| "-".to_string())
+                    );
+                    self.pending_repository.update_status(
+                        operation.id,
+                        PendingOperationStatus::Rejected,
+                        Some(&message),
+                        None,
+                    )?;
+                    self.undo_locally(&operations[index + 1..], &operation)
+                        .await;
+                }
+                PushOutcome::Rejected(message) => {
+                    self.pending_repository.update_status(
+                        operation.id,
+                        PendingOperationStatus::Rejected,
+                        Some(message),
+                        None,
+                    )?;
+                    self.undo_locally(&operations[index + 1..], &operation)
+                        .await;
+                }
+                PushOutcome::Skipped(message) => {
+                    warn!(
+                        "Skipped pending operation {} ({}): {}",
+                        operation.id, operation.issue_key, message
+                    );
+                }
+            }
+
+            result
+                .results
+                .push(PushOperationResult { operation, outcome });
+        }
+
+        // Applied and rejected operations both refreshed local issues
+        if result.applied_count() + result.rejected_count() > 0 {
+            if let Some((ref search_repo, ref search_fields)) = self.search_index {
+                match search_repo.rebuild(search_fields) {
+                    Ok(count) => info!("Indexed {} issues for search", count),
+                    Err(e) => warn!("Failed to refresh search index: {}", e),
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn push_one(&self, operation: &PendingOperation) -> PushOutcome {
+        if let PendingOperationKind::CreateIssue {
+            summary,
+            description,
+            issue_type,
+        } = &operation.kind
+        {
+            return match self
+                .jira_service
+                .create_issue(
+                    &operation.issue_key,
+                    summary,
+                    description.as_deref(),
+                    issue_type,
+                )
+                .await
+            {
+                Ok(created) => {
+                    info!(
+                        "Created {} from pending operation {}",
+                        created.key, operation.id
+                    );
+                    if let Err(e) = self.refresh_local_issue(&created.key).await {
+                        warn!("Failed to fetch created issue {}: {}", created.key, e);
+                    }
+                    PushOutcome::Applied
+                }
+                Err(e) => PushOutcome::Rejected(e.to_string()),
+            };
+        }
+
+        let remote = match self.jira_service.fetch_issue(&operation.issue_key).await {
+            Ok(issue) => issue,
+            Err(DomainError::NotFound(_)) => {
+                return PushOutcome::Rejected("Issue no longer exists in JIRA".to_string());
+            }
+            Err(e) => return PushOutcome::Skipped(e.to_string()),
+        };
+
+        if operation.conflicts_with(remote.updated_date) {
+            return PushOutcome::Conflict {
+                remote_updated_at: remote.updated_date,
+            };
+        }
+
+        let applied = match &operation.kind {
+            PendingOperationKind::Transition { to_status } => {
+                if remote
+                    .status
+                    .as_deref()
+                    .is_some_and(|s| s.eq_ignore_ascii_case(to_status))
+                {
+                    // Already in the target status
+                    return PushOutcome::Applied;
+                }
+                match self
+                    .jira_service
+                    .get_issue_transitions(&operation.issue_key)
+                    .await
+                {
+                    Ok(transitions) => {
+                        match transitions
+                            .iter()
+                            .find(|t| t.to_status.eq_ignore_ascii_case(to_status))
+                        {
+                            Some(transition) => {
+                                self.jira_service
+                                    .transition_issue(&operation.issue_key, &transition.id)
+                                    .await
+                            }
+                            None => {
+                                return PushOutcome::Rejected(format!(
+                                    "No transition to '{}' available from '{}'",
+                                    to_status,
+                                    remote.status.as_deref().unwrap_or("-")
+                                ));
+                            }
+                        }
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            PendingOperationKind::Comment { body } => {
+                self.jira_service
+                    .add_comment(&operation.issue_key, body)
+                    .await
+            }
+            PendingOperationKind::UpdateDueDate { due_date } => {
+                self.jira_service
+                    .update_issue_due_date(&operation.issue_key, due_date)
+                    .await
+            }
+            PendingOperationKind::UpdateFields { body } => {
+                self.jira_service
+                    .update_issue_fields(
+                        &operation.issue_key,
+                        &IssueFieldUpdate::from_request_body(body),
+                    )
+                    .await
+            }
+            PendingOperationKind::CreateIssue { .. } => unreachable!(), // Handled above
+        };
+
+        match applied {
+            Ok(()) => PushOutcome::Applied,
+            Err(e) => PushOutcome::Rejected(e.to_string()),
+        }
+    }
+
+    /// Restore the optimistic local changes of operations still waiting in the queue,
+    /// after a refresh replaced the local row with JIRA's state
+    fn reapply_later(&self, later: &[PendingOperation], issue_key: &str) {
+        for operation in later.iter().filter(|op| op.issue_key == issue_key) {
+            if let Err(e) = apply_locally(
+                self.issue_repository.as_ref(),
+                &operation.issue_key,
+                &operation.kind,
+            ) {
+                warn!(
+                    "Failed to re-apply pending operation {} locally: {}",
+                    operation.id, e
+                );
+            }
+        }
+    }
+
+    /// Replace the optimistic change of a rejected operation with JIRA's state.
+    /// Falls back to the last synced state when JIRA can't be reached.
+    async fn undo_locally(&self, later: &[PendingOperation], operation: &PendingOperation) {
+        if !changes_locally(&operation.kind) {
+            return;
+        }
+        if let Err(e) = self.refresh_local_issue(&operation.issue_key).await {
+            warn!(
+                "Failed to refresh local issue {}, restoring the last synced state: {}",
+                operation.issue_key, e
+            );
+            if let Err(e) = restore_locally(self.issue_repository.as_ref(), &operation.issue_key) {
+                warn!(
+                    "Failed to restore local issue {}: {}",
+                    operation.issue_key, e
+                );
+            }
+        }
+        self.reapply_later(later, &operation.issue_key);
+    }
+
+    /// Re-fetch the issue from JIRA and store it locally.
+    /// Returns the new `updated_date`.
+    async fn refresh_local_issue(&self, issue_key: &str) -> DomainResult<Option<DateTime<Utc>>> {
+        let issue = self.jira_service.fetch_issue(issue_key).await?;
+        let updated_at = issue.updated_date;
+        self.issue_repository.batch_insert(&[issue])?;
+        Ok(updated_at)
+    }
+}
+
+/// Whether `apply_locally` changes the local issue row for this kind of operation
+fn changes_locally(kind: &PendingOperationKind) -> bool {
+    !matches!(
+        kind,
+        PendingOperationKind::Comment { .. } | PendingOperationKind::CreateIssue { .. }
+    )
+}
+
+/// Apply an operation optimistically to the local issue row
+fn apply_locally<I: IssueRepository + ?Sized>(
+    issue_repository: &I,
+    issue_key: &str,
+    kind: &PendingOperationKind,
+) -> DomainResult<()> {
+    match kind {
+        PendingOperationKind::Transition { to_status } => {
+            issue_repository.update_status(issue_key, to_status)
+        }
+        PendingOperationKind::UpdateDueDate { due_date } => {
+            issue_repository.update_due_date(issue_key, parse_due_date(due_date))
+        }
+        PendingOperationKind::UpdateFields { body } => {
+            let update = IssueFieldUpdate::from_request_body(body);
+            if let Some(due_date) = update.fields.get("duedate") {
+                issue_repository
+                    .update_due_date(issue_key, due_date.as_str().and_then(parse_due_date))?;
+            }
+            match issue_repository.find_by_key(issue_key)? {
+                Some(mut issue) => {
+                    apply_field_update(&mut issue, &update);
+                    issue_repository.update_editable_fields(&issue)
+                }
+                None => Ok(()),
+            }
+        }
+        PendingOperationKind::Comment { .. } | PendingOperationKind::CreateIssue { .. } => Ok(()),
+    }
+}
+
+/// Reset the locally editable columns of an issue to its last synced JIRA payload,
+/// dropping every optimistic change
+fn restore_locally<I: IssueRepository + ?Sized>(
+    issue_repository: &I,
+    issue_key: &str,
+) -> DomainResult<()> {
+    let synced = issue_repository
+        .find_by_key(issue_key)?
+        .and_then(|issue| issue.raw_json)
+        .and_then(|raw| serde_json::from_str::<Value>(&raw).ok())
+        .and_then(|json| JiraApiClient::parse_issue(&json))
+        .ok_or_else(|| {
+            DomainError::NotFound(format!("No synced JIRA data for issue {}", issue_key))
+        })?;
+
+    if let Some(status) = &synced.status {
+        issue_repository.update_status(issue_key, status)?;
+    }
+    issue_repository.update_due_date(issue_key, synced.due_date)?;
+    issue_repository.update_editable_fields(&synced)
+}
+
+/// Apply the locally stored fields of an edit payload to an issue.
+/// Other fields (assignee, description, custom fields) appear after the push.
+fn apply_field_update(issue: &mut Issue, update: &IssueFieldUpdate) {
+    fn name(value: &Value) -> Option<String> {
+        value
+            .as_str()
+            .or_else(|| value["name"].as_str())
+            .map(String::from)
+    }
+
+    for (field_id, value) in &update.fields {
+        let names = || -> Option<Vec<String>> {
+            value
+                .as_array()
+                .map(|v| v.iter().filter_map(name).collect())
+        };
+        match field_id.as_str() {
+            "summary" => {
+                if let Some(summary) = value.as_str() {
+                    issue.summary = summary.to_string();
+                }
+            }
+            "priority" => issue.priority = name(value),
+            "labels" => issue.labels = names(),
+            "components" => issue.components = names(),
+            "fixVersions" => issue.fix_versions = names(),
+            _ => {}
+        }
+    }
+
+    for (field_id, operations) in &update.update {
+        let values = match field_id.as_str() {
+            "labels" => &mut issue.labels,
+            "components" => &mut issue.components,
+            "fixVersions" => &mut issue.fix_versions,
+            _ => continue,
+        };
+        for operation in operations.as_array().into_iter().flatten() {
+            let list = values.get_or_insert_with(Vec::new);
+            if let Some(value) = operation.get("add").and_then(name) {
+                if !list.contains(&value) {
+                    list.push(value);
+                }
+            } else if let Some(value) = operation.get("remove").and_then(name) {
+                list.retain(|v| v != &value);
+            }
+        }
+    }
+}
+
+/// Convert a YYYY-MM-DD due date to the timestamp stored in `issues.due_date`
+fn parse_due_date(due_date: &str) -> Option<DateTime<Utc>> {
+    NaiveDate::parse_from_str(due_date, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::sync_tests::{
+        MockIssueRepository, MockJiraService, create_test_issue,
+    };
+    use chrono::Duration;
+    use std::sync::Mutex;
+
+    /// In-memory pending operation queue
+    #[derive(Default)]
+    struct MockPendingOperationRepository {
+        operations: Mutex<Vec<PendingOperation>>,
+    }
+
+    impl PendingOperationRepository for MockPendingOperationRepository {
+        fn insert(
+            &self,
+            issue_key: &str,
+            kind: &PendingOperationKind,
+            base_updated_at: Option<DateTime<Utc>>,
+        ) -> DomainResult<i64> {
+            let mut operations = self.operations.lock().unwrap();
+            let id = operations.len() as i64 + 1;
+            operations.push(PendingOperation {
+                id,
+                issue_key: issue_key.to_string(),
+                kind: kind.clone(),
+                base_updated_at,
+                status: PendingOperationStatus::Pending,
+                error_message: None,
+                created_at: Utc::now(),
+                applied_at: None,
+            });
+            Ok(id)
+        }
+
+        fn find_by_id(&self, id: i64) -> DomainResult<Option<PendingOperation>> {
+            let operations = self.operations.lock().unwrap();
+            Ok(operations.iter().find(|o| o.id == id).cloned())
+        }
+
+        fn find_by_status(
+            &self,
+            status: Option<PendingOperationStatus>,
+        ) -> DomainResult<Vec<PendingOperation>> {
+            let operations = self.operations.lock().unwrap();
+            Ok(operations
+                .iter()
+                .filter(|o| status.is_none_or(|s| o.status == s))
+                .cloned()
+                .collect())
+        }
+
+        fn update_status(
+            &self,
+            id: i64,
+            status: PendingOperationStatus,
+            error_message: Option<&str>,
+            applied_at: Option<DateTime<Utc>>,
+        ) -> DomainResult<()> {
+            let mut operations = self.operations.lock().unwrap();
+            if let Some(op) = operations.iter_mut().find(|o| o.id == id) {
+                op.status = status;
+                op.error_message = error_message.map(String::from);
+                op.applied_at = applied_at;
+            }
+            Ok(())
+        }
+
+        fn requeue(&self, id: i64) -> DomainResult<()> {
+            let mut operations = self.operations.lock().unwrap();
+            if let Some(op) = operations.iter_mut().find(|o| o.id == id) {
+                op.status = PendingOperationStatus::Pending;
+                op.error_message = None;
+                op.base_updated_at = None;
+            }
+            Ok(())
+        }
+    }
+
+    fn setup(
+        local_updated_at: DateTime<Utc>,
+        remote_updated_at: DateTime<Utc>,
+    ) -> (
+        Arc<MockIssueRepository>,
+        Arc<MockPendingOperationRepository>,
+        Arc<MockJiraService>,
+    ) {
+        let issue_repo = Arc::new(MockIssueRepository::new());
+        issue_repo
+            .batch_insert(&[synced_issue(local_updated_at)])
+            .unwrap();
+        let jira = Arc::new(MockJiraService::new(vec![vec![synced_issue(
+            remote_updated_at,
+        )]]));
+        (
+            issue_repo,
+            Arc::new(MockPendingOperationRepository::default()),
+            jira,
+        )
+    }
+
+    /// PROJ-1 with the JIRA payload it was synced from
+    fn synced_issue(updated_at: DateTime<Utc>) -> Issue {
+        let mut issue = create_test_issue("1", "PROJ-1", "10000", updated_at);
+        issue.raw_json = Some(
+            serde_json::json!({
+                "id": "1",
+                "key": "PROJ-1",
+                "fields": {
+                    "project": { "id": "10000" },
+                    "summary": "Test Issue PROJ-1",
+                    "status": { "name": "Open" },
+                    "priority": { "name": "High" },
+                    "labels": ["label1"]
+                }
+            })
+            .to_string(),
+        );
+        issue
+    }
+
+    #[test]
+    fn test_enqueue_applies_locally() {
+        let now = Utc::now();
+        let (issue_repo, pending_repo, _) = setup(now, now);
+        let use_case = QueuePendingOperationUseCase::new(issue_repo.clone(), pending_repo);
+
+        let op = use_case
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::Transition {
+                    to_status: "Done".to_string(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(op.base_updated_at, Some(now));
+        assert_eq!(op.status, PendingOperationStatus::Pending);
+        let local = issue_repo.find_by_key("PROJ-1").unwrap().unwrap();
+        assert_eq!(local.status.as_deref(), Some("Done"));
+
+        // Invalid input is rejected before anything is queued
+        assert!(
+            use_case
+                .enqueue(
+                    "PROJ-1",
+                    PendingOperationKind::UpdateDueDate {
+                        due_date: "tomorrow".to_string(),
+                    },
+                )
+                .is_err()
+        );
+        assert!(
+            use_case
+                .enqueue(
+                    "PROJ-404",
+                    PendingOperationKind::Comment {
+                        body: "hi".to_string(),
+                    },
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_enqueue_field_edit_and_create() {
+        let now = Utc::now();
+        let (issue_repo, pending_repo, _) = setup(now, now);
+        let use_case = QueuePendingOperationUseCase::new(issue_repo.clone(), pending_repo);
+
+        let op = use_case
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::UpdateFields {
+                    body: serde_json::json!({
+                        "fields": { "summary": "Renamed", "priority": { "name": "Highest" } },
+                        "update": { "labels": [{ "add": "offline" }] }
+                    }),
+                },
+            )
+            .unwrap();
+        assert_eq!(op.kind.describe(), "Edit summary, priority, labels");
+        let local = issue_repo.find_by_key("PROJ-1").unwrap().unwrap();
+        assert_eq!(local.summary, "Renamed");
+        assert_eq!(local.priority.as_deref(), Some("Highest"));
+        assert!(local.labels.unwrap().contains(&"offline".to_string()));
+
+        assert!(
+            use_case
+                .enqueue(
+                    "PROJ-1",
+                    PendingOperationKind::UpdateFields {
+                        body: serde_json::json!({}),
+                    },
+                )
+                .is_err()
+        );
+
+        // Creation is queued against the project and has no local row yet
+        let op = use_case
+            .enqueue(
+                "PROJ",
+                PendingOperationKind::CreateIssue {
+                    summary: "Written on the train".to_string(),
+                    description: None,
+                    issue_type: "Task".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(op.base_updated_at, None);
+    }
+
+    #[test]
+    fn test_discard_restores_last_synced_state() {
+        let now = Utc::now();
+        let (issue_repo, pending_repo, _) = setup(now, now);
+        let use_case = QueuePendingOperationUseCase::new(issue_repo.clone(), pending_repo);
+        let transition = use_case
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::Transition {
+                    to_status: "Done".to_string(),
+                },
+            )
+            .unwrap();
+        use_case
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::UpdateFields {
+                    body: serde_json::json!({ "fields": { "summary": "Renamed" } }),
+                },
+            )
+            .unwrap();
+
+        // The transition is undone, the edit still queued for the issue stays visible
+        use_case.discard(transition.id).unwrap();
+        let local = issue_repo.find_by_key("PROJ-1").unwrap().unwrap();
+        assert_eq!(local.status.as_deref(), Some("Open"));
+        assert_eq!(local.summary, "Renamed");
+    }
+
+    #[tokio::test]
+    async fn test_push_undoes_rejected_operations_locally() {
+        let now = Utc::now();
+        let (issue_repo, pending_repo, jira) = setup(now, now);
+        let queue = QueuePendingOperationUseCase::new(issue_repo.clone(), pending_repo.clone());
+        queue
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::Comment {
+                    body: "first".to_string(),
+                },
+            )
+            .unwrap();
+        let transition = queue
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::Transition {
+                    to_status: "Done".to_string(),
+                },
+            )
+            .unwrap();
+
+        // No transitions are available, so JIRA rejects the transition and the
+        // local row shows JIRA's status again
+        let push = PushPendingOperationsUseCase::new(issue_repo.clone(), pending_repo, jira);
+        let result = push.execute().await.unwrap();
+        assert_eq!(result.applied_count(), 1);
+        assert_eq!(result.rejected_count(), 1);
+        let local = issue_repo.find_by_key("PROJ-1").unwrap().unwrap();
+        assert_eq!(local.status.as_deref(), Some("Open"));
+
+        // Retrying shows the change locally again
+        queue.retry(transition.id).unwrap();
+        let local = issue_repo.find_by_key("PROJ-1").unwrap().unwrap();
+        assert_eq!(local.status.as_deref(), Some("Done"));
+    }
+
+    #[tokio::test]
+    async fn test_push_applies_when_unchanged_remotely() {
+        let now = Utc::now();
+        let (issue_repo, pending_repo, jira) = setup(now, now);
+        let queue = QueuePendingOperationUseCase::new(issue_repo.clone(), pending_repo.clone());
+        queue
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::Comment {
+                    body: "first".to_string(),
+                },
+            )
+            .unwrap();
+        queue
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::UpdateDueDate {
+                    due_date: "2024-12-31".to_string(),
+                },
+            )
+            .unwrap();
+
+        let push = PushPendingOperationsUseCase::new(issue_repo, pending_repo.clone(), jira);
+        let result = push.execute().await.unwrap();
+
+        assert_eq!(result.applied_count(), 2);
+        assert_eq!(result.rejected_count(), 0);
+        assert!(
+            pending_repo
+                .find_by_status(Some(PendingOperationStatus::Pending))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_detects_conflict_and_retry_overrides() {
+        let now = Utc::now();
+        let (issue_repo, pending_repo, jira) = setup(now, now + Duration::minutes(5));
+        let queue = QueuePendingOperationUseCase::new(issue_repo.clone(), pending_repo.clone());
+        let op = queue
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::Comment {
+                    body: "offline note".to_string(),
+                },
+            )
+            .unwrap();
+
+        let push = PushPendingOperationsUseCase::new(issue_repo, pending_repo.clone(), jira);
+        let result = push.execute().await.unwrap();
+        assert_eq!(result.rejected_count(), 1);
+        assert!(matches!(
+            result.results[0].outcome,
+            PushOutcome::Conflict { .. }
+        ));
+
+        let rejected = pending_repo.find_by_id(op.id).unwrap().unwrap();
+        assert_eq!(rejected.status, PendingOperationStatus::Rejected);
+        assert!(rejected.error_message.unwrap().starts_with("Conflict"));
+
+        // Retrying clears the base timestamp so the operation goes through
+        queue.retry(op.id).unwrap();
+        let result = push.execute().await.unwrap();
+        assert_eq!(result.applied_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_discarded_operations_are_not_pushed() {
+        let now = Utc::now();
+        let (issue_repo, pending_repo, jira) = setup(now, now);
+        let queue = QueuePendingOperationUseCase::new(issue_repo.clone(), pending_repo.clone());
+        let op = queue
+            .enqueue(
+                "PROJ-1",
+                PendingOperationKind::Comment {
+                    body: "never mind".to_string(),
+                },
+            )
+            .unwrap();
+        queue.discard(op.id).unwrap();
+
+        let push = PushPendingOperationsUseCase::new(issue_repo, pending_repo, jira);
+        let result = push.execute().await.unwrap();
+        assert!(result.results.is_empty());
+    }
+}
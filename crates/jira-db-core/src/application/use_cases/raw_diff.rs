@@ -0,0 +1,282 @@
+//! Raw payload diff use case
+//!
+//! Compares two recorded versions of an issue's raw API payload. This shows changes that
+//! never appear in the changelog (some custom fields, rank, computed fields).
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::domain::error::{DomainError, DomainResult};
+use crate::infrastructure::database::{RawIssueVersion, SharedRawDataRepository};
+
+/// How a value changed between two payloads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// A single changed value, addressed by its path (e.g., "fields.labels[0]")
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonChange {
+    pub path: String,
+    pub op: JsonChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Value>,
+}
+
+/// Differences between two versions of an issue's raw payload
+#[derive(Debug, Clone, Serialize)]
+pub struct RawIssueDiff {
+    pub issue_key: String,
+    pub from: RawIssueVersion,
+    pub to: RawIssueVersion,
+    pub changes: Vec<JsonChange>,
+}
+
+impl RawIssueDiff {
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+pub struct RawDiffUseCase {
+    raw_repository: SharedRawDataRepository,
+}
+
+impl RawDiffUseCase {
+    pub fn new(raw_repository: SharedRawDataRepository) -> Self {
+        Self { raw_repository }
+    }
+
+    pub fn versions(&self, issue_key: &str) -> DomainResult<Vec<RawIssueVersion>> {
+        self.raw_repository.find_issue_raw_versions(issue_key)
+    }
+
+    /// Diff the payloads as of two sync runs.
+    ///
+    /// `to_run` defaults to the latest version; `from_run` defaults to the version before it.
+    /// A run selects the latest version recorded at or before that run.
+    pub fn execute(
+        &self,
+        issue_key: &str,
+        from_run: Option<i64>,
+        to_run: Option<i64>,
+    ) -> DomainResult<RawIssueDiff> {
+        let versions = self.versions(issue_key)?;
+        let Some(latest) = versions.last() else {
+            return Err(DomainError::NotFound(format!(
+                "No raw payload versions recorded for {}. Sync the project first",
+                issue_key
+            )));
+        };
+
+        let to = match to_run {
+            Some(run) => version_at_run(&versions, run)?,
+            None => latest.clone(),
+        };
+        let from = match from_run {
+            Some(run) => version_at_run(&versions, run)?,
+            None => versions
+                .iter()
+                .rev()
+                .find(|v| v.version < to.version)
+                .cloned()
+                .ok_or_else(|| {
+                    DomainError::Validation(format!(
+                        "No earlier payload version of {} to compare with",
+                        issue_key
+                    ))
+                })?,
+        };
+
+        let changes = diff_json(&self.payload(&from)?, &self.payload(&to)?);
+        Ok(RawIssueDiff {
+            issue_key: issue_key.to_string(),
+            from,
+            to,
+            changes,
+        })
+    }
+
+    fn payload(&self, version: &RawIssueVersion) -> DomainResult<Value> {
+        let raw = self
+            .raw_repository
+            .get_issue_raw_version(&version.issue_key, version.version)?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!(
+                    "Raw payload version {} of {} not found",
+                    version.version, version.issue_key
+                ))
+            })?;
+        serde_json::from_str(&raw)
+            .map_err(|e| DomainError::Repository(format!("Failed to parse raw payload: {}", e)))
+    }
+}
+
+/// Latest version recorded at or before a sync run
+fn version_at_run(versions: &[RawIssueVersion], run: i64) -> DomainResult<RawIssueVersion> {
+    versions
+        .iter()
+        .rev()
+        .find(|v| v.sync_run_id.is_some_and(|id| id <= run))
+        .cloned()
+        .ok_or_else(|| {
+            DomainError::NotFound(format!(
+                "No payload version recorded at or before run {}",
+                run
+            ))
+        })
+}
+
+/// Structural diff of two JSON values.
+///
+/// Arrays whose elements all carry an "id" are matched by ID (so a new changelog entry
+/// does not shift every other entry); other arrays are compared by index.
+pub fn diff_json(from: &Value, to: &Value) -> Vec<JsonChange> {
+    let mut changes = Vec::new();
+    diff_value("", from, to, &mut changes);
+    changes
+}
+
+fn diff_value(path: &str, from: &Value, to: &Value, changes: &mut Vec<JsonChange>) {
+    match (from, to) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, old) in a {
+                let child = join_key(path, key);
+                match b.get(key) {
+                    Some(new) => diff_value(&child, old, new, changes),
+                    None => changes.push(removed(child, old)),
+                }
+            }
+            for (key, new) in b {
+                if !a.contains_key(key) {
+                    changes.push(added(join_key(path, key), new));
+                }
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => match (ids(a), ids(b)) {
+            (Some(a_ids), Some(b_ids)) => {
+                for (id, old) in a_ids.iter().zip(a) {
+                    let child = format!("{}[id={}]", path, id);
+                    match b_ids.iter().position(|other| other == id) {
+                        Some(i) => diff_value(&child, old, &b[i], changes),
+                        None => changes.push(removed(child, old)),
+                    }
+                }
+                for (id, new) in b_ids.iter().zip(b) {
+                    if !a_ids.contains(id) {
+                        changes.push(added(format!("{}[id={}]", path, id), new));
+                    }
+                }
+            }
+            _ => {
+                for i in 0..a.len().max(b.len()) {
+                    let child = format!("{}[{}]", path, i);
+                    match (a.get(i), b.get(i)) {
+                        (Some(old), Some(new)) => diff_value(&child, old, new, changes),
+                        (Some(old), None) => changes.push(removed(child, old)),
+                        (None, Some(new)) => changes.push(added(child, new)),
+                        (None, None) => {}
+                    }
+                }
+            }
+        },
+        _ if from != to => changes.push(JsonChange {
+            path: path.to_string(),
+            op: JsonChangeKind::Changed,
+            from: Some(from.clone()),
+            to: Some(to.clone()),
+        }),
+        _ => {}
+    }
+}
+
+/// IDs of all elements, if every element is an object with a string or numeric "id"
+fn ids(items: &[Value]) -> Option<Vec<String>> {
+    items
+        .iter()
+        .map(|item| match item.get("id")? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn added(path: String, value: &Value) -> JsonChange {
+    JsonChange {
+        path,
+        op: JsonChangeKind::Added,
+        from: None,
+        to: Some(value.clone()),
+    }
+}
+
+fn removed(path: String, value: &Value) -> JsonChange {
+    JsonChange {
+        path,
+        op: JsonChangeKind::Removed,
+        from: Some(value.clone()),
+        to: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_json_fields() {
+        let from = json!({
+            "key": "PROJ-1",
+            "fields": {
+                "customfield_10019": "0|i0001:",
+                "labels": ["a", "b"],
+                "summary": "Same"
+            }
+        });
+        let to = json!({
+            "key": "PROJ-1",
+            "fields": {
+                "customfield_10019": "0|i0002:",
+                "labels": ["a"],
+                "summary": "Same",
+                "customfield_10050": { "value": "High" }
+            }
+        });
+
+        let changes = diff_json(&from, &to);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].path, "fields.customfield_10019");
+        assert_eq!(changes[0].op, JsonChangeKind::Changed);
+        assert_eq!(changes[1].path, "fields.labels[1]");
+        assert_eq!(changes[1].op, JsonChangeKind::Removed);
+        assert_eq!(changes[2].path, "fields.customfield_10050");
+        assert_eq!(changes[2].to, Some(json!({ "value": "High" })));
+    }
+
+    #[test]
+    fn test_diff_json_matches_array_items_by_id() {
+        let from = json!({ "histories": [{ "id": "10", "items": [] }] });
+        let to = json!({ "histories": [{ "id": "11", "items": [] }, { "id": "10", "items": [] }] });
+
+        let changes = diff_json(&from, &to);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "histories[id=11]");
+        assert_eq!(changes[0].op, JsonChangeKind::Added);
+    }
+}
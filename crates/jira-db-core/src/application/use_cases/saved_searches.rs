@@ -0,0 +1,333 @@
+//! Run saved searches
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use super::execute_jql::ExecuteJqlUseCase;
+use super::execute_sql::{ExecuteSqlUseCase, SqlResult};
+use super::search_issues::SearchIssuesUseCase;
+use crate::domain::entities::{
+    Issue, SavedSearch, SavedSearchFilter, SavedSearchQuery, SavedSearchValue, render_jql,
+    render_sql,
+};
+use crate::domain::error::DomainResult;
+use crate::domain::repositories::{DateRange, LabelMatch, SearchParams, SearchSort};
+use crate::infrastructure::database::{DatabaseFactory, DbConnection, DuckDbIssueRepository};
+
+/// Columns of filter-based results, matching the default JQL columns
+const FILTER_COLUMNS: [&str; 8] = [
+    "key",
+    "summary",
+    "status",
+    "assignee",
+    "priority",
+    "issue_type",
+    "created_date",
+    "updated_date",
+];
+
+/// Result of running a saved search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearchResult {
+    pub name: String,
+    /// "sql", "jql" or "filter"
+    pub kind: String,
+    /// SQL that was executed, for SQL and JQL searches
+    pub sql: Option<String>,
+    /// Number of matching issues across all pages, for filter searches
+    pub total: Option<usize>,
+    pub result: SqlResult,
+}
+
+/// Runs a saved search with typed arguments
+pub struct RunSavedSearchUseCase {
+    db_conn: DbConnection,
+    current_user: Option<String>,
+}
+
+impl RunSavedSearchUseCase {
+    pub fn new(db_conn: DbConnection) -> Self {
+        Self {
+            db_conn,
+            current_user: None,
+        }
+    }
+
+    /// Connect to `project`, else the search's own project, else every project
+    pub fn connect(
+        db_factory: &DatabaseFactory,
+        search: &SavedSearch,
+        project: Option<&str>,
+    ) -> DomainResult<Self> {
+        let db_conn = match project.or(search.project.as_deref()) {
+            Some(project_key) => db_factory.get_read_connection(project_key)?,
+            None => db_factory.get_all_projects_connection()?,
+        };
+        Ok(Self::new(db_conn))
+    }
+
+    /// User that `currentUser()` refers to in JQL searches
+    pub fn with_current_user(mut self, user: impl Into<String>) -> Self {
+        self.current_user = Some(user.into());
+        self
+    }
+
+    /// Bind `args` to the search's parameters and run it, returning at most
+    /// `limit` rows (default 100)
+    pub fn execute(
+        &self,
+        search: &SavedSearch,
+        args: &HashMap<String, String>,
+        limit: Option<usize>,
+    ) -> DomainResult<SavedSearchResult> {
+        let values = search.bind(args)?;
+        let limit = limit.unwrap_or(100);
+
+        let (sql, total, result) = match &search.query {
+            SavedSearchQuery::Sql { sql } => {
+                let (sql, bound) = render_sql(sql, &values)?;
+                let bound: Vec<duckdb::types::Value> = bound.into_iter().map(sql_value).collect();
+                let result = ExecuteSqlUseCase::new(self.db_conn.clone()).execute_with_params(
+                    &sql,
+                    duckdb::params_from_iter(bound),
+                    Some(limit),
+                )?;
+                (Some(sql), None, result)
+            }
+            SavedSearchQuery::Jql { jql } => {
+                let jql = render_jql(jql, &values)?;
+                let use_case = ExecuteJqlUseCase::new(self.db_conn.clone());
+                let use_case = match &self.current_user {
+                    Some(user) => use_case.with_current_user(user.clone()),
+                    None => use_case,
+                };
+                let result = use_case.execute(&jql, Some(limit))?;
+                (Some(result.sql), None, result.result)
+            }
+            SavedSearchQuery::Filter { filter } => {
+                let params = search_params(&filter.render(&values)?, limit)?;
+                let use_case = SearchIssuesUseCase::new(Arc::new(DuckDbIssueRepository::new(
+                    self.db_conn.clone(),
+                )));
+                let total = use_case.count(&params)?;
+                let issues = use_case.execute(params)?;
+                (None, Some(total), issue_rows(issues))
+            }
+        };
+
+        Ok(SavedSearchResult {
+            name: search.name.clone(),
+            kind: search.query.kind().to_string(),
+            sql,
+            total,
+            result,
+        })
+    }
+}
+
+fn sql_value(value: SavedSearchValue) -> duckdb::types::Value {
+    match value {
+        SavedSearchValue::Null => duckdb::types::Value::Null,
+        SavedSearchValue::Text(text) => duckdb::types::Value::Text(text),
+        SavedSearchValue::Number(n) if n.fract() == 0.0 && n.abs() < i64::MAX as f64 => {
+            duckdb::types::Value::BigInt(n as i64)
+        }
+        SavedSearchValue::Number(n) => duckdb::types::Value::Double(n),
+        SavedSearchValue::Boolean(b) => duckdb::types::Value::Boolean(b),
+    }
+}
+
+/// Search parameters for a filter whose placeholders have been substituted
+fn search_params(filter: &SavedSearchFilter, limit: usize) -> DomainResult<SearchParams> {
+    let range = |from: &Option<String>, to: &Option<String>| {
+        DateRange::parse(from.as_deref(), to.as_deref())
+    };
+
+    Ok(SearchParams {
+        query: filter.query.clone(),
+        status: filter.status.clone(),
+        status_category: filter.status_category.clone(),
+        assignee: filter.assignee.clone(),
+        issue_type: filter.issue_type.clone(),
+        priority: filter.priority.clone(),
+        team: filter.team.clone(),
+        resolution: filter.resolution.clone(),
+        labels: filter.labels.clone(),
+        labels_match: match &filter.labels_match {
+            Some(labels_match) => labels_match.parse()?,
+            None => LabelMatch::default(),
+        },
+        components: filter.components.clone(),
+        fix_versions: filter.fix_versions.clone(),
+        sprint: filter.sprint.clone(),
+        parent_key: filter.parent_key.clone(),
+        created: range(&filter.created_from, &filter.created_to)?,
+        updated: range(&filter.updated_from, &filter.updated_to)?,
+        due: range(&filter.due_from, &filter.due_to)?,
+        resolved: range(&filter.resolved_from, &filter.resolved_to)?,
+        sort: match &filter.sort {
+            Some(sort) => SearchSort::parse_list(sort)?,
+            None => Vec::new(),
+        },
+        limit: Some(limit),
+        ..Default::default()
+    })
+}
+
+fn issue_rows(issues: Vec<Issue>) -> SqlResult {
+    let text = |value: Option<String>| value.map_or(serde_json::Value::Null, Into::into);
+    let rows: Vec<Vec<serde_json::Value>> = issues
+        .into_iter()
+        .map(|issue| {
+            vec![
+                issue.key.into(),
+                issue.summary.into(),
+                text(issue.status),
+                text(issue.assignee),
+                text(issue.priority),
+                text(issue.issue_type),
+                text(issue.created_date.map(|d| d.to_rfc3339())),
+                text(issue.updated_date.map(|d| d.to_rfc3339())),
+            ]
+        })
+        .collect();
+
+    SqlResult {
+        columns: FILTER_COLUMNS.iter().map(|c| c.to_string()).collect(),
+        row_count: rows.len(),
+        rows,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{SavedSearchParameter, SavedSearchParameterType};
+    use crate::infrastructure::database::test_support::test_connection;
+
+    fn create_test_db() -> DbConnection {
+        let conn = test_connection(
+            r#"
+            INSERT INTO issues
+                (id, project_id, key, summary, status, priority, assignee, issue_type,
+                 created_date, updated_date, raw_data, is_deleted)
+            VALUES
+                ('1', '100', 'PROJ-1', 'Login fails', 'Open', 'High', 'Alice', 'Bug',
+                 '2024-05-01 09:00:00+00', '2024-05-20 09:00:00+00', '{"fields": {}}', false),
+                ('2', '100', 'PROJ-2', 'Dark mode', 'Done', 'Low', NULL, 'Story',
+                 '2024-05-10 09:00:00+00', '2024-05-12 09:00:00+00', '{"fields": {}}', false),
+                ('3', '100', 'PROJ-3', 'Login slow', 'Open', 'High', 'Bob', 'Bug',
+                 '2024-05-15 09:00:00+00', '2024-05-16 09:00:00+00', '{"fields": {}}', false);
+
+            INSERT INTO issues_expanded
+                (id, project_id, issue_key, summary, status, assignee, priority, issue_type)
+            VALUES
+                ('1', '100', 'PROJ-1', 'Login fails', 'Open', 'Alice', 'High', 'Bug'),
+                ('2', '100', 'PROJ-2', 'Dark mode', 'Done', NULL, 'Low', 'Story'),
+                ('3', '100', 'PROJ-3', 'Login slow', 'Open', 'Bob', 'High', 'Bug');
+            "#,
+        );
+        conn
+    }
+
+    fn parameter(name: &str, param_type: SavedSearchParameterType) -> SavedSearchParameter {
+        SavedSearchParameter {
+            name: name.into(),
+            param_type,
+            description: None,
+            required: false,
+            default: None,
+        }
+    }
+
+    fn keys(result: &SavedSearchResult) -> Vec<String> {
+        let key = result
+            .result
+            .columns
+            .iter()
+            .position(|c| c == "key")
+            .unwrap();
+        result
+            .result
+            .rows
+            .iter()
+            .map(|row| row[key].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    fn args(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_run_sql_search_binds_parameters() {
+        let conn = create_test_db();
+        let mut search = SavedSearch::new(
+            "by-status",
+            SavedSearchQuery::Sql {
+                sql: "SELECT key FROM issues WHERE status = {{status}} ORDER BY key".into(),
+            },
+        );
+        search.parameters = vec![parameter("status", SavedSearchParameterType::String)];
+
+        let result = RunSavedSearchUseCase::new(conn)
+            .execute(&search, &args(&[("status", "Open")]), None)
+            .unwrap();
+        assert_eq!(keys(&result), vec!["PROJ-1", "PROJ-3"]);
+        assert_eq!(result.kind, "sql");
+    }
+
+    #[test]
+    fn test_run_jql_search_quotes_parameters() {
+        let conn = create_test_db();
+        let mut search = SavedSearch::new(
+            "assigned",
+            SavedSearchQuery::Jql {
+                jql: "assignee = {{who}} ORDER BY key".into(),
+            },
+        );
+        search.parameters = vec![parameter("who", SavedSearchParameterType::String)];
+        let use_case = RunSavedSearchUseCase::new(conn);
+
+        let result = use_case
+            .execute(&search, &args(&[("who", "Bob")]), None)
+            .unwrap();
+        assert_eq!(keys(&result), vec!["PROJ-3"]);
+
+        // An unset parameter becomes EMPTY
+        let result = use_case.execute(&search, &args(&[]), None).unwrap();
+        assert_eq!(keys(&result), vec!["PROJ-2"]);
+    }
+
+    #[test]
+    fn test_run_filter_search_drops_unset_filters() {
+        let conn = create_test_db();
+        let mut search = SavedSearch::new(
+            "bugs",
+            SavedSearchQuery::Filter {
+                filter: SavedSearchFilter {
+                    issue_type: Some("Bug".into()),
+                    assignee: Some("{{assignee}}".into()),
+                    sort: Some("key".into()),
+                    ..Default::default()
+                },
+            },
+        );
+        search.parameters = vec![parameter("assignee", SavedSearchParameterType::String)];
+        let use_case = RunSavedSearchUseCase::new(conn);
+
+        let result = use_case.execute(&search, &args(&[]), Some(1)).unwrap();
+        assert_eq!(keys(&result), vec!["PROJ-1"]);
+        assert_eq!(result.total, Some(2));
+
+        let result = use_case
+            .execute(&search, &args(&[("assignee", "Bob")]), None)
+            .unwrap();
+        assert_eq!(keys(&result), vec!["PROJ-3"]);
+    }
+}
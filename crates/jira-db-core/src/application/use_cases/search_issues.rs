@@ -1,6 +1,8 @@
 use crate::domain::entities::Issue;
 use crate::domain::error::DomainResult;
-use crate::domain::repositories::{IssueRepository, SearchParams};
+use crate::domain::repositories::{
+    FacetField, IssueRepository, IssueSearchHit, SearchFacet, SearchParams,
+};
 use std::sync::Arc;
 
 pub struct SearchIssuesUseCase<I>
@@ -21,4 +23,23 @@ where
     pub fn execute(&self, params: SearchParams) -> DomainResult<Vec<Issue>> {
         self.issue_repository.search(&params)
     }
+
+    /// Search ranked by relevance, with a highlighted snippet for each hit
+    pub fn execute_ranked(&self, params: SearchParams) -> DomainResult<Vec<IssueSearchHit>> {
+        self.issue_repository.search_ranked(&params)
+    }
+
+    /// Number of issues matching the search across all pages
+    pub fn count(&self, params: &SearchParams) -> DomainResult<usize> {
+        self.issue_repository.count_matching(params)
+    }
+
+    /// Counts of each field's values over every matching issue, not just one page
+    pub fn facets(
+        &self,
+        params: &SearchParams,
+        fields: &[FacetField],
+    ) -> DomainResult<Vec<SearchFacet>> {
+        self.issue_repository.facet_counts(params, fields)
+    }
 }
@@ -0,0 +1,225 @@
+//! Find issues similar to a given issue from its stored embedding
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::domain::entities::Issue;
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{DateRange, IssueRepository, SearchParams};
+use crate::infrastructure::database::{
+    DatabaseFactory, DbConnection, DuckDbIssueRepository, EmbeddingsRepository, NeighborFilter,
+};
+
+/// Issue similar to the source issue
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarIssue {
+    pub issue: Issue,
+    /// Cosine similarity to the source issue (1 = same direction)
+    pub similarity: f64,
+}
+
+/// Issues most similar to a source issue, most similar first
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarIssuesResult {
+    pub source: Issue,
+    pub similar: Vec<SimilarIssue>,
+}
+
+pub struct FindSimilarIssuesUseCase {
+    issue_repository: DuckDbIssueRepository,
+    embeddings_repository: EmbeddingsRepository,
+    same_project: bool,
+    exclude_resolved: bool,
+    created: DateRange,
+}
+
+impl FindSimilarIssuesUseCase {
+    pub fn new(db_conn: DbConnection) -> Self {
+        Self {
+            issue_repository: DuckDbIssueRepository::new(db_conn.clone()),
+            embeddings_repository: EmbeddingsRepository::new(db_conn),
+            same_project: false,
+            exclude_resolved: false,
+            created: DateRange::default(),
+        }
+    }
+
+    /// Connect to the issue's own project when `same_project` is set, else to every project
+    pub fn connect(
+        db_factory: &DatabaseFactory,
+        issue_key: &str,
+        same_project: bool,
+    ) -> DomainResult<Self> {
+        let db_conn = if same_project {
+            let (project_key, _) = issue_key.rsplit_once('-').ok_or_else(|| {
+                DomainError::Validation(format!("Invalid issue key: {}", issue_key))
+            })?;
+            db_factory.get_read_connection(project_key)?
+        } else {
+            db_factory.get_all_projects_connection()?
+        };
+        Ok(Self::new(db_conn).with_same_project(same_project))
+    }
+
+    /// Only return issues of the source issue's project
+    pub fn with_same_project(mut self, same_project: bool) -> Self {
+        self.same_project = same_project;
+        self
+    }
+
+    /// Leave out resolved issues
+    pub fn with_exclude_resolved(mut self, exclude_resolved: bool) -> Self {
+        self.exclude_resolved = exclude_resolved;
+        self
+    }
+
+    /// Only return issues created within `created`
+    pub fn with_created(mut self, created: DateRange) -> Self {
+        self.created = created;
+        self
+    }
+
+    /// Up to `limit` issues most similar to `issue_key`
+    pub fn execute(&self, issue_key: &str, limit: usize) -> DomainResult<SimilarIssuesResult> {
+        let embedded = self
+            .embeddings_repository
+            .find_by_key(issue_key)?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!(
+                    "No embedding for {}. Run 'jira-db embeddings' first",
+                    issue_key
+                ))
+            })?;
+        let source = self
+            .issues(vec![issue_key.to_string()])?
+            .remove(issue_key)
+            .ok_or_else(|| DomainError::NotFound(format!("Issue not found: {}", issue_key)))?;
+
+        let filter = NeighborFilter {
+            project_id: self.same_project.then(|| source.project_id.clone()),
+            exclude_resolved: self.exclude_resolved,
+            created: self.created.clone(),
+        };
+        let neighbors = self
+            .embeddings_repository
+            .similar_to(&embedded, &filter, limit)?;
+
+        let mut issues = self.issues(
+            neighbors
+                .iter()
+                .map(|neighbor| neighbor.issue_key.clone())
+                .collect(),
+        )?;
+        let similar = neighbors
+            .into_iter()
+            .filter_map(|neighbor| {
+                issues
+                    .remove(&neighbor.issue_key)
+                    .map(|issue| SimilarIssue {
+                        issue,
+                        similarity: 1.0 - neighbor.distance as f64,
+                    })
+            })
+            .collect();
+
+        Ok(SimilarIssuesResult { source, similar })
+    }
+
+    /// Non-deleted issues by key
+    fn issues(&self, keys: Vec<String>) -> DomainResult<HashMap<String, Issue>> {
+        if keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+        Ok(self
+            .issue_repository
+            .search(&SearchParams {
+                limit: Some(keys.len()),
+                keys,
+                ..Default::default()
+            })?
+            .into_iter()
+            .map(|issue| (issue.key.clone(), issue))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::test_support::{store_embeddings, test_connection};
+
+    #[test]
+    fn test_execute_applies_filters() {
+        let conn = test_connection(
+            r#"
+            INSERT INTO issues
+                (id, project_id, key, summary, status, resolution,
+                 created_date, updated_date, raw_data, is_deleted)
+            VALUES
+                ('1', '100', 'PROJ-1', 'Login fails', 'Open', NULL,
+                 '2024-05-01 09:00:00+00', '2024-05-01 09:00:00+00', '{"fields": {}}', false),
+                ('2', '100', 'PROJ-2', 'Cannot log in', 'Done', 'Fixed',
+                 '2024-05-02 09:00:00+00', '2024-05-02 09:00:00+00', '{"fields": {}}', false),
+                ('3', '100', 'PROJ-3', 'Login error', 'Open', NULL,
+                 '2024-06-03 09:00:00+00', '2024-06-03 09:00:00+00', '{"fields": {}}', false),
+                ('4', '200', 'OTHER-4', 'Sign-in broken', 'Open', NULL,
+                 '2024-05-04 09:00:00+00', '2024-05-04 09:00:00+00', '{"fields": {}}', false),
+                ('5', '100', 'PROJ-5', 'Dark mode', 'Open', NULL,
+                 '2024-05-05 09:00:00+00', '2024-05-05 09:00:00+00', '{"fields": {}}', false),
+                ('6', '100', 'PROJ-6', 'Login broken', 'Open', NULL,
+                 '2024-05-06 09:00:00+00', '2024-05-06 09:00:00+00', '{"fields": {}}', false);
+            "#,
+        );
+        store_embeddings(
+            &conn,
+            "text-embedding-3-small",
+            &[
+                ("1", "PROJ-1", vec![1.0, 0.0, 0.0]),
+                ("2", "PROJ-2", vec![0.99, 0.1, 0.0]),
+                ("3", "PROJ-3", vec![0.9, 0.3, 0.0]),
+                ("4", "OTHER-4", vec![0.98, 0.0, 0.2]),
+                ("5", "PROJ-5", vec![0.0, 1.0, 0.0]),
+            ],
+        );
+        store_embeddings(
+            &conn,
+            "nomic-embed-text",
+            &[("6", "PROJ-6", vec![1.0, 0.0, 0.0])],
+        );
+
+        let keys = |result: SimilarIssuesResult| -> Vec<String> {
+            result
+                .similar
+                .into_iter()
+                .map(|similar| similar.issue.key)
+                .collect()
+        };
+
+        // Embeddings from another model are never compared
+        let result = FindSimilarIssuesUseCase::new(conn.clone())
+            .execute("PROJ-1", 10)
+            .unwrap();
+        assert_eq!(result.source.key, "PROJ-1");
+        assert!(result.similar[0].similarity > 0.99);
+        assert_eq!(keys(result), vec!["PROJ-2", "OTHER-4", "PROJ-3", "PROJ-5"]);
+
+        let result = FindSimilarIssuesUseCase::new(conn.clone())
+            .with_same_project(true)
+            .with_exclude_resolved(true)
+            .execute("PROJ-1", 2)
+            .unwrap();
+        assert_eq!(keys(result), vec!["PROJ-3", "PROJ-5"]);
+
+        let result = FindSimilarIssuesUseCase::new(conn.clone())
+            .with_created(DateRange::parse(Some("2024-05-01"), Some("2024-05-31")).unwrap())
+            .execute("PROJ-1", 10)
+            .unwrap();
+        assert_eq!(keys(result), vec!["PROJ-2", "OTHER-4", "PROJ-5"]);
+
+        assert!(matches!(
+            FindSimilarIssuesUseCase::new(conn).execute("PROJ-9", 10),
+            Err(DomainError::NotFound(_))
+        ));
+    }
+}
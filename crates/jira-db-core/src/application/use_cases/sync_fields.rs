@@ -2,6 +2,7 @@ use crate::application::services::JiraService;
 use crate::domain::entities::JiraField;
 use crate::domain::error::DomainResult;
 use crate::infrastructure::database::{DuckDbFieldRepository, DuckDbIssuesExpandedRepository};
+use log::info;
 use std::sync::Arc;
 
 /// Result of field synchronization
@@ -36,9 +37,41 @@ impl SyncFieldsUseCase {
     }
 
     /// Sync fields from JIRA API and store in database
+    ///
+    /// Placeholder fields registered by a file import are replaced by the JIRA field
+    /// with the same name, and the affected issues are re-expanded.
     pub async fn sync_fields(&self) -> DomainResult<usize> {
         let fields = self.jira_service.fetch_fields().await?;
-        self.field_repo.upsert_fields(&fields)
+        let synced = self.field_repo.upsert_fields(&fields)?;
+        if self.resolve_import_placeholders(&fields)? > 0 {
+            self.add_columns()?;
+            self.expand_issues(None)?;
+            self.create_readable_view()?;
+        }
+        Ok(synced)
+    }
+
+    /// Move values stored under import placeholder fields to the matching JIRA fields.
+    /// Returns the number of placeholders resolved.
+    pub fn resolve_import_placeholders(&self, fields: &[JiraField]) -> DomainResult<usize> {
+        let mut resolved = 0;
+        for placeholder in self
+            .field_repo
+            .find_all()?
+            .iter()
+            .filter(|f| f.is_import_placeholder())
+        {
+            let Some(field) = JiraField::find_custom_by_name(fields, &placeholder.name) else {
+                continue;
+            };
+            let issues = self.field_repo.replace_placeholder(placeholder, field)?;
+            info!(
+                "Moved placeholder field {} to {} ({} issues)",
+                placeholder.id, field.id, issues
+            );
+            resolved += 1;
+        }
+        Ok(resolved)
     }
 
     /// Add columns to issues_expanded table based on stored field definitions
@@ -196,6 +196,7 @@ where
             .sync_internal_resumable(
                 project_key,
                 project_id,
+                history_id,
                 checkpoint,
                 &mut on_progress,
                 &mut on_snapshot_progress,
@@ -337,6 +338,7 @@ where
         &self,
         project_key: &str,
         project_id: &str,
+        sync_run_id: i64,
         checkpoint: Option<SyncCheckpoint>,
         on_progress: &mut F,
         on_snapshot_progress: &mut G,
@@ -451,6 +453,30 @@ where
                     items_processed + issues_to_process.len()
                 ));
 
+                // Diff against the stored payloads before they are overwritten, so changes
+                // JIRA does not log (some custom fields, app updates) still get history rows
+                let synced_at = Utc::now();
+                let mut derived_history = Vec::new();
+                for issue in &issues_to_process {
+                    let Some(raw_json) = &issue.raw_json else {
+                        continue;
+                    };
+                    let stored = self
+                        .issue_repository
+                        .find_by_key(&issue.key)
+                        .map_err(|e| (e, last_checkpoint.clone(), None))?;
+                    if let Some(previous_raw) = stored.and_then(|s| s.raw_json) {
+                        derived_history.extend(ChangeHistoryItem::derive_from_payload_diff(
+                            &issue.id,
+                            &issue.key,
+                            &previous_raw,
+                            raw_json,
+                            &format!("sync-{}", sync_run_id),
+                            synced_at,
+                        ));
+                    }
+                }
+
                 // Save issues to database
                 self.issue_repository
                     .batch_insert(&issues_to_process)
@@ -476,14 +502,26 @@ where
                         raw_repo
                             .batch_upsert_issue_raw_data(&raw_data_items)
                             .map_err(|e| (e, last_checkpoint.clone(), None))?;
+
+                        // Keep every distinct payload so changes outside the changelog stay visible
+                        let new_versions = raw_repo
+                            .batch_append_issue_raw_versions(&raw_data_items, Some(sync_run_id))
+                            .map_err(|e| (e, last_checkpoint.clone(), None))?;
+                        if new_versions > 0 {
+                            step1.detail(&format!(
+                                "  -> Recorded {} new raw payload versions",
+                                new_versions
+                            ));
+                        }
                     }
                 }
 
                 // Extract and save change history for this batch
                 for issue in &issues_to_process {
                     if let Some(raw_json) = &issue.raw_json {
+                        // Rows derived from earlier payload diffs cannot be rebuilt, so keep them
                         self.change_history_repository
-                            .delete_by_issue_id(&issue.id)
+                            .delete_changelog_by_issue_id(&issue.id)
                             .map_err(|e| (e, last_checkpoint.clone(), None))?;
 
                         let history_items = ChangeHistoryItem::extract_from_raw_json(
@@ -498,6 +536,16 @@ where
                     }
                 }
 
+                if !derived_history.is_empty() {
+                    self.change_history_repository
+                        .batch_insert(&derived_history)
+                        .map_err(|e| (e, last_checkpoint.clone(), None))?;
+                    step1.detail(&format!(
+                        "  -> Derived {} changes from payload diffs",
+                        derived_history.len()
+                    ));
+                }
+
                 // Update checkpoint after successful batch processing
                 let batch_len = issues_to_process.len();
                 if batch_len > 0 {
@@ -794,6 +842,40 @@ where
             }
         }
 
+        // Fetch workflows (requires permission to browse the workflow scheme)
+        match self.jira_service.fetch_project_workflows(project_id).await {
+            Ok(workflows) => {
+                if !workflows.is_empty() {
+                    self.metadata_repository
+                        .replace_workflows(project_id, &workflows)?;
+                    step.detail(&format!("Saved {} workflows", workflows.len()));
+                }
+            }
+            Err(e) => {
+                warn!("Failed to fetch workflows: {}", e);
+                step.detail(&format!("Warning: Failed to fetch workflows: {}", e));
+            }
+        }
+
+        // Fetch select field options from the create screens
+        match self
+            .jira_service
+            .fetch_field_options(project_key, project_id)
+            .await
+        {
+            Ok(options) => {
+                if !options.is_empty() {
+                    self.metadata_repository
+                        .replace_field_options(project_id, &options)?;
+                    step.detail(&format!("Saved {} field options", options.len()));
+                }
+            }
+            Err(e) => {
+                warn!("Failed to fetch field options: {}", e);
+                step.detail(&format!("Warning: Failed to fetch field options: {}", e));
+            }
+        }
+
         Ok(())
     }
 }
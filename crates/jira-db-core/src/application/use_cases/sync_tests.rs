@@ -9,12 +9,12 @@ use std::sync::{Arc, Mutex};
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
 
-use crate::application::dto::{CreatedIssueDto, TransitionDto};
+use crate::application::dto::{CreatedIssueDto, IssueFieldUpdate, TransitionDto};
 use crate::application::services::{FetchProgress, JiraService};
 use crate::application::use_cases::{GenerateSnapshotsUseCase, SyncProjectUseCase};
 use crate::domain::entities::{
-    ChangeHistoryItem, Component, FixVersion, Issue, IssueSnapshot, IssueType, JiraField, Label,
-    Priority, Project, Status,
+    ChangeHistoryItem, Component, FieldOption, FixVersion, Issue, IssueSnapshot, IssueType,
+    JiraField, Label, Priority, Project, Status, Workflow,
 };
 use crate::domain::error::{DomainError, DomainResult};
 use crate::domain::repositories::{
@@ -147,6 +147,43 @@ impl IssueRepository for MockIssueRepository {
             has_more,
         })
     }
+
+    fn find_by_key(&self, issue_key: &str) -> DomainResult<Option<Issue>> {
+        let stored = self.issues.lock().unwrap();
+        Ok(stored.iter().find(|i| i.key == issue_key).cloned())
+    }
+
+    fn update_status(&self, issue_key: &str, status: &str) -> DomainResult<()> {
+        let mut stored = self.issues.lock().unwrap();
+        if let Some(issue) = stored.iter_mut().find(|i| i.key == issue_key) {
+            issue.status = Some(status.to_string());
+        }
+        Ok(())
+    }
+
+    fn update_due_date(
+        &self,
+        issue_key: &str,
+        due_date: Option<DateTime<Utc>>,
+    ) -> DomainResult<()> {
+        let mut stored = self.issues.lock().unwrap();
+        if let Some(issue) = stored.iter_mut().find(|i| i.key == issue_key) {
+            issue.due_date = due_date;
+        }
+        Ok(())
+    }
+
+    fn update_editable_fields(&self, issue: &Issue) -> DomainResult<()> {
+        let mut stored = self.issues.lock().unwrap();
+        if let Some(stored) = stored.iter_mut().find(|i| i.key == issue.key) {
+            stored.summary = issue.summary.clone();
+            stored.priority = issue.priority.clone();
+            stored.labels = issue.labels.clone();
+            stored.components = issue.components.clone();
+            stored.fix_versions = issue.fix_versions.clone();
+        }
+        Ok(())
+    }
 }
 
 /// Mock Change History Repository
@@ -178,6 +215,12 @@ impl ChangeHistoryRepository for MockChangeHistoryRepository {
         Ok(())
     }
 
+    fn delete_changelog_by_issue_id(&self, issue_id: &str) -> DomainResult<()> {
+        let mut stored = self.history.lock().unwrap();
+        stored.retain(|h| h.issue_id != issue_id || h.is_sync_diff());
+        Ok(())
+    }
+
     fn find_by_issue_key(&self, issue_key: &str) -> DomainResult<Vec<ChangeHistoryItem>> {
         let stored = self.history.lock().unwrap();
         Ok(stored
@@ -204,6 +247,27 @@ impl ChangeHistoryRepository for MockChangeHistoryRepository {
         let stored = self.history.lock().unwrap();
         Ok(stored.iter().filter(|h| h.issue_key == issue_key).count())
     }
+
+    fn find_by_field(&self, field: &str) -> DomainResult<Vec<ChangeHistoryItem>> {
+        let stored = self.history.lock().unwrap();
+        Ok(stored
+            .iter()
+            .filter(|h| h.field == field)
+            .cloned()
+            .collect())
+    }
+
+    fn find_status_transitions(&self) -> DomainResult<Vec<(String, String)>> {
+        let stored = self.history.lock().unwrap();
+        let mut pairs: Vec<(String, String)> = stored
+            .iter()
+            .filter(|h| h.field == "status")
+            .filter_map(|h| Some((h.from_string.clone()?, h.to_string.clone()?)))
+            .collect();
+        pairs.sort();
+        pairs.dedup();
+        Ok(pairs)
+    }
 }
 
 /// Mock Metadata Repository
@@ -215,6 +279,8 @@ pub struct MockMetadataRepository {
     labels: Mutex<Vec<Label>>,
     components: Mutex<Vec<Component>>,
     fix_versions: Mutex<Vec<FixVersion>>,
+    workflows: Mutex<Vec<Workflow>>,
+    field_options: Mutex<Vec<FieldOption>>,
 }
 
 impl MockMetadataRepository {
@@ -287,6 +353,30 @@ impl MetadataRepository for MockMetadataRepository {
     fn find_fix_versions_by_project(&self, _project_id: &str) -> DomainResult<Vec<FixVersion>> {
         Ok(self.fix_versions.lock().unwrap().clone())
     }
+
+    fn replace_workflows(&self, _project_id: &str, workflows: &[Workflow]) -> DomainResult<()> {
+        let mut stored = self.workflows.lock().unwrap();
+        *stored = workflows.to_vec();
+        Ok(())
+    }
+
+    fn find_workflows_by_project(&self, _project_id: &str) -> DomainResult<Vec<Workflow>> {
+        Ok(self.workflows.lock().unwrap().clone())
+    }
+
+    fn replace_field_options(
+        &self,
+        _project_id: &str,
+        options: &[FieldOption],
+    ) -> DomainResult<()> {
+        let mut stored = self.field_options.lock().unwrap();
+        *stored = options.to_vec();
+        Ok(())
+    }
+
+    fn find_field_options_by_project(&self, _project_id: &str) -> DomainResult<Vec<FieldOption>> {
+        Ok(self.field_options.lock().unwrap().clone())
+    }
 }
 
 /// Mock Sync History Repository
@@ -459,6 +549,10 @@ pub struct MockJiraService {
     total_count: usize,
     /// Simulated failure at batch index (None = no failure)
     fail_at_batch: Option<usize>,
+    /// Workflow transitions available per status: (from status, transition)
+    transitions: Vec<(String, TransitionDto)>,
+    /// Track transition IDs applied, per issue
+    transition_calls: Mutex<Vec<(String, String)>>,
 }
 
 #[derive(Debug, Clone)]
@@ -477,9 +571,32 @@ impl MockJiraService {
             fetch_calls: Mutex::new(Vec::new()),
             total_count,
             fail_at_batch: None,
+            transitions: Vec::new(),
+            transition_calls: Mutex::new(Vec::new()),
         }
     }
 
+    pub fn with_transitions(mut self, transitions: Vec<(&str, TransitionDto)>) -> Self {
+        self.transitions = transitions
+            .into_iter()
+            .map(|(from, t)| (from.to_string(), t))
+            .collect();
+        self
+    }
+
+    pub fn get_transition_calls(&self) -> Vec<(String, String)> {
+        self.transition_calls.lock().unwrap().clone()
+    }
+
+    fn current_status(&self, issue_key: &str) -> Option<String> {
+        let batches = self.issues_batches.lock().unwrap();
+        batches
+            .iter()
+            .flatten()
+            .find(|i| i.key == issue_key)
+            .and_then(|i| i.status.clone())
+    }
+
     pub fn with_failure_at_batch(mut self, batch_index: usize) -> Self {
         self.fail_at_batch = Some(batch_index);
         self
@@ -561,6 +678,16 @@ impl JiraService for MockJiraService {
         })
     }
 
+    async fn fetch_issue(&self, issue_key: &str) -> DomainResult<Issue> {
+        let batches = self.issues_batches.lock().unwrap();
+        batches
+            .iter()
+            .flatten()
+            .find(|i| i.key == issue_key)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("Issue not found: {}", issue_key)))
+    }
+
     async fn test_connection(&self) -> DomainResult<()> {
         Ok(())
     }
@@ -610,11 +737,37 @@ impl JiraService for MockJiraService {
         unimplemented!()
     }
 
-    async fn get_issue_transitions(&self, _issue_key: &str) -> DomainResult<Vec<TransitionDto>> {
-        Ok(vec![])
+    async fn get_issue_transitions(&self, issue_key: &str) -> DomainResult<Vec<TransitionDto>> {
+        let status = self.current_status(issue_key).unwrap_or_default();
+        Ok(self
+            .transitions
+            .iter()
+            .filter(|(from, _)| *from == status)
+            .map(|(_, t)| t.clone())
+            .collect())
     }
 
-    async fn transition_issue(&self, _issue_key: &str, _transition_id: &str) -> DomainResult<()> {
+    async fn transition_issue(&self, issue_key: &str, transition_id: &str) -> DomainResult<()> {
+        self.transition_calls
+            .lock()
+            .unwrap()
+            .push((issue_key.to_string(), transition_id.to_string()));
+
+        let status = self.current_status(issue_key).unwrap_or_default();
+        let Some((_, transition)) = self
+            .transitions
+            .iter()
+            .find(|(from, t)| *from == status && t.id == transition_id)
+        else {
+            return Ok(());
+        };
+
+        let mut batches = self.issues_batches.lock().unwrap();
+        for issue in batches.iter_mut().flatten() {
+            if issue.key == issue_key {
+                issue.status = Some(transition.to_status.clone());
+            }
+        }
         Ok(())
     }
 
@@ -641,13 +794,42 @@ impl JiraService for MockJiraService {
     async fn update_issue_due_date(&self, _issue_key: &str, _due_date: &str) -> DomainResult<()> {
         Ok(())
     }
+
+    async fn add_comment(&self, _issue_key: &str, _body: &str) -> DomainResult<()> {
+        Ok(())
+    }
+
+    async fn fetch_project_workflows(&self, _project_id: &str) -> DomainResult<Vec<Workflow>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_field_options(
+        &self,
+        _project_key: &str,
+        _project_id: &str,
+    ) -> DomainResult<Vec<FieldOption>> {
+        Ok(vec![])
+    }
+
+    async fn update_issue_fields(
+        &self,
+        _issue_key: &str,
+        _update: &IssueFieldUpdate,
+    ) -> DomainResult<()> {
+        Ok(())
+    }
 }
 
 // ============================================================================
 // Test Helpers
 // ============================================================================
 
-fn create_test_issue(id: &str, key: &str, project_id: &str, updated_at: DateTime<Utc>) -> Issue {
+pub fn create_test_issue(
+    id: &str,
+    key: &str,
+    project_id: &str,
+    updated_at: DateTime<Utc>,
+) -> Issue {
     Issue::new(
         id.to_string(),
         project_id.to_string(),
@@ -993,6 +1175,7 @@ mod tests {
                 to_value: None,
                 to_string: Some("In Progress".to_string()),
                 changed_at: now - Duration::days(1),
+                source: ChangeHistoryItem::SOURCE_CHANGELOG.to_string(),
             },
             ChangeHistoryItem {
                 issue_id: "1".to_string(),
@@ -1007,6 +1190,7 @@ mod tests {
                 to_value: None,
                 to_string: Some("Done".to_string()),
                 changed_at: now,
+                source: ChangeHistoryItem::SOURCE_CHANGELOG.to_string(),
             },
         ];
         history_repo.batch_insert(&history).unwrap();
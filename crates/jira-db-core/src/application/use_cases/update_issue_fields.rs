@@ -0,0 +1,522 @@
+use crate::application::dto::{FieldEdit, IssueFieldUpdate};
+use crate::application::services::JiraService;
+use crate::domain::entities::{Issue, JiraField};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{IssueRepository, MetadataRepository};
+use crate::infrastructure::database::DuckDbFieldRepository;
+use chrono::{DateTime, NaiveDate};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Fields that JIRA does not allow to be edited through the edit issue API
+const READ_ONLY_FIELDS: &[&str] = &[
+    "status",
+    "created",
+    "updated",
+    "creator",
+    "project",
+    "resolutiondate",
+    "lastViewed",
+    "statuscategorychangedate",
+    "votes",
+    "watches",
+    "worklog",
+    "comment",
+    "attachment",
+    "subtasks",
+    "issuelinks",
+    "progress",
+    "aggregateprogress",
+    "workratio",
+    "thumbnail",
+];
+
+/// Values accepted by a project's metadata-backed fields.
+/// An empty list means the metadata has not been synced, and any value is accepted.
+#[derive(Debug, Clone, Default)]
+pub struct AllowedFieldValues {
+    pub priorities: Vec<String>,
+    pub issue_types: Vec<String>,
+    pub components: Vec<String>,
+    pub fix_versions: Vec<String>,
+    /// Enabled top-level option values of select fields, keyed by field ID
+    pub field_options: HashMap<String, Vec<String>>,
+}
+
+impl AllowedFieldValues {
+    fn options(&self, field_id: &str) -> &[String] {
+        self.field_options
+            .get(field_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Validates field edits against the synced field definitions and metadata,
+/// and converts them into JIRA's edit issue payload
+pub struct FieldEditValidator {
+    fields: Vec<JiraField>,
+    allowed: AllowedFieldValues,
+}
+
+impl FieldEditValidator {
+    pub fn new(fields: Vec<JiraField>, allowed: AllowedFieldValues) -> Self {
+        Self { fields, allowed }
+    }
+
+    pub fn build(&self, edits: &[FieldEdit]) -> DomainResult<IssueFieldUpdate> {
+        if edits.is_empty() {
+            return Err(DomainError::Validation("No field changes given".into()));
+        }
+
+        let mut update = IssueFieldUpdate::default();
+
+        for edit in edits {
+            let field = self.resolve_field(edit.field())?;
+            match edit {
+                FieldEdit::Set { value, .. } => {
+                    let value = self.convert_set_value(field, value)?;
+                    update.fields.insert(field.id.clone(), value);
+                }
+                FieldEdit::Add { value, .. } => {
+                    let item = self.convert_array_item(field, value)?;
+                    update.push_operation(&field.id, "add", item);
+                }
+                FieldEdit::Remove { value, .. } => {
+                    let item = self.convert_array_item(field, value)?;
+                    update.push_operation(&field.id, "remove", item);
+                }
+            }
+        }
+
+        Ok(update)
+    }
+
+    /// Find a field by ID, key or (case-insensitive) name
+    fn resolve_field(&self, name: &str) -> DomainResult<&JiraField> {
+        let field = match self.fields.iter().find(|f| f.id == name || f.key == name) {
+            Some(field) => field,
+            None => {
+                let matches: Vec<&JiraField> = self
+                    .fields
+                    .iter()
+                    .filter(|f| f.name.eq_ignore_ascii_case(name))
+                    .collect();
+                match matches.as_slice() {
+                    [field] => field,
+                    [] => {
+                        return Err(DomainError::Validation(format!(
+                            "Unknown field: {}. Run 'jira-db fields list' to see available fields",
+                            name
+                        )));
+                    }
+                    _ => {
+                        return Err(DomainError::Validation(format!(
+                            "Field name '{}' is ambiguous ({}); use the field ID instead",
+                            name,
+                            matches
+                                .iter()
+                                .map(|f| f.id.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )));
+                    }
+                }
+            }
+        };
+
+        if field.id == "status" {
+            return Err(DomainError::Validation(
+                "Status cannot be edited directly; use a transition instead".into(),
+            ));
+        }
+        if READ_ONLY_FIELDS.contains(&field.id.as_str()) {
+            return Err(DomainError::Validation(format!(
+                "Field '{}' is read-only",
+                field.name
+            )));
+        }
+
+        Ok(field)
+    }
+
+    fn convert_set_value(&self, field: &JiraField, value: &str) -> DomainResult<Value> {
+        let schema_type = field.schema_type.as_deref().unwrap_or("");
+
+        if schema_type == "array" {
+            // Comma-separated list replaces the whole array (empty clears it)
+            return value
+                .split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(|v| self.convert_array_item(field, v))
+                .collect::<DomainResult<Vec<_>>>()
+                .map(Value::Array);
+        }
+
+        if value.is_empty() || value.eq_ignore_ascii_case("none") {
+            if field.id == "summary" || field.id == "issuetype" {
+                return Err(DomainError::Validation(format!(
+                    "Field '{}' cannot be cleared",
+                    field.name
+                )));
+            }
+            return Ok(Value::Null);
+        }
+
+        match schema_type {
+            "priority" => {
+                let name = Self::match_allowed(field, value, &self.allowed.priorities)?;
+                Ok(json!({ "name": name }))
+            }
+            "issuetype" => {
+                let name = Self::match_allowed(field, value, &self.allowed.issue_types)?;
+                Ok(json!({ "name": name }))
+            }
+            "resolution" => Ok(json!({ "name": value })),
+            "user" => Ok(json!({ "accountId": value })),
+            "option" => {
+                let value = Self::match_allowed(field, value, self.allowed.options(&field.id))?;
+                Ok(json!({ "value": value }))
+            }
+            "number" => value
+                .parse::<f64>()
+                .map(|n| json!(n))
+                .map_err(|_| Self::invalid(field, value, "a number")),
+            "date" => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|_| json!(value))
+                .map_err(|_| Self::invalid(field, value, "a date (YYYY-MM-DD)")),
+            "datetime" => DateTime::parse_from_rfc3339(value)
+                .map(|_| json!(value))
+                .map_err(|_| Self::invalid(field, value, "an RFC 3339 timestamp")),
+            "string" if Self::is_rich_text(field) => Ok(Self::to_adf(value)),
+            "string" => Ok(json!(value)),
+            other => Err(DomainError::Validation(format!(
+                "Field '{}' has unsupported type '{}'",
+                field.name,
+                if other.is_empty() { "unknown" } else { other }
+            ))),
+        }
+    }
+
+    fn convert_array_item(&self, field: &JiraField, value: &str) -> DomainResult<Value> {
+        if field.schema_type.as_deref() != Some("array") {
+            return Err(DomainError::Validation(format!(
+                "Field '{}' is not a list; use --set instead",
+                field.name
+            )));
+        }
+
+        match field.schema_items.as_deref().unwrap_or("") {
+            "string" => {
+                if field.id == "labels" && value.chars().any(char::is_whitespace) {
+                    return Err(Self::invalid(field, value, "a label without spaces"));
+                }
+                Ok(json!(value))
+            }
+            "component" => {
+                let name = Self::match_allowed(field, value, &self.allowed.components)?;
+                Ok(json!({ "name": name }))
+            }
+            "version" => {
+                let name = Self::match_allowed(field, value, &self.allowed.fix_versions)?;
+                Ok(json!({ "name": name }))
+            }
+            "option" => {
+                let value = Self::match_allowed(field, value, self.allowed.options(&field.id))?;
+                Ok(json!({ "value": value }))
+            }
+            "user" => Ok(json!({ "accountId": value })),
+            other => Err(DomainError::Validation(format!(
+                "Field '{}' has unsupported item type '{}'",
+                field.name,
+                if other.is_empty() { "unknown" } else { other }
+            ))),
+        }
+    }
+
+    /// Return the canonical spelling of `value` from the allowed list
+    fn match_allowed(field: &JiraField, value: &str, allowed: &[String]) -> DomainResult<String> {
+        if allowed.is_empty() {
+            return Ok(value.to_string());
+        }
+        allowed
+            .iter()
+            .find(|a| a.eq_ignore_ascii_case(value))
+            .cloned()
+            .ok_or_else(|| {
+                DomainError::Validation(format!(
+                    "Invalid value '{}' for {}. Allowed: {}",
+                    value,
+                    field.name,
+                    allowed.join(", ")
+                ))
+            })
+    }
+
+    fn is_rich_text(field: &JiraField) -> bool {
+        field.id == "description"
+            || field.id == "environment"
+            || field
+                .schema_custom
+                .as_deref()
+                .is_some_and(|c| c.ends_with(":textarea"))
+    }
+
+    /// Wrap plain text in an Atlassian Document Format paragraph
+    fn to_adf(text: &str) -> Value {
+        json!({
+            "type": "doc",
+            "version": 1,
+            "content": [{
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": text }]
+            }]
+        })
+    }
+
+    fn invalid(field: &JiraField, value: &str, expected: &str) -> DomainError {
+        DomainError::Validation(format!(
+            "Invalid value '{}' for {}: expected {}",
+            value, field.name, expected
+        ))
+    }
+}
+
+/// Use case for editing issue fields in JIRA
+pub struct UpdateIssueFieldsUseCase<I, M, J>
+where
+    I: IssueRepository,
+    M: MetadataRepository,
+    J: JiraService,
+{
+    issue_repository: Arc<I>,
+    metadata_repository: Arc<M>,
+    field_repository: Arc<DuckDbFieldRepository>,
+    jira_service: Arc<J>,
+}
+
+impl<I, M, J> UpdateIssueFieldsUseCase<I, M, J>
+where
+    I: IssueRepository,
+    M: MetadataRepository,
+    J: JiraService,
+{
+    pub fn new(
+        issue_repository: Arc<I>,
+        metadata_repository: Arc<M>,
+        field_repository: Arc<DuckDbFieldRepository>,
+        jira_service: Arc<J>,
+    ) -> Self {
+        Self {
+            issue_repository,
+            metadata_repository,
+            field_repository,
+            jira_service,
+        }
+    }
+
+    /// Validate the edits and build the JIRA payload without sending it
+    pub fn prepare(&self, issue_key: &str, edits: &[FieldEdit]) -> DomainResult<IssueFieldUpdate> {
+        prepare_field_update(
+            self.issue_repository.as_ref(),
+            self.metadata_repository.as_ref(),
+            &self.field_repository,
+            issue_key,
+            edits,
+        )
+    }
+
+    /// Apply the edits in JIRA and refresh the local issue row.
+    /// Returns the re-fetched issue.
+    pub async fn execute(&self, issue_key: &str, edits: &[FieldEdit]) -> DomainResult<Issue> {
+        let update = self.prepare(issue_key, edits)?;
+
+        self.jira_service
+            .update_issue_fields(issue_key, &update)
+            .await?;
+
+        let issue = self.jira_service.fetch_issue(issue_key).await?;
+        self.issue_repository
+            .batch_insert(std::slice::from_ref(&issue))?;
+
+        Ok(issue)
+    }
+}
+
+/// Validate edits against the synced fields and the metadata of the issue's project
+/// and build the JIRA payload, without contacting JIRA (also used to queue edits offline)
+pub fn prepare_field_update<I, M>(
+    issue_repository: &I,
+    metadata_repository: &M,
+    field_repository: &DuckDbFieldRepository,
+    issue_key: &str,
+    edits: &[FieldEdit],
+) -> DomainResult<IssueFieldUpdate>
+where
+    I: IssueRepository + ?Sized,
+    M: MetadataRepository + ?Sized,
+{
+    let issue = issue_repository
+        .find_by_key(issue_key)?
+        .ok_or_else(|| DomainError::NotFound(format!("Issue not found: {}", issue_key)))?;
+
+    let fields = field_repository.find_all()?;
+    if fields.is_empty() {
+        return Err(DomainError::Validation(
+            "No field definitions found. Run 'jira-db fields sync' first.".into(),
+        ));
+    }
+
+    let project_id = &issue.project_id;
+    let allowed = AllowedFieldValues {
+        priorities: names(
+            metadata_repository.find_priorities_by_project(project_id)?,
+            |p| p.name,
+        ),
+        issue_types: names(
+            metadata_repository.find_issue_types_by_project(project_id)?,
+            |t| t.name,
+        ),
+        components: names(
+            metadata_repository.find_components_by_project(project_id)?,
+            |c| c.name,
+        ),
+        fix_versions: names(
+            metadata_repository.find_fix_versions_by_project(project_id)?,
+            |v| v.name,
+        ),
+        field_options: metadata_repository
+            .find_field_options_by_project(project_id)?
+            .into_iter()
+            .filter(|o| o.parent_option_id.is_none() && !o.disabled)
+            .fold(HashMap::new(), |mut map, o| {
+                map.entry(o.field_id).or_insert_with(Vec::new).push(o.value);
+                map
+            }),
+    };
+
+    FieldEditValidator::new(fields, allowed).build(edits)
+}
+
+fn names<T>(items: Vec<T>, name: impl Fn(T) -> String) -> Vec<String> {
+    items.into_iter().map(name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(id: &str, name: &str, schema_type: &str, items: Option<&str>) -> JiraField {
+        JiraField {
+            id: id.to_string(),
+            key: id.to_string(),
+            name: name.to_string(),
+            custom: id.starts_with("customfield_"),
+            searchable: true,
+            navigable: true,
+            orderable: true,
+            schema_type: Some(schema_type.to_string()),
+            schema_items: items.map(String::from),
+            schema_system: None,
+            schema_custom: None,
+            schema_custom_id: None,
+        }
+    }
+
+    fn validator() -> FieldEditValidator {
+        FieldEditValidator::new(
+            vec![
+                field("priority", "Priority", "priority", None),
+                field("labels", "Labels", "array", Some("string")),
+                field("components", "Components", "array", Some("component")),
+                field("assignee", "Assignee", "user", None),
+                field("status", "Status", "status", None),
+                field("customfield_10016", "Story Points", "number", None),
+                field("customfield_10050", "Severity", "option", None),
+            ],
+            AllowedFieldValues {
+                priorities: vec!["High".to_string(), "Low".to_string()],
+                components: vec!["Backend".to_string()],
+                field_options: HashMap::from([(
+                    "customfield_10050".to_string(),
+                    vec!["Critical".to_string(), "Minor".to_string()],
+                )]),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn set(field: &str, value: &str) -> FieldEdit {
+        FieldEdit::Set {
+            field: field.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_build_update_payload() {
+        let update = validator()
+            .build(&[
+                set("priority", "high"),
+                set("Story Points", "5"),
+                set("assignee", "none"),
+                set("Severity", "critical"),
+                FieldEdit::Add {
+                    field: "labels".to_string(),
+                    value: "backend".to_string(),
+                },
+                FieldEdit::Remove {
+                    field: "components".to_string(),
+                    value: "backend".to_string(),
+                },
+            ])
+            .unwrap();
+
+        assert_eq!(
+            update.to_request_body(),
+            json!({
+                "fields": {
+                    "priority": { "name": "High" },
+                    "customfield_10016": 5.0,
+                    "assignee": null,
+                    "customfield_10050": { "value": "Critical" }
+                },
+                "update": {
+                    "labels": [{ "add": "backend" }],
+                    "components": [{ "remove": { "name": "Backend" } }]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_edits() {
+        let v = validator();
+        assert!(v.build(&[set("priority", "Urgent")]).is_err());
+        assert!(v.build(&[set("Story Points", "many")]).is_err());
+        assert!(v.build(&[set("Severity", "Blocker")]).is_err());
+        assert!(v.build(&[set("status", "Done")]).is_err());
+        assert!(v.build(&[set("unknown_field", "x")]).is_err());
+        assert!(
+            v.build(&[FieldEdit::Add {
+                field: "priority".to_string(),
+                value: "High".to_string(),
+            }])
+            .is_err()
+        );
+        assert!(v.build(&[]).is_err());
+    }
+
+    #[test]
+    fn test_parse_set_argument() {
+        assert_eq!(
+            FieldEdit::parse_set("priority=High"),
+            Some(set("priority", "High"))
+        );
+        assert_eq!(FieldEdit::parse_set("assignee="), Some(set("assignee", "")));
+        assert_eq!(FieldEdit::parse_set("priority"), None);
+        assert_eq!(FieldEdit::parse_set("=High"), None);
+    }
+}
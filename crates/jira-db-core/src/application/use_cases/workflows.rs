@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::entities::{Workflow, WorkflowViolation};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::{ChangeHistoryRepository, IssueRepository, MetadataRepository};
+
+/// Use case for reading synced workflows
+pub struct GetWorkflowUseCase<M>
+where
+    M: MetadataRepository,
+{
+    metadata_repository: Arc<M>,
+}
+
+impl<M> GetWorkflowUseCase<M>
+where
+    M: MetadataRepository,
+{
+    pub fn new(metadata_repository: Arc<M>) -> Self {
+        Self {
+            metadata_repository,
+        }
+    }
+
+    pub fn list(&self, project_id: &str) -> DomainResult<Vec<Workflow>> {
+        self.metadata_repository
+            .find_workflows_by_project(project_id)
+    }
+
+    /// Workflow used by an issue type (explicit scheme mapping, else the default workflow)
+    pub fn for_issue_type(&self, project_id: &str, issue_type: &str) -> DomainResult<Workflow> {
+        let workflows = self.list(project_id)?;
+        if workflows.is_empty() {
+            return Err(DomainError::NotFound(
+                "No workflows synced for this project. Run sync first".to_string(),
+            ));
+        }
+        Workflow::for_issue_type(&workflows, issue_type)
+            .cloned()
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("No workflow found for issue type: {}", issue_type))
+            })
+    }
+}
+
+/// Use case for checking status changes in the change history against the workflows
+pub struct ValidateWorkflowTransitionsUseCase<I, C, M>
+where
+    I: IssueRepository,
+    C: ChangeHistoryRepository,
+    M: MetadataRepository,
+{
+    issue_repository: Arc<I>,
+    change_history_repository: Arc<C>,
+    metadata_repository: Arc<M>,
+}
+
+impl<I, C, M> ValidateWorkflowTransitionsUseCase<I, C, M>
+where
+    I: IssueRepository,
+    C: ChangeHistoryRepository,
+    M: MetadataRepository,
+{
+    pub fn new(
+        issue_repository: Arc<I>,
+        change_history_repository: Arc<C>,
+        metadata_repository: Arc<M>,
+    ) -> Self {
+        Self {
+            issue_repository,
+            change_history_repository,
+            metadata_repository,
+        }
+    }
+
+    /// Find status changes the workflow does not allow (illegal or admin-forced jumps).
+    ///
+    /// Issues whose type has no workflow are skipped.
+    pub fn execute(
+        &self,
+        project_id: &str,
+        issue_type_filter: Option<&str>,
+    ) -> DomainResult<Vec<WorkflowViolation>> {
+        let workflows = self
+            .metadata_repository
+            .find_workflows_by_project(project_id)?;
+        if workflows.is_empty() {
+            return Err(DomainError::NotFound(
+                "No workflows synced for this project. Run sync first".to_string(),
+            ));
+        }
+
+        let issue_types: HashMap<String, String> = self
+            .issue_repository
+            .find_by_project(project_id)?
+            .into_iter()
+            .filter_map(|issue| Some((issue.key, issue.issue_type?)))
+            .collect();
+
+        let mut violations = Vec::new();
+        for change in self.change_history_repository.find_by_field("status")? {
+            let (Some(from), Some(to)) = (&change.from_string, &change.to_string) else {
+                continue;
+            };
+            let Some(issue_type) = issue_types.get(&change.issue_key) else {
+                continue;
+            };
+            if issue_type_filter.is_some_and(|f| !f.eq_ignore_ascii_case(issue_type)) {
+                continue;
+            }
+            let Some(workflow) = Workflow::for_issue_type(&workflows, issue_type) else {
+                continue;
+            };
+
+            if !workflow.allows(from, to) {
+                violations.push(WorkflowViolation {
+                    issue_key: change.issue_key.clone(),
+                    issue_type: issue_type.clone(),
+                    workflow: workflow.name.clone(),
+                    from_status: from.clone(),
+                    to_status: to.clone(),
+                    changed_at: change.changed_at,
+                    author: change.author_display_name.clone(),
+                });
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::sync_tests::{
+        MockChangeHistoryRepository, MockIssueRepository, MockMetadataRepository, create_test_issue,
+    };
+    use crate::domain::entities::{ChangeHistoryItem, WorkflowTransition};
+    use chrono::Utc;
+
+    fn status_change(issue_key: &str, from: &str, to: &str) -> ChangeHistoryItem {
+        ChangeHistoryItem {
+            issue_id: "1".to_string(),
+            issue_key: issue_key.to_string(),
+            history_id: "100".to_string(),
+            author_account_id: None,
+            author_display_name: Some("Admin".to_string()),
+            field: "status".to_string(),
+            field_type: Some("jira".to_string()),
+            from_value: None,
+            from_string: Some(from.to_string()),
+            to_value: None,
+            to_string: Some(to.to_string()),
+            changed_at: Utc::now(),
+            source: ChangeHistoryItem::SOURCE_CHANGELOG.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_flags_undefined_transitions() {
+        let issue_repo = Arc::new(MockIssueRepository::new());
+        issue_repo
+            .batch_insert(&[create_test_issue("1", "PROJ-1", "10000", Utc::now())])
+            .unwrap();
+
+        let history_repo = Arc::new(MockChangeHistoryRepository::new());
+        history_repo
+            .batch_insert(&[
+                status_change("PROJ-1", "Open", "In Progress"),
+                status_change("PROJ-1", "In Progress", "Open"),
+                status_change("PROJ-1", "Open", "Closed"),
+            ])
+            .unwrap();
+
+        let metadata_repo = Arc::new(MockMetadataRepository::new());
+        let transition = |from: &str, to: &str| WorkflowTransition {
+            id: "1".to_string(),
+            name: to.to_string(),
+            from_status: Some(from.to_string()),
+            to_status: to.to_string(),
+            transition_type: "directed".to_string(),
+        };
+        metadata_repo
+            .replace_workflows(
+                "10000",
+                &[Workflow {
+                    name: "Bug Workflow".to_string(),
+                    description: None,
+                    scheme_name: None,
+                    is_default: true,
+                    issue_types: vec![],
+                    statuses: vec![
+                        "Open".to_string(),
+                        "In Progress".to_string(),
+                        "Closed".to_string(),
+                    ],
+                    transitions: vec![
+                        transition("Open", "In Progress"),
+                        transition("In Progress", "Closed"),
+                    ],
+                }],
+            )
+            .unwrap();
+
+        let use_case =
+            ValidateWorkflowTransitionsUseCase::new(issue_repo, history_repo, metadata_repo);
+        let violations = use_case.execute("10000", None).unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].from_status, "In Progress");
+        assert_eq!(violations[0].to_status, "Open");
+        assert_eq!(violations[1].to_status, "Closed");
+        assert_eq!(violations[1].workflow, "Bug Workflow");
+    }
+}
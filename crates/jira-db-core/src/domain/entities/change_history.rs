@@ -1,8 +1,34 @@
+use std::collections::HashSet;
+
 use chrono::{DateTime, Utc};
 use log::warn;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Fields that change on their own or are tracked elsewhere, so payload diffs ignore them
+const UNTRACKED_FIELDS: &[&str] = &[
+    "updated",
+    "lastViewed",
+    "statuscategorychangedate",
+    "statusCategory",
+    "votes",
+    "watches",
+    "worklog",
+    "comment",
+    "attachment",
+    "subtasks",
+    "issuelinks",
+    "progress",
+    "aggregateprogress",
+    "timespent",
+    "aggregatetimespent",
+    "timeestimate",
+    "aggregatetimeestimate",
+    "timetracking",
+    "workratio",
+    "thumbnail",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChangeHistoryItem {
     pub issue_id: String,
@@ -17,9 +43,25 @@ pub struct ChangeHistoryItem {
     pub to_value: Option<String>,
     pub to_string: Option<String>,
     pub changed_at: DateTime<Utc>,
+    /// Where the change came from: JIRA's changelog or a payload diff between syncs
+    #[serde(default = "default_source")]
+    pub source: String,
+}
+
+fn default_source() -> String {
+    ChangeHistoryItem::SOURCE_CHANGELOG.to_string()
 }
 
 impl ChangeHistoryItem {
+    /// Change taken from the issue's changelog
+    pub const SOURCE_CHANGELOG: &'static str = "changelog";
+    /// Change derived by comparing the stored payload with the incoming one during sync
+    pub const SOURCE_SYNC_DIFF: &'static str = "sync-diff";
+
+    pub fn is_sync_diff(&self) -> bool {
+        self.source == Self::SOURCE_SYNC_DIFF
+    }
+
     pub fn extract_from_raw_json(issue_id: &str, issue_key: &str, raw_json: &str) -> Vec<Self> {
         let mut items = Vec::new();
 
@@ -123,10 +165,256 @@ impl ChangeHistoryItem {
                     to_value,
                     to_string,
                     changed_at,
+                    source: Self::SOURCE_CHANGELOG.to_string(),
                 });
             }
         }
 
         items
     }
+
+    /// Derive change rows for fields that differ between the stored and the incoming payload
+    /// but have no changelog entry since the stored version (custom fields JIRA does not log,
+    /// fields changed by apps).
+    ///
+    /// Fields are named by their payload key (e.g., "customfield_10050"), and the rows are
+    /// stamped with the sync time.
+    pub fn derive_from_payload_diff(
+        issue_id: &str,
+        issue_key: &str,
+        previous_raw_json: &str,
+        current_raw_json: &str,
+        history_id: &str,
+        synced_at: DateTime<Utc>,
+    ) -> Vec<Self> {
+        let (Ok(previous), Ok(current)) = (
+            serde_json::from_str::<Value>(previous_raw_json),
+            serde_json::from_str::<Value>(current_raw_json),
+        ) else {
+            warn!("Failed to parse raw_json for payload diff of {}", issue_key);
+            return Vec::new();
+        };
+
+        // Without both field sets (e.g., a stored row that was not an API payload) there is
+        // nothing meaningful to compare
+        let (Some(previous_fields), Some(current_fields)) = (
+            previous["fields"].as_object(),
+            current["fields"].as_object(),
+        ) else {
+            return Vec::new();
+        };
+
+        let previous_updated = previous["fields"]["updated"]
+            .as_str()
+            .and_then(parse_jira_timestamp);
+        let logged = Self::logged_fields_since(&current, previous_updated);
+
+        let mut keys: Vec<&String> = previous_fields
+            .keys()
+            .chain(current_fields.keys())
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut items = Vec::new();
+        for key in keys {
+            if UNTRACKED_FIELDS.contains(&key.as_str()) || logged.contains(&key.to_lowercase()) {
+                continue;
+            }
+            let before = previous_fields.get(key).unwrap_or(&Value::Null);
+            let after = current_fields.get(key).unwrap_or(&Value::Null);
+            if before == after || (is_empty_value(before) && is_empty_value(after)) {
+                continue;
+            }
+
+            items.push(ChangeHistoryItem {
+                issue_id: issue_id.to_string(),
+                issue_key: issue_key.to_string(),
+                history_id: history_id.to_string(),
+                author_account_id: None,
+                author_display_name: None,
+                field: key.clone(),
+                field_type: Some(if key.starts_with("customfield_") {
+                    "custom".to_string()
+                } else {
+                    "jira".to_string()
+                }),
+                from_value: value_id(before),
+                from_string: display_value(before),
+                to_value: value_id(after),
+                to_string: display_value(after),
+                changed_at: synced_at,
+                source: Self::SOURCE_SYNC_DIFF.to_string(),
+            });
+        }
+
+        items
+    }
+
+    /// Lowercased field IDs and names in changelog entries newer than `since`
+    fn logged_fields_since(raw: &Value, since: Option<DateTime<Utc>>) -> HashSet<String> {
+        let mut fields = HashSet::new();
+        for history in raw["changelog"]["histories"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            let created = history["created"].as_str().and_then(parse_jira_timestamp);
+            if since.is_some_and(|since| created.is_some_and(|c| c <= since)) {
+                continue;
+            }
+            for item in history["items"].as_array().into_iter().flatten() {
+                for key in ["fieldId", "field"] {
+                    if let Some(name) = item[key].as_str() {
+                        fields.insert(name.to_lowercase());
+                    }
+                }
+            }
+        }
+        // Changelog names differ from payload keys for a few system fields
+        for (logged, key) in [
+            ("fix version", "fixversions"),
+            ("version", "versions"),
+            ("component", "components"),
+        ] {
+            if fields.contains(logged) {
+                fields.insert(key.to_string());
+            }
+        }
+        fields
+    }
+}
+
+/// Parse JIRA timestamps ("2024-01-15T10:30:00.000+0900") as well as RFC 3339
+fn parse_jira_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .or_else(|_| DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.is_empty(),
+        Value::String(s) => s.is_empty(),
+        _ => false,
+    }
+}
+
+/// Display form of a field value, matching the changelog's fromString/toString conventions
+/// (labels separated by spaces, named objects by commas)
+fn display_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Array(items) if items.iter().all(Value::is_string) => Some(
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        Value::Array(items) => Some(
+            items
+                .iter()
+                .filter_map(display_value)
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        Value::Object(_) => ["displayName", "name", "value", "key"]
+            .iter()
+            .find_map(|k| value[*k].as_str())
+            .map(|s| match value["child"]["value"].as_str() {
+                // Cascading select: "Parent - Child"
+                Some(child) => format!("{} - {}", s, child),
+                None => s.to_string(),
+            })
+            .or_else(|| Some(value.to_string())),
+    }
+}
+
+/// ID form of a field value (option, user or entity ID), like the changelog's from/to
+fn value_id(value: &Value) -> Option<String> {
+    match value {
+        Value::Object(_) => ["accountId", "id"]
+            .iter()
+            .find_map(|k| value[*k].as_str())
+            .map(|s| s.to_string()),
+        Value::Array(items) => {
+            let ids: Vec<String> = items.iter().filter_map(value_id).collect();
+            (!ids.is_empty()).then(|| ids.join(", "))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_from_payload_diff_skips_logged_fields() {
+        let previous = r#"{
+            "fields": {
+                "updated": "2024-01-10T10:00:00.000+0000",
+                "status": { "name": "Open", "id": "1" },
+                "customfield_10050": { "value": "Low", "id": "100" },
+                "customfield_10060": null,
+                "labels": ["a"]
+            }
+        }"#;
+        let current = r#"{
+            "fields": {
+                "updated": "2024-01-12T10:00:00.000+0000",
+                "status": { "name": "Done", "id": "3" },
+                "customfield_10050": { "value": "High", "id": "101" },
+                "customfield_10060": [],
+                "labels": ["a", "b"]
+            },
+            "changelog": {
+                "histories": [
+                    {
+                        "created": "2024-01-11T10:00:00.000+0000",
+                        "items": [{ "field": "status", "fieldId": "status" }]
+                    },
+                    {
+                        "created": "2024-01-05T10:00:00.000+0000",
+                        "items": [{ "field": "labels", "fieldId": "labels" }]
+                    }
+                ]
+            }
+        }"#;
+
+        let synced_at = Utc::now();
+        let items = ChangeHistoryItem::derive_from_payload_diff(
+            "1", "PROJ-1", previous, current, "sync-7", synced_at,
+        );
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].field, "customfield_10050");
+        assert_eq!(items[0].from_string.as_deref(), Some("Low"));
+        assert_eq!(items[0].to_string.as_deref(), Some("High"));
+        assert_eq!(items[0].to_value.as_deref(), Some("101"));
+        assert!(items[0].is_sync_diff());
+        assert_eq!(items[0].changed_at, synced_at);
+        assert_eq!(items[1].field, "labels");
+        assert_eq!(items[1].to_string.as_deref(), Some("a b"));
+    }
+
+    #[test]
+    fn test_derive_from_identical_payloads_is_empty() {
+        let raw = r#"{ "fields": { "customfield_10050": { "value": "Low" } } }"#;
+        let items = ChangeHistoryItem::derive_from_payload_diff(
+            "1",
+            "PROJ-1",
+            raw,
+            raw,
+            "sync-1",
+            Utc::now(),
+        );
+        assert!(items.is_empty());
+    }
 }
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+/// Id prefix of the placeholder fields registered for imported CSV columns
+const IMPORT_PLACEHOLDER_PREFIX: &str = "customfield_import_";
+
 /// Represents a JIRA field definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraField {
@@ -104,4 +107,71 @@ impl JiraField {
             name
         }
     }
+
+    /// Placeholder custom field for an imported CSV column named `name` that matches no
+    /// single known field. The id is derived from the name, so re-imports reuse it.
+    pub fn import_placeholder(name: &str) -> Self {
+        let slug: String = name
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+            .to_lowercase();
+        let id = format!("{}{}", IMPORT_PLACEHOLDER_PREFIX, slug);
+        Self {
+            id: id.clone(),
+            key: id,
+            name: name.to_string(),
+            custom: true,
+            searchable: false,
+            navigable: true,
+            orderable: false,
+            schema_type: None,
+            schema_items: None,
+            schema_system: None,
+            schema_custom: None,
+            schema_custom_id: None,
+        }
+    }
+
+    /// Whether this is a placeholder from [`Self::import_placeholder`]
+    pub fn is_import_placeholder(&self) -> bool {
+        self.id.starts_with(IMPORT_PLACEHOLDER_PREFIX)
+    }
+
+    /// The only custom field of `fields` named `name` (exact name first, then ignoring
+    /// ASCII case), leaving out import placeholders
+    pub fn find_custom_by_name<'a>(fields: &'a [JiraField], name: &str) -> Option<&'a JiraField> {
+        let custom = || {
+            fields
+                .iter()
+                .filter(|field| field.custom && !field.is_import_placeholder())
+        };
+        let mut matches: Vec<&JiraField> = custom().filter(|field| field.name == name).collect();
+        if matches.is_empty() {
+            matches = custom()
+                .filter(|field| field.name.eq_ignore_ascii_case(name))
+                .collect();
+        }
+        match matches.as_slice() {
+            [field] => Some(field),
+            _ => None,
+        }
+    }
+}
+
+/// An allowed value of a select, multi-select or cascading select field in a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldOption {
+    /// Field ID (e.g., "customfield_10050")
+    pub field_id: String,
+    /// Field name as shown in the create screen
+    pub field_name: Option<String>,
+    /// Option ID
+    pub option_id: String,
+    /// Option value (display text)
+    pub value: String,
+    /// Parent option ID (child options of cascading select fields)
+    pub parent_option_id: Option<String>,
+    /// Whether the option is disabled (kept for existing issues but not selectable)
+    pub disabled: bool,
 }
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Link between two issues, read from the `issuelinks` field of the raw issue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueLink {
+    /// Issue the link was read from
+    pub key: String,
+    /// Issue on the other end of the link
+    pub linked_key: String,
+    /// Link type name (e.g., "Duplicate", "Blocks")
+    pub link_type: String,
+    /// Relation as seen from `key` (e.g., "duplicates", "is duplicated by")
+    pub relation: Option<String>,
+}
+
+impl IssueLink {
+    /// Parse the `issuelinks` array of an issue. Entries without a linked
+    /// issue key are skipped.
+    pub fn from_issue_links(key: &str, links: &Value) -> Vec<Self> {
+        let Some(links) = links.as_array() else {
+            return Vec::new();
+        };
+
+        links
+            .iter()
+            .filter_map(|link| {
+                let link_type = &link["type"];
+                let (linked, relation) = if link["inwardIssue"].is_object() {
+                    (&link["inwardIssue"], &link_type["inward"])
+                } else {
+                    (&link["outwardIssue"], &link_type["outward"])
+                };
+
+                Some(Self {
+                    key: key.to_string(),
+                    linked_key: linked["key"].as_str()?.to_string(),
+                    link_type: link_type["name"].as_str().unwrap_or_default().to_string(),
+                    relation: relation.as_str().map(str::to_string),
+                })
+            })
+            .collect()
+    }
+
+    /// Whether the link marks one issue as a duplicate of the other
+    pub fn is_duplicate(&self) -> bool {
+        let mentions_duplicate = |text: &str| text.to_lowercase().contains("duplicat");
+        mentions_duplicate(&self.link_type)
+            || self.relation.as_deref().is_some_and(mentions_duplicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_issue_links_reads_both_directions() {
+        let links = serde_json::json!([
+            {
+                "type": {"name": "Duplicate", "inward": "is duplicated by", "outward": "duplicates"},
+                "inwardIssue": {"key": "PROJ-2"}
+            },
+            {
+                "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+                "outwardIssue": {"key": "PROJ-3"}
+            },
+            {"type": {"name": "Relates"}}
+        ]);
+
+        let parsed = IssueLink::from_issue_links("PROJ-1", &links);
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].linked_key, "PROJ-2");
+        assert_eq!(parsed[0].relation.as_deref(), Some("is duplicated by"));
+        assert!(parsed[0].is_duplicate());
+        assert_eq!(parsed[1].linked_key, "PROJ-3");
+        assert_eq!(parsed[1].relation.as_deref(), Some("blocks"));
+        assert!(!parsed[1].is_duplicate());
+    }
+
+    #[test]
+    fn test_is_duplicate_matches_localized_relation() {
+        let link = IssueLink {
+            key: "PROJ-1".into(),
+            linked_key: "PROJ-2".into(),
+            link_type: "重複".into(),
+            relation: Some("duplicates".into()),
+        };
+        assert!(link.is_duplicate());
+        assert!(IssueLink::from_issue_links("PROJ-1", &Value::Null).is_empty());
+    }
+}
@@ -1,13 +1,26 @@
 mod change_history;
 mod field;
 mod issue;
+mod issue_link;
 mod issue_snapshot;
 mod metadata;
+mod pending_operation;
 mod project;
+mod saved_search;
+mod topic;
+mod workflow;
 
 pub use change_history::ChangeHistoryItem;
-pub use field::JiraField;
+pub use field::{FieldOption, JiraField};
 pub use issue::Issue;
+pub use issue_link::IssueLink;
 pub use issue_snapshot::IssueSnapshot;
 pub use metadata::{Component, FixVersion, IssueType, Label, Priority, Status};
+pub use pending_operation::{PendingOperation, PendingOperationKind, PendingOperationStatus};
 pub use project::Project;
+pub use saved_search::{
+    SavedSearch, SavedSearchFilter, SavedSearchParameter, SavedSearchParameterType,
+    SavedSearchQuery, SavedSearchValue, render_jql, render_sql,
+};
+pub use topic::{TopicAssignment, TopicCluster, TopicIssue};
+pub use workflow::{Workflow, WorkflowGraph, WorkflowTransition, WorkflowViolation, same_status};
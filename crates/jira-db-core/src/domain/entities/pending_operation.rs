@@ -0,0 +1,201 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A write operation recorded locally while offline, to be replayed against JIRA
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PendingOperationKind {
+    /// Move the issue to a status (the transition is resolved at push time)
+    Transition { to_status: String },
+    /// Add a plain-text comment
+    Comment { body: String },
+    /// Set the due date (YYYY-MM-DD)
+    UpdateDueDate { due_date: String },
+    /// Edit fields with a validated edit issue payload (`{"fields": ..., "update": ...}`)
+    UpdateFields { body: serde_json::Value },
+    /// Create an issue; the operation's `issue_key` holds the project key until it is pushed
+    CreateIssue {
+        summary: String,
+        description: Option<String>,
+        issue_type: String,
+    },
+}
+
+impl PendingOperationKind {
+    /// Operation type name stored in the `operation_type` column
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Transition { .. } => "transition",
+            Self::Comment { .. } => "comment",
+            Self::UpdateDueDate { .. } => "update_due_date",
+            Self::UpdateFields { .. } => "update_fields",
+            Self::CreateIssue { .. } => "create_issue",
+        }
+    }
+
+    /// Short human-readable description for reports
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Transition { to_status } => format!("Transition to '{}'", to_status),
+            Self::Comment { body } => {
+                let preview: String = body.chars().take(40).collect();
+                if preview.len() < body.len() {
+                    format!("Comment: {}...", preview)
+                } else {
+                    format!("Comment: {}", preview)
+                }
+            }
+            Self::UpdateDueDate { due_date } => format!("Due date = {}", due_date),
+            Self::UpdateFields { body } => {
+                let mut fields: Vec<&str> = Vec::new();
+                for object in ["fields", "update"]
+                    .iter()
+                    .filter_map(|k| body[*k].as_object())
+                {
+                    for field in object.keys() {
+                        if !fields.contains(&field.as_str()) {
+                            fields.push(field);
+                        }
+                    }
+                }
+                format!("Edit {}", fields.join(", "))
+            }
+            Self::CreateIssue {
+                summary,
+                issue_type,
+                ..
+            } => format!("Create {}: {}", issue_type, summary),
+        }
+    }
+}
+
+/// Lifecycle state of a pending operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingOperationStatus {
+    /// Waiting to be pushed
+    Pending,
+    /// Successfully applied to JIRA
+    Applied,
+    /// Rejected by JIRA or by conflict detection
+    Rejected,
+    /// Dropped by the user
+    Discarded,
+}
+
+impl PendingOperationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Applied => "applied",
+            Self::Rejected => "rejected",
+            Self::Discarded => "discarded",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(Self::Pending),
+            "applied" => Some(Self::Applied),
+            "rejected" => Some(Self::Rejected),
+            "discarded" => Some(Self::Discarded),
+            _ => None,
+        }
+    }
+}
+
+/// Represents a row in the `pending_operations` table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingOperation {
+    pub id: i64,
+    pub issue_key: String,
+    pub kind: PendingOperationKind,
+    /// Local `updated_date` of the issue when the operation was queued.
+    /// `None` skips conflict detection (e.g. after an explicit retry).
+    pub base_updated_at: Option<DateTime<Utc>>,
+    pub status: PendingOperationStatus,
+    pub error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub applied_at: Option<DateTime<Utc>>,
+}
+
+impl PendingOperation {
+    /// Whether the remote issue changed after this operation was queued.
+    ///
+    /// Compared at second precision: the local `updated_date` is read back
+    /// truncated to whole seconds while JIRA reports milliseconds.
+    pub fn conflicts_with(&self, remote_updated_at: Option<DateTime<Utc>>) -> bool {
+        match (self.base_updated_at, remote_updated_at) {
+            (Some(base), Some(remote)) => remote.timestamp() > base.timestamp(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_kind_serialization_roundtrip() {
+        let kind = PendingOperationKind::Transition {
+            to_status: "Done".to_string(),
+        };
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(json, r#"{"type":"transition","to_status":"Done"}"#);
+
+        let parsed: PendingOperationKind = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, kind);
+        assert_eq!(parsed.type_name(), "transition");
+    }
+
+    #[test]
+    fn test_status_parse() {
+        for status in [
+            PendingOperationStatus::Pending,
+            PendingOperationStatus::Applied,
+            PendingOperationStatus::Rejected,
+            PendingOperationStatus::Discarded,
+        ] {
+            assert_eq!(PendingOperationStatus::parse(status.as_str()), Some(status));
+        }
+        assert_eq!(PendingOperationStatus::parse("unknown"), None);
+    }
+
+    #[test]
+    fn test_conflicts_with() {
+        let base = Utc::now();
+        let mut op = PendingOperation {
+            id: 1,
+            issue_key: "PROJ-1".to_string(),
+            kind: PendingOperationKind::Comment {
+                body: "hello".to_string(),
+            },
+            base_updated_at: Some(base),
+            status: PendingOperationStatus::Pending,
+            error_message: None,
+            created_at: base,
+            applied_at: None,
+        };
+
+        assert!(!op.conflicts_with(Some(base)));
+        assert!(!op.conflicts_with(Some(base - Duration::minutes(1))));
+        assert!(op.conflicts_with(Some(base + Duration::minutes(1))));
+        assert!(!op.conflicts_with(None));
+
+        // The local copy is stored without milliseconds
+        let remote = DateTime::parse_from_rfc3339("2024-03-01T10:15:30.482+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        op.base_updated_at = DateTime::parse_from_rfc3339("2024-03-01T10:15:30+00:00")
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc));
+        assert!(!op.conflicts_with(Some(remote)));
+        assert!(op.conflicts_with(Some(remote + Duration::seconds(1))));
+
+        // Without a base timestamp the operation never conflicts
+        op.base_updated_at = None;
+        assert!(!op.conflicts_with(Some(base + Duration::minutes(1))));
+    }
+}
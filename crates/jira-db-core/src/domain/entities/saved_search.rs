@@ -0,0 +1,675 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::{DomainError, DomainResult};
+
+/// A named, parameterized search shared by the CLI, MCP server and web API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedSearch {
+    /// Unique name (letters, digits, `-` and `_`), used on the command line and as an MCP tool name
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Who created the search (usually the JIRA username)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Project to run against; every project when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub query: SavedSearchQuery,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<SavedSearchParameter>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// What a saved search runs. `{{name}}` placeholders refer to its parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SavedSearchQuery {
+    /// Read-only SQL; placeholders are bound as query parameters, so they must not be quoted
+    Sql { sql: String },
+    /// JQL; placeholders are replaced with quoted literals (`EMPTY` when unset)
+    Jql { jql: String },
+    /// Structured filters; placeholders are substituted into each value and
+    /// a filter that ends up empty is dropped
+    Filter { filter: SavedSearchFilter },
+}
+
+impl SavedSearchQuery {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Sql { .. } => "sql",
+            Self::Jql { .. } => "jql",
+            Self::Filter { .. } => "filter",
+        }
+    }
+
+    /// Every text that may contain placeholders
+    fn templates(&self) -> Vec<&str> {
+        match self {
+            Self::Sql { sql } => vec![sql.as_str()],
+            Self::Jql { jql } => vec![jql.as_str()],
+            Self::Filter { filter } => filter.templates(),
+        }
+    }
+}
+
+/// Issue search filters, mirroring `jira-db search` options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedSearchFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// "any" or "all"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels_match: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fix_versions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_to: Option<String>,
+    /// Sort spec such as `updated:desc,key`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+impl SavedSearchFilter {
+    fn optional_fields(&self) -> [&Option<String>; 20] {
+        [
+            &self.query,
+            &self.status,
+            &self.status_category,
+            &self.assignee,
+            &self.issue_type,
+            &self.priority,
+            &self.team,
+            &self.resolution,
+            &self.labels_match,
+            &self.sprint,
+            &self.parent_key,
+            &self.created_from,
+            &self.created_to,
+            &self.updated_from,
+            &self.updated_to,
+            &self.due_from,
+            &self.due_to,
+            &self.resolved_from,
+            &self.resolved_to,
+            &self.sort,
+        ]
+    }
+
+    fn templates(&self) -> Vec<&str> {
+        self.optional_fields()
+            .into_iter()
+            .flatten()
+            .chain(&self.labels)
+            .chain(&self.components)
+            .chain(&self.fix_versions)
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Filter with every placeholder replaced by its argument
+    pub fn render(&self, values: &HashMap<String, SavedSearchValue>) -> DomainResult<Self> {
+        let one = |value: &Option<String>| -> DomainResult<Option<String>> {
+            match value {
+                Some(template) => render_text(template, values),
+                None => Ok(None),
+            }
+        };
+        let list = |items: &[String]| -> DomainResult<Vec<String>> {
+            let mut rendered = Vec::new();
+            for item in items {
+                rendered.extend(render_text(item, values)?);
+            }
+            Ok(rendered)
+        };
+
+        Ok(Self {
+            query: one(&self.query)?,
+            status: one(&self.status)?,
+            status_category: one(&self.status_category)?,
+            assignee: one(&self.assignee)?,
+            issue_type: one(&self.issue_type)?,
+            priority: one(&self.priority)?,
+            team: one(&self.team)?,
+            resolution: one(&self.resolution)?,
+            labels: list(&self.labels)?,
+            labels_match: one(&self.labels_match)?,
+            components: list(&self.components)?,
+            fix_versions: list(&self.fix_versions)?,
+            sprint: one(&self.sprint)?,
+            parent_key: one(&self.parent_key)?,
+            created_from: one(&self.created_from)?,
+            created_to: one(&self.created_to)?,
+            updated_from: one(&self.updated_from)?,
+            updated_to: one(&self.updated_to)?,
+            due_from: one(&self.due_from)?,
+            due_to: one(&self.due_to)?,
+            resolved_from: one(&self.resolved_from)?,
+            resolved_to: one(&self.resolved_to)?,
+            sort: one(&self.sort)?,
+        })
+    }
+}
+
+/// Type a parameter's argument must have
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SavedSearchParameterType {
+    #[default]
+    String,
+    Number,
+    /// `YYYY-MM-DD` or RFC 3339
+    Date,
+    Boolean,
+}
+
+impl SavedSearchParameterType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Date => "date",
+            Self::Boolean => "boolean",
+        }
+    }
+
+    /// Check and convert a raw argument
+    pub fn parse_value(&self, raw: &str) -> DomainResult<SavedSearchValue> {
+        let invalid =
+            || DomainError::Validation(format!("Invalid {} value '{}'", self.as_str(), raw));
+        match self {
+            Self::String => Ok(SavedSearchValue::Text(raw.to_string())),
+            Self::Number => raw
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|n| n.is_finite())
+                .map(SavedSearchValue::Number)
+                .ok_or_else(invalid),
+            Self::Date => {
+                let raw = raw.trim();
+                let valid = NaiveDate::parse_from_str(raw, "%Y-%m-%d").is_ok()
+                    || DateTime::parse_from_rfc3339(raw).is_ok();
+                if valid {
+                    Ok(SavedSearchValue::Text(raw.to_string()))
+                } else {
+                    Err(invalid())
+                }
+            }
+            Self::Boolean => match raw.trim().to_lowercase().as_str() {
+                "true" | "yes" | "1" => Ok(SavedSearchValue::Boolean(true)),
+                "false" | "no" | "0" => Ok(SavedSearchValue::Boolean(false)),
+                _ => Err(invalid()),
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for SavedSearchParameterType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "string" | "text" => Ok(Self::String),
+            "number" => Ok(Self::Number),
+            "date" => Ok(Self::Date),
+            "boolean" | "bool" => Ok(Self::Boolean),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid parameter type '{}': expected string, number, date or boolean",
+                s
+            ))),
+        }
+    }
+}
+
+/// A typed parameter of a saved search
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedSearchParameter {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub param_type: SavedSearchParameterType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    /// Used when no argument is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+/// A checked parameter value
+#[derive(Debug, Clone, PartialEq)]
+pub enum SavedSearchValue {
+    Null,
+    Text(String),
+    Number(f64),
+    Boolean(bool),
+}
+
+impl SavedSearchValue {
+    /// The value as it appears in filters, `None` for null
+    pub fn as_text(&self) -> Option<String> {
+        match self {
+            Self::Null => None,
+            Self::Text(text) => Some(text.clone()),
+            Self::Number(n) => Some(n.to_string()),
+            Self::Boolean(b) => Some(b.to_string()),
+        }
+    }
+
+    /// The value as a JQL operand
+    pub fn to_jql(&self) -> String {
+        match self {
+            Self::Null => "EMPTY".to_string(),
+            Self::Text(text) => format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"")),
+            Self::Number(n) => n.to_string(),
+            Self::Boolean(b) => format!("\"{}\"", b),
+        }
+    }
+}
+
+impl SavedSearch {
+    pub fn new(name: impl Into<String>, query: SavedSearchQuery) -> Self {
+        let now = Utc::now();
+        Self {
+            name: name.into(),
+            description: None,
+            owner: None,
+            project: None,
+            query,
+            parameters: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// A saved search name for a free-form title: characters other than letters,
+    /// digits, `-` and `_` become `_`
+    pub fn name_from_title(title: &str) -> String {
+        let name: String = title
+            .trim()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        if name.is_empty() {
+            "query".to_string()
+        } else {
+            name
+        }
+    }
+
+    /// Check the name, the parameters and that every placeholder refers to a parameter
+    pub fn validate(&self) -> DomainResult<()> {
+        validate_name("saved search", &self.name)?;
+
+        let mut names = HashSet::new();
+        for param in &self.parameters {
+            validate_name("parameter", &param.name)?;
+            if !names.insert(param.name.as_str()) {
+                return Err(DomainError::Validation(format!(
+                    "Duplicate parameter '{}'",
+                    param.name
+                )));
+            }
+            if let Some(default) = &param.default {
+                param.param_type.parse_value(default).map_err(|e| {
+                    DomainError::Validation(format!("Default of parameter '{}': {}", param.name, e))
+                })?;
+            }
+        }
+
+        for template in self.query.templates() {
+            for placeholder in placeholders(template)? {
+                if !names.contains(placeholder) {
+                    return Err(DomainError::Validation(format!(
+                        "Placeholder '{{{{{}}}}}' has no matching parameter",
+                        placeholder
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the arguments against the declared parameters, applying defaults.
+    /// Every parameter gets a value; unset optional ones are `Null`.
+    pub fn bind(
+        &self,
+        args: &HashMap<String, String>,
+    ) -> DomainResult<HashMap<String, SavedSearchValue>> {
+        if let Some(unknown) = args
+            .keys()
+            .find(|name| !self.parameters.iter().any(|p| &p.name == *name))
+        {
+            return Err(DomainError::Validation(format!(
+                "Unknown parameter '{}' for saved search '{}'",
+                unknown, self.name
+            )));
+        }
+
+        let mut values = HashMap::new();
+        for param in &self.parameters {
+            let raw = args.get(&param.name).or(param.default.as_ref());
+            let value = match raw {
+                Some(raw) => param.param_type.parse_value(raw).map_err(|e| {
+                    DomainError::Validation(format!("Parameter '{}': {}", param.name, e))
+                })?,
+                None if param.required => {
+                    return Err(DomainError::Validation(format!(
+                        "Missing required parameter '{}'",
+                        param.name
+                    )));
+                }
+                None => SavedSearchValue::Null,
+            };
+            values.insert(param.name.clone(), value);
+        }
+        Ok(values)
+    }
+}
+
+fn validate_name(what: &str, name: &str) -> DomainResult<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(DomainError::Validation(format!(
+            "Invalid {} name '{}': use letters, digits, '-' and '_'",
+            what, name
+        )))
+    }
+}
+
+/// Split a template into literal text and placeholder names
+fn segments(template: &str) -> DomainResult<Vec<(&str, Option<&str>)>> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..].find("}}").ok_or_else(|| {
+            DomainError::Validation(format!("Unclosed placeholder in '{}'", template))
+        })?;
+        let name = rest[start + 2..start + end].trim();
+        segments.push((&rest[..start], Some(name)));
+        rest = &rest[start + end + 2..];
+    }
+    segments.push((rest, None));
+    Ok(segments)
+}
+
+fn placeholders(template: &str) -> DomainResult<Vec<&str>> {
+    Ok(segments(template)?
+        .into_iter()
+        .filter_map(|(_, name)| name)
+        .collect())
+}
+
+fn lookup<'a>(
+    values: &'a HashMap<String, SavedSearchValue>,
+    name: &str,
+) -> DomainResult<&'a SavedSearchValue> {
+    values
+        .get(name)
+        .ok_or_else(|| DomainError::Validation(format!("Unknown parameter '{}'", name)))
+}
+
+/// Replace placeholders with `?` and return the values to bind, in order
+pub fn render_sql(
+    sql: &str,
+    values: &HashMap<String, SavedSearchValue>,
+) -> DomainResult<(String, Vec<SavedSearchValue>)> {
+    let mut rendered = String::new();
+    let mut bound = Vec::new();
+    for (text, name) in segments(sql)? {
+        rendered.push_str(text);
+        if let Some(name) = name {
+            rendered.push('?');
+            bound.push(lookup(values, name)?.clone());
+        }
+    }
+    Ok((rendered, bound))
+}
+
+/// Replace placeholders with JQL literals
+pub fn render_jql(jql: &str, values: &HashMap<String, SavedSearchValue>) -> DomainResult<String> {
+    let mut rendered = String::new();
+    for (text, name) in segments(jql)? {
+        rendered.push_str(text);
+        if let Some(name) = name {
+            rendered.push_str(&lookup(values, name)?.to_jql());
+        }
+    }
+    Ok(rendered)
+}
+
+/// Replace placeholders with plain text; `None` when nothing is left
+fn render_text(
+    template: &str,
+    values: &HashMap<String, SavedSearchValue>,
+) -> DomainResult<Option<String>> {
+    let mut rendered = String::new();
+    for (text, name) in segments(template)? {
+        rendered.push_str(text);
+        if let Some(name) = name {
+            rendered.push_str(&lookup(values, name)?.as_text().unwrap_or_default());
+        }
+    }
+    let rendered = rendered.trim();
+    Ok((!rendered.is_empty()).then(|| rendered.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search_with(query: SavedSearchQuery) -> SavedSearch {
+        let mut search = SavedSearch::new("open-bugs", query);
+        search.parameters = vec![
+            SavedSearchParameter {
+                name: "assignee".into(),
+                param_type: SavedSearchParameterType::String,
+                description: None,
+                required: false,
+                default: None,
+            },
+            SavedSearchParameter {
+                name: "points".into(),
+                param_type: SavedSearchParameterType::Number,
+                description: None,
+                required: true,
+                default: None,
+            },
+            SavedSearchParameter {
+                name: "since".into(),
+                param_type: SavedSearchParameterType::Date,
+                description: None,
+                required: false,
+                default: Some("2024-01-01".into()),
+            },
+        ];
+        search
+    }
+
+    fn args(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_bind_checks_types_and_applies_defaults() {
+        let search = search_with(SavedSearchQuery::Sql {
+            sql: "SELECT 1".into(),
+        });
+
+        let values = search.bind(&args(&[("points", "3")])).unwrap();
+        assert_eq!(values["points"], SavedSearchValue::Number(3.0));
+        assert_eq!(values["since"], SavedSearchValue::Text("2024-01-01".into()));
+        assert_eq!(values["assignee"], SavedSearchValue::Null);
+
+        assert!(search.bind(&args(&[])).is_err());
+        assert!(search.bind(&args(&[("points", "many")])).is_err());
+        assert!(
+            search
+                .bind(&args(&[("points", "1"), ("since", "yesterday")]))
+                .is_err()
+        );
+        assert!(
+            search
+                .bind(&args(&[("points", "1"), ("other", "x")]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_undeclared_placeholders() {
+        let search = search_with(SavedSearchQuery::Jql {
+            jql: "assignee = {{ assignee }} AND created >= {{since}}".into(),
+        });
+        assert!(search.validate().is_ok());
+
+        let search = search_with(SavedSearchQuery::Jql {
+            jql: "status = {{status}}".into(),
+        });
+        assert!(search.validate().is_err());
+
+        let search = SavedSearch::new(
+            "bad name",
+            SavedSearchQuery::Sql {
+                sql: "SELECT 1".into(),
+            },
+        );
+        assert!(search.validate().is_err());
+    }
+
+    #[test]
+    fn test_render_sql_binds_placeholders_in_order() {
+        let search = search_with(SavedSearchQuery::Sql { sql: String::new() });
+        let values = search
+            .bind(&args(&[("points", "5"), ("assignee", "O'Brien")]))
+            .unwrap();
+
+        let (sql, bound) = render_sql(
+            "SELECT * FROM issues_expanded WHERE assignee = {{assignee}} AND points >= {{points}}",
+            &values,
+        )
+        .unwrap();
+        assert_eq!(
+            sql,
+            "SELECT * FROM issues_expanded WHERE assignee = ? AND points >= ?"
+        );
+        assert_eq!(
+            bound,
+            vec![
+                SavedSearchValue::Text("O'Brien".into()),
+                SavedSearchValue::Number(5.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_jql_quotes_values() {
+        let search = search_with(SavedSearchQuery::Jql { jql: String::new() });
+        let values = search
+            .bind(&args(&[("points", "2"), ("since", "2024-05-01")]))
+            .unwrap();
+
+        let jql = render_jql(
+            "assignee = {{assignee}} AND points > {{points}} AND created >= {{since}}",
+            &values,
+        )
+        .unwrap();
+        assert_eq!(
+            jql,
+            "assignee = EMPTY AND points > 2 AND created >= \"2024-05-01\""
+        );
+    }
+
+    #[test]
+    fn test_render_filter_drops_empty_values() {
+        let filter = SavedSearchFilter {
+            assignee: Some("{{assignee}}".into()),
+            status: Some("Open".into()),
+            labels: vec!["{{assignee}}".into(), "backend".into()],
+            created_from: Some("{{since}}".into()),
+            ..Default::default()
+        };
+        let search = search_with(SavedSearchQuery::Filter {
+            filter: filter.clone(),
+        });
+        let values = search.bind(&args(&[("points", "1")])).unwrap();
+
+        let rendered = filter.render(&values).unwrap();
+        assert_eq!(rendered.assignee, None);
+        assert_eq!(rendered.status.as_deref(), Some("Open"));
+        assert_eq!(rendered.labels, vec!["backend".to_string()]);
+        assert_eq!(rendered.created_from.as_deref(), Some("2024-01-01"));
+    }
+
+    #[test]
+    fn test_saved_search_round_trips_as_json() {
+        let search = search_with(SavedSearchQuery::Filter {
+            filter: SavedSearchFilter {
+                status: Some("Open".into()),
+                ..Default::default()
+            },
+        });
+        let json = serde_json::to_value(&search).unwrap();
+        assert_eq!(json["query"]["type"], "filter");
+        assert_eq!(json["query"]["filter"]["status"], "Open");
+        assert_eq!(json["parameters"][1]["type"], "number");
+
+        let parsed: SavedSearch = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, search);
+    }
+}
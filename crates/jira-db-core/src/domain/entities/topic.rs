@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Issue close to the centre of a topic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicIssue {
+    pub key: String,
+    pub summary: String,
+    /// Cosine similarity to the topic centroid
+    pub similarity: f64,
+}
+
+/// Recurring theme found by clustering a project's issue embeddings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicCluster {
+    pub cluster_id: i32,
+    pub name: String,
+    /// "summaries" when named from representative summaries, "llm" when named by Claude
+    pub named_by: String,
+    /// Number of issues assigned to the topic
+    pub size: usize,
+    /// Issues closest to the centroid, most representative first
+    pub representatives: Vec<TopicIssue>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Topic an issue was assigned to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicAssignment {
+    pub issue_id: String,
+    pub issue_key: String,
+    pub cluster_id: i32,
+    /// Cosine similarity to the topic centroid
+    pub similarity: f64,
+}
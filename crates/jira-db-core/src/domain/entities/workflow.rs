@@ -0,0 +1,363 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A JIRA workflow as used by a project's workflow scheme
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workflow {
+    pub name: String,
+    pub description: Option<String>,
+    /// Workflow scheme the project uses
+    pub scheme_name: Option<String>,
+    /// Default workflow of the scheme (used by issue types without an explicit mapping)
+    pub is_default: bool,
+    /// Issue type names mapped to this workflow
+    pub issue_types: Vec<String>,
+    /// Status names in this workflow
+    pub statuses: Vec<String>,
+    pub transitions: Vec<WorkflowTransition>,
+}
+
+/// A transition defined in a workflow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowTransition {
+    pub id: String,
+    pub name: String,
+    /// Source status (`None` for global and initial transitions)
+    pub from_status: Option<String>,
+    pub to_status: String,
+    /// "initial", "global" or "directed"
+    pub transition_type: String,
+}
+
+impl WorkflowTransition {
+    /// Global transitions can be taken from any status
+    pub fn is_global(&self) -> bool {
+        self.transition_type == "global"
+    }
+}
+
+impl Workflow {
+    /// Pick the workflow used by an issue type: an explicit mapping first, then the default
+    pub fn for_issue_type<'a>(workflows: &'a [Workflow], issue_type: &str) -> Option<&'a Workflow> {
+        workflows
+            .iter()
+            .find(|w| {
+                w.issue_types
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(issue_type))
+            })
+            .or_else(|| workflows.iter().find(|w| w.is_default))
+    }
+
+    /// Whether a status change is allowed by a directed or global transition
+    pub fn allows(&self, from: &str, to: &str) -> bool {
+        self.transitions.iter().any(|t| {
+            same_status(&t.to_status, to)
+                && (t.is_global()
+                    || t.from_status
+                        .as_deref()
+                        .is_some_and(|f| same_status(f, from)))
+        })
+    }
+
+    /// Status graph of this workflow (global transitions expand to every status)
+    pub fn graph(&self) -> WorkflowGraph {
+        let mut graph = WorkflowGraph::new();
+        for t in &self.transitions {
+            match t.from_status.as_deref() {
+                Some(from) => graph.add_transition(from, &t.to_status),
+                None if t.is_global() => {
+                    for status in &self.statuses {
+                        graph.add_transition(status, &t.to_status);
+                    }
+                }
+                None => {}
+            }
+        }
+        graph
+    }
+
+    /// Render the workflow as a Mermaid state diagram
+    pub fn to_mermaid(&self) -> String {
+        let ids: HashMap<String, String> = self
+            .statuses
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (normalize(s), format!("s{}", i)))
+            .collect();
+        let id = |status: &str| {
+            ids.get(&normalize(status))
+                .cloned()
+                .unwrap_or_else(|| "unknown".to_string())
+        };
+        let label = |s: &str| s.replace('"', "'");
+
+        let mut lines = vec!["stateDiagram-v2".to_string()];
+        for status in &self.statuses {
+            lines.push(format!("    state \"{}\" as {}", label(status), id(status)));
+        }
+        if self.transitions.iter().any(|t| t.is_global()) {
+            lines.push("    state \"Any status\" as any".to_string());
+        }
+
+        for t in &self.transitions {
+            let from = match (&t.from_status, t.transition_type.as_str()) {
+                (Some(from), _) => id(from),
+                (None, "global") => "any".to_string(),
+                (None, _) => "[*]".to_string(),
+            };
+            lines.push(format!(
+                "    {} --> {} : {}",
+                from,
+                id(&t.to_status),
+                label(&t.name).replace(':', " ")
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// A status change in the change history that the workflow does not define
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowViolation {
+    pub issue_key: String,
+    pub issue_type: String,
+    pub workflow: String,
+    pub from_status: String,
+    pub to_status: String,
+    pub changed_at: DateTime<Utc>,
+    pub author: Option<String>,
+}
+
+/// Directed graph of status transitions (status name -> reachable status names)
+///
+/// Status names are matched case-insensitively; the first spelling seen is kept for display.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowGraph {
+    edges: HashMap<String, BTreeSet<String>>,
+    names: HashMap<String, String>,
+}
+
+impl WorkflowGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a graph from (from, to) status pairs
+    pub fn from_transitions<I, S>(transitions: I) -> Self
+    where
+        I: IntoIterator<Item = (S, S)>,
+        S: AsRef<str>,
+    {
+        let mut graph = Self::new();
+        for (from, to) in transitions {
+            graph.add_transition(from.as_ref(), to.as_ref());
+        }
+        graph
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn add_transition(&mut self, from: &str, to: &str) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        if from != to {
+            self.edges.entry(from).or_default().insert(to);
+        }
+    }
+
+    /// Replace the outgoing transitions of a status with the ones actually available
+    pub fn set_transitions_from<'a, I>(&mut self, from: &str, to: I)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let from_key = self.intern(from);
+        self.edges.remove(&from_key);
+        for status in to {
+            self.add_transition(from, status);
+        }
+    }
+
+    /// Shortest path from one status to another (both included), using BFS
+    pub fn shortest_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let start = normalize(from);
+        let goal = normalize(to);
+        if start == goal {
+            return Some(vec![self.display(&start)]);
+        }
+
+        let mut previous: HashMap<&str, &str> = HashMap::new();
+        let mut visited: HashSet<&str> = HashSet::from([start.as_str()]);
+        let mut queue = VecDeque::from([start.as_str()]);
+
+        while let Some(current) = queue.pop_front() {
+            for next in self.edges.get(current).into_iter().flatten() {
+                if !visited.insert(next.as_str()) {
+                    continue;
+                }
+                previous.insert(next.as_str(), current);
+
+                if *next == goal {
+                    let mut path = vec![self.display(next)];
+                    let mut node = next.as_str();
+                    while let Some(prev) = previous.get(node) {
+                        path.push(self.display(prev));
+                        node = prev;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+
+                queue.push_back(next.as_str());
+            }
+        }
+
+        None
+    }
+
+    /// Add all transitions of another graph
+    pub fn extend(&mut self, other: &WorkflowGraph) {
+        for (from, targets) in &other.edges {
+            for to in targets {
+                self.add_transition(&other.display(from), &other.display(to));
+            }
+        }
+    }
+
+    fn intern(&mut self, status: &str) -> String {
+        let key = normalize(status);
+        self.names
+            .entry(key.clone())
+            .or_insert_with(|| status.trim().to_string());
+        key
+    }
+
+    fn display(&self, key: &str) -> String {
+        self.names
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+}
+
+/// Compare two status names the way JIRA users type them
+pub fn same_status(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+fn normalize(status: &str) -> String {
+    status.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from: Option<&str>, to: &str, transition_type: &str) -> WorkflowTransition {
+        WorkflowTransition {
+            id: "1".to_string(),
+            name: format!("To {}", to),
+            from_status: from.map(String::from),
+            to_status: to.to_string(),
+            transition_type: transition_type.to_string(),
+        }
+    }
+
+    fn workflow() -> Workflow {
+        Workflow {
+            name: "Software Workflow".to_string(),
+            description: None,
+            scheme_name: None,
+            is_default: false,
+            issue_types: vec!["Bug".to_string()],
+            statuses: vec![
+                "To Do".to_string(),
+                "In Progress".to_string(),
+                "Done".to_string(),
+            ],
+            transitions: vec![
+                transition(None, "To Do", "initial"),
+                transition(Some("To Do"), "In Progress", "directed"),
+                transition(Some("In Progress"), "Done", "directed"),
+                transition(None, "To Do", "global"),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_workflow_allows() {
+        let workflow = workflow();
+        assert!(workflow.allows("to do", "In Progress"));
+        assert!(workflow.allows("Done", "To Do"));
+        assert!(!workflow.allows("To Do", "Done"));
+    }
+
+    #[test]
+    fn test_workflow_graph_expands_global_transitions() {
+        let graph = workflow().graph();
+        assert_eq!(
+            graph.shortest_path("Done", "In Progress"),
+            Some(vec![
+                "Done".to_string(),
+                "To Do".to_string(),
+                "In Progress".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_workflow_to_mermaid() {
+        let mermaid = workflow().to_mermaid();
+        assert!(mermaid.starts_with("stateDiagram-v2"));
+        assert!(mermaid.contains("state \"In Progress\" as s1"));
+        assert!(mermaid.contains("[*] --> s0 : To To Do"));
+        assert!(mermaid.contains("s0 --> s1 : To In Progress"));
+        assert!(mermaid.contains("any --> s0"));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let graph = WorkflowGraph::from_transitions([
+            ("To Do", "In Progress"),
+            ("In Progress", "In Review"),
+            ("In Review", "Done"),
+            ("In Progress", "Done"),
+            ("Done", "To Do"),
+        ]);
+
+        assert_eq!(
+            graph.shortest_path("to do", "DONE"),
+            Some(vec![
+                "To Do".to_string(),
+                "In Progress".to_string(),
+                "Done".to_string()
+            ])
+        );
+        assert_eq!(
+            graph.shortest_path("Done", "Done"),
+            Some(vec!["Done".to_string()])
+        );
+        assert_eq!(graph.shortest_path("Done", "Blocked"), None);
+    }
+
+    #[test]
+    fn test_set_transitions_from_replaces_edges() {
+        let mut graph = WorkflowGraph::from_transitions([("To Do", "Done")]);
+        graph.set_transitions_from("To Do", ["In Progress"]);
+        graph.add_transition("In Progress", "Done");
+
+        assert_eq!(
+            graph.shortest_path("To Do", "Done"),
+            Some(vec![
+                "To Do".to_string(),
+                "In Progress".to_string(),
+                "Done".to_string()
+            ])
+        );
+    }
+}
@@ -7,6 +7,8 @@ use crate::domain::error::DomainResult;
 pub trait ChangeHistoryRepository: Send + Sync {
     fn batch_insert(&self, items: &[ChangeHistoryItem]) -> DomainResult<()>;
     fn delete_by_issue_id(&self, issue_id: &str) -> DomainResult<()>;
+    /// Delete changelog-sourced rows only, keeping rows derived from payload diffs
+    fn delete_changelog_by_issue_id(&self, issue_id: &str) -> DomainResult<()>;
     fn find_by_issue_key(&self, issue_key: &str) -> DomainResult<Vec<ChangeHistoryItem>>;
     fn find_by_issue_key_and_field(
         &self,
@@ -14,4 +16,8 @@ pub trait ChangeHistoryRepository: Send + Sync {
         field_filter: Option<&str>,
     ) -> DomainResult<Vec<ChangeHistoryItem>>;
     fn count_by_issue_key(&self, issue_key: &str) -> DomainResult<usize>;
+    /// All changes of one field across the project, ordered by change time
+    fn find_by_field(&self, field: &str) -> DomainResult<Vec<ChangeHistoryItem>>;
+    /// Distinct (from, to) status pairs observed in the change history
+    fn find_status_transitions(&self) -> DomainResult<Vec<(String, String)>>;
 }
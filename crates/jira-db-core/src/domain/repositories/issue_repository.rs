@@ -1,6 +1,9 @@
 use crate::domain::entities::Issue;
-use crate::domain::error::DomainResult;
+use crate::domain::error::{DomainError, DomainResult};
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::str::FromStr;
 
 /// Search parameters for issues
 #[derive(Debug, Default, Clone)]
@@ -8,14 +11,373 @@ pub struct SearchParams {
     pub query: Option<String>,
     pub project_key: Option<String>,
     pub status: Option<String>,
+    /// Status category: `new`/`To Do`, `indeterminate`/`In Progress` or `done`/`Done`
+    pub status_category: Option<String>,
     pub assignee: Option<String>,
     pub issue_type: Option<String>,
     pub priority: Option<String>,
     pub team: Option<String>,
+    /// Resolution name, or `Unresolved` for issues without one
+    pub resolution: Option<String>,
+    /// Labels to match, combined according to `labels_match`
+    pub labels: Vec<String>,
+    pub labels_match: LabelMatch,
+    /// Issues with any of these components
+    pub components: Vec<String>,
+    /// Issues with any of these fix versions
+    pub fix_versions: Vec<String>,
+    pub sprint: Option<String>,
+    /// Parent issue or epic key
+    pub parent_key: Option<String>,
+    /// Restrict the search to these issue keys
+    pub keys: Vec<String>,
+    pub created: DateRange,
+    pub updated: DateRange,
+    pub due: DateRange,
+    pub resolved: DateRange,
+    /// Sort keys in priority order; empty sorts by relevance
+    pub sort: Vec<SearchSort>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 }
 
+impl SearchParams {
+    /// Whether results are ordered by relevance rather than by a field
+    pub fn sorts_by_relevance(&self) -> bool {
+        self.sort
+            .first()
+            .is_none_or(|sort| sort.field == SortField::Relevance)
+    }
+}
+
+/// How multiple label filters combine
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LabelMatch {
+    /// Issue has at least one of the labels
+    #[default]
+    Any,
+    /// Issue has every label
+    All,
+}
+
+impl FromStr for LabelMatch {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "any" => Ok(Self::Any),
+            "all" => Ok(Self::All),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid label match '{}': expected 'any' or 'all'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Date range filter: `from <= value < to`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DateRange {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl DateRange {
+    /// Parse bounds given as `YYYY-MM-DD` or RFC 3339. A date-only upper bound
+    /// includes the whole day.
+    pub fn parse(from: Option<&str>, to: Option<&str>) -> DomainResult<Self> {
+        Ok(Self {
+            from: from.map(|value| parse_bound(value, false)).transpose()?,
+            to: to.map(|value| parse_bound(value, true)).transpose()?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+
+    /// Whether `value` is within the range. A missing value is only within an empty range.
+    pub fn contains(&self, value: Option<DateTime<Utc>>) -> bool {
+        match value {
+            Some(value) => {
+                self.from.is_none_or(|from| from <= value) && self.to.is_none_or(|to| value < to)
+            }
+            None => self.is_empty(),
+        }
+    }
+}
+
+fn parse_bound(value: &str, upper: bool) -> DomainResult<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        DomainError::Validation(format!(
+            "Invalid date '{}': expected YYYY-MM-DD or RFC 3339",
+            value
+        ))
+    })?;
+    let date = if upper {
+        date.checked_add_days(Days::new(1)).unwrap_or(date)
+    } else {
+        date
+    };
+    Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+}
+
+/// Field an issue search can be ordered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+    /// Text relevance (BM25), newest first when there is no text query
+    Relevance,
+    /// Project key, then issue number
+    Key,
+    Summary,
+    Created,
+    Updated,
+    Due,
+    Resolved,
+}
+
+impl FromStr for SortField {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "relevance" | "score" => Ok(Self::Relevance),
+            "key" => Ok(Self::Key),
+            "summary" => Ok(Self::Summary),
+            "created" => Ok(Self::Created),
+            "updated" => Ok(Self::Updated),
+            "due" | "duedate" => Ok(Self::Due),
+            "resolved" | "resolutiondate" => Ok(Self::Resolved),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid sort field '{}': expected relevance, key, summary, created, updated, due or resolved",
+                s
+            ))),
+        }
+    }
+}
+
+/// One sort key with its direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchSort {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+impl SearchSort {
+    /// Parse a comma-separated sort spec such as `updated:desc,key`.
+    /// Dates and relevance default to descending, other fields to ascending.
+    pub fn parse_list(spec: &str) -> DomainResult<Vec<Self>> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+
+    /// Order two issues by this key the way the database does, with missing
+    /// values last. Relevance compares equal since it is not an issue field;
+    /// resolution dates are read from `raw_json`, which searches sorted by
+    /// `resolved` return.
+    pub fn compare(&self, a: &Issue, b: &Issue) -> Ordering {
+        fn nulls_last<T: Ord>(a: Option<T>, b: Option<T>, descending: bool) -> Ordering {
+            match (a, b) {
+                (Some(a), Some(b)) if descending => b.cmp(&a),
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }
+
+        let descending = self.descending;
+        match self.field {
+            SortField::Relevance => Ordering::Equal,
+            SortField::Key => {
+                nulls_last(Some(key_order(&a.key)), Some(key_order(&b.key)), descending)
+            }
+            SortField::Summary => nulls_last(Some(&a.summary), Some(&b.summary), descending),
+            SortField::Created => nulls_last(a.created_date, b.created_date, descending),
+            SortField::Updated => nulls_last(a.updated_date, b.updated_date, descending),
+            SortField::Due => nulls_last(a.due_date, b.due_date, descending),
+            SortField::Resolved => nulls_last(resolution_date(a), resolution_date(b), descending),
+        }
+    }
+}
+
+/// Project key, then issue number, so PROJ-2 sorts before PROJ-10
+pub(crate) fn key_order(key: &str) -> (&str, u64) {
+    match key.rsplit_once('-') {
+        Some((project, number)) => (project, number.parse().unwrap_or(0)),
+        None => (key, 0),
+    }
+}
+
+fn resolution_date(issue: &Issue) -> Option<DateTime<Utc>> {
+    let raw: serde_json::Value = serde_json::from_str(issue.raw_json.as_deref()?).ok()?;
+    let value = raw.get("fields")?.get("resolutiondate")?.as_str()?;
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+impl FromStr for SearchSort {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (field, direction) = match s.split_once(':') {
+            Some((field, direction)) => (field.trim(), Some(direction.trim())),
+            None => (s.trim(), None),
+        };
+        let field: SortField = field.parse()?;
+        let descending = match direction.map(str::to_lowercase).as_deref() {
+            None => !matches!(field, SortField::Key | SortField::Summary),
+            Some("asc") => false,
+            Some("desc") => true,
+            Some(other) => {
+                return Err(DomainError::Validation(format!(
+                    "Invalid sort direction '{}': expected 'asc' or 'desc'",
+                    other
+                )));
+            }
+        };
+        Ok(Self { field, descending })
+    }
+}
+
+/// Issue returned by a text search, with its relevance
+#[derive(Debug, Clone)]
+pub struct IssueSearchHit {
+    pub issue: Issue,
+    /// BM25 relevance score (None when no full-text index was used)
+    pub score: Option<f64>,
+    /// Excerpt around the first match, with matched terms wrapped in `**`
+    pub snippet: Option<String>,
+}
+
+/// Issue field that search results can be counted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FacetField {
+    Status,
+    IssueType,
+    Priority,
+    Assignee,
+    Labels,
+    Components,
+    FixVersions,
+}
+
+impl FacetField {
+    /// Every facet, in display order
+    pub const ALL: [FacetField; 7] = [
+        Self::Status,
+        Self::IssueType,
+        Self::Priority,
+        Self::Assignee,
+        Self::Labels,
+        Self::Components,
+        Self::FixVersions,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Status => "status",
+            Self::IssueType => "issue_type",
+            Self::Priority => "priority",
+            Self::Assignee => "assignee",
+            Self::Labels => "labels",
+            Self::Components => "components",
+            Self::FixVersions => "fix_versions",
+        }
+    }
+
+    /// Whether the field holds a list, so one issue counts towards several values
+    pub fn is_list(&self) -> bool {
+        matches!(self, Self::Labels | Self::Components | Self::FixVersions)
+    }
+}
+
+impl FromStr for FacetField {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "status" => Ok(Self::Status),
+            "issue_type" | "issuetype" | "type" => Ok(Self::IssueType),
+            "priority" => Ok(Self::Priority),
+            "assignee" => Ok(Self::Assignee),
+            "labels" | "label" => Ok(Self::Labels),
+            "components" | "component" => Ok(Self::Components),
+            "fix_versions" | "fixversions" | "fix_version" => Ok(Self::FixVersions),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid facet '{}': expected status, issue_type, priority, assignee, labels, components or fix_versions",
+                s
+            ))),
+        }
+    }
+}
+
+/// Number of matching issues with one field value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FacetValue {
+    /// Field value, None for issues without one (e.g. unassigned)
+    pub value: Option<String>,
+    pub count: usize,
+}
+
+/// Counts of one field over every issue matching a search, largest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchFacet {
+    pub field: FacetField,
+    pub values: Vec<FacetValue>,
+}
+
+impl SearchFacet {
+    /// Number of values shown per facet unless the caller asks otherwise
+    pub const DEFAULT_SIZE: usize = 10;
+
+    /// Add the counts of another result set, e.g. from another project database
+    pub fn merge(&mut self, other: SearchFacet) {
+        for value in other.values {
+            match self.values.iter_mut().find(|v| v.value == value.value) {
+                Some(existing) => existing.count += value.count,
+                None => self.values.push(value),
+            }
+        }
+        self.sort();
+    }
+
+    /// Keep the `size` most frequent values
+    pub fn truncate(&mut self, size: usize) {
+        self.sort();
+        self.values.truncate(size);
+    }
+
+    /// Largest count first, then by value with missing values last
+    fn sort(&mut self) {
+        self.values.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| (a.value.is_none(), &a.value).cmp(&(b.value.is_none(), &b.value)))
+        });
+    }
+}
+
+/// Merge facet counts field by field
+pub fn merge_facets(facets: &mut Vec<SearchFacet>, other: Vec<SearchFacet>) {
+    for facet in other {
+        match facets.iter_mut().find(|f| f.field == facet.field) {
+            Some(existing) => existing.merge(facet),
+            None => facets.push(facet),
+        }
+    }
+}
+
 /// Result of paginated issue fetch
 #[derive(Debug, Clone)]
 pub struct IssuePage {
@@ -35,6 +397,70 @@ pub trait IssueRepository: Send + Sync {
     fn find_by_project(&self, project_id: &str) -> DomainResult<Vec<Issue>>;
     fn count_by_project(&self, project_id: &str) -> DomainResult<usize>;
     fn search(&self, params: &SearchParams) -> DomainResult<Vec<Issue>>;
+
+    /// Search issues ranked by relevance, with highlighted snippets
+    /// Implementations without a full-text index return `search` results unranked
+    fn search_ranked(&self, params: &SearchParams) -> DomainResult<Vec<IssueSearchHit>> {
+        Ok(self
+            .search(params)?
+            .into_iter()
+            .map(|issue| IssueSearchHit {
+                issue,
+                score: None,
+                snippet: None,
+            })
+            .collect())
+    }
+
+    /// Count every issue matching the search, ignoring `limit` and `offset`
+    fn count_matching(&self, params: &SearchParams) -> DomainResult<usize> {
+        let params = SearchParams {
+            limit: None,
+            offset: None,
+            ..params.clone()
+        };
+        Ok(self.search(&params)?.len())
+    }
+
+    /// Count every issue matching the search by each of `fields`, ignoring
+    /// `limit`, `offset` and `sort`
+    fn facet_counts(
+        &self,
+        params: &SearchParams,
+        fields: &[FacetField],
+    ) -> DomainResult<Vec<SearchFacet>> {
+        if fields.is_empty() {
+            return Ok(Vec::new());
+        }
+        let params = SearchParams {
+            limit: None,
+            offset: None,
+            ..params.clone()
+        };
+        let issues = self.search(&params)?;
+
+        Ok(fields
+            .iter()
+            .map(|&field| {
+                let mut counts: HashMap<Option<String>, usize> = HashMap::new();
+                for issue in &issues {
+                    for value in facet_values(issue, field) {
+                        *counts.entry(value).or_default() += 1;
+                    }
+                }
+                let mut facet = SearchFacet {
+                    field,
+                    values: counts
+                        .into_iter()
+                        .map(|(value, count)| FacetValue { value, count })
+                        .collect(),
+                };
+                facet.sort();
+                facet
+            })
+            .collect())
+    }
+
     /// Mark issues as deleted if they are not in the given list of keys (soft delete)
     /// Also unmarks previously deleted issues if they appear in the keys list (restore)
     /// Returns the number of issues marked as deleted
@@ -60,4 +486,35 @@ pub trait IssueRepository: Send + Sync {
         after_issue_id: &str,
         limit: usize,
     ) -> DomainResult<IssuePage>;
+
+    /// Find a single issue by key (including soft-deleted issues)
+    fn find_by_key(&self, issue_key: &str) -> DomainResult<Option<Issue>>;
+
+    /// Overwrite the local status of an issue (optimistic offline update)
+    fn update_status(&self, issue_key: &str, status: &str) -> DomainResult<()>;
+
+    /// Overwrite the local due date of an issue (optimistic offline update)
+    fn update_due_date(&self, issue_key: &str, due_date: Option<DateTime<Utc>>)
+    -> DomainResult<()>;
+
+    /// Overwrite the local summary, priority, labels, components and fix versions
+    /// of `issue.key` with the values of `issue` (optimistic offline update)
+    fn update_editable_fields(&self, issue: &Issue) -> DomainResult<()>;
+}
+
+/// Values an issue contributes to a facet; list fields contribute one per element
+fn facet_values(issue: &Issue, field: FacetField) -> Vec<Option<String>> {
+    match field {
+        FacetField::Status => vec![issue.status.clone()],
+        FacetField::IssueType => vec![issue.issue_type.clone()],
+        FacetField::Priority => vec![issue.priority.clone()],
+        FacetField::Assignee => vec![issue.assignee.clone()],
+        FacetField::Labels => list_values(&issue.labels),
+        FacetField::Components => list_values(&issue.components),
+        FacetField::FixVersions => list_values(&issue.fix_versions),
+    }
+}
+
+fn list_values(values: &Option<Vec<String>>) -> Vec<Option<String>> {
+    values.iter().flatten().cloned().map(Some).collect()
 }
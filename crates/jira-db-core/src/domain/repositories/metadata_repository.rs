@@ -1,4 +1,6 @@
-use crate::domain::entities::{Component, FixVersion, IssueType, Label, Priority, Status};
+use crate::domain::entities::{
+    Component, FieldOption, FixVersion, IssueType, Label, Priority, Status, Workflow,
+};
 use crate::domain::error::DomainResult;
 
 /// Repository trait for metadata entities
@@ -31,4 +33,12 @@ pub trait MetadataRepository: Send + Sync {
         fix_versions: &[FixVersion],
     ) -> DomainResult<()>;
     fn find_fix_versions_by_project(&self, project_id: &str) -> DomainResult<Vec<FixVersion>>;
+
+    // Workflow operations (replaces all workflows and transitions of the project)
+    fn replace_workflows(&self, project_id: &str, workflows: &[Workflow]) -> DomainResult<()>;
+    fn find_workflows_by_project(&self, project_id: &str) -> DomainResult<Vec<Workflow>>;
+
+    // Field option operations (replaces all options of the project)
+    fn replace_field_options(&self, project_id: &str, options: &[FieldOption]) -> DomainResult<()>;
+    fn find_field_options_by_project(&self, project_id: &str) -> DomainResult<Vec<FieldOption>>;
 }
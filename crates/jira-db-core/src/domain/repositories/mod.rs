@@ -2,12 +2,20 @@ mod change_history_repository;
 mod issue_repository;
 mod issue_snapshot_repository;
 mod metadata_repository;
+mod pending_operation_repository;
 mod project_repository;
 mod sync_history_repository;
+mod topic_repository;
 
 pub use change_history_repository::ChangeHistoryRepository;
-pub use issue_repository::{IssuePage, IssueRepository, SearchParams};
+pub(crate) use issue_repository::key_order;
+pub use issue_repository::{
+    DateRange, FacetField, FacetValue, IssuePage, IssueRepository, IssueSearchHit, LabelMatch,
+    SearchFacet, SearchParams, SearchSort, SortField, merge_facets,
+};
 pub use issue_snapshot_repository::IssueSnapshotRepository;
 pub use metadata_repository::MetadataRepository;
+pub use pending_operation_repository::PendingOperationRepository;
 pub use project_repository::ProjectRepository;
 pub use sync_history_repository::SyncHistoryRepository;
+pub use topic_repository::TopicRepository;
@@ -0,0 +1,35 @@
+use crate::domain::entities::{PendingOperation, PendingOperationKind, PendingOperationStatus};
+use crate::domain::error::DomainResult;
+use chrono::{DateTime, Utc};
+
+/// Repository trait for the offline write queue
+/// Infrastructure layer will implement this trait
+pub trait PendingOperationRepository: Send + Sync {
+    /// Queue a new operation and return its ID
+    fn insert(
+        &self,
+        issue_key: &str,
+        kind: &PendingOperationKind,
+        base_updated_at: Option<DateTime<Utc>>,
+    ) -> DomainResult<i64>;
+
+    fn find_by_id(&self, id: i64) -> DomainResult<Option<PendingOperation>>;
+
+    /// Find operations in the given status (all statuses if `None`), oldest first
+    fn find_by_status(
+        &self,
+        status: Option<PendingOperationStatus>,
+    ) -> DomainResult<Vec<PendingOperation>>;
+
+    fn update_status(
+        &self,
+        id: i64,
+        status: PendingOperationStatus,
+        error_message: Option<&str>,
+        applied_at: Option<DateTime<Utc>>,
+    ) -> DomainResult<()>;
+
+    /// Put a rejected operation back in the queue.
+    /// Clears the base timestamp so the next push does not re-detect the same conflict.
+    fn requeue(&self, id: i64) -> DomainResult<()>;
+}
@@ -0,0 +1,20 @@
+use crate::domain::entities::{TopicAssignment, TopicCluster};
+use crate::domain::error::DomainResult;
+
+/// Repository trait for the topic clusters of a project
+/// Infrastructure layer will implement this trait
+pub trait TopicRepository: Send + Sync {
+    /// Replace all topic clusters and assignments of the project
+    fn replace(
+        &self,
+        project_id: &str,
+        clusters: &[TopicCluster],
+        assignments: &[TopicAssignment],
+    ) -> DomainResult<()>;
+
+    /// Topic clusters of the project, largest first
+    fn find_clusters(&self, project_id: &str) -> DomainResult<Vec<TopicCluster>>;
+
+    /// Topic assignments of the project, by issue key
+    fn find_assignments(&self, project_id: &str) -> DomainResult<Vec<TopicAssignment>>;
+}
@@ -1,6 +1,7 @@
 mod settings;
 
 pub use settings::{
-    DatabaseConfig, EmbeddingsConfig, JiraConfig, JiraEndpoint, LogConfig, ProjectConfig, Settings,
-    SnapshotCheckpoint, SyncCheckpoint, SyncSettings,
+    BulkEditCheckpoint, BulkEditFailure, DatabaseConfig, EmbeddingsConfig, JiraConfig,
+    JiraEndpoint, LogConfig, ProjectConfig, SearchSettings, Settings, SnapshotCheckpoint,
+    SyncCheckpoint, SyncSettings,
 };
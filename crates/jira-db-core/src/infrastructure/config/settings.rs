@@ -1,9 +1,29 @@
+use crate::domain::entities::{SavedSearch, SavedSearchQuery};
 use crate::domain::error::{DomainError, DomainResult};
+use crate::infrastructure::database::DEFAULT_READ_POOL_SIZE;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Where the SQL screen of the desktop and web apps kept its queries before they
+/// became saved searches
+pub const LEGACY_SAVED_QUERIES_FILE: &str = "./data/saved_queries.json";
+
+/// An entry of the legacy saved queries file
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LegacySavedQuery {
+    name: String,
+    query: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
     /// Legacy single JIRA config (for backward compatibility)
@@ -25,6 +45,12 @@ pub struct Settings {
     /// Sync configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sync: Option<SyncSettings>,
+    /// Search configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<SearchSettings>,
+    /// Saved searches shared by the CLI, MCP server and web API
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub saved_searches: Vec<SavedSearch>,
     /// Debug mode enables JIRA test data creation features and verbose logging
     #[serde(default)]
     pub debug_mode: bool,
@@ -43,6 +69,10 @@ pub struct SyncSettings {
     /// Default: 5 minutes
     #[serde(default = "default_incremental_sync_margin_minutes")]
     pub incremental_sync_margin_minutes: u32,
+    /// Custom fields (by id or name) added to the full-text search index
+    /// alongside summary, description and comments
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_fields: Vec<String>,
 }
 
 fn default_incremental_sync_enabled() -> bool {
@@ -58,6 +88,43 @@ impl Default for SyncSettings {
         Self {
             incremental_sync_enabled: default_incremental_sync_enabled(),
             incremental_sync_margin_minutes: default_incremental_sync_margin_minutes(),
+            search_fields: Vec::new(),
+        }
+    }
+}
+
+/// Configuration for hybrid (keyword + semantic) search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchSettings {
+    /// Weight of the keyword (BM25) ranking in reciprocal rank fusion
+    /// Default: 1.0
+    #[serde(default = "default_search_weight")]
+    pub lexical_weight: f64,
+    /// Weight of the embedding similarity ranking in reciprocal rank fusion
+    /// Default: 1.0
+    #[serde(default = "default_search_weight")]
+    pub semantic_weight: f64,
+    /// Rank offset `k` in `weight / (k + rank)`; larger values flatten the
+    /// advantage of the top ranks
+    /// Default: 60
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: f64,
+}
+
+fn default_search_weight() -> f64 {
+    1.0
+}
+
+fn default_rrf_k() -> f64 {
+    60.0
+}
+
+impl Default for SearchSettings {
+    fn default() -> Self {
+        Self {
+            lexical_weight: default_search_weight(),
+            semantic_weight: default_search_weight(),
+            rrf_k: default_rrf_k(),
         }
     }
 }
@@ -151,6 +218,32 @@ pub struct SnapshotCheckpoint {
     pub snapshots_generated: usize,
 }
 
+/// Checkpoint for resumable bulk edits
+/// Stored in settings.json so an interrupted bulk edit can continue where it stopped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkEditCheckpoint {
+    /// SQL predicate used to select the issues
+    pub predicate: String,
+    /// JIRA edit payload being applied to every issue
+    pub payload: serde_json::Value,
+    /// Issue keys selected for editing, in processing order
+    pub issue_keys: Vec<String>,
+    /// Number of issues processed so far
+    pub items_processed: usize,
+    /// Issues that failed, with the error returned by JIRA
+    #[serde(default)]
+    pub failures: Vec<BulkEditFailure>,
+    /// When the bulk edit was started
+    pub started_at: DateTime<Utc>,
+}
+
+/// A single failed issue in a bulk edit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkEditFailure {
+    pub issue_key: String,
+    pub error: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectConfig {
     pub id: String,
@@ -168,6 +261,9 @@ pub struct ProjectConfig {
     /// Checkpoint for resuming interrupted snapshot generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snapshot_checkpoint: Option<SnapshotCheckpoint>,
+    /// Checkpoint for resuming an interrupted bulk edit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bulk_edit_checkpoint: Option<BulkEditCheckpoint>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -179,12 +275,23 @@ pub struct DatabaseConfig {
     /// Each project will have its own database at {database_dir}/{project_key}.duckdb
     #[serde(default = "default_database_dir")]
     pub database_dir: PathBuf,
+    /// Reader connections per database for concurrent queries (0 shares the writer)
+    #[serde(default = "default_read_pool_size")]
+    pub read_pool_size: usize,
+    /// Publish read-only copies of each project database after sync (`data.read.duckdb`,
+    /// `raw.read.duckdb`), which servers in other processes read instead of the locked files
+    #[serde(default)]
+    pub read_replicas: bool,
 }
 
 fn default_database_dir() -> PathBuf {
     PathBuf::from("./data")
 }
 
+fn default_read_pool_size() -> usize {
+    DEFAULT_READ_POOL_SIZE
+}
+
 /// Configuration for embedding generation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmbeddingsConfig {
@@ -273,10 +380,14 @@ impl Settings {
             database: DatabaseConfig {
                 path: None,
                 database_dir,
+                read_pool_size: DEFAULT_READ_POOL_SIZE,
+                read_replicas: false,
             },
             embeddings: None,
             log: None,
             sync: None,
+            search: None,
+            saved_searches: Vec::new(),
             debug_mode: false,
         }
     }
@@ -295,10 +406,14 @@ impl Settings {
             database: DatabaseConfig {
                 path: None,
                 database_dir,
+                read_pool_size: DEFAULT_READ_POOL_SIZE,
+                read_replicas: false,
             },
             embeddings: None,
             log: None,
             sync: None,
+            search: None,
+            saved_searches: Vec::new(),
             debug_mode: false,
         }
     }
@@ -384,10 +499,14 @@ impl Settings {
             database: DatabaseConfig {
                 path: None,
                 database_dir: PathBuf::from("./data"),
+                read_pool_size: DEFAULT_READ_POOL_SIZE,
+                read_replicas: false,
             },
             embeddings: None,
             log: None,
             sync: None,
+            search: None,
+            saved_searches: Vec::new(),
             debug_mode: false,
         };
 
@@ -452,6 +571,32 @@ impl Settings {
         self.get_active_endpoint().map(|e| e.to_jira_config())
     }
 
+    /// Get the endpoint name a project syncs against
+    /// Returns the project-specific endpoint name if set, otherwise the active endpoint name
+    pub fn endpoint_name_for_project(&self, project: &ProjectConfig) -> Option<String> {
+        project
+            .endpoint
+            .clone()
+            .or_else(|| self.get_active_endpoint().map(|e| e.name.clone()))
+    }
+
+    /// Group projects by the endpoint they sync against
+    /// Groups keep the order in which each endpoint first appears in `projects`
+    pub fn group_projects_by_endpoint<'a>(
+        &self,
+        projects: &[&'a ProjectConfig],
+    ) -> Vec<(Option<String>, Vec<&'a ProjectConfig>)> {
+        let mut groups: Vec<(Option<String>, Vec<&'a ProjectConfig>)> = Vec::new();
+        for project in projects {
+            let endpoint_name = self.endpoint_name_for_project(project);
+            match groups.iter_mut().find(|(name, _)| *name == endpoint_name) {
+                Some((_, group)) => group.push(project),
+                None => groups.push((endpoint_name, vec![project])),
+            }
+        }
+        groups
+    }
+
     /// Get the current JIRA config (for backward compatibility)
     /// Prefers active endpoint, falls back to legacy jira config
     pub fn get_jira_config(&self) -> Option<JiraConfig> {
@@ -496,6 +641,11 @@ impl Settings {
         self.sync.clone().unwrap_or_default()
     }
 
+    /// Get the search configuration (returns default if not set)
+    pub fn get_search_settings(&self) -> SearchSettings {
+        self.search.clone().unwrap_or_default()
+    }
+
     /// Get the log directory path
     pub fn get_log_dir(&self) -> PathBuf {
         self.log
@@ -611,6 +761,95 @@ impl Settings {
         }
     }
 
+    pub fn find_saved_search(&self, name: &str) -> Option<&SavedSearch> {
+        self.saved_searches.iter().find(|s| s.name == name)
+    }
+
+    /// Add or replace a saved search by name, keeping the original creation time
+    pub fn upsert_saved_search(&mut self, mut search: SavedSearch) -> DomainResult<()> {
+        search.validate()?;
+        match self
+            .saved_searches
+            .iter_mut()
+            .find(|s| s.name == search.name)
+        {
+            Some(existing) => {
+                search.created_at = existing.created_at;
+                *existing = search;
+            }
+            None => self.saved_searches.push(search),
+        }
+        Ok(())
+    }
+
+    pub fn remove_saved_search(&mut self, name: &str) -> bool {
+        let before = self.saved_searches.len();
+        self.saved_searches.retain(|s| s.name != name);
+        self.saved_searches.len() != before
+    }
+
+    /// Add the queries of a legacy saved queries file as SQL saved searches.
+    ///
+    /// Titles are turned into saved search names, with a `-2`, `-3`, ... suffix when the
+    /// name is taken, so existing searches are never replaced. Queries that are not valid
+    /// saved searches are skipped with a warning. Returns how many were added.
+    pub fn import_saved_queries<P: AsRef<Path>>(&mut self, path: P) -> DomainResult<usize> {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| DomainError::Repository(format!("Failed to read saved queries: {}", e)))?;
+        let queries: Vec<LegacySavedQuery> = serde_json::from_str(&content).map_err(|e| {
+            DomainError::Repository(format!("Failed to parse saved queries: {}", e))
+        })?;
+
+        let mut imported = 0;
+        for query in queries {
+            let base = SavedSearch::name_from_title(&query.name);
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while self.find_saved_search(&name).is_some() {
+                name = format!("{}-{}", base, suffix);
+                suffix += 1;
+            }
+
+            let mut search = SavedSearch::new(&name, SavedSearchQuery::Sql { sql: query.query });
+            search.description = query
+                .description
+                .or_else(|| (name != query.name).then(|| query.name.clone()));
+            if let Some(created_at) = query.created_at {
+                search.created_at = created_at;
+            }
+            if let Some(updated_at) = query.updated_at {
+                search.updated_at = updated_at;
+            }
+            match self.upsert_saved_search(search) {
+                Ok(()) => imported += 1,
+                Err(e) => log::warn!("Skipping saved query '{}': {}", query.name, e),
+            }
+        }
+        Ok(imported)
+    }
+
+    /// Move the queries of [`LEGACY_SAVED_QUERIES_FILE`] into saved searches, once.
+    ///
+    /// The settings are saved to `settings_path` and the legacy file is renamed to
+    /// `saved_queries.json.migrated`, so later starts do not import it again.
+    pub fn migrate_legacy_saved_queries<P: AsRef<Path>>(
+        &mut self,
+        settings_path: P,
+    ) -> DomainResult<usize> {
+        let legacy = Path::new(LEGACY_SAVED_QUERIES_FILE);
+        if !legacy.exists() {
+            return Ok(0);
+        }
+
+        let imported = self.import_saved_queries(legacy)?;
+        self.save(&settings_path)?;
+        fs::rename(legacy, legacy.with_extension("json.migrated")).map_err(|e| {
+            DomainError::Repository(format!("Failed to rename saved queries file: {}", e))
+        })?;
+        log::info!("Moved {} saved queries into saved searches", imported);
+        Ok(imported)
+    }
+
     pub fn sync_enabled_projects(&self) -> Vec<&ProjectConfig> {
         self.projects.iter().filter(|p| p.sync_enabled).collect()
     }
@@ -648,10 +887,14 @@ mod tests {
             database: DatabaseConfig {
                 path: None,
                 database_dir: PathBuf::from("./data"),
+                read_pool_size: DEFAULT_READ_POOL_SIZE,
+                read_replicas: false,
             },
             embeddings: None,
             log: None,
             sync: None,
+            search: None,
+            saved_searches: Vec::new(),
             debug_mode: false,
         }
     }
@@ -793,10 +1036,14 @@ mod tests {
             database: DatabaseConfig {
                 path: None,
                 database_dir: PathBuf::from("./data"),
+                read_pool_size: DEFAULT_READ_POOL_SIZE,
+                read_replicas: false,
             },
             embeddings: None,
             log: None,
             sync: None,
+            search: None,
+            saved_searches: Vec::new(),
             debug_mode: false,
         };
 
@@ -833,6 +1080,7 @@ mod tests {
             endpoint: Some("staging".to_string()),
             sync_checkpoint: None,
             snapshot_checkpoint: None,
+            bulk_edit_checkpoint: None,
         });
 
         let config = settings.get_jira_config_for_project("PROJ").unwrap();
@@ -848,9 +1096,123 @@ mod tests {
             endpoint: None,
             sync_checkpoint: None,
             snapshot_checkpoint: None,
+            bulk_edit_checkpoint: None,
         });
 
         let config2 = settings.get_jira_config_for_project("OTHER").unwrap();
         assert_eq!(config2.endpoint, "https://test.atlassian.net");
     }
+
+    #[test]
+    fn test_group_projects_by_endpoint() {
+        let mut settings = create_test_settings();
+
+        settings.add_endpoint(JiraEndpoint {
+            name: "staging".to_string(),
+            display_name: Some("Staging".to_string()),
+            endpoint: "https://staging.atlassian.net".into(),
+            username: "staging@example.com".into(),
+            api_key: "staging-key".into(),
+        });
+
+        for (id, key, endpoint) in [
+            ("1", "AAA", Some("staging")),
+            ("2", "BBB", None),
+            ("3", "CCC", Some("staging")),
+            ("4", "DDD", Some("default")),
+        ] {
+            settings.projects.push(ProjectConfig {
+                id: id.to_string(),
+                key: key.to_string(),
+                name: key.to_string(),
+                sync_enabled: true,
+                last_synced: None,
+                endpoint: endpoint.map(String::from),
+                sync_checkpoint: None,
+                snapshot_checkpoint: None,
+                bulk_edit_checkpoint: None,
+            });
+        }
+
+        let projects = settings.sync_enabled_projects();
+        let groups = settings.group_projects_by_endpoint(&projects);
+
+        // Projects without an endpoint fall into the active endpoint's group
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0.as_deref(), Some("staging"));
+        let staging_keys: Vec<_> = groups[0].1.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(staging_keys, vec!["AAA", "CCC"]);
+        assert_eq!(groups[1].0.as_deref(), Some("default"));
+        let default_keys: Vec<_> = groups[1].1.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(default_keys, vec!["BBB", "DDD"]);
+    }
+
+    #[test]
+    fn test_upsert_saved_search_keeps_created_at() {
+        let mut settings = create_test_settings();
+        let query = SavedSearchQuery::Sql {
+            sql: "SELECT 1".into(),
+        };
+        let original = SavedSearch::new("weekly", query.clone());
+        settings.upsert_saved_search(original.clone()).unwrap();
+
+        let mut updated = SavedSearch::new("weekly", query.clone());
+        updated.description = Some("Updated".into());
+        settings.upsert_saved_search(updated).unwrap();
+
+        assert_eq!(settings.saved_searches.len(), 1);
+        let saved = settings.find_saved_search("weekly").unwrap();
+        assert_eq!(saved.created_at, original.created_at);
+        assert_eq!(saved.description.as_deref(), Some("Updated"));
+
+        assert!(
+            settings
+                .upsert_saved_search(SavedSearch::new("not valid", query))
+                .is_err()
+        );
+        assert!(settings.remove_saved_search("weekly"));
+        assert!(!settings.remove_saved_search("weekly"));
+    }
+
+    #[test]
+    fn test_import_saved_queries() {
+        let mut settings = create_test_settings();
+        settings
+            .upsert_saved_search(SavedSearch::new(
+                "weekly",
+                SavedSearchQuery::Sql {
+                    sql: "SELECT 1".into(),
+                },
+            ))
+            .unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("jira-db-saved-queries-{}.json", std::process::id()));
+        fs::write(
+            &path,
+            r#"[
+                {"id": "1", "name": "Open bugs", "query": "SELECT key FROM issues",
+                 "createdAt": "2024-01-02T03:04:05Z", "updatedAt": "2024-01-03T03:04:05Z"},
+                {"id": "2", "name": "weekly", "query": "SELECT 2", "description": "Weekly"},
+                {"id": "3", "name": "broken", "query": "SELECT {{missing}}"}
+            ]"#,
+        )
+        .unwrap();
+        let imported = settings.import_saved_queries(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(imported, 2);
+        assert_eq!(
+            settings.find_saved_search("weekly").unwrap().query,
+            SavedSearchQuery::Sql {
+                sql: "SELECT 1".into()
+            }
+        );
+        let bugs = settings.find_saved_search("Open_bugs").unwrap();
+        assert_eq!(bugs.description.as_deref(), Some("Open bugs"));
+        assert_eq!(bugs.created_at.to_rfc3339(), "2024-01-02T03:04:05+00:00");
+        let weekly = settings.find_saved_search("weekly-2").unwrap();
+        assert_eq!(weekly.description.as_deref(), Some("Weekly"));
+        assert!(settings.find_saved_search("broken").is_none());
+    }
 }
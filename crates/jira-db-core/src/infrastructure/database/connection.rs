@@ -1,10 +1,14 @@
+use super::federated::FederatedDatabase;
+use super::migrations::{self, MigrationStatus, SchemaKind};
+use super::pool::{ConnectionPool, DEFAULT_READ_POOL_SIZE};
 use super::schema::Schema;
 use crate::domain::error::{DomainError, DomainResult};
 use crate::infrastructure::config::Settings;
-use duckdb::Connection;
+use duckdb::{AccessMode, Config, Connection};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 /// Type alias for the database connection handle
 pub type DbConnection = Arc<Mutex<Connection>>;
@@ -28,12 +32,12 @@ impl Database {
             })?;
         }
 
-        let conn = Connection::open(path)
+        let conn = Connection::open(path.as_ref())
             .map_err(|e| DomainError::Repository(format!("Failed to open database: {}", e)))?;
 
         // Initialize schema
         if init_main_schema {
-            Schema::init(&conn)?;
+            Schema::init(&conn).map_err(|e| with_path(e, path.as_ref()))?;
         }
 
         Ok(Self {
@@ -50,17 +54,88 @@ impl Database {
             })?;
         }
 
-        let conn = Connection::open(path)
+        let conn = Connection::open(path.as_ref())
             .map_err(|e| DomainError::Repository(format!("Failed to open database: {}", e)))?;
 
         // Initialize raw data schema
-        Schema::init_raw(&conn)?;
+        Schema::init_raw(&conn).map_err(|e| with_path(e, path.as_ref()))?;
+
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Open an existing database file read-only (no schema changes, no write lock)
+    ///
+    /// Several processes can hold the same file read-only at once, as long as none opens
+    /// it read-write.
+    pub fn open_read_only<P: AsRef<Path>>(path: P) -> DomainResult<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(DomainError::NotFound(format!(
+                "Database not found: {}",
+                path.display()
+            )));
+        }
+
+        let config = Config::default()
+            .access_mode(AccessMode::ReadOnly)
+            .map_err(|e| DomainError::Repository(format!("Failed to configure database: {}", e)))?;
+        let conn = Connection::open_with_flags(path, config).map_err(|e| {
+            with_path(
+                DomainError::Repository(format!("Failed to open database read-only: {}", e)),
+                path,
+            )
+        })?;
+
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Open an existing database file without creating or migrating its schema
+    pub fn open_existing<P: AsRef<Path>>(path: P) -> DomainResult<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(DomainError::NotFound(format!(
+                "Database not found: {}",
+                path.display()
+            )));
+        }
+
+        let conn = Connection::open(path)
+            .map_err(|e| DomainError::Repository(format!("Failed to open database: {}", e)))?;
 
         Ok(Self {
             conn: Arc::new(Mutex::new(conn)),
         })
     }
 
+    /// Applied and pending schema migrations of this database
+    pub fn migration_status(&self, kind: SchemaKind) -> DomainResult<Vec<MigrationStatus>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        migrations::status(&conn, kind)
+    }
+
+    /// Apply pending schema migrations, returning the ones applied
+    pub fn migrate(&self, kind: SchemaKind) -> DomainResult<Vec<MigrationStatus>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        Schema::migrate(&conn, kind)
+    }
+
+    /// Check pending schema migrations in a rolled-back transaction, returning the ones
+    /// that would be applied
+    pub fn dry_run_migrations(&self, kind: SchemaKind) -> DomainResult<Vec<MigrationStatus>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        Schema::dry_run(&conn, kind)
+    }
+
     pub fn connection(&self) -> Arc<Mutex<Connection>> {
         self.conn.clone()
     }
@@ -81,6 +156,16 @@ impl Database {
     }
 }
 
+/// Name the database file in schema errors (e.g., a file written by a newer jira-db)
+fn with_path(error: DomainError, path: &Path) -> DomainError {
+    match error {
+        DomainError::Configuration(msg) => {
+            DomainError::Configuration(format!("{}: {}", path.display(), msg))
+        }
+        other => other,
+    }
+}
+
 /// Run checkpoint on a database connection.
 /// This is a standalone function for use with DbConnection type.
 pub fn checkpoint_connection(conn: &DbConnection) -> DomainResult<()> {
@@ -101,14 +186,54 @@ struct ConnectionKey {
     is_raw: bool,
 }
 
+/// How a [`DatabaseFactory`] opens project databases
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DatabaseAccess {
+    /// Open `data.duckdb` / `raw.duckdb` read-write (sync, CLI, desktop app)
+    #[default]
+    ReadWrite,
+    /// Open the published read replicas (`data.read.duckdb` / `raw.read.duckdb`) read-only,
+    /// and reopen them when newer replicas are published. The live files are never opened.
+    /// For long-running servers sharing files with `jira-db sync`.
+    ReadReplica,
+}
+
+/// A read-only database opened from a specific version of a file
+struct OpenReplica {
+    path: PathBuf,
+    version: Option<(SystemTime, u64)>,
+    pool: Arc<ConnectionPool>,
+}
+
+/// Modification time and length, used to notice that a file was replaced
+fn file_version(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Cached federated connection and the files it was opened against
+struct FederatedCache {
+    projects: Vec<(String, PathBuf)>,
+    modified: Vec<Option<SystemTime>>,
+    pool: Arc<ConnectionPool>,
+}
+
 /// Factory for managing per-project database connections
 ///
 /// Each project has its own subdirectory with separate database files:
 /// - {database_dir}/{project_key}/data.duckdb - processed data
 /// - {database_dir}/{project_key}/raw.duckdb - raw JSON data
+/// - {database_dir}/{project_key}/data.read.duckdb - published read replica (optional)
+/// - {database_dir}/{project_key}/raw.read.duckdb - published raw data replica (optional)
 pub struct DatabaseFactory {
     database_dir: PathBuf,
+    access: DatabaseAccess,
+    publish_read_replicas: bool,
     connections: Arc<Mutex<HashMap<ConnectionKey, DbConnection>>>,
+    read_pools: Arc<Mutex<HashMap<ConnectionKey, Arc<ConnectionPool>>>>,
+    replicas: Arc<Mutex<HashMap<ConnectionKey, OpenReplica>>>,
+    read_pool_size: usize,
+    federated: Arc<Mutex<Option<FederatedCache>>>,
 }
 
 impl DatabaseFactory {
@@ -116,7 +241,13 @@ impl DatabaseFactory {
     pub fn new(settings: &Settings) -> Self {
         Self {
             database_dir: settings.database.database_dir.clone(),
+            access: DatabaseAccess::ReadWrite,
+            publish_read_replicas: settings.database.read_replicas,
             connections: Arc::new(Mutex::new(HashMap::new())),
+            read_pools: Arc::new(Mutex::new(HashMap::new())),
+            replicas: Arc::new(Mutex::new(HashMap::new())),
+            read_pool_size: settings.database.read_pool_size,
+            federated: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -124,21 +255,55 @@ impl DatabaseFactory {
     pub fn with_dir<P: AsRef<Path>>(database_dir: P) -> Self {
         Self {
             database_dir: database_dir.as_ref().to_path_buf(),
+            access: DatabaseAccess::ReadWrite,
+            publish_read_replicas: false,
             connections: Arc::new(Mutex::new(HashMap::new())),
+            read_pools: Arc::new(Mutex::new(HashMap::new())),
+            replicas: Arc::new(Mutex::new(HashMap::new())),
+            read_pool_size: DEFAULT_READ_POOL_SIZE,
+            federated: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Set the number of reader connections opened per database (0 shares the writer)
+    pub fn with_read_pool_size(mut self, size: usize) -> Self {
+        self.read_pool_size = size;
+        self
+    }
+
+    /// Choose how project databases are opened (see [`DatabaseAccess`])
+    pub fn with_access(mut self, access: DatabaseAccess) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Publish a read replica after each successful write (see [`Self::publish_read_replica`])
+    pub fn with_read_replica_publishing(mut self, enabled: bool) -> Self {
+        self.publish_read_replicas = enabled;
+        self
+    }
+
+    /// How this factory opens project databases
+    pub fn access(&self) -> DatabaseAccess {
+        self.access
+    }
+
     /// Get the project directory path
     fn get_project_dir(&self, project_key: &str) -> PathBuf {
         self.database_dir.join(project_key)
     }
 
     /// Get or create a database connection for a specific project (main data)
+    ///
+    /// In [`DatabaseAccess::ReadReplica`] mode this is a read-only connection to the replica.
     pub fn get_connection(&self, project_key: &str) -> DomainResult<DbConnection> {
         let key = ConnectionKey {
             project_key: project_key.to_string(),
             is_raw: false,
         };
+        if self.access == DatabaseAccess::ReadReplica {
+            return Ok(self.replica_pool(key)?.writer());
+        }
 
         let mut connections = self.connections.lock().map_err(|e| {
             DomainError::Repository(format!("Failed to acquire connections lock: {}", e))
@@ -163,6 +328,9 @@ impl DatabaseFactory {
             project_key: project_key.to_string(),
             is_raw: true,
         };
+        if self.access == DatabaseAccess::ReadReplica {
+            return Ok(self.replica_pool(key)?.writer());
+        }
 
         let mut connections = self.connections.lock().map_err(|e| {
             DomainError::Repository(format!("Failed to acquire connections lock: {}", e))
@@ -181,6 +349,174 @@ impl DatabaseFactory {
         Ok(conn)
     }
 
+    /// Get a reader connection for queries on a project's main database
+    ///
+    /// Readers are cloned from the writer returned by [`Self::get_connection`], so they see
+    /// everything sync has committed while letting queries run side by side.
+    pub fn get_read_connection(&self, project_key: &str) -> DomainResult<DbConnection> {
+        let key = ConnectionKey {
+            project_key: project_key.to_string(),
+            is_raw: false,
+        };
+        if self.access == DatabaseAccess::ReadReplica {
+            return Ok(self.replica_pool(key)?.reader());
+        }
+        Ok(self
+            .read_pool(key, || self.get_connection(project_key))?
+            .reader())
+    }
+
+    /// Get a reader connection for queries on a project's raw data database
+    pub fn get_raw_read_connection(&self, project_key: &str) -> DomainResult<DbConnection> {
+        let key = ConnectionKey {
+            project_key: project_key.to_string(),
+            is_raw: true,
+        };
+        if self.access == DatabaseAccess::ReadReplica {
+            return Ok(self.replica_pool(key)?.reader());
+        }
+        Ok(self
+            .read_pool(key, || self.get_raw_connection(project_key))?
+            .reader())
+    }
+
+    fn read_pool<F>(&self, key: ConnectionKey, writer: F) -> DomainResult<Arc<ConnectionPool>>
+    where
+        F: FnOnce() -> DomainResult<DbConnection>,
+    {
+        let pools = self.read_pools.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire read pool lock: {}", e))
+        })?;
+        if let Some(pool) = pools.get(&key) {
+            return Ok(pool.clone());
+        }
+        drop(pools);
+
+        let pool = Arc::new(ConnectionPool::new(writer()?, self.read_pool_size)?);
+        let mut pools = self.read_pools.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire read pool lock: {}", e))
+        })?;
+        Ok(pools.entry(key).or_insert(pool).clone())
+    }
+
+    /// Read-only pool for the current version of a project's replica
+    ///
+    /// The file is checked on every call; once a newer replica has been published the pool
+    /// is reopened. Connections already handed out keep the previous file open until they
+    /// are dropped, so every query sees one consistent version.
+    ///
+    /// Fails with `NotFound` while no replica has been published: falling back to the live
+    /// file would lock `jira-db sync` out of it.
+    fn replica_pool(&self, key: ConnectionKey) -> DomainResult<Arc<ConnectionPool>> {
+        let path = if key.is_raw {
+            self.get_raw_read_replica_path(&key.project_key)
+        } else {
+            self.get_read_replica_path(&key.project_key)
+        };
+        let version = file_version(&path);
+        if version.is_none() {
+            return Err(DomainError::NotFound(format!(
+                "No read replica has been published for project {} ({}). \
+                 Enable database.read_replicas and run 'jira-db sync'",
+                key.project_key,
+                path.display()
+            )));
+        }
+
+        let mut replicas = self.replicas.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire replica lock: {}", e))
+        })?;
+        if let Some(open) = replicas
+            .get(&key)
+            .filter(|open| open.path == path && open.version == version)
+        {
+            return Ok(open.pool.clone());
+        }
+
+        let pool = Arc::new(ConnectionPool::open_read_only(&path, self.read_pool_size)?);
+        if replicas.contains_key(&key) {
+            log::info!(
+                "Reopened {} after a new replica was published",
+                path.display()
+            );
+        }
+        replicas.insert(
+            key,
+            OpenReplica {
+                path,
+                version,
+                pool: pool.clone(),
+            },
+        );
+        Ok(pool)
+    }
+
+    /// Get the published read replica path for a specific project
+    pub fn get_read_replica_path(&self, project_key: &str) -> PathBuf {
+        self.get_project_dir(project_key).join("data.read.duckdb")
+    }
+
+    /// Get the published raw data replica path for a specific project
+    pub fn get_raw_read_replica_path(&self, project_key: &str) -> PathBuf {
+        self.get_project_dir(project_key).join("raw.read.duckdb")
+    }
+
+    /// Main database file that queries should read from
+    ///
+    /// In [`DatabaseAccess::ReadReplica`] mode this is always the published replica,
+    /// which may not exist yet.
+    pub fn query_database_path(&self, project_key: &str) -> PathBuf {
+        if self.access == DatabaseAccess::ReadReplica {
+            self.get_read_replica_path(project_key)
+        } else {
+            self.get_database_path(project_key)
+        }
+    }
+
+    /// Publish read-only copies of a project's main and raw databases for other processes
+    ///
+    /// Each writer is checkpointed and copied while holding its lock, and the copy replaces
+    /// `data.read.duckdb` / `raw.read.duckdb` with an atomic rename, so readers only ever see
+    /// a complete file. Does nothing (returns `false`) unless publishing is enabled.
+    pub fn publish_read_replica(&self, project_key: &str) -> DomainResult<bool> {
+        if !self.publish_read_replicas || self.access == DatabaseAccess::ReadReplica {
+            return Ok(false);
+        }
+
+        Self::copy_replica(
+            &self.get_connection(project_key)?,
+            &self.get_database_path(project_key),
+            &self.get_read_replica_path(project_key),
+        )?;
+        if self.get_raw_database_path(project_key).exists() {
+            Self::copy_replica(
+                &self.get_raw_connection(project_key)?,
+                &self.get_raw_database_path(project_key),
+                &self.get_raw_read_replica_path(project_key),
+            )?;
+        }
+
+        Ok(true)
+    }
+
+    fn copy_replica(conn: &DbConnection, source: &Path, replica: &Path) -> DomainResult<()> {
+        let conn = conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        conn.execute_batch("CHECKPOINT").map_err(|e| {
+            DomainError::Repository(format!("Failed to checkpoint database: {}", e))
+        })?;
+
+        let staging = replica.with_extension("duckdb.tmp");
+        std::fs::copy(source, &staging).map_err(|e| {
+            DomainError::Repository(format!("Failed to copy database for replica: {}", e))
+        })?;
+        std::fs::rename(&staging, replica).map_err(|e| {
+            DomainError::Repository(format!("Failed to publish read replica: {}", e))
+        })?;
+        Ok(())
+    }
+
     /// Get the main database path for a specific project
     pub fn get_database_path(&self, project_key: &str) -> PathBuf {
         self.get_project_dir(project_key).join("data.duckdb")
@@ -283,7 +619,15 @@ impl DatabaseFactory {
             checkpoint_connection(conn)?;
         }
         connections.remove(&raw_key);
+        drop(connections);
 
+        if let Ok(mut pools) = self.read_pools.lock() {
+            pools.retain(|key, _| key.project_key != project_key);
+        }
+        if let Ok(mut replicas) = self.replicas.lock() {
+            replicas.retain(|key, _| key.project_key != project_key);
+        }
+        self.invalidate_federated();
         Ok(())
     }
 
@@ -304,11 +648,148 @@ impl DatabaseFactory {
         }
 
         connections.clear();
+        drop(connections);
+
+        if let Ok(mut pools) = self.read_pools.lock() {
+            pools.clear();
+        }
+        if let Ok(mut replicas) = self.replicas.lock() {
+            replicas.clear();
+        }
+        self.invalidate_federated();
         Ok(())
     }
 
+    /// Get a read-only connection spanning the main databases of the given projects
+    ///
+    /// Open connections are checkpointed first so the attached files hold their latest data.
+    /// Every table and view gets a leading `project_key` column, and `all_issues`,
+    /// `all_change_history`, ... aliases are created (see [`FederatedDatabase`]).
+    ///
+    /// The attachments are cached and reused until the project list changes or one of the
+    /// database files is modified (e.g. by a sync), in which case they are reopened.
+    pub fn get_federated_connection(&self, project_keys: &[String]) -> DomainResult<DbConnection> {
+        self.checkpoint_all()?;
+
+        // Projects without a published replica are left out rather than opening the live file
+        let mut projects: Vec<(String, PathBuf)> = project_keys
+            .iter()
+            .map(|key| (key.clone(), self.query_database_path(key)))
+            .filter(|(_, path)| self.access == DatabaseAccess::ReadWrite || path.exists())
+            .collect();
+        projects.sort();
+        projects.dedup();
+        let modified: Vec<Option<SystemTime>> = projects
+            .iter()
+            .map(|(_, path)| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+            .collect();
+
+        let mut cache = self.federated.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire federated lock: {}", e))
+        })?;
+
+        if let Some(cached) = cache
+            .as_ref()
+            .filter(|c| c.projects == projects && c.modified == modified)
+        {
+            return Ok(cached.pool.reader());
+        }
+
+        let pool = Arc::new(ConnectionPool::new(
+            FederatedDatabase::open(&projects)?,
+            self.read_pool_size,
+        )?);
+        let conn = pool.reader();
+        *cache = Some(FederatedCache {
+            projects,
+            modified,
+            pool,
+        });
+        Ok(conn)
+    }
+
+    /// Get a read-only connection spanning every project database in the directory
+    pub fn get_all_projects_connection(&self) -> DomainResult<DbConnection> {
+        let projects = self.list_project_databases()?;
+        self.get_federated_connection(&projects)
+    }
+
+    /// Drop the cached federated connection so the next request reattaches the databases
+    pub fn invalidate_federated(&self) {
+        if let Ok(mut cache) = self.federated.lock() {
+            *cache = None;
+        }
+    }
+
     /// Get the number of open connections
     pub fn open_connection_count(&self) -> usize {
         self.connections.lock().map(|c| c.len()).unwrap_or(0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_rows(factory: &DatabaseFactory) -> i64 {
+        let conn = factory.get_read_connection("PROJ").unwrap();
+        let conn = conn.lock().unwrap();
+        conn.query_row("SELECT COUNT(*) FROM replica_test", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_read_replica_is_reopened_after_publish() {
+        let dir = std::env::temp_dir().join(format!("jira-db-replica-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("PROJ")).unwrap();
+
+        let writer = DatabaseFactory::with_dir(&dir).with_read_replica_publishing(true);
+        let reader = DatabaseFactory::with_dir(&dir).with_access(DatabaseAccess::ReadReplica);
+
+        let conn = writer.get_connection("PROJ").unwrap();
+        conn.lock()
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE replica_test (n INTEGER); INSERT INTO replica_test VALUES (1);",
+            )
+            .unwrap();
+        writer.get_raw_connection("PROJ").unwrap();
+
+        // Nothing published yet: the live files are never opened
+        assert!(matches!(
+            reader.get_read_connection("PROJ"),
+            Err(DomainError::NotFound(_))
+        ));
+        assert!(matches!(
+            reader.get_raw_read_connection("PROJ"),
+            Err(DomainError::NotFound(_))
+        ));
+
+        assert!(writer.publish_read_replica("PROJ").unwrap());
+        assert_eq!(count_rows(&reader), 1);
+        assert!(reader.get_raw_read_connection("PROJ").is_ok());
+
+        // Unpublished writes are invisible until the next publish
+        conn.lock()
+            .unwrap()
+            .execute_batch("INSERT INTO replica_test SELECT range FROM range(2, 100000);")
+            .unwrap();
+        assert_eq!(count_rows(&reader), 1);
+
+        assert!(writer.publish_read_replica("PROJ").unwrap());
+        assert_eq!(count_rows(&reader), 99999);
+
+        // Publishing is opt-in
+        assert!(
+            !DatabaseFactory::with_dir(&dir)
+                .publish_read_replica("PROJ")
+                .unwrap()
+        );
+
+        drop(conn);
+        writer.close_all().unwrap();
+        reader.close_all().unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
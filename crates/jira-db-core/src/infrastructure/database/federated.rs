@@ -0,0 +1,195 @@
+//! Read-only view over several project databases
+//!
+//! Each project database is attached read-only to an in-memory connection, and every table
+//! or view found in any of them is exposed as a `UNION ALL BY NAME` view with a leading
+//! `project_key` column, so queries written for one project run across all of them.
+//! Each view is also available under an `all_` name (`all_issues`, `all_change_history`,
+//! `all_snapshots`, ...) so cross-project queries read unambiguously.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use duckdb::Connection;
+
+use super::connection::DbConnection;
+use crate::domain::error::{DomainError, DomainResult};
+
+pub struct FederatedDatabase;
+
+impl FederatedDatabase {
+    /// Attach the given `(project_key, database_path)` pairs and create the union views.
+    ///
+    /// Projects whose database file does not exist yet are skipped.
+    pub fn open(projects: &[(String, PathBuf)]) -> DomainResult<DbConnection> {
+        let conn = Connection::open_in_memory().map_err(|e| {
+            DomainError::Repository(format!("Failed to create in-memory database: {}", e))
+        })?;
+
+        let mut attached = Vec::new();
+        for (project_key, path) in projects {
+            if !path.exists() {
+                continue;
+            }
+            let alias = Self::alias(project_key);
+            conn.execute(
+                &format!(
+                    "ATTACH '{}' AS \"{}\" (READ_ONLY)",
+                    path.to_string_lossy().replace('\'', "''"),
+                    alias
+                ),
+                [],
+            )
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to attach database {}: {}", project_key, e))
+            })?;
+            attached.push((project_key.clone(), alias));
+        }
+
+        if attached.is_empty() {
+            return Err(DomainError::NotFound(
+                "No synced project databases to query".to_string(),
+            ));
+        }
+
+        Self::create_union_views(&conn, &attached)?;
+        Ok(Arc::new(Mutex::new(conn)))
+    }
+
+    /// Database alias for a project key (alphanumerics and underscores only)
+    fn alias(project_key: &str) -> String {
+        let sanitized: String = project_key
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("p_{}", sanitized.to_lowercase())
+    }
+
+    /// `all_` view name for a relation (`issue_change_history` -> `all_change_history`)
+    fn all_view_name(relation: &str) -> String {
+        let name = relation.strip_prefix("issue_").unwrap_or(relation);
+        format!("all_{}", name)
+    }
+
+    fn create_union_views(conn: &Connection, attached: &[(String, String)]) -> DomainResult<()> {
+        // Relation name -> projects that have it
+        let mut relations: BTreeMap<String, Vec<&(String, String)>> = BTreeMap::new();
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT database_name, table_name FROM duckdb_tables()
+                UNION
+                SELECT database_name, view_name FROM duckdb_views() WHERE NOT internal
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+        for row in rows {
+            let (database, relation) = row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            if let Some(project) = attached.iter().find(|(_, alias)| *alias == database) {
+                relations.entry(relation).or_default().push(project);
+            }
+        }
+
+        for (relation, projects) in relations {
+            let unions: Vec<String> = projects
+                .iter()
+                .map(|(key, alias)| {
+                    format!(
+                        "SELECT '{}' AS project_key, * FROM \"{}\".\"{}\"",
+                        key.replace('\'', "''"),
+                        alias,
+                        relation
+                    )
+                })
+                .collect();
+            let sql = format!(
+                "CREATE OR REPLACE VIEW \"{}\" AS {}",
+                relation,
+                unions.join(" UNION ALL BY NAME ")
+            );
+            // A relation that already has a project_key column cannot get another one
+            if let Err(e) = conn.execute(&sql, []) {
+                log::debug!("Skipped federated view {}: {}", relation, e);
+                continue;
+            }
+
+            let alias_sql = format!(
+                "CREATE OR REPLACE VIEW \"{}\" AS SELECT * FROM \"{}\"",
+                Self::all_view_name(&relation),
+                relation
+            );
+            if let Err(e) = conn.execute(&alias_sql, []) {
+                log::debug!("Skipped federated alias for {}: {}", relation, e);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_views_add_project_key() {
+        let dir = std::env::temp_dir().join(format!("jira-db-federated-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut projects = Vec::new();
+        for (key, summary) in [("PROJ", "first"), ("OTHER-1", "second")] {
+            let path = dir.join(format!("{}.duckdb", key));
+            let _ = std::fs::remove_file(&path);
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(&format!(
+                "CREATE TABLE issues (key VARCHAR, summary VARCHAR);
+                 INSERT INTO issues VALUES ('{}-1', '{}');",
+                key, summary
+            ))
+            .unwrap();
+            drop(conn);
+            projects.push((key.to_string(), path));
+        }
+        projects.push(("MISSING".to_string(), dir.join("missing.duckdb")));
+
+        let conn = FederatedDatabase::open(&projects).unwrap();
+        let conn = conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT project_key, summary FROM all_issues ORDER BY project_key")
+            .unwrap();
+        let rows: Vec<(String, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                ("OTHER-1".to_string(), "second".to_string()),
+                ("PROJ".to_string(), "first".to_string())
+            ]
+        );
+        drop(stmt);
+        drop(conn);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_all_view_name() {
+        assert_eq!(FederatedDatabase::all_view_name("issues"), "all_issues");
+        assert_eq!(
+            FederatedDatabase::all_view_name("issue_change_history"),
+            "all_change_history"
+        );
+        assert_eq!(
+            FederatedDatabase::all_view_name("issue_snapshots"),
+            "all_snapshots"
+        );
+    }
+}
@@ -0,0 +1,380 @@
+//! Maintenance operations on a project database file
+//!
+//! Size reporting, checkpoint/vacuum, index rebuilds, and referential consistency checks
+//! between the issue tables and the tables derived from them.
+
+use duckdb::Connection;
+use serde::Serialize;
+
+use super::connection::DbConnection;
+use super::repositories::EmbeddingsRepository;
+use crate::domain::error::{DomainError, DomainResult};
+
+/// Row count and approximate storage of one table
+#[derive(Debug, Clone, Serialize)]
+pub struct TableSize {
+    pub table_name: String,
+    pub row_count: usize,
+    /// Bytes of the blocks holding the table's checkpointed data (blocks can be shared, so
+    /// this is an estimate)
+    pub estimated_bytes: u64,
+}
+
+/// Block usage of the whole database file
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockUsage {
+    pub block_size: u64,
+    pub total_blocks: u64,
+    pub used_blocks: u64,
+    pub free_blocks: u64,
+}
+
+/// Result of one consistency check
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrityFinding {
+    pub check: String,
+    pub description: String,
+    /// Number of inconsistent rows
+    pub count: usize,
+    /// Whether `repair` can fix it (by deleting the rows)
+    pub repairable: bool,
+}
+
+struct IntegrityCheck {
+    name: &'static str,
+    description: &'static str,
+    /// Table holding the inconsistent rows (the check is skipped when it does not exist)
+    table: &'static str,
+    /// Condition selecting the inconsistent rows of `table`
+    predicate: &'static str,
+    repairable: bool,
+}
+
+const INTEGRITY_CHECKS: &[IntegrityCheck] = &[
+    IntegrityCheck {
+        name: "change_history_orphans",
+        description: "Change history rows for issues that are not in the issues table",
+        table: "issue_change_history",
+        predicate: "issue_id NOT IN (SELECT id FROM issues)",
+        repairable: true,
+    },
+    IntegrityCheck {
+        name: "snapshot_orphans",
+        description: "Snapshots of issues that are not in the issues table",
+        table: "issue_snapshots",
+        predicate: "issue_id NOT IN (SELECT id FROM issues)",
+        repairable: true,
+    },
+    IntegrityCheck {
+        name: "expanded_deleted",
+        description: "Expanded rows of issues that were deleted or are not in the issues table",
+        table: "issues_expanded",
+        predicate: "id NOT IN (SELECT id FROM issues WHERE is_deleted IS NULL OR is_deleted = false)",
+        repairable: true,
+    },
+    IntegrityCheck {
+        name: "embedding_orphans",
+        description: "Embeddings of issues that are not in the issues table",
+        table: "issue_embeddings",
+        predicate: "issue_id NOT IN (SELECT id FROM issues)",
+        repairable: true,
+    },
+    IntegrityCheck {
+        name: "topic_assignment_orphans",
+        description: "Topic assignments of issues that are not in the issues table",
+        table: "issue_topic_assignments",
+        predicate: "issue_id NOT IN (SELECT id FROM issues)",
+        repairable: true,
+    },
+    IntegrityCheck {
+        name: "issues_without_project",
+        description: "Issues whose project is not in the projects table (re-sync the project)",
+        table: "issues",
+        predicate: "project_id NOT IN (SELECT id FROM projects)",
+        repairable: false,
+    },
+];
+
+pub struct DatabaseMaintenance {
+    conn: DbConnection,
+}
+
+impl DatabaseMaintenance {
+    pub fn new(conn: DbConnection) -> Self {
+        Self { conn }
+    }
+
+    fn lock(&self) -> DomainResult<std::sync::MutexGuard<'_, Connection>> {
+        self.conn
+            .lock()
+            .map_err(|e| DomainError::Repository(format!("Failed to acquire database lock: {}", e)))
+    }
+
+    /// Row counts and estimated sizes of all tables, largest first
+    pub fn table_sizes(&self) -> DomainResult<Vec<TableSize>> {
+        let conn = self.lock()?;
+        let block_size = Self::block_usage_locked(&conn)?.block_size;
+
+        let mut sizes = Vec::new();
+        for table in Self::table_names(&conn)? {
+            let row_count: i64 = conn
+                .query_row(&format!("SELECT COUNT(*) FROM \"{}\"", table), [], |row| {
+                    row.get(0)
+                })
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to count {}: {}", table, e))
+                })?;
+            let blocks: i64 = conn
+                .query_row(
+                    "SELECT COUNT(DISTINCT block_id) FROM pragma_storage_info(?) WHERE block_id >= 0",
+                    duckdb::params![table],
+                    |row| row.get(0),
+                )
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to read storage of {}: {}", table, e))
+                })?;
+
+            sizes.push(TableSize {
+                table_name: table,
+                row_count: row_count as usize,
+                estimated_bytes: blocks as u64 * block_size,
+            });
+        }
+
+        sizes.sort_by_key(|s| std::cmp::Reverse(s.estimated_bytes));
+        Ok(sizes)
+    }
+
+    pub fn block_usage(&self) -> DomainResult<BlockUsage> {
+        let conn = self.lock()?;
+        Self::block_usage_locked(&conn)
+    }
+
+    fn block_usage_locked(conn: &Connection) -> DomainResult<BlockUsage> {
+        conn.query_row(
+            r#"
+            SELECT block_size, total_blocks, used_blocks, free_blocks
+            FROM pragma_database_size()
+            WHERE database_name = current_database()
+            "#,
+            [],
+            |row| {
+                Ok(BlockUsage {
+                    block_size: row.get::<_, i64>(0)? as u64,
+                    total_blocks: row.get::<_, i64>(1)? as u64,
+                    used_blocks: row.get::<_, i64>(2)? as u64,
+                    free_blocks: row.get::<_, i64>(3)? as u64,
+                })
+            },
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to read database size: {}", e)))
+    }
+
+    fn table_names(conn: &Connection) -> DomainResult<Vec<String>> {
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT table_name FROM duckdb_tables()
+                WHERE database_name = current_database()
+                ORDER BY table_name
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+        let rows = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+        rows.collect::<Result<Vec<String>, _>>()
+            .map_err(|e| DomainError::Repository(e.to_string()))
+    }
+
+    fn table_exists(conn: &Connection, table: &str) -> DomainResult<bool> {
+        Ok(Self::table_names(conn)?.iter().any(|t| t == table))
+    }
+
+    /// Flush the WAL into the database file, even if other transactions are open
+    pub fn checkpoint(&self) -> DomainResult<()> {
+        let conn = self.lock()?;
+        conn.execute_batch("FORCE CHECKPOINT")
+            .map_err(|e| DomainError::Repository(format!("Failed to checkpoint database: {}", e)))
+    }
+
+    /// Refresh table statistics and checkpoint so blocks freed by deletes can be reused.
+    ///
+    /// DuckDB never shrinks a file in place; freed blocks are reused by later writes.
+    pub fn vacuum(&self) -> DomainResult<()> {
+        let conn = self.lock()?;
+        conn.execute_batch("VACUUM ANALYZE; FORCE CHECKPOINT")
+            .map_err(|e| DomainError::Repository(format!("Failed to vacuum database: {}", e)))
+    }
+
+    /// Drop and re-create every secondary index, including the HNSW indexes of the
+    /// embedding vector tables. Returns the rebuilt index names.
+    pub fn rebuild_indexes(&self) -> DomainResult<Vec<String>> {
+        // HNSW indexes need the VSS extension and persistence enabled on this connection
+        if Self::table_exists(&*self.lock()?, "issue_embeddings")? {
+            EmbeddingsRepository::new(self.conn.clone()).init_schema()?;
+        }
+
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT index_name, sql FROM duckdb_indexes()
+                WHERE database_name = current_database() AND sql IS NOT NULL
+                ORDER BY table_name, index_name
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+        let indexes = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut rebuilt = Vec::new();
+        for (name, sql) in indexes {
+            conn.execute_batch(&format!(
+                "BEGIN TRANSACTION; DROP INDEX \"{}\"; {}; COMMIT;",
+                name,
+                sql.trim_end_matches(';')
+            ))
+            .map_err(|e| {
+                let _ = conn.execute_batch("ROLLBACK");
+                DomainError::Repository(format!("Failed to rebuild index {}: {}", name, e))
+            })?;
+            log::info!("Rebuilt index {}", name);
+            rebuilt.push(name);
+        }
+        Ok(rebuilt)
+    }
+
+    /// Run the consistency checks that apply to this database
+    pub fn check(&self) -> DomainResult<Vec<IntegrityFinding>> {
+        let conn = self.lock()?;
+        let tables = Self::table_names(&conn)?;
+
+        let mut findings = Vec::new();
+        for check in INTEGRITY_CHECKS {
+            if !tables.iter().any(|t| t == check.table) {
+                continue;
+            }
+            let count: i64 = conn
+                .query_row(
+                    &format!(
+                        "SELECT COUNT(*) FROM {} WHERE {}",
+                        check.table, check.predicate
+                    ),
+                    [],
+                    |row| row.get(0),
+                )
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to run check {}: {}", check.name, e))
+                })?;
+            findings.push(IntegrityFinding {
+                check: check.name.to_string(),
+                description: check.description.to_string(),
+                count: count as usize,
+                repairable: check.repairable,
+            });
+        }
+        Ok(findings)
+    }
+
+    /// Delete the rows flagged by repairable checks, in one transaction.
+    ///
+    /// Returns the number of rows deleted per check.
+    pub fn repair(&self) -> DomainResult<Vec<(String, usize)>> {
+        let conn = self.lock()?;
+        let tables = Self::table_names(&conn)?;
+
+        conn.execute_batch("BEGIN TRANSACTION")
+            .map_err(|e| DomainError::Repository(format!("Failed to begin transaction: {}", e)))?;
+
+        let mut repaired = Vec::new();
+        for check in INTEGRITY_CHECKS.iter().filter(|c| c.repairable) {
+            if !tables.iter().any(|t| t == check.table) {
+                continue;
+            }
+            let deleted = conn
+                .execute(
+                    &format!("DELETE FROM {} WHERE {}", check.table, check.predicate),
+                    [],
+                )
+                .map_err(|e| {
+                    let _ = conn.execute_batch("ROLLBACK");
+                    DomainError::Repository(format!("Failed to repair {}: {}", check.name, e))
+                })?;
+            if deleted > 0 {
+                log::info!("Repaired {}: deleted {} rows", check.name, deleted);
+                repaired.push((check.name.to_string(), deleted));
+            }
+        }
+
+        conn.execute_batch("COMMIT")
+            .map_err(|e| DomainError::Repository(format!("Failed to commit repair: {}", e)))?;
+        Ok(repaired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn maintenance() -> DatabaseMaintenance {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            r#"
+            CREATE TABLE projects (id VARCHAR PRIMARY KEY);
+            CREATE TABLE issues (id VARCHAR PRIMARY KEY, project_id VARCHAR, is_deleted BOOLEAN);
+            CREATE TABLE issue_change_history (issue_id VARCHAR, field VARCHAR);
+            CREATE TABLE issues_expanded (id VARCHAR PRIMARY KEY);
+            INSERT INTO projects VALUES ('p1');
+            INSERT INTO issues VALUES ('1', 'p1', false), ('2', 'p1', true), ('3', 'p2', false);
+            INSERT INTO issue_change_history VALUES ('1', 'status'), ('9', 'status');
+            INSERT INTO issues_expanded VALUES ('1'), ('2');
+            "#,
+        )
+        .unwrap();
+        DatabaseMaintenance::new(Arc::new(Mutex::new(conn)))
+    }
+
+    fn count(findings: &[IntegrityFinding], check: &str) -> usize {
+        findings
+            .iter()
+            .find(|f| f.check == check)
+            .map(|f| f.count)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_check_and_repair() {
+        let maintenance = maintenance();
+
+        let findings = maintenance.check().unwrap();
+        // Checks on missing tables are skipped
+        assert!(!findings.iter().any(|f| f.check == "snapshot_orphans"));
+        assert_eq!(count(&findings, "change_history_orphans"), 1);
+        assert_eq!(count(&findings, "expanded_deleted"), 1);
+        assert_eq!(count(&findings, "issues_without_project"), 1);
+
+        let repaired = maintenance.repair().unwrap();
+        assert_eq!(repaired.len(), 2);
+
+        let findings = maintenance.check().unwrap();
+        assert_eq!(count(&findings, "change_history_orphans"), 0);
+        assert_eq!(count(&findings, "expanded_deleted"), 0);
+        // Not repairable: the issue stays
+        assert_eq!(count(&findings, "issues_without_project"), 1);
+    }
+
+    #[test]
+    fn test_table_sizes() {
+        let sizes = maintenance().table_sizes().unwrap();
+        let issues = sizes.iter().find(|s| s.table_name == "issues").unwrap();
+        assert_eq!(issues.row_count, 3);
+        assert_eq!(sizes.len(), 4);
+    }
+}
@@ -0,0 +1,565 @@
+//! Versioned schema migrations
+//!
+//! Every database file records the migrations applied to it in `schema_migrations`. When a
+//! database is opened, pending migrations run in version order, each in its own transaction.
+//! A file whose recorded version is newer than this build knows about is refused rather
+//! than modified.
+//!
+//! The `CREATE TABLE IF NOT EXISTS` statements in `Schema` always describe the latest layout,
+//! so a migration must also work against a table that was just created that way (check with
+//! `column_exists` before altering).
+
+use chrono::{DateTime, Utc};
+use duckdb::Connection;
+use serde::Serialize;
+
+use super::repositories::EmbeddingsRepository;
+use crate::domain::error::{DomainError, DomainResult};
+
+/// Version of this build, recorded with each applied migration
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Which database file a list of migrations applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaKind {
+    /// Processed data (data.duckdb)
+    Main,
+    /// Raw API payloads (raw.duckdb)
+    Raw,
+}
+
+impl SchemaKind {
+    fn migrations(self) -> &'static [Migration] {
+        match self {
+            SchemaKind::Main => MAIN_MIGRATIONS,
+            SchemaKind::Raw => RAW_MIGRATIONS,
+        }
+    }
+
+    /// Latest schema version known to this build
+    pub fn latest_version(self) -> i64 {
+        self.migrations().last().map_or(0, |m| m.version)
+    }
+}
+
+/// Applied or pending migration of one database file
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    /// When the migration was applied (None while pending)
+    pub applied_at: Option<DateTime<Utc>>,
+    /// jira-db version that applied it
+    pub applied_by: Option<String>,
+}
+
+impl MigrationStatus {
+    pub fn is_pending(&self) -> bool {
+        self.applied_at.is_none()
+    }
+}
+
+struct Migration {
+    version: i64,
+    description: &'static str,
+    up: fn(&Connection) -> DomainResult<()>,
+}
+
+impl Migration {
+    fn pending_status(&self) -> MigrationStatus {
+        MigrationStatus {
+            version: self.version,
+            description: self.description.to_string(),
+            applied_at: None,
+            applied_by: None,
+        }
+    }
+}
+
+/// Migrations of the main database, in version order. Never edit or renumber an entry that
+/// has shipped; add a new one instead.
+const MAIN_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Add columns introduced before versioned migrations",
+        up: add_legacy_columns,
+    },
+    Migration {
+        version: 2,
+        description: "Record the source of change history rows",
+        up: add_change_history_source,
+    },
+    Migration {
+        version: 3,
+        description: "Store embeddings in fixed-size vectors per model and dimension",
+        up: split_embedding_vectors,
+    },
+];
+
+/// Migrations of the raw database, in version order
+const RAW_MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    description: "Baseline raw payload schema",
+    up: baseline,
+}];
+
+fn baseline(_conn: &Connection) -> DomainResult<()> {
+    Ok(())
+}
+
+/// 既存のデータベースに対して、バージョン管理導入前に追加されたカラムを揃える
+fn add_legacy_columns(conn: &Connection) -> DomainResult<()> {
+    // issuesテーブルにsprintカラムを追加
+    add_column_if_not_exists(conn, "issues", "sprint", "VARCHAR")?;
+    // issue_snapshotsテーブルにraw_dataカラムを追加
+    add_column_if_not_exists(conn, "issue_snapshots", "raw_data", "JSON")?;
+    // issuesテーブルにis_deletedカラムを追加（JIRA上で削除されたissueを論理削除するため）
+    add_column_if_not_exists(conn, "issues", "is_deleted", "BOOLEAN DEFAULT false")?;
+    // issuesテーブルにdue_dateカラムを追加（期限日）
+    add_column_if_not_exists(conn, "issues", "due_date", "TIMESTAMPTZ")?;
+    // issuesテーブルにteamカラムを追加（チーム）
+    add_column_if_not_exists(conn, "issues", "team", "VARCHAR")?;
+    // issues_expandedテーブルにresolved_dateカラムを追加（解決日）
+    add_column_if_not_exists(conn, "issues_expanded", "resolved_date", "TIMESTAMPTZ")?;
+    // issues_expandedテーブルにdue_dateカラムを追加（期限日）
+    add_column_if_not_exists(conn, "issues_expanded", "due_date", "TIMESTAMPTZ")?;
+    // issue_snapshotsテーブルにresolved_dateカラムを追加（解決日）
+    add_column_if_not_exists(conn, "issue_snapshots", "resolved_date", "TIMESTAMPTZ")?;
+    // issue_snapshotsテーブルにdue_dateカラムを追加（期限日）
+    add_column_if_not_exists(conn, "issue_snapshots", "due_date", "TIMESTAMPTZ")?;
+    // issues_expandedテーブルにenvironmentカラムを追加（環境）
+    add_column_if_not_exists(conn, "issues_expanded", "environment", "TEXT")?;
+    // issues_expandedテーブルにcreatorカラムを追加（作成者）
+    add_column_if_not_exists(conn, "issues_expanded", "creator", "VARCHAR")?;
+    // issues_expandedテーブルにaffected_versionsカラムを追加（影響バージョン）
+    add_column_if_not_exists(conn, "issues_expanded", "affected_versions", "JSON")?;
+    // issues_expandedテーブルにsecurity_levelカラムを追加（セキュリティレベル）
+    add_column_if_not_exists(conn, "issues_expanded", "security_level", "VARCHAR")?;
+    // issue_snapshotsテーブルにupdated_dateカラムを追加（課題の更新日）
+    add_column_if_not_exists(conn, "issue_snapshots", "updated_date", "TIMESTAMPTZ")?;
+    Ok(())
+}
+
+/// issue_change_historyテーブルにsourceカラムを追加し、既存行をchangelogとして埋める
+fn add_change_history_source(conn: &Connection) -> DomainResult<()> {
+    add_column_if_not_exists(
+        conn,
+        "issue_change_history",
+        "source",
+        "VARCHAR DEFAULT 'changelog'",
+    )?;
+    conn.execute(
+        "UPDATE issue_change_history SET source = 'changelog' WHERE source IS NULL",
+        [],
+    )
+    .map_err(|e| DomainError::Repository(format!("Failed to backfill source: {}", e)))?;
+    Ok(())
+}
+
+/// issue_embeddingsの可変長ベクトルを、HNSWインデックスを張れるモデル・次元ごとの
+/// 固定長テーブルへ移し、issue_embeddingsからembeddingカラムを取り除く
+fn split_embedding_vectors(conn: &Connection) -> DomainResult<()> {
+    if !table_exists(conn, "issue_embeddings")?
+        || !column_exists(conn, "issue_embeddings", "embedding")?
+    {
+        return Ok(());
+    }
+    EmbeddingsRepository::create_table(conn)?;
+
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT DISTINCT model, len(embedding) FROM issue_embeddings
+            WHERE len(embedding) > 0
+            ORDER BY model, len(embedding)
+            "#,
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+    let groups = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+    for (model, dimensions) in groups {
+        let space = EmbeddingsRepository::ensure_space(conn, &model, dimensions as usize)?;
+        conn.execute(
+            &format!(
+                "INSERT INTO {} (issue_id, embedding) SELECT issue_id, embedding::FLOAT[{}] FROM issue_embeddings WHERE model = ? AND len(embedding) = ?",
+                space.table(),
+                dimensions
+            ),
+            duckdb::params![model, dimensions],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to copy embeddings: {}", e)))?;
+        log::info!(
+            "Migration: Moved {} embeddings ({} dimensions) to {}",
+            model,
+            dimensions,
+            space.table()
+        );
+    }
+
+    execute_batch(
+        conn,
+        r#"
+        CREATE TEMP TABLE issue_embeddings_without_vectors AS
+        SELECT issue_id, issue_key, embedded_text, provider, model,
+               len(embedding) AS dimensions, created_at
+        FROM issue_embeddings
+        WHERE len(embedding) > 0;
+        DROP TABLE issue_embeddings;
+        "#,
+    )?;
+    EmbeddingsRepository::create_table(conn)?;
+    execute_batch(
+        conn,
+        r#"
+        INSERT INTO issue_embeddings (issue_id, issue_key, embedded_text, provider, model, dimensions, created_at)
+        SELECT issue_id, issue_key, embedded_text, provider, model, dimensions, created_at
+        FROM issue_embeddings_without_vectors;
+        DROP TABLE issue_embeddings_without_vectors;
+        "#,
+    )?;
+    Ok(())
+}
+
+/// カラムが存在しない場合に追加する
+fn add_column_if_not_exists(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    column_type: &str,
+) -> DomainResult<()> {
+    if !column_exists(conn, table, column)? {
+        // Identifiers cannot be bound as parameters; they are compile-time constants here
+        let alter_sql = format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            table, column, column_type
+        );
+        conn.execute(&alter_sql, []).map_err(|e| {
+            DomainError::Repository(format!("Failed to add column {}.{}: {}", table, column, e))
+        })?;
+        log::info!("Migration: Added column {}.{}", table, column);
+    }
+    Ok(())
+}
+
+fn column_exists(conn: &Connection, table: &str, column: &str) -> DomainResult<bool> {
+    let count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM information_schema.columns WHERE table_name = ? AND column_name = ?",
+            duckdb::params![table, column],
+            |row| row.get(0),
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to check column existence: {}", e))
+        })?;
+    Ok(count > 0)
+}
+
+fn table_exists(conn: &Connection, table: &str) -> DomainResult<bool> {
+    let count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM information_schema.tables WHERE table_name = ?",
+            duckdb::params![table],
+            |row| row.get(0),
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to check table existence: {}", e)))?;
+    Ok(count > 0)
+}
+
+fn create_migrations_table(conn: &Connection) -> DomainResult<()> {
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            version BIGINT PRIMARY KEY,
+            description VARCHAR NOT NULL,
+            applied_at TIMESTAMPTZ NOT NULL,
+            app_version VARCHAR
+        )
+        "#,
+        [],
+    )
+    .map_err(|e| {
+        DomainError::Repository(format!("Failed to create schema_migrations table: {}", e))
+    })?;
+    Ok(())
+}
+
+/// Migrations recorded in the database file, in version order
+fn applied_migrations(conn: &Connection) -> DomainResult<Vec<MigrationStatus>> {
+    if !table_exists(conn, "schema_migrations")? {
+        return Ok(Vec::new());
+    }
+
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT version, description,
+                   strftime(applied_at::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' as applied_at,
+                   app_version
+            FROM schema_migrations
+            ORDER BY version
+            "#,
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            let applied_at: String = row.get(2)?;
+            Ok(MigrationStatus {
+                version: row.get(0)?,
+                description: row.get(1)?,
+                applied_at: Some(
+                    DateTime::parse_from_rfc3339(&applied_at)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                ),
+                applied_by: row.get(3)?,
+            })
+        })
+        .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| DomainError::Repository(e.to_string()))
+}
+
+/// Applied and pending migrations, in version order
+pub(super) fn status(conn: &Connection, kind: SchemaKind) -> DomainResult<Vec<MigrationStatus>> {
+    let mut statuses = applied_migrations(conn)?;
+    for migration in kind.migrations() {
+        if !statuses.iter().any(|s| s.version == migration.version) {
+            statuses.push(migration.pending_status());
+        }
+    }
+    statuses.sort_by_key(|s| s.version);
+    Ok(statuses)
+}
+
+/// Refuse databases migrated by a newer jira-db, whose layout this build cannot know
+pub(super) fn ensure_supported(conn: &Connection, kind: SchemaKind) -> DomainResult<()> {
+    let latest = kind.latest_version();
+    let Some(newest) = applied_migrations(conn)?.pop() else {
+        return Ok(());
+    };
+    if newest.version > latest {
+        return Err(DomainError::Configuration(format!(
+            "Database schema version {} was written by jira-db {}, but this jira-db ({}) supports up to version {}. Upgrade jira-db to open it",
+            newest.version,
+            newest.applied_by.as_deref().unwrap_or("(unknown)"),
+            APP_VERSION,
+            latest
+        )));
+    }
+    Ok(())
+}
+
+fn pending(conn: &Connection, kind: SchemaKind) -> DomainResult<Vec<&'static Migration>> {
+    let applied = applied_migrations(conn)?;
+    Ok(kind
+        .migrations()
+        .iter()
+        .filter(|m| !applied.iter().any(|a| a.version == m.version))
+        .collect())
+}
+
+fn apply(conn: &Connection, migration: &Migration) -> DomainResult<()> {
+    (migration.up)(conn).map_err(|e| {
+        DomainError::Repository(format!(
+            "Migration {} ({}) failed: {}",
+            migration.version, migration.description, e
+        ))
+    })?;
+    conn.execute(
+        "INSERT INTO schema_migrations (version, description, applied_at, app_version) VALUES (?, ?, ?, ?)",
+        duckdb::params![
+            migration.version,
+            migration.description,
+            Utc::now().to_rfc3339(),
+            APP_VERSION
+        ],
+    )
+    .map_err(|e| DomainError::Repository(format!("Failed to record migration: {}", e)))?;
+    Ok(())
+}
+
+fn execute_batch(conn: &Connection, sql: &str) -> DomainResult<()> {
+    conn.execute_batch(sql)
+        .map_err(|e| DomainError::Repository(format!("Failed to execute {}: {}", sql, e)))
+}
+
+/// Apply pending migrations, each in its own transaction.
+///
+/// Returns the migrations that were applied.
+pub(super) fn migrate(conn: &Connection, kind: SchemaKind) -> DomainResult<Vec<MigrationStatus>> {
+    create_migrations_table(conn)?;
+
+    let mut applied = Vec::new();
+    for migration in pending(conn, kind)? {
+        execute_batch(conn, "BEGIN TRANSACTION")?;
+        if let Err(e) = apply(conn, migration) {
+            let _ = conn.execute_batch("ROLLBACK");
+            return Err(e);
+        }
+        execute_batch(conn, "COMMIT")?;
+
+        log::info!(
+            "Migration: Applied {} ({})",
+            migration.version,
+            migration.description
+        );
+        applied.push(migration.pending_status());
+    }
+    Ok(applied)
+}
+
+/// Run `prepare` and the pending migrations in one transaction that is always rolled back.
+///
+/// Returns the migrations that would be applied, or the first error.
+pub(super) fn dry_run(
+    conn: &Connection,
+    kind: SchemaKind,
+    prepare: impl FnOnce() -> DomainResult<()>,
+) -> DomainResult<Vec<MigrationStatus>> {
+    let pending = pending(conn, kind)?;
+
+    execute_batch(conn, "BEGIN TRANSACTION")?;
+    let result = prepare()
+        .and_then(|_| create_migrations_table(conn))
+        .and_then(|_| pending.iter().try_for_each(|m| apply(conn, m)));
+    execute_batch(conn, "ROLLBACK")?;
+    result?;
+
+    Ok(pending.iter().map(|m| m.pending_status()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_change_history_without_source(conn: &Connection) {
+        conn.execute_batch(
+            "CREATE TABLE issue_change_history (id INTEGER, field VARCHAR);
+             INSERT INTO issue_change_history VALUES (1, 'status');",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_migrate_records_versions_once() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_change_history_without_source(&conn);
+
+        // Only the change history table exists, so run the migration that touches it
+        create_migrations_table(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO schema_migrations VALUES (1, 'legacy', CURRENT_TIMESTAMP, '0.0.0')",
+            [],
+        )
+        .unwrap();
+
+        let applied = migrate(&conn, SchemaKind::Main).unwrap();
+        assert_eq!(applied.len(), 2);
+        assert_eq!(applied[0].version, 2);
+
+        let source: String = conn
+            .query_row("SELECT source FROM issue_change_history", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(source, "changelog");
+
+        assert!(migrate(&conn, SchemaKind::Main).unwrap().is_empty());
+        let statuses = status(&conn, SchemaKind::Main).unwrap();
+        assert!(statuses.iter().all(|s| !s.is_pending()));
+    }
+
+    #[test]
+    fn test_split_embedding_vectors() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            r#"
+            CREATE TABLE issue_embeddings (
+                issue_id VARCHAR PRIMARY KEY,
+                issue_key VARCHAR NOT NULL,
+                embedding FLOAT[] NOT NULL,
+                embedded_text TEXT NOT NULL,
+                provider VARCHAR NOT NULL DEFAULT 'openai',
+                model VARCHAR NOT NULL DEFAULT 'text-embedding-3-small',
+                dimensions INTEGER NOT NULL DEFAULT 1536,
+                created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            );
+            INSERT INTO issue_embeddings (issue_id, issue_key, embedding, embedded_text, model) VALUES
+                ('1', 'PROJ-1', [1.0, 0.0, 0.0], 'a', 'small'),
+                ('2', 'PROJ-2', [0.0, 1.0, 0.0], 'b', 'small'),
+                ('3', 'PROJ-3', [0.5, 0.5], 'c', 'nomic');
+            "#,
+        )
+        .unwrap();
+
+        split_embedding_vectors(&conn).unwrap();
+
+        assert!(!column_exists(&conn, "issue_embeddings", "embedding").unwrap());
+        let spaces = EmbeddingsRepository::spaces(&conn).unwrap();
+        assert_eq!(spaces.len(), 2);
+        let small = spaces.iter().find(|s| s.model == "small").unwrap();
+        assert_eq!(small.dimensions, 3);
+        let moved: i64 = conn
+            .query_row(
+                &format!("SELECT COUNT(*) FROM {}", small.table()),
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(moved, 2);
+        let dimensions: i64 = conn
+            .query_row(
+                "SELECT dimensions FROM issue_embeddings WHERE issue_id = '3'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(dimensions, 2);
+
+        // The new layout is left alone
+        split_embedding_vectors(&conn).unwrap();
+        assert_eq!(EmbeddingsRepository::spaces(&conn).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_dry_run_rolls_back() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_change_history_without_source(&conn);
+        create_migrations_table(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO schema_migrations VALUES (1, 'legacy', CURRENT_TIMESTAMP, '0.0.0')",
+            [],
+        )
+        .unwrap();
+
+        let pending = dry_run(&conn, SchemaKind::Main, || Ok(())).unwrap();
+        assert_eq!(pending.len(), 2);
+        assert!(!column_exists(&conn, "issue_change_history", "source").unwrap());
+        assert_eq!(status(&conn, SchemaKind::Main).unwrap()[1].version, 2);
+        assert!(status(&conn, SchemaKind::Main).unwrap()[1].is_pending());
+    }
+
+    #[test]
+    fn test_refuses_newer_database() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_migrations_table(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO schema_migrations VALUES (999, 'future', CURRENT_TIMESTAMP, '99.0.0')",
+            [],
+        )
+        .unwrap();
+
+        let err = ensure_supported(&conn, SchemaKind::Main).unwrap_err();
+        assert!(err.to_string().contains("99.0.0"));
+        assert!(ensure_supported(&conn, SchemaKind::Raw).is_err());
+    }
+}
@@ -1,11 +1,26 @@
 mod connection;
+mod federated;
+mod maintenance;
+mod migrations;
+mod pool;
 mod repositories;
 mod schema;
+#[cfg(test)]
+pub(crate) mod test_support;
 
-pub use connection::{Database, DatabaseFactory, DbConnection, checkpoint_connection};
+pub use connection::{
+    Database, DatabaseAccess, DatabaseFactory, DbConnection, checkpoint_connection,
+};
+pub use federated::FederatedDatabase;
+pub use maintenance::{BlockUsage, DatabaseMaintenance, IntegrityFinding, TableSize};
+pub use migrations::{MigrationStatus, SchemaKind};
+pub use pool::{ConnectionPool, DEFAULT_READ_POOL_SIZE};
 pub use repositories::{
     DuckDbChangeHistoryRepository, DuckDbFieldRepository, DuckDbIssueRepository,
     DuckDbIssueSnapshotRepository, DuckDbIssuesExpandedRepository, DuckDbMetadataRepository,
-    DuckDbProjectRepository, DuckDbSyncHistoryRepository, EmbeddingsRepository, IssueEmbedding,
-    RawDataRepository, SemanticSearchResult, SharedRawDataRepository,
+    DuckDbPendingOperationRepository, DuckDbProjectRepository, DuckDbSyncHistoryRepository,
+    DuckDbTopicRepository, EmbeddedIssue, EmbeddingNeighbor, EmbeddingsRepository,
+    FullTextSearchRepository, IssueEmbedding, NeighborFilter, RawDataRepository, RawIssueVersion,
+    SemanticSearchResult, SharedRawDataRepository,
 };
+pub(crate) use repositories::{is_cjk, tokenize};
@@ -0,0 +1,152 @@
+//! Per-database connection pool
+//!
+//! DuckDB allows several connections to the same database instance within one process
+//! (`Connection::try_clone`), each running its own transaction. A pool keeps the original
+//! connection as the single writer used by sync and hands out a few cloned reader
+//! connections for queries, so one slow query no longer blocks every other request.
+//!
+//! DuckDB fixes the access mode per database instance, not per connection. Pools for files
+//! this process only reads (published replicas) are therefore opened with
+//! [`ConnectionPool::open_read_only`], and their readers reject writes. Readers cloned from
+//! a read-write writer share its access mode; that is what lets them see sync's commits
+//! immediately, so read-only SQL must still be enforced by the caller (`ExecuteSqlUseCase`).
+
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::connection::{Database, DbConnection};
+use crate::domain::error::{DomainError, DomainResult};
+
+/// Default number of reader connections per database
+pub const DEFAULT_READ_POOL_SIZE: usize = 4;
+
+pub struct ConnectionPool {
+    writer: DbConnection,
+    readers: Vec<DbConnection>,
+    next: AtomicUsize,
+}
+
+impl ConnectionPool {
+    /// Create a pool around `writer` with `size` reader connections cloned from it
+    ///
+    /// A size of 0 disables the readers and every caller shares the writer.
+    pub fn new(writer: DbConnection, size: usize) -> DomainResult<Self> {
+        let mut readers = Vec::with_capacity(size);
+        {
+            let conn = writer.lock().map_err(|e| {
+                DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+            })?;
+            for _ in 0..size {
+                let reader = conn.try_clone().map_err(|e| {
+                    DomainError::Repository(format!("Failed to open reader connection: {}", e))
+                })?;
+                readers.push(Arc::new(Mutex::new(reader)));
+            }
+        }
+
+        Ok(Self {
+            writer,
+            readers,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Open an existing file with `AccessMode::ReadOnly` and pool readers on it
+    ///
+    /// Every connection of the pool, including [`Self::writer`], is read-only.
+    pub fn open_read_only(path: &Path, size: usize) -> DomainResult<Self> {
+        Self::new(Database::open_read_only(path)?.connection(), size)
+    }
+
+    /// The single connection used for writes (sync, imports, migrations)
+    pub fn writer(&self) -> DbConnection {
+        self.writer.clone()
+    }
+
+    /// A reader connection for queries, preferring one that is not currently in use
+    ///
+    /// When every reader is busy the caller queues on one of them in round-robin order.
+    pub fn reader(&self) -> DbConnection {
+        if self.readers.is_empty() {
+            return self.writer.clone();
+        }
+
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let count = self.readers.len();
+        (0..count)
+            .map(|offset| &self.readers[(start + offset) % count])
+            .find(|reader| reader.try_lock().is_ok())
+            .unwrap_or(&self.readers[start % count])
+            .clone()
+    }
+
+    /// Number of reader connections
+    pub fn size(&self) -> usize {
+        self.readers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duckdb::Connection;
+
+    #[test]
+    fn test_readers_see_writer_commits() {
+        let writer: DbConnection = Arc::new(Mutex::new(Connection::open_in_memory().unwrap()));
+        let pool = ConnectionPool::new(writer, 2).unwrap();
+        assert_eq!(pool.size(), 2);
+
+        pool.writer()
+            .lock()
+            .unwrap()
+            .execute_batch("CREATE TABLE t (n INTEGER); INSERT INTO t VALUES (1), (2);")
+            .unwrap();
+
+        // A busy reader is skipped in favour of an idle one
+        let first = pool.reader();
+        let _busy = first.lock().unwrap();
+        let second = pool.reader();
+        assert!(!Arc::ptr_eq(&first, &second));
+
+        let count: i64 = second
+            .lock()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_read_only_pool_rejects_writes() {
+        let path = std::env::temp_dir().join(format!("jira-db-pool-{}.duckdb", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch("CREATE TABLE t (n INTEGER); INSERT INTO t VALUES (1);")
+                .unwrap();
+        }
+
+        let pool = ConnectionPool::open_read_only(&path, 2).unwrap();
+        let reader = pool.reader();
+        let reader = reader.lock().unwrap();
+        let count: i64 = reader
+            .query_row("SELECT COUNT(*) FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+        assert!(reader.execute_batch("INSERT INTO t VALUES (2)").is_err());
+        assert!(reader.execute_batch("CREATE TABLE u (n INTEGER)").is_err());
+
+        drop(reader);
+        drop(pool);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_empty_pool_falls_back_to_writer() {
+        let writer: DbConnection = Arc::new(Mutex::new(Connection::open_in_memory().unwrap()));
+        let pool = ConnectionPool::new(writer.clone(), 0).unwrap();
+        assert!(Arc::ptr_eq(&pool.reader(), &writer));
+    }
+}
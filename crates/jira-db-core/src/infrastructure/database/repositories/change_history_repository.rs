@@ -36,9 +36,9 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                     author_account_id, author_display_name,
                     field, field_type,
                     from_value, from_string, to_value, to_string,
-                    changed_at, created_at
+                    changed_at, source, created_at
                 )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
                 duckdb::params![
                     &item.issue_id,
@@ -53,6 +53,7 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                     &item.to_value,
                     &item.to_string,
                     &item.changed_at.to_rfc3339(),
+                    &item.source,
                     &now,
                 ],
             )
@@ -76,6 +77,18 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
         Ok(())
     }
 
+    fn delete_changelog_by_issue_id(&self, issue_id: &str) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        conn.execute(
+            "DELETE FROM issue_change_history WHERE issue_id = ? AND COALESCE(source, 'changelog') = 'changelog'",
+            duckdb::params![issue_id],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to delete change history: {}", e)))?;
+        Ok(())
+    }
+
     fn find_by_issue_key(&self, issue_key: &str) -> DomainResult<Vec<ChangeHistoryItem>> {
         let conn = self.conn.lock().map_err(|e| {
             DomainError::Repository(format!("Failed to acquire database lock: {}", e))
@@ -87,7 +100,8 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                    author_account_id, author_display_name,
                    field, field_type,
                    from_value, from_string, to_value, to_string,
-                   CAST(changed_at AS VARCHAR) as changed_at
+                   CAST(changed_at AS VARCHAR) as changed_at,
+                   COALESCE(source, 'changelog') as source
             FROM issue_change_history
             WHERE issue_key = ?
             ORDER BY changed_at DESC
@@ -113,6 +127,7 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                         .get::<_, String>(11)?
                         .parse::<DateTime<Utc>>()
                         .unwrap_or_else(|_| Utc::now()),
+                    source: row.get(12)?,
                 })
             })
             .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
@@ -140,7 +155,8 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                    author_account_id, author_display_name,
                    field, field_type,
                    from_value, from_string, to_value, to_string,
-                   CAST(changed_at AS VARCHAR) as changed_at
+                   CAST(changed_at AS VARCHAR) as changed_at,
+                   COALESCE(source, 'changelog') as source
             FROM issue_change_history
             WHERE issue_key = ? AND field = ?
             ORDER BY changed_at DESC
@@ -151,7 +167,8 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                    author_account_id, author_display_name,
                    field, field_type,
                    from_value, from_string, to_value, to_string,
-                   CAST(changed_at AS VARCHAR) as changed_at
+                   CAST(changed_at AS VARCHAR) as changed_at,
+                   COALESCE(source, 'changelog') as source
             FROM issue_change_history
             WHERE issue_key = ?
             ORDER BY changed_at DESC
@@ -180,6 +197,7 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                         .get::<_, String>(11)?
                         .parse::<DateTime<Utc>>()
                         .unwrap_or_else(|_| Utc::now()),
+                    source: row.get(12)?,
                 })
             })
             .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?
@@ -202,6 +220,7 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
                         .get::<_, String>(11)?
                         .parse::<DateTime<Utc>>()
                         .unwrap_or_else(|_| Utc::now()),
+                    source: row.get(12)?,
                 })
             })
             .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?
@@ -232,4 +251,79 @@ impl ChangeHistoryRepository for DuckDbChangeHistoryRepository {
 
         Ok(count as usize)
     }
+
+    fn find_by_field(&self, field: &str) -> DomainResult<Vec<ChangeHistoryItem>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        let mut stmt = conn
+            .prepare(
+                r#"
+            SELECT issue_id, issue_key, history_id,
+                   author_account_id, author_display_name,
+                   field, field_type,
+                   from_value, from_string, to_value, to_string,
+                   CAST(changed_at AS VARCHAR) as changed_at,
+                   COALESCE(source, 'changelog') as source
+            FROM issue_change_history
+            WHERE field = ?
+            ORDER BY changed_at
+            "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(duckdb::params![field], |row| {
+                Ok(ChangeHistoryItem {
+                    issue_id: row.get(0)?,
+                    issue_key: row.get(1)?,
+                    history_id: row.get(2)?,
+                    author_account_id: row.get(3)?,
+                    author_display_name: row.get(4)?,
+                    field: row.get(5)?,
+                    field_type: row.get(6)?,
+                    from_value: row.get(7)?,
+                    from_string: row.get(8)?,
+                    to_value: row.get(9)?,
+                    to_string: row.get(10)?,
+                    changed_at: row
+                        .get::<_, String>(11)?
+                        .parse::<DateTime<Utc>>()
+                        .unwrap_or_else(|_| Utc::now()),
+                    source: row.get(12)?,
+                })
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let mut items = Vec::new();
+        for item in rows {
+            items.push(item.map_err(|e| DomainError::Repository(e.to_string()))?);
+        }
+
+        Ok(items)
+    }
+
+    fn find_status_transitions(&self) -> DomainResult<Vec<(String, String)>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT DISTINCT from_string, to_string
+                FROM issue_change_history
+                WHERE field = 'status' AND from_string IS NOT NULL AND to_string IS NOT NULL
+                ORDER BY from_string, to_string
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DomainError::Repository(e.to_string()))
+    }
 }
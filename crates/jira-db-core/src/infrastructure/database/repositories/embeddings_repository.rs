@@ -1,11 +1,14 @@
 //! Repository for issue embeddings
 //!
 //! Provides storage and retrieval of vector embeddings for semantic search.
+//! Vectors are kept in one fixed-size `FLOAT[N]` table per model and dimension
+//! (an "embedding space") so each can carry an HNSW index.
 
 use duckdb::Connection;
 use std::sync::{Arc, Mutex};
 
 use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::DateRange;
 
 /// Represents an issue with its embedding
 #[derive(Debug, Clone)]
@@ -45,6 +48,64 @@ pub struct SemanticSearchResult {
     pub similarity_score: f32,
 }
 
+/// Stored embedding of a non-deleted issue
+#[derive(Debug, Clone)]
+pub struct EmbeddedIssue {
+    pub issue_id: String,
+    pub issue_key: String,
+    pub embedding: Vec<f32>,
+    /// Model that produced the embedding
+    pub model: String,
+}
+
+/// Stored embedding close to a query vector
+#[derive(Debug, Clone)]
+pub struct EmbeddingNeighbor {
+    pub issue_id: String,
+    pub issue_key: String,
+    /// Cosine distance to the query vector (0 = same direction)
+    pub distance: f32,
+}
+
+/// Restrictions on the issues returned by [`EmbeddingsRepository::similar_to`]
+#[derive(Debug, Clone, Default)]
+pub struct NeighborFilter {
+    /// Only issues of this project
+    pub project_id: Option<String>,
+    /// Leave out issues with a resolution or in the "done" status category
+    pub exclude_resolved: bool,
+    /// Only issues created within this window
+    pub created: DateRange,
+}
+
+/// Vectors of one embedding model and dimension, stored in their own `FLOAT[N]` table
+#[derive(Debug, Clone)]
+pub(crate) struct EmbeddingSpace {
+    pub id: i64,
+    pub model: String,
+    pub dimensions: usize,
+}
+
+impl EmbeddingSpace {
+    /// Table holding the vectors of this space
+    pub fn table(&self) -> String {
+        format!("issue_embedding_vectors_{}", self.id)
+    }
+
+    fn index(&self) -> String {
+        format!("idx_embeddings_hnsw_{}", self.id)
+    }
+
+    /// `vector` as a constant of the table's column type
+    fn vector_literal(&self, vector: &[f32]) -> String {
+        format!(
+            "'{}'::FLOAT[{}]",
+            embedding_literal(vector),
+            self.dimensions
+        )
+    }
+}
+
 /// Repository for managing issue embeddings
 pub struct EmbeddingsRepository {
     conn: Arc<Mutex<Connection>>,
@@ -56,7 +117,8 @@ impl EmbeddingsRepository {
         Self { conn }
     }
 
-    /// Initialize the embeddings table and load VSS extension
+    /// Initialize the embeddings tables, load the VSS extension and create the
+    /// HNSW index of every embedding space
     pub fn init_schema(&self) -> DomainResult<()> {
         let conn = self.conn.lock().map_err(|e| {
             DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
@@ -75,14 +137,26 @@ impl EmbeddingsRepository {
                 DomainError::Repository(format!("Failed to enable HNSW persistence: {}", e))
             })?;
 
-        // Create embeddings table with ARRAY type for vectors
-        // Using FLOAT[] (variable length) to support different embedding providers
+        Self::create_table(&conn)?;
+        for space in Self::spaces(&conn)? {
+            Self::create_index(&conn, &space)?;
+        }
+
+        log::info!("Initialized embeddings schema with VSS extension");
+        Ok(())
+    }
+
+    /// Create the embeddings tables (no extension needed)
+    ///
+    /// `issue_embeddings` holds what was embedded and by which model; the vectors live in
+    /// one `FLOAT[N]` table per model and dimension listed in `embedding_spaces`, because
+    /// the HNSW index only works on fixed-size arrays.
+    pub(crate) fn create_table(conn: &Connection) -> DomainResult<()> {
         conn.execute(
             r#"
             CREATE TABLE IF NOT EXISTS issue_embeddings (
                 issue_id VARCHAR PRIMARY KEY,
                 issue_key VARCHAR NOT NULL,
-                embedding FLOAT[] NOT NULL,
                 embedded_text TEXT NOT NULL,
                 provider VARCHAR NOT NULL DEFAULT 'openai',
                 model VARCHAR NOT NULL DEFAULT 'text-embedding-3-small',
@@ -96,23 +170,158 @@ impl EmbeddingsRepository {
             DomainError::Repository(format!("Failed to create issue_embeddings table: {}", e))
         })?;
 
-        // Create HNSW index for fast similarity search
-        // Use cosine metric which is most appropriate for text embeddings
         conn.execute(
             r#"
-            CREATE INDEX IF NOT EXISTS idx_embeddings_hnsw
-            ON issue_embeddings
-            USING HNSW (embedding)
-            WITH (metric = 'cosine')
+            CREATE TABLE IF NOT EXISTS embedding_spaces (
+                id INTEGER PRIMARY KEY,
+                model VARCHAR NOT NULL,
+                dimensions INTEGER NOT NULL,
+                UNIQUE (model, dimensions)
+            )
             "#,
             [],
         )
-        .map_err(|e| DomainError::Repository(format!("Failed to create HNSW index: {}", e)))?;
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to create embedding_spaces table: {}", e))
+        })?;
 
-        log::info!("Initialized embeddings schema with VSS extension");
         Ok(())
     }
 
+    /// Registered embedding spaces, oldest first
+    pub(crate) fn spaces(conn: &Connection) -> DomainResult<Vec<EmbeddingSpace>> {
+        let mut stmt = conn
+            .prepare("SELECT id, model, dimensions FROM embedding_spaces ORDER BY id")
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to prepare embedding spaces query: {}", e))
+            })?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(EmbeddingSpace {
+                    id: row.get(0)?,
+                    model: row.get(1)?,
+                    dimensions: row.get::<_, i64>(2)? as usize,
+                })
+            })
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to query embedding spaces: {}", e))
+            })?;
+
+        rows.collect::<Result<_, _>>().map_err(|e| {
+            DomainError::Repository(format!("Failed to read embedding space row: {}", e))
+        })
+    }
+
+    /// The space of `model` and `dimensions`, registering it and creating its vector
+    /// table on first use. The HNSW index is created too when VSS is loaded; otherwise
+    /// [`Self::init_schema`] creates it later.
+    pub(crate) fn ensure_space(
+        conn: &Connection,
+        model: &str,
+        dimensions: usize,
+    ) -> DomainResult<EmbeddingSpace> {
+        let existing = Self::spaces(conn)?
+            .into_iter()
+            .find(|space| space.model == model && space.dimensions == dimensions);
+        let space = match existing {
+            Some(space) => space,
+            None => {
+                let id: i64 = conn
+                    .query_row(
+                        r#"
+                        INSERT INTO embedding_spaces (id, model, dimensions)
+                        SELECT COALESCE(MAX(id), 0) + 1, ?, ? FROM embedding_spaces
+                        RETURNING id
+                        "#,
+                        duckdb::params![model, dimensions as i64],
+                        |row| row.get(0),
+                    )
+                    .map_err(|e| {
+                        DomainError::Repository(format!(
+                            "Failed to register embedding space: {}",
+                            e
+                        ))
+                    })?;
+                EmbeddingSpace {
+                    id,
+                    model: model.to_string(),
+                    dimensions,
+                }
+            }
+        };
+
+        // Identifiers and sizes cannot be bound as parameters; both come from the registry
+        conn.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} (issue_id VARCHAR NOT NULL, embedding FLOAT[{}] NOT NULL)",
+                space.table(),
+                space.dimensions
+            ),
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to create {}: {}", space.table(), e))
+        })?;
+        if Self::vss_loaded(conn)? {
+            Self::create_index(conn, &space)?;
+        }
+
+        Ok(space)
+    }
+
+    /// Create the HNSW index of a space, using the cosine metric that suits text embeddings
+    fn create_index(conn: &Connection, space: &EmbeddingSpace) -> DomainResult<()> {
+        conn.execute(
+            &format!(
+                "CREATE INDEX IF NOT EXISTS {} ON {} USING HNSW (embedding) WITH (metric = 'cosine')",
+                space.index(),
+                space.table()
+            ),
+            [],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to create HNSW index: {}", e)))?;
+        Ok(())
+    }
+
+    fn vss_loaded(conn: &Connection) -> DomainResult<bool> {
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM duckdb_extensions() WHERE extension_name = 'vss' AND loaded",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to check VSS extension: {}", e))
+            })?;
+        Ok(count > 0)
+    }
+
+    /// Space holding the vector of an issue, if it has one
+    fn space_of(conn: &Connection, issue_id: &str) -> DomainResult<Option<EmbeddingSpace>> {
+        let result = conn.query_row(
+            "SELECT model, dimensions FROM issue_embeddings WHERE issue_id = ?",
+            [issue_id],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)),
+        );
+        let space = match result {
+            Ok(space) => Some(space),
+            Err(duckdb::Error::QueryReturnedNoRows) => None,
+            Err(e) => {
+                return Err(DomainError::Repository(format!(
+                    "Failed to read embedding model: {}",
+                    e
+                )));
+            }
+        };
+
+        let Some((model, dimensions)) = space else {
+            return Ok(None);
+        };
+        Ok(Self::spaces(conn)?
+            .into_iter()
+            .find(|space| space.model == model && space.dimensions == dimensions as usize))
+    }
+
     /// Insert or update an embedding for an issue
     pub fn upsert_embedding(
         &self,
@@ -123,43 +332,173 @@ impl EmbeddingsRepository {
         provider: &str,
         model: &str,
     ) -> DomainResult<()> {
+        if embedding.is_empty() || embedding.iter().any(|value| !value.is_finite()) {
+            return Err(DomainError::Validation(format!(
+                "Embedding of {} is empty or not finite",
+                issue_key
+            )));
+        }
+
         let conn = self.conn.lock().map_err(|e| {
             DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
         })?;
 
-        // Convert embedding to array string format for DuckDB
-        let embedding_str = format!(
-            "[{}]",
-            embedding
-                .iter()
-                .map(|f| f.to_string())
-                .collect::<Vec<_>>()
-                .join(",")
+        let spaces = Self::spaces(&conn)?;
+        let space = Self::ensure_space(&conn, model, embedding.len())?;
+
+        conn.execute("BEGIN TRANSACTION", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to begin transaction: {}", e)))?;
+        let written = Self::write_embedding(
+            &conn,
+            &spaces,
+            &space,
+            issue_id,
+            issue_key,
+            embedding,
+            embedded_text,
+            provider,
         );
+        if let Err(e) = written {
+            let _ = conn.execute("ROLLBACK", []);
+            return Err(e);
+        }
+        conn.execute("COMMIT", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to commit transaction: {}", e)))?;
 
-        let dimensions = embedding.len() as i32;
+        Ok(())
+    }
+
+    /// Replace the vector of an issue (which may move to another space) and its metadata
+    #[allow(clippy::too_many_arguments)]
+    fn write_embedding(
+        conn: &Connection,
+        spaces: &[EmbeddingSpace],
+        space: &EmbeddingSpace,
+        issue_id: &str,
+        issue_key: &str,
+        embedding: &[f32],
+        embedded_text: &str,
+        provider: &str,
+    ) -> DomainResult<()> {
+        for existing in spaces {
+            conn.execute(
+                &format!("DELETE FROM {} WHERE issue_id = ?", existing.table()),
+                [issue_id],
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to replace embedding: {}", e)))?;
+        }
+        conn.execute(
+            &format!(
+                "INSERT INTO {} (issue_id, embedding) VALUES (?, ?::FLOAT[{}])",
+                space.table(),
+                space.dimensions
+            ),
+            duckdb::params![issue_id, embedding_literal(embedding)],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to insert embedding: {}", e)))?;
 
         conn.execute(
             r#"
-            INSERT INTO issue_embeddings (issue_id, issue_key, embedding, embedded_text, provider, model, dimensions, created_at)
-            VALUES (?, ?, ?::FLOAT[], ?, ?, ?, ?, CURRENT_TIMESTAMP)
+            INSERT INTO issue_embeddings (issue_id, issue_key, embedded_text, provider, model, dimensions, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
             ON CONFLICT (issue_id) DO UPDATE SET
                 issue_key = excluded.issue_key,
-                embedding = excluded.embedding,
                 embedded_text = excluded.embedded_text,
                 provider = excluded.provider,
                 model = excluded.model,
                 dimensions = excluded.dimensions,
                 created_at = CURRENT_TIMESTAMP
             "#,
-            duckdb::params![issue_id, issue_key, embedding_str, embedded_text, provider, model, dimensions],
+            duckdb::params![
+                issue_id,
+                issue_key,
+                embedded_text,
+                provider,
+                space.model,
+                space.dimensions as i64
+            ],
         )
         .map_err(|e| DomainError::Repository(format!("Failed to upsert embedding: {}", e)))?;
 
         Ok(())
     }
 
+    /// The `limit` closest issues to `vector` within one space, closest first, among
+    /// non-deleted issues also matching `conditions` (SQL over `e` = issue_embeddings
+    /// and `i` = issues, each starting with `AND`).
+    ///
+    /// The inner query is a plain `ORDER BY distance LIMIT k` over the vector table with
+    /// the vector inlined as a constant, which is the shape the HNSW index serves. The
+    /// filters are applied to its result, so it fetches more candidates until `limit`
+    /// rows pass them or the whole table has been considered.
+    #[allow(clippy::too_many_arguments)]
+    fn top_k<T>(
+        conn: &Connection,
+        space: &EmbeddingSpace,
+        vector: &[f32],
+        columns: &str,
+        conditions: &str,
+        params: &[Box<dyn duckdb::ToSql>],
+        limit: usize,
+        map: impl Fn(&duckdb::Row) -> Result<T, duckdb::Error>,
+    ) -> DomainResult<Vec<T>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let total: i64 = conn
+            .query_row(
+                &format!("SELECT COUNT(*) FROM {}", space.table()),
+                [],
+                |row| row.get(0),
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to count embeddings: {}", e)))?;
+        let total = total as usize;
+        let vector = space.vector_literal(vector);
+        let param_refs: Vec<&dyn duckdb::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+
+        let mut fetch = limit * 2 + 16;
+        loop {
+            let sql = format!(
+                r#"
+                SELECT {columns}
+                FROM (
+                    SELECT issue_id, array_cosine_distance(embedding, {vector}) AS distance
+                    FROM {table}
+                    ORDER BY array_cosine_distance(embedding, {vector})
+                    LIMIT {fetch}
+                ) v
+                JOIN issue_embeddings e ON e.issue_id = v.issue_id
+                JOIN issues i ON i.id = v.issue_id
+                WHERE (i.is_deleted IS NULL OR i.is_deleted = false){conditions}
+                ORDER BY v.distance, e.issue_key
+                LIMIT {limit}
+                "#,
+                table = space.table(),
+            );
+            let mut stmt = conn.prepare(&sql).map_err(|e| {
+                DomainError::Repository(format!("Failed to prepare nearest neighbor query: {}", e))
+            })?;
+            let rows = stmt
+                .query_map(param_refs.as_slice(), &map)
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to query nearest neighbors: {}", e))
+                })?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to read neighbor row: {}", e))
+                })?;
+
+            if rows.len() >= limit || fetch >= total {
+                return Ok(rows);
+            }
+            fetch = fetch.saturating_mul(4);
+        }
+    }
+
     /// Perform semantic search using the query embedding
+    ///
+    /// Every embedding space with the query's dimension is searched.
     pub fn semantic_search(
         &self,
         query_embedding: &[f32],
@@ -170,89 +509,193 @@ impl EmbeddingsRepository {
             DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
         })?;
 
-        // Convert query embedding to array string format
-        let embedding_str = format!(
-            "[{}]",
-            query_embedding
-                .iter()
-                .map(|f| f.to_string())
-                .collect::<Vec<_>>()
-                .join(",")
-        );
+        let mut conditions = String::new();
+        let mut params: Vec<Box<dyn duckdb::ToSql>> = Vec::new();
+        if let Some(project) = project_filter {
+            conditions.push_str(" AND i.project_id = ?");
+            params.push(Box::new(project.to_string()));
+        }
 
-        // Build the query with optional project filter
-        // Using FLOAT[] cast to support variable-length embeddings from different providers
-        let query = match project_filter {
-            Some(_) => {
-                r#"
-                SELECT
-                    e.issue_key,
-                    i.summary,
-                    i.description,
-                    i.status,
-                    i.project_id,
-                    array_cosine_distance(e.embedding, ?::FLOAT[]) as distance
-                FROM issue_embeddings e
-                JOIN issues i ON e.issue_id = i.id
-                WHERE i.project_id = ?
-                ORDER BY distance ASC
-                LIMIT ?
-                "#
-            }
-            None => {
-                r#"
-                SELECT
-                    e.issue_key,
-                    i.summary,
-                    i.description,
-                    i.status,
-                    i.project_id,
-                    array_cosine_distance(e.embedding, ?::FLOAT[]) as distance
-                FROM issue_embeddings e
-                JOIN issues i ON e.issue_id = i.id
-                ORDER BY distance ASC
-                LIMIT ?
-                "#
+        let mut search_results = Vec::new();
+        for space in Self::spaces(&conn)? {
+            if space.dimensions != query_embedding.len() {
+                continue;
             }
-        };
+            search_results.extend(Self::top_k(
+                &conn,
+                &space,
+                query_embedding,
+                "e.issue_key, i.summary, i.description, i.status, i.project_id, v.distance",
+                &conditions,
+                &params,
+                limit,
+                |row| {
+                    Ok(SemanticSearchResult {
+                        issue_key: row.get(0)?,
+                        summary: row.get(1)?,
+                        description: row.get(2)?,
+                        status: row.get(3)?,
+                        project_id: row.get(4)?,
+                        similarity_score: row.get(5)?,
+                    })
+                },
+            )?);
+        }
 
-        let mut stmt = conn.prepare(&query).map_err(|e| {
-            DomainError::Repository(format!("Failed to prepare semantic search query: {}", e))
+        search_results.sort_by(|a, b| {
+            a.similarity_score
+                .total_cmp(&b.similarity_score)
+                .then_with(|| a.issue_key.cmp(&b.issue_key))
+        });
+        search_results.truncate(limit);
+        Ok(search_results)
+    }
+
+    /// Embeddings of non-deleted issues, optionally only those of one project
+    /// and those created at or after `created_since`
+    pub fn embedded_issues(
+        &self,
+        project_id: Option<&str>,
+        created_since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> DomainResult<Vec<EmbeddedIssue>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
         })?;
 
-        // Helper closure to parse rows
-        fn parse_row(row: &duckdb::Row) -> Result<SemanticSearchResult, duckdb::Error> {
-            Ok(SemanticSearchResult {
-                issue_key: row.get(0)?,
-                summary: row.get(1)?,
-                description: row.get(2)?,
-                status: row.get(3)?,
-                project_id: row.get(4)?,
-                similarity_score: row.get(5)?,
-            })
+        let since = created_since.map(|since| since.to_rfc3339());
+        let mut issues = Vec::new();
+        for space in Self::spaces(&conn)? {
+            let mut stmt = conn
+                .prepare(&format!(
+                    r#"
+                    SELECT e.issue_id, e.issue_key, CAST(v.embedding AS VARCHAR), e.model
+                    FROM {} v
+                    JOIN issue_embeddings e ON e.issue_id = v.issue_id
+                    JOIN issues i ON e.issue_id = i.id
+                    WHERE (i.is_deleted IS NULL OR i.is_deleted = false)
+                      AND (CAST(? AS VARCHAR) IS NULL OR i.project_id = ?)
+                      AND (CAST(? AS TIMESTAMPTZ) IS NULL OR i.created_date >= CAST(? AS TIMESTAMPTZ))
+                    "#,
+                    space.table()
+                ))
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to prepare embeddings query: {}", e))
+                })?;
+
+            let rows = stmt
+                .query_map(
+                    duckdb::params![project_id, project_id, since, since],
+                    parse_embedded_issue,
+                )
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to query embeddings: {}", e))
+                })?;
+            for row in rows {
+                issues.push(row.map_err(|e| {
+                    DomainError::Repository(format!("Failed to read embedding row: {}", e))
+                })?);
+            }
         }
 
-        let mut rows = match project_filter {
-            Some(project) => stmt.query(duckdb::params![embedding_str, project, limit as i64]),
-            None => stmt.query(duckdb::params![embedding_str, limit as i64]),
-        }
-        .map_err(|e| {
-            DomainError::Repository(format!("Failed to execute semantic search: {}", e))
+        issues.sort_by(|a, b| a.issue_key.cmp(&b.issue_key));
+        Ok(issues)
+    }
+
+    /// Embedding of a non-deleted issue by key
+    pub fn find_by_key(&self, issue_key: &str) -> DomainResult<Option<EmbeddedIssue>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
         })?;
 
-        let mut search_results = Vec::new();
-        while let Some(row) = rows.next().map_err(|e| {
-            DomainError::Repository(format!("Failed to read search result row: {}", e))
-        })? {
-            match parse_row(row) {
-                Ok(r) => search_results.push(r),
-                Err(e) => {
-                    log::warn!("Error parsing search result row: {}", e);
-                }
+        for space in Self::spaces(&conn)? {
+            let mut stmt = conn
+                .prepare(&format!(
+                    r#"
+                    SELECT e.issue_id, e.issue_key, CAST(v.embedding AS VARCHAR), e.model
+                    FROM {} v
+                    JOIN issue_embeddings e ON e.issue_id = v.issue_id
+                    JOIN issues i ON e.issue_id = i.id
+                    WHERE e.issue_key = ?
+                      AND (i.is_deleted IS NULL OR i.is_deleted = false)
+                    "#,
+                    space.table()
+                ))
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to prepare embeddings query: {}", e))
+                })?;
+
+            let mut rows = stmt
+                .query_map([issue_key], parse_embedded_issue)
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to query embeddings: {}", e))
+                })?;
+            if let Some(row) = rows.next() {
+                return row.map(Some).map_err(|e| {
+                    DomainError::Repository(format!("Failed to read embedding row: {}", e))
+                });
             }
         }
 
-        Ok(search_results)
+        Ok(None)
+    }
+
+    /// The `limit` issues closest to `source` by cosine distance, closest first,
+    /// among non-deleted issues matching `filter`.
+    ///
+    /// Only the space of the source (same model and dimensions) is searched, so on an
+    /// all-projects connection distances stay meaningful, and its HNSW index serves the
+    /// lookup.
+    pub fn similar_to(
+        &self,
+        source: &EmbeddedIssue,
+        filter: &NeighborFilter,
+        limit: usize,
+    ) -> DomainResult<Vec<EmbeddingNeighbor>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
+        })?;
+
+        let Some(space) = Self::space_of(&conn, &source.issue_id)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut conditions = String::from(" AND e.issue_id <> ?");
+        let mut params: Vec<Box<dyn duckdb::ToSql>> = vec![Box::new(source.issue_id.clone())];
+        if let Some(project_id) = &filter.project_id {
+            conditions.push_str(" AND i.project_id = ?");
+            params.push(Box::new(project_id.clone()));
+        }
+        if filter.exclude_resolved {
+            conditions.push_str(
+                " AND i.resolution IS NULL \
+                 AND lower(COALESCE(i.raw_data->'fields'->'status'->'statusCategory'->>'key', '')) <> 'done'",
+            );
+        }
+        if let Some(from) = filter.created.from {
+            conditions.push_str(" AND i.created_date >= CAST(? AS TIMESTAMPTZ)");
+            params.push(Box::new(from.to_rfc3339()));
+        }
+        if let Some(to) = filter.created.to {
+            conditions.push_str(" AND i.created_date < CAST(? AS TIMESTAMPTZ)");
+            params.push(Box::new(to.to_rfc3339()));
+        }
+
+        Self::top_k(
+            &conn,
+            &space,
+            &source.embedding,
+            "e.issue_id, e.issue_key, v.distance",
+            &conditions,
+            &params,
+            limit,
+            |row| {
+                Ok(EmbeddingNeighbor {
+                    issue_id: row.get(0)?,
+                    issue_key: row.get(1)?,
+                    distance: row.get(2)?,
+                })
+            },
+        )
     }
 
     /// Get the count of embeddings in the database
@@ -295,6 +738,13 @@ impl EmbeddingsRepository {
             DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
         })?;
 
+        for space in Self::spaces(&conn)? {
+            conn.execute(
+                &format!("DELETE FROM {} WHERE issue_id = ?", space.table()),
+                [issue_id],
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to delete embedding: {}", e)))?;
+        }
         conn.execute(
             "DELETE FROM issue_embeddings WHERE issue_id = ?",
             [issue_id],
@@ -310,6 +760,18 @@ impl EmbeddingsRepository {
             DomainError::Repository(format!("Failed to acquire connection lock: {}", e))
         })?;
 
+        for space in Self::spaces(&conn)? {
+            conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE issue_id IN (SELECT id FROM issues WHERE project_id = ?)",
+                    space.table()
+                ),
+                [project_id],
+            )
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to delete embeddings by project: {}", e))
+            })?;
+        }
         let count = conn
             .execute(
                 r#"
@@ -328,8 +790,50 @@ impl EmbeddingsRepository {
     }
 }
 
+/// Vector in the `[x, y, ...]` text form DuckDB casts to `FLOAT[]`
+fn embedding_literal(embedding: &[f32]) -> String {
+    format!(
+        "[{}]",
+        embedding
+            .iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+fn parse_embedded_issue(row: &duckdb::Row) -> Result<EmbeddedIssue, duckdb::Error> {
+    let text: String = row.get(2)?;
+    Ok(EmbeddedIssue {
+        issue_id: row.get(0)?,
+        issue_key: row.get(1)?,
+        embedding: parse_embedding(&text),
+        model: row.get(3)?,
+    })
+}
+
+/// Parse DuckDB's text form of a `FLOAT[]`, skipping unreadable elements
+fn parse_embedding(text: &str) -> Vec<f32> {
+    text.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .filter_map(|value| value.trim().parse().ok())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedding_literal_round_trips() {
+        let embedding = vec![0.25, -1.5, 3.0];
+        assert_eq!(parse_embedding(&embedding_literal(&embedding)), embedding);
+        assert_eq!(parse_embedding("[0.5, -0.25]"), vec![0.5, -0.25]);
+        assert!(parse_embedding("[]").is_empty());
+    }
+
     #[test]
     fn test_embedding_conversion() {
         // Test that embedding conversion to string format works correctly
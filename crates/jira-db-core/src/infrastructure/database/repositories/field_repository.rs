@@ -2,8 +2,12 @@ use crate::domain::entities::JiraField;
 use crate::domain::error::{DomainError, DomainResult};
 use chrono::Utc;
 use duckdb::Connection;
+use serde_json::Value;
 use std::sync::{Arc, Mutex};
 
+/// Tables whose `raw_data` holds issue JSON keyed by field id (`issues` first)
+const RAW_DATA_TABLES: [&str; 3] = ["issues", "issue_raw_data", "issue_snapshots"];
+
 pub struct DuckDbFieldRepository {
     conn: Arc<Mutex<Connection>>,
 }
@@ -211,6 +215,94 @@ impl DuckDbFieldRepository {
         Ok(count)
     }
 
+    /// Move the values stored under an import placeholder field to `field` in the raw data
+    /// of issues and snapshots, and remove the placeholder definition.
+    ///
+    /// Rows that already have a value for `field` (synced since the import) keep it.
+    /// Returns the number of issues updated.
+    pub fn replace_placeholder(
+        &self,
+        placeholder: &JiraField,
+        field: &JiraField,
+    ) -> DomainResult<usize> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut updates = Vec::new();
+        for table in RAW_DATA_TABLES {
+            let rows = Self::moved_raw_data(&conn, table, placeholder, field)?;
+            updates.push((table, rows));
+        }
+
+        conn.execute("BEGIN TRANSACTION", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to begin transaction: {}", e)))?;
+        if let Err(e) = Self::write_placeholder_replacement(&conn, placeholder, &updates) {
+            let _ = conn.execute("ROLLBACK", []);
+            return Err(e);
+        }
+        conn.execute("COMMIT", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(updates[0].1.len())
+    }
+
+    /// Raw data rows of `table` holding a placeholder value, rewritten to store it under `field`
+    fn moved_raw_data(
+        conn: &Connection,
+        table: &str,
+        placeholder: &JiraField,
+        field: &JiraField,
+    ) -> DomainResult<Vec<(i64, String)>> {
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT rowid, raw_data::VARCHAR FROM {} WHERE contains(raw_data::VARCHAR, ?)",
+                table
+            ))
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+        let rows = stmt
+            .query_map([format!("\"{}\"", placeholder.id)], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let mut moved = Vec::new();
+        for row in rows {
+            let (rowid, raw_data) = row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            let Ok(mut raw) = serde_json::from_str::<Value>(&raw_data) else {
+                continue;
+            };
+            let Some(fields) = raw["fields"].as_object_mut() else {
+                continue;
+            };
+            let Some(value) = fields.remove(&placeholder.id) else {
+                continue;
+            };
+            fields.entry(field.id.clone()).or_insert(value);
+            moved.push((rowid, raw.to_string()));
+        }
+        Ok(moved)
+    }
+
+    fn write_placeholder_replacement(
+        conn: &Connection,
+        placeholder: &JiraField,
+        updates: &[(&str, Vec<(i64, String)>)],
+    ) -> DomainResult<()> {
+        for (table, rows) in updates {
+            let sql = format!("UPDATE {} SET raw_data = ? WHERE rowid = ?", table);
+            for (rowid, raw_data) in rows {
+                conn.execute(&sql, duckdb::params![raw_data, rowid])
+                    .map_err(|e| {
+                        DomainError::Repository(format!("Failed to update {}: {}", table, e))
+                    })?;
+            }
+        }
+        conn.execute("DELETE FROM jira_fields WHERE id = ?", [&placeholder.id])
+            .map_err(|e| DomainError::Repository(format!("Failed to delete field: {}", e)))?;
+        Ok(())
+    }
+
     /// Delete all fields
     pub fn delete_all(&self) -> DomainResult<()> {
         let conn = self.conn.lock().map_err(|e| {
@@ -221,3 +313,93 @@ impl DuckDbFieldRepository {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::test_support::test_connection;
+
+    fn custom_field(id: &str, name: &str) -> JiraField {
+        JiraField {
+            id: id.to_string(),
+            key: id.to_string(),
+            name: name.to_string(),
+            custom: true,
+            searchable: true,
+            navigable: true,
+            orderable: true,
+            schema_type: Some("string".to_string()),
+            schema_items: None,
+            schema_system: None,
+            schema_custom: None,
+            schema_custom_id: None,
+        }
+    }
+
+    fn raw_field(conn: &Arc<Mutex<Connection>>, sql: &str) -> Vec<Option<String>> {
+        let conn = conn.lock().unwrap();
+        let mut stmt = conn.prepare(sql).unwrap();
+        stmt.query_map([], |row| row.get(0))
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_replace_placeholder_moves_values() {
+        let conn = test_connection(
+            r#"
+            INSERT INTO issues (id, project_id, key, summary, raw_data) VALUES
+                ('1', '100', 'PROJ-1', 'Imported', '{"fields": {"customfield_import_severity": "High"}}'),
+                ('2', '100', 'PROJ-2', 'Synced', '{"fields": {"customfield_import_severity": "Low", "customfield_10050": "Major"}}'),
+                ('3', '100', 'PROJ-3', 'Empty', '{"fields": {}}');
+            INSERT INTO issue_raw_data (id, issue_key, project_id, raw_data) VALUES
+                ('1', 'PROJ-1', '100', '{"fields": {"customfield_import_severity": "High"}}');
+            INSERT INTO issue_snapshots
+                (issue_id, issue_key, project_id, version, valid_from, summary, raw_data)
+            VALUES
+                ('1', 'PROJ-1', '100', 1, '2024-05-01 09:00:00+00', 'Imported',
+                 '{"fields": {"customfield_import_severity": "Medium"}}');
+            "#,
+        );
+        let repo = DuckDbFieldRepository::new(conn.clone());
+        let placeholder = JiraField::import_placeholder("Severity");
+        repo.upsert_fields(std::slice::from_ref(&placeholder))
+            .unwrap();
+
+        let updated = repo
+            .replace_placeholder(&placeholder, &custom_field("customfield_10050", "Severity"))
+            .unwrap();
+
+        assert_eq!(updated, 2);
+        assert_eq!(
+            raw_field(
+                &conn,
+                "SELECT raw_data->'fields'->>'customfield_10050' FROM issues ORDER BY id"
+            ),
+            vec![Some("High".to_string()), Some("Major".to_string()), None]
+        );
+        assert_eq!(
+            raw_field(
+                &conn,
+                "SELECT raw_data->'fields'->>'customfield_import_severity' FROM issues ORDER BY id"
+            ),
+            vec![None, None, None]
+        );
+        assert_eq!(
+            raw_field(
+                &conn,
+                "SELECT raw_data->'fields'->>'customfield_10050' FROM issue_raw_data"
+            ),
+            vec![Some("High".to_string())]
+        );
+        assert_eq!(
+            raw_field(
+                &conn,
+                "SELECT raw_data->'fields'->>'customfield_10050' FROM issue_snapshots"
+            ),
+            vec![Some("Medium".to_string())]
+        );
+        assert!(repo.find_by_id(&placeholder.id).unwrap().is_none());
+    }
+}
@@ -0,0 +1,504 @@
+//! Full-text search index over issues
+//!
+//! DuckDB's `fts` extension splits documents on whitespace and punctuation, which leaves
+//! Japanese text as one unsearchable token per sentence. Documents are therefore tokenized
+//! here before indexing: words in alphabetic scripts are kept whole, and runs of CJK
+//! characters are split into overlapping bigrams ("同期処理" -> "同期 期処 処理"). The FTS
+//! index is built over the pre-tokenized column with whitespace as the only separator, and
+//! queries are tokenized the same way before being ranked with BM25.
+
+use duckdb::Connection;
+use log::debug;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use crate::domain::error::{DomainError, DomainResult};
+
+/// Table holding one search document per issue
+const DOCS_TABLE: &str = "issue_search_docs";
+
+/// Schema created by `create_fts_index` for the documents table
+pub(crate) const FTS_SCHEMA: &str = "fts_main_issue_search_docs";
+
+/// Number of characters shown in a search snippet
+pub(crate) const SNIPPET_WIDTH: usize = 160;
+
+/// Repository maintaining the issue full-text index
+pub struct FullTextSearchRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+struct SearchDocument {
+    issue_id: String,
+    issue_key: String,
+    project_id: String,
+    body: String,
+}
+
+impl FullTextSearchRepository {
+    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+        Self { conn }
+    }
+
+    /// Rebuild the index from the current (non-deleted) issues
+    ///
+    /// Summary, description and comments are always indexed; `search_fields` adds custom
+    /// fields by id (`customfield_10010`) or display name. Returns the number of indexed issues.
+    pub fn rebuild(&self, search_fields: &[String]) -> DomainResult<usize> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        conn.execute_batch("INSTALL fts; LOAD fts;")
+            .map_err(|e| DomainError::Repository(format!("Failed to load FTS extension: {}", e)))?;
+
+        let field_ids = Self::resolve_fields(&conn, search_fields)?;
+        let documents = Self::collect_documents(&conn, &field_ids)?;
+
+        conn.execute("BEGIN TRANSACTION", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to begin transaction: {}", e)))?;
+        if let Err(e) = Self::write_documents(&conn, &documents) {
+            let _ = conn.execute("ROLLBACK", []);
+            return Err(e);
+        }
+        conn.execute("COMMIT", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to commit transaction: {}", e)))?;
+
+        conn.execute_batch(&format!(
+            r"PRAGMA create_fts_index('{}', 'issue_id', 'tokens', stemmer = 'porter', stopwords = 'none', ignore = '\s+', strip_accents = 0, lower = 1, overwrite = 1)",
+            DOCS_TABLE
+        ))
+        .map_err(|e| DomainError::Repository(format!("Failed to create FTS index: {}", e)))?;
+
+        debug!(
+            "[FTS] Indexed {} issues ({} custom fields)",
+            documents.len(),
+            field_ids.len()
+        );
+        Ok(documents.len())
+    }
+
+    /// Whether the index has been built for this database
+    pub fn exists(&self) -> DomainResult<bool> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        Ok(index_exists(&conn))
+    }
+
+    /// Map configured field ids or names to field ids
+    fn resolve_fields(conn: &Connection, search_fields: &[String]) -> DomainResult<Vec<String>> {
+        if search_fields.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT id, name FROM jira_fields")
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+        let fields: Vec<(String, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?
+            .collect::<Result<_, _>>()
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(search_fields
+            .iter()
+            .map(|wanted| {
+                fields
+                    .iter()
+                    .find(|(id, name)| id == wanted || name.eq_ignore_ascii_case(wanted))
+                    .map(|(id, _)| id.clone())
+                    .unwrap_or_else(|| wanted.clone())
+            })
+            .collect())
+    }
+
+    fn collect_documents(
+        conn: &Connection,
+        field_ids: &[String],
+    ) -> DomainResult<Vec<SearchDocument>> {
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT id, key, project_id, summary, description, raw_data
+                FROM issues
+                WHERE is_deleted IS NULL OR is_deleted = false
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                ))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let mut documents = Vec::new();
+        for row in rows {
+            let (issue_id, issue_key, project_id, summary, description, raw_data) =
+                row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            let raw: Value = raw_data
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or(Value::Null);
+            let fields = &raw["fields"];
+
+            let mut parts = vec![summary];
+            match description {
+                Some(text) => parts.push(text),
+                None => parts.push(value_text(&fields["description"])),
+            }
+            if let Some(comments) = fields["comment"]["comments"].as_array() {
+                parts.extend(comments.iter().map(|c| value_text(&c["body"])));
+            }
+            parts.extend(field_ids.iter().map(|id| value_text(&fields[id.as_str()])));
+
+            let body = parts
+                .into_iter()
+                .filter(|part| !part.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            documents.push(SearchDocument {
+                issue_id,
+                issue_key,
+                project_id,
+                body,
+            });
+        }
+
+        Ok(documents)
+    }
+
+    fn write_documents(conn: &Connection, documents: &[SearchDocument]) -> DomainResult<()> {
+        conn.execute(
+            &format!(
+                "CREATE OR REPLACE TABLE {} (issue_id VARCHAR PRIMARY KEY, issue_key VARCHAR, project_id VARCHAR, body VARCHAR, tokens VARCHAR)",
+                DOCS_TABLE
+            ),
+            [],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to create search table: {}", e)))?;
+
+        let mut stmt = conn
+            .prepare(&format!(
+                "INSERT INTO {} (issue_id, issue_key, project_id, body, tokens) VALUES (?, ?, ?, ?, ?)",
+                DOCS_TABLE
+            ))
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare statement: {}", e)))?;
+
+        for doc in documents {
+            stmt.execute(duckdb::params![
+                &doc.issue_id,
+                &doc.issue_key,
+                &doc.project_id,
+                &doc.body,
+                // The key is searchable but kept out of the snippet text
+                &tokenize(&format!("{} {}", doc.issue_key, doc.body)).join(" "),
+            ])
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to insert search document: {}", e))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the FTS index exists and the extension can be loaded on this connection
+pub(crate) fn index_exists(conn: &Connection) -> bool {
+    let count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM duckdb_schemas() WHERE schema_name = ?",
+            duckdb::params![FTS_SCHEMA],
+            |row| row.get(0),
+        )
+        .unwrap_or(0);
+    count > 0 && conn.execute_batch("LOAD fts").is_ok()
+}
+
+/// Whether a character belongs to a script written without spaces between words
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF      // Hiragana, Katakana
+        | 0x3400..=0x4DBF    // CJK Extension A
+        | 0x4E00..=0x9FFF    // CJK Unified Ideographs
+        | 0xAC00..=0xD7AF    // Hangul syllables
+        | 0xF900..=0xFAFF    // CJK Compatibility Ideographs
+        | 0xFF66..=0xFF9F // Halfwidth Katakana
+    )
+}
+
+/// Split text into index tokens: lowercase words, and bigrams for CJK runs
+pub(crate) fn tokenize(text: &str) -> Vec<String> {
+    fn flush_cjk(run: &mut Vec<char>, tokens: &mut Vec<String>) {
+        if run.len() == 1 {
+            tokens.push(run[0].to_string());
+        } else {
+            tokens.extend(run.windows(2).map(|pair| pair.iter().collect::<String>()));
+        }
+        run.clear();
+    }
+
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut cjk = Vec::new();
+
+    for c in text.chars() {
+        if is_cjk(c) {
+            if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+            cjk.push(c);
+        } else {
+            if !cjk.is_empty() {
+                flush_cjk(&mut cjk, &mut tokens);
+            }
+            if c.is_alphanumeric() {
+                word.extend(c.to_lowercase());
+            } else if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    if !cjk.is_empty() {
+        flush_cjk(&mut cjk, &mut tokens);
+    }
+
+    tokens
+}
+
+/// Distinct tokens of a search query, in order
+pub(crate) fn query_tokens(query: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    tokenize(query)
+        .into_iter()
+        .filter(|token| seen.insert(token.clone()))
+        .collect()
+}
+
+/// Excerpt of `text` around the first occurrence of a query term, with every
+/// occurrence inside the excerpt wrapped in `**`
+///
+/// Falls back to the start of the text when no term occurs literally (e.g. a stemmed match).
+pub(crate) fn snippet(text: &str, query: &str, width: usize) -> Option<String> {
+    let chars: Vec<char> = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect();
+    if chars.is_empty() {
+        return None;
+    }
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    // Whole words and CJK runs of the query, matched case-insensitively
+    let terms: Vec<Vec<char>> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| {
+            term.chars()
+                .map(|c| c.to_lowercase().next().unwrap_or(c))
+                .collect()
+        })
+        .collect();
+
+    let mut matches: Vec<(usize, usize)> = Vec::new();
+    for term in &terms {
+        let mut start = 0;
+        while start + term.len() <= lower.len() {
+            if lower[start..start + term.len()] == term[..] {
+                matches.push((start, start + term.len()));
+                start += term.len();
+            } else {
+                start += 1;
+            }
+        }
+    }
+    matches.sort_unstable();
+
+    let first = matches.first().map(|(start, _)| *start).unwrap_or(0);
+    let from = first.saturating_sub(width / 4);
+    let to = (from + width).min(chars.len());
+
+    let mut out = String::new();
+    if from > 0 {
+        out.push('…');
+    }
+    let mut pos = from;
+    for (start, end) in matches {
+        if start < pos || end > to {
+            continue;
+        }
+        out.extend(&chars[pos..start]);
+        out.push_str("**");
+        out.extend(&chars[start..end]);
+        out.push_str("**");
+        pos = end;
+    }
+    out.extend(&chars[pos..to]);
+    if to < chars.len() {
+        out.push('…');
+    }
+
+    Some(out)
+}
+
+/// Plain text of a field value (strings, ADF documents, option objects and arrays)
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(value_text)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" "),
+        Value::Object(map) => {
+            if map.contains_key("content")
+                || map.get("type").and_then(Value::as_str) == Some("text")
+            {
+                let mut text = Vec::new();
+                collect_adf_text(value, &mut text);
+                return text.join(" ");
+            }
+            ["value", "name", "displayName"]
+                .iter()
+                .find_map(|k| map.get(*k).and_then(Value::as_str))
+                .unwrap_or_default()
+                .to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+/// Collect text nodes from an Atlassian Document Format value
+fn collect_adf_text(value: &Value, out: &mut Vec<String>) {
+    if let Some(text) = value.get("text").and_then(Value::as_str) {
+        out.push(text.to_string());
+    }
+    if let Some(content) = value.get("content").and_then(Value::as_array) {
+        for child in content {
+            collect_adf_text(child, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_tokenize_mixed_scripts() {
+        assert_eq!(
+            tokenize("Sync 同期処理が失敗 (v2)"),
+            vec!["sync", "同期", "期処", "処理", "理が", "が失", "失敗", "v2"]
+        );
+        assert_eq!(tokenize("バグ"), vec!["バグ"]);
+        assert_eq!(tokenize("a 字"), vec!["a", "字"]);
+    }
+
+    #[test]
+    fn test_query_tokens_are_distinct() {
+        assert_eq!(
+            query_tokens("login LOGIN ログイン"),
+            vec!["login", "ログ", "グイ", "イン"]
+        );
+    }
+
+    #[test]
+    fn test_snippet_highlights_matches() {
+        assert_eq!(
+            snippet("The login page fails after login", "Login", 100).unwrap(),
+            "The **login** page fails after **login**"
+        );
+        assert_eq!(
+            snippet("ログイン画面で同期処理が失敗する", "同期処理", 100).unwrap(),
+            "ログイン画面で**同期処理**が失敗する"
+        );
+
+        let long = format!("{} needle {}", "a ".repeat(100), "b ".repeat(100));
+        let excerpt = snippet(&long, "needle", 40).unwrap();
+        assert!(excerpt.starts_with('…') && excerpt.ends_with('…'));
+        assert!(excerpt.contains("**needle**"));
+    }
+
+    #[test]
+    fn test_value_text_reads_adf() {
+        let adf = json!({
+            "type": "doc",
+            "content": [{ "type": "paragraph", "content": [
+                { "type": "text", "text": "first" },
+                { "type": "text", "text": "second" }
+            ]}]
+        });
+        assert_eq!(value_text(&adf), "first second");
+        assert_eq!(
+            value_text(&json!([{ "value": "A" }, { "name": "B" }])),
+            "A B"
+        );
+    }
+
+    #[test]
+    fn test_rebuild_and_rank() {
+        let conn = Connection::open_in_memory().unwrap();
+        if conn.execute_batch("INSTALL fts; LOAD fts;").is_err() {
+            // The extension cannot be downloaded in this environment
+            return;
+        }
+        conn.execute_batch(
+            r#"
+            CREATE TABLE jira_fields (id VARCHAR, name VARCHAR);
+            INSERT INTO jira_fields VALUES ('customfield_1', 'Root Cause');
+            CREATE TABLE issues (id VARCHAR, key VARCHAR, project_id VARCHAR, summary VARCHAR,
+                                 description VARCHAR, raw_data VARCHAR, is_deleted BOOLEAN);
+            INSERT INTO issues VALUES
+                ('1', 'P-1', 'p', 'ログイン画面のエラー', NULL,
+                 '{"fields": {"comment": {"comments": [{"body": "同期処理を再実行"}]}}}', false),
+                ('2', 'P-2', 'p', 'Export fails', 'timeout',
+                 '{"fields": {"customfield_1": "memory leak"}}', false),
+                ('3', 'P-3', 'p', 'ログイン', NULL, NULL, true);
+            "#,
+        )
+        .unwrap();
+        let conn = Arc::new(Mutex::new(conn));
+
+        let repo = FullTextSearchRepository::new(conn.clone());
+        assert!(!repo.exists().unwrap());
+        assert_eq!(repo.rebuild(&["Root Cause".to_string()]).unwrap(), 2);
+        assert!(repo.exists().unwrap());
+
+        let conn = conn.lock().unwrap();
+        let hit = |query: &str| -> Vec<String> {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT issue_key FROM (SELECT issue_key, fts_main_issue_search_docs.match_bm25(issue_id, ?, conjunctive := 1) AS score FROM issue_search_docs) WHERE score IS NOT NULL",
+                )
+                .unwrap();
+            stmt.query_map(duckdb::params![query_tokens(query).join(" ")], |row| {
+                row.get(0)
+            })
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+        };
+        assert_eq!(hit("同期処理"), vec!["P-1"]);
+        assert_eq!(hit("memory leak"), vec!["P-2"]);
+        assert!(hit("ログイン 同期 missing").is_empty());
+    }
+}
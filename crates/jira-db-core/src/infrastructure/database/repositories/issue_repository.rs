@@ -1,6 +1,10 @@
-use crate::domain::entities::Issue;
+use super::full_text_repository;
+use crate::domain::entities::{Issue, IssueLink};
 use crate::domain::error::{DomainError, DomainResult};
-use crate::domain::repositories::{IssuePage, IssueRepository, SearchParams};
+use crate::domain::repositories::{
+    FacetField, FacetValue, IssuePage, IssueRepository, IssueSearchHit, LabelMatch, SearchFacet,
+    SearchParams, SearchSort, SortField,
+};
 use chrono::{DateTime, Utc};
 use duckdb::Connection;
 use log::debug;
@@ -15,6 +19,256 @@ impl DuckDbIssueRepository {
     pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
         Self { conn }
     }
+
+    /// Links of the project's non-deleted issues, read from the raw issue data
+    pub fn find_links(&self, project_id: &str) -> DomainResult<Vec<IssueLink>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        let mut stmt = conn
+            .prepare(
+                r#"
+            SELECT key, CAST(raw_data->'fields'->'issuelinks' AS VARCHAR)
+            FROM issues
+            WHERE project_id = ?
+              AND (is_deleted IS NULL OR is_deleted = false)
+              AND json_array_length(raw_data->'fields'->'issuelinks') > 0
+            "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(duckdb::params![project_id], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to query issue links: {}", e)))?;
+
+        let mut links = Vec::new();
+        for row in rows {
+            let (key, raw_links) =
+                row.map_err(|e| DomainError::Repository(format!("Failed to read row: {}", e)))?;
+            match serde_json::from_str(&raw_links) {
+                Ok(value) => links.extend(IssueLink::from_issue_links(&key, &value)),
+                Err(e) => debug!("Skipping unreadable issue links of {}: {}", key, e),
+            }
+        }
+        Ok(links)
+    }
+}
+
+/// Resolution date, which only lives in the raw JIRA fields
+const RESOLVED_DATE: &str = "TRY_CAST(i.raw_data->'fields'->>'resolutiondate' AS TIMESTAMPTZ)";
+
+/// The `FROM ... WHERE ...` part of an issue search, shared by ranked search and counting
+struct SearchQuery {
+    /// Whether the BM25 full-text index ranks the results
+    use_index: bool,
+    from: String,
+    params: Vec<Box<dyn duckdb::ToSql>>,
+}
+
+impl SearchQuery {
+    fn build(conn: &Connection, params: &SearchParams) -> Self {
+        let query = params.query.as_deref().unwrap_or_default();
+        let terms = full_text_repository::query_tokens(query);
+        let use_index = !terms.is_empty() && full_text_repository::index_exists(conn);
+
+        let mut conditions: Vec<String> = Vec::new();
+        let mut sql_params: Vec<Box<dyn duckdb::ToSql>> = Vec::new();
+
+        let mut from = if use_index {
+            // Every term must match; the terms are bound in the WITH clause
+            sql_params.push(Box::new(terms.join(" ")));
+            r#"
+            FROM ranked r
+            JOIN issues i ON i.id = r.issue_id
+            LEFT JOIN projects p ON i.project_id = p.id
+            WHERE (i.is_deleted IS NULL OR i.is_deleted = false) AND r.score IS NOT NULL
+            "#
+            .to_string()
+        } else {
+            if let Some(query) = &params.query {
+                conditions.push("(i.summary LIKE ? OR i.description LIKE ?)".to_string());
+                let search_pattern = format!("%{}%", query);
+                sql_params.push(Box::new(search_pattern.clone()));
+                sql_params.push(Box::new(search_pattern));
+            }
+            r#"
+            FROM issues i
+            LEFT JOIN projects p ON i.project_id = p.id
+            WHERE (i.is_deleted IS NULL OR i.is_deleted = false)
+            "#
+            .to_string()
+        };
+
+        if let Some(project_key) = &params.project_key {
+            // Filter by issue key prefix (e.g., "PROJ-%" for project key "PROJ")
+            conditions.push("i.key LIKE ?".to_string());
+            sql_params.push(Box::new(format!("{}-%", project_key)));
+        }
+
+        if let Some(status) = &params.status {
+            conditions.push("i.status = ?".to_string());
+            sql_params.push(Box::new(status.clone()));
+        }
+
+        if let Some(category) = &params.status_category {
+            // Match the category key ("done") or its display name ("Done")
+            conditions.push(
+                "lower(?) IN (lower(i.raw_data->'fields'->'status'->'statusCategory'->>'key'), \
+                 lower(i.raw_data->'fields'->'status'->'statusCategory'->>'name'))"
+                    .to_string(),
+            );
+            sql_params.push(Box::new(category.clone()));
+        }
+
+        if let Some(assignee) = &params.assignee {
+            conditions.push("i.assignee LIKE ?".to_string());
+            sql_params.push(Box::new(format!("%{}%", assignee)));
+        }
+
+        let equals = [
+            ("i.issue_type", &params.issue_type),
+            ("i.priority", &params.priority),
+            ("i.team", &params.team),
+            ("i.sprint", &params.sprint),
+            ("i.parent_key", &params.parent_key),
+        ];
+        for (column, value) in equals {
+            if let Some(value) = value {
+                conditions.push(format!("{} = ?", column));
+                sql_params.push(Box::new(value.clone()));
+            }
+        }
+
+        if let Some(resolution) = &params.resolution {
+            if resolution.eq_ignore_ascii_case("unresolved") {
+                conditions.push("i.resolution IS NULL".to_string());
+            } else {
+                conditions.push("i.resolution = ?".to_string());
+                sql_params.push(Box::new(resolution.clone()));
+            }
+        }
+
+        let label_join = match params.labels_match {
+            LabelMatch::Any => " OR ",
+            LabelMatch::All => " AND ",
+        };
+        let lists = [
+            ("i.labels", &params.labels, label_join),
+            ("i.components", &params.components, " OR "),
+            ("i.fix_versions", &params.fix_versions, " OR "),
+        ];
+        for (column, values, join) in lists {
+            if values.is_empty() {
+                continue;
+            }
+            let membership = format!("list_contains(json_extract_string({}, '$[*]'), ?)", column);
+            conditions.push(format!("({})", vec![membership; values.len()].join(join)));
+            for value in values {
+                sql_params.push(Box::new(value.clone()));
+            }
+        }
+
+        if !params.keys.is_empty() {
+            conditions.push(format!(
+                "i.key IN ({})",
+                vec!["?"; params.keys.len()].join(", ")
+            ));
+            for key in &params.keys {
+                sql_params.push(Box::new(key.clone()));
+            }
+        }
+
+        let ranges = [
+            ("i.created_date", &params.created),
+            ("i.updated_date", &params.updated),
+            ("i.due_date", &params.due),
+            (RESOLVED_DATE, &params.resolved),
+        ];
+        for (column, range) in ranges {
+            if let Some(from) = range.from {
+                conditions.push(format!("{} >= CAST(? AS TIMESTAMPTZ)", column));
+                sql_params.push(Box::new(from.to_rfc3339()));
+            }
+            if let Some(to) = range.to {
+                conditions.push(format!("{} < CAST(? AS TIMESTAMPTZ)", column));
+                sql_params.push(Box::new(to.to_rfc3339()));
+            }
+        }
+
+        for condition in conditions {
+            from.push_str(" AND ");
+            from.push_str(&condition);
+        }
+
+        Self {
+            use_index,
+            from,
+            params: sql_params,
+        }
+    }
+
+    /// Rank with BM25 over the pre-tokenized search documents
+    fn with_clause(&self) -> String {
+        if !self.use_index {
+            return String::new();
+        }
+        format!(
+            r#"
+            WITH ranked AS (
+                SELECT issue_id, body,
+                       {}.match_bm25(issue_id, ?, conjunctive := 1) AS score
+                FROM issue_search_docs
+            )
+            "#,
+            full_text_repository::FTS_SCHEMA
+        )
+    }
+
+    /// Score and snippet body columns
+    fn ranking_columns(&self) -> &'static str {
+        if self.use_index {
+            "r.score, r.body"
+        } else {
+            "NULL::DOUBLE as score, i.summary || chr(10) || COALESCE(i.description, '') as body"
+        }
+    }
+
+    fn order_by(&self, sort: &[SearchSort]) -> String {
+        if sort.is_empty() {
+            return if self.use_index {
+                " ORDER BY r.score DESC, i.created_date DESC".to_string()
+            } else {
+                " ORDER BY i.created_date DESC".to_string()
+            };
+        }
+
+        let mut terms = Vec::new();
+        for sort in sort {
+            let direction = if sort.descending { "DESC" } else { "ASC" };
+            let columns: &[&str] = match sort.field {
+                SortField::Relevance if self.use_index => &["r.score"],
+                SortField::Relevance => &["i.created_date"],
+                SortField::Key => &[
+                    "split_part(i.key, '-', 1)",
+                    "TRY_CAST(split_part(i.key, '-', 2) AS INTEGER)",
+                ],
+                SortField::Summary => &["i.summary"],
+                SortField::Created => &["i.created_date"],
+                SortField::Updated => &["i.updated_date"],
+                SortField::Due => &["i.due_date"],
+                SortField::Resolved => &[RESOLVED_DATE],
+            };
+            for column in columns {
+                terms.push(format!("{} {} NULLS LAST", column, direction));
+            }
+        }
+        // Stable pages when the sort keys tie
+        terms.push("i.key".to_string());
+
+        format!(" ORDER BY {}", terms.join(", "))
+    }
 }
 
 impl IssueRepository for DuckDbIssueRepository {
@@ -207,73 +461,44 @@ impl IssueRepository for DuckDbIssueRepository {
     }
 
     fn search(&self, params: &SearchParams) -> DomainResult<Vec<Issue>> {
+        Ok(self
+            .search_ranked(params)?
+            .into_iter()
+            .map(|hit| hit.issue)
+            .collect())
+    }
+
+    fn search_ranked(&self, params: &SearchParams) -> DomainResult<Vec<IssueSearchHit>> {
         let conn = self.conn.lock().map_err(|e| {
             DomainError::Repository(format!("Failed to acquire database lock: {}", e))
         })?;
 
-        let mut sql = String::from(
+        let search = SearchQuery::build(&conn, params);
+        // Raw data is only needed to merge results ordered by resolution date
+        let raw_data = if params.sort.iter().any(|s| s.field == SortField::Resolved) {
+            "CAST(i.raw_data AS VARCHAR) as raw_data"
+        } else {
+            "NULL::VARCHAR as raw_data"
+        };
+        let mut sql = format!(
             r#"
+            {}
             SELECT i.id, i.project_id, i.key, i.summary, i.description,
                    i.status, i.priority, i.assignee, i.reporter,
                    i.issue_type, i.resolution, i.labels, i.components, i.fix_versions, i.sprint, i.team, i.parent_key,
                    CASE WHEN i.due_date IS NOT NULL THEN strftime(i.due_date::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END as due_date,
                    CASE WHEN i.created_date IS NOT NULL THEN strftime(i.created_date::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END as created_date,
-                   CASE WHEN i.updated_date IS NOT NULL THEN strftime(i.updated_date::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END as updated_date
-            FROM issues i
-            LEFT JOIN projects p ON i.project_id = p.id
-            WHERE (i.is_deleted IS NULL OR i.is_deleted = false)
+                   CASE WHEN i.updated_date IS NOT NULL THEN strftime(i.updated_date::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END as updated_date,
+                   {},
+                   {}
+            {}
             "#,
+            search.with_clause(),
+            raw_data,
+            search.ranking_columns(),
+            search.from
         );
-
-        let mut conditions = Vec::new();
-        let mut sql_params: Vec<Box<dyn duckdb::ToSql>> = Vec::new();
-
-        if let Some(query) = &params.query {
-            conditions.push("(i.summary LIKE ? OR i.description LIKE ?)");
-            let search_pattern = format!("%{}%", query);
-            sql_params.push(Box::new(search_pattern.clone()));
-            sql_params.push(Box::new(search_pattern));
-        }
-
-        if let Some(project_key) = &params.project_key {
-            // Filter by issue key prefix (e.g., "PROJ-%" for project key "PROJ")
-            conditions.push("i.key LIKE ?");
-            let project_pattern = format!("{}-%", project_key);
-            sql_params.push(Box::new(project_pattern));
-        }
-
-        if let Some(status) = &params.status {
-            conditions.push("i.status = ?");
-            sql_params.push(Box::new(status.clone()));
-        }
-
-        if let Some(assignee) = &params.assignee {
-            conditions.push("i.assignee LIKE ?");
-            let assignee_pattern = format!("%{}%", assignee);
-            sql_params.push(Box::new(assignee_pattern));
-        }
-
-        if let Some(issue_type) = &params.issue_type {
-            conditions.push("i.issue_type = ?");
-            sql_params.push(Box::new(issue_type.clone()));
-        }
-
-        if let Some(priority) = &params.priority {
-            conditions.push("i.priority = ?");
-            sql_params.push(Box::new(priority.clone()));
-        }
-
-        if let Some(team) = &params.team {
-            conditions.push("i.team = ?");
-            sql_params.push(Box::new(team.clone()));
-        }
-
-        for condition in conditions {
-            sql.push_str(" AND ");
-            sql.push_str(condition);
-        }
-
-        sql.push_str(" ORDER BY i.created_date DESC");
+        sql.push_str(&search.order_by(&params.sort));
 
         if let Some(limit) = params.limit {
             sql.push_str(&format!(" LIMIT {}", limit));
@@ -286,64 +511,115 @@ impl IssueRepository for DuckDbIssueRepository {
             .prepare(&sql)
             .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
 
-        let param_refs: Vec<&dyn duckdb::ToSql> = sql_params.iter().map(|p| p.as_ref()).collect();
+        let param_refs: Vec<&dyn duckdb::ToSql> =
+            search.params.iter().map(|p| p.as_ref()).collect();
 
         let rows = stmt
             .query_map(param_refs.as_slice(), |row| {
-                let labels: Option<Vec<String>> = row
-                    .get::<_, Option<String>>(11)?
-                    .and_then(|s| serde_json::from_str(&s).ok());
-                let components: Option<Vec<String>> = row
-                    .get::<_, Option<String>>(12)?
-                    .and_then(|s| serde_json::from_str(&s).ok());
-                let fix_versions: Option<Vec<String>> = row
-                    .get::<_, Option<String>>(13)?
-                    .and_then(|s| serde_json::from_str(&s).ok());
-
-                Ok(Issue {
-                    id: row.get(0)?,
-                    project_id: row.get(1)?,
-                    key: row.get(2)?,
-                    summary: row.get(3)?,
-                    description: row.get(4)?,
-                    status: row.get(5)?,
-                    priority: row.get(6)?,
-                    assignee: row.get(7)?,
-                    reporter: row.get(8)?,
-                    issue_type: row.get(9)?,
-                    resolution: row.get(10)?,
-                    labels,
-                    components,
-                    fix_versions,
-                    sprint: row.get(14)?,
-                    team: row.get(15)?,
-                    parent_key: row.get(16)?,
-                    due_date: row.get::<_, Option<String>>(17)?.and_then(|s| {
-                        DateTime::parse_from_rfc3339(&s)
-                            .ok()
-                            .map(|dt| dt.with_timezone(&Utc))
-                    }),
-                    created_date: row.get::<_, Option<String>>(18)?.and_then(|s| {
-                        DateTime::parse_from_rfc3339(&s)
-                            .ok()
-                            .map(|dt| dt.with_timezone(&Utc))
-                    }),
-                    updated_date: row.get::<_, Option<String>>(19)?.and_then(|s| {
-                        DateTime::parse_from_rfc3339(&s)
-                            .ok()
-                            .map(|dt| dt.with_timezone(&Utc))
-                    }),
-                    raw_json: None,
-                })
+                Ok((
+                    Self::map_issue_row(row)?,
+                    row.get::<_, Option<f64>>(21)?,
+                    row.get::<_, Option<String>>(22)?,
+                ))
             })
             .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
 
-        let mut issues = Vec::new();
-        for issue in rows {
-            issues.push(issue.map_err(|e| DomainError::Repository(e.to_string()))?);
+        let mut hits = Vec::new();
+        for row in rows {
+            let (issue, score, body) = row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            let snippet = params.query.as_ref().and_then(|query| {
+                full_text_repository::snippet(
+                    body.as_deref().unwrap_or_default(),
+                    query,
+                    full_text_repository::SNIPPET_WIDTH,
+                )
+            });
+            hits.push(IssueSearchHit {
+                issue,
+                score,
+                snippet,
+            });
         }
 
-        Ok(issues)
+        Ok(hits)
+    }
+
+    fn count_matching(&self, params: &SearchParams) -> DomainResult<usize> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let search = SearchQuery::build(&conn, params);
+        let sql = format!("{} SELECT COUNT(*) {}", search.with_clause(), search.from);
+        let param_refs: Vec<&dyn duckdb::ToSql> =
+            search.params.iter().map(|p| p.as_ref()).collect();
+
+        let count: i64 = conn
+            .query_row(&sql, param_refs.as_slice(), |row| row.get(0))
+            .map_err(|e| DomainError::Repository(format!("Failed to count issues: {}", e)))?;
+
+        Ok(count as usize)
+    }
+
+    fn facet_counts(
+        &self,
+        params: &SearchParams,
+        fields: &[FacetField],
+    ) -> DomainResult<Vec<SearchFacet>> {
+        if fields.is_empty() {
+            return Ok(Vec::new());
+        }
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let search = SearchQuery::build(&conn, params);
+        let param_refs: Vec<&dyn duckdb::ToSql> =
+            search.params.iter().map(|p| p.as_ref()).collect();
+
+        let mut facets = Vec::new();
+        for &field in fields {
+            let column = match field {
+                FacetField::Status => "i.status",
+                FacetField::IssueType => "i.issue_type",
+                FacetField::Priority => "i.priority",
+                FacetField::Assignee => "i.assignee",
+                FacetField::Labels => "i.labels",
+                FacetField::Components => "i.components",
+                FacetField::FixVersions => "i.fix_versions",
+            };
+            // List fields count each element; issues with an empty list add nothing
+            let value = if field.is_list() {
+                format!("unnest(json_extract_string({}, '$[*]'))", column)
+            } else {
+                column.to_string()
+            };
+            let sql = format!(
+                "{} SELECT facet_value, COUNT(*) FROM (SELECT {} AS facet_value {}) \
+                 GROUP BY facet_value ORDER BY COUNT(*) DESC, facet_value NULLS LAST",
+                search.with_clause(),
+                value,
+                search.from
+            );
+
+            let mut stmt = conn
+                .prepare(&sql)
+                .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+            let values = stmt
+                .query_map(param_refs.as_slice(), |row| {
+                    Ok(FacetValue {
+                        value: row.get(0)?,
+                        count: row.get::<_, i64>(1)? as usize,
+                    })
+                })
+                .map_err(|e| DomainError::Repository(format!("Failed to count facet: {}", e)))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+            facets.push(SearchFacet { field, values });
+        }
+
+        Ok(facets)
     }
 
     fn mark_deleted_not_in_keys(&self, project_id: &str, keys: &[String]) -> DomainResult<usize> {
@@ -594,6 +870,96 @@ impl IssueRepository for DuckDbIssueRepository {
             has_more,
         })
     }
+
+    fn find_by_key(&self, issue_key: &str) -> DomainResult<Option<Issue>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+            SELECT id, project_id, key, summary, description,
+                   status, priority, assignee, reporter,
+                   issue_type, resolution, labels, components, fix_versions, sprint, team, parent_key,
+                   CASE WHEN due_date IS NOT NULL THEN strftime(due_date::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END as due_date,
+                   CASE WHEN created_date IS NOT NULL THEN strftime(created_date::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END as created_date,
+                   CASE WHEN updated_date IS NOT NULL THEN strftime(updated_date::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END as updated_date,
+                   raw_data
+            FROM issues
+            WHERE key = ?
+            LIMIT 1
+            "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let mut rows = stmt
+            .query_map(duckdb::params![issue_key], |row| Self::map_issue_row(row))
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        match rows.next() {
+            Some(issue) => Ok(Some(
+                issue.map_err(|e| DomainError::Repository(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn update_status(&self, issue_key: &str, status: &str) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        conn.execute(
+            "UPDATE issues SET status = ? WHERE key = ?",
+            duckdb::params![status, issue_key],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to update issue status: {}", e)))?;
+        Ok(())
+    }
+
+    fn update_due_date(
+        &self,
+        issue_key: &str,
+        due_date: Option<DateTime<Utc>>,
+    ) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        conn.execute(
+            "UPDATE issues SET due_date = ? WHERE key = ?",
+            duckdb::params![due_date.map(|d| d.to_rfc3339()), issue_key],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to update issue due date: {}", e)))?;
+        Ok(())
+    }
+
+    fn update_editable_fields(&self, issue: &Issue) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        let to_json = |values: &Option<Vec<String>>| {
+            values
+                .as_ref()
+                .map(|v| serde_json::to_string(v).unwrap_or_default())
+        };
+        conn.execute(
+            r#"
+            UPDATE issues
+            SET summary = ?, priority = ?, labels = ?, components = ?, fix_versions = ?
+            WHERE key = ?
+            "#,
+            duckdb::params![
+                &issue.summary,
+                &issue.priority,
+                to_json(&issue.labels),
+                to_json(&issue.components),
+                to_json(&issue.fix_versions),
+                &issue.key,
+            ],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to update issue fields: {}", e)))?;
+        Ok(())
+    }
 }
 
 impl DuckDbIssueRepository {
@@ -646,3 +1012,230 @@ impl DuckDbIssueRepository {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::{DateRange, merge_facets};
+    use crate::infrastructure::database::test_support::test_connection;
+
+    fn create_test_repository() -> DuckDbIssueRepository {
+        let conn = test_connection(
+            r#"
+            INSERT INTO issues
+                (id, project_id, key, summary, status, priority, issue_type, resolution,
+                 labels, components, fix_versions, sprint, parent_key,
+                 created_date, updated_date, due_date, raw_data, is_deleted)
+            VALUES
+                ('1', '100', 'PROJ-1', 'Login fails', 'Open', 'High', 'Bug', NULL,
+                 '["backend", "auth"]', '["API"]', '["1.0"]', 'Sprint 1', 'PROJ-9',
+                 '2024-05-01 09:00:00+00', '2024-05-20 09:00:00+00', '2024-06-01 00:00:00+00',
+                 '{"fields": {"status": {"statusCategory": {"key": "new", "name": "To Do"}}}}', false),
+                ('2', '100', 'PROJ-2', 'Dark mode', 'Done', 'Low', 'Story', 'Fixed',
+                 '["frontend"]', '["UI"]', '["1.1"]', 'Sprint 2', 'PROJ-9',
+                 '2024-05-10 09:00:00+00', '2024-05-12 09:00:00+00', NULL,
+                 '{"fields": {"resolutiondate": "2024-05-12T09:00:00.000+0000", "status": {"statusCategory": {"key": "done", "name": "Done"}}}}', false),
+                ('3', '100', 'PROJ-10', 'Login slow', 'In Progress', 'High', 'Bug', NULL,
+                 '["backend"]', '["API", "UI"]', '[]', 'Sprint 2', NULL,
+                 '2024-05-15 09:00:00+00', '2024-05-16 09:00:00+00', NULL,
+                 '{"fields": {"status": {"statusCategory": {"key": "indeterminate", "name": "In Progress"}}}}', false),
+                ('4', '100', 'PROJ-11', 'Login deleted', 'Open', 'High', 'Bug', NULL,
+                 '["backend"]', '[]', '[]', NULL, NULL,
+                 '2024-05-16 09:00:00+00', '2024-05-16 09:00:00+00', NULL, '{"fields": {}}', true);
+            "#,
+        );
+
+        DuckDbIssueRepository::new(conn)
+    }
+
+    fn keys(repo: &DuckDbIssueRepository, params: SearchParams) -> Vec<String> {
+        repo.search(&params)
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.key)
+            .collect()
+    }
+
+    fn sorted_by_key() -> Vec<SearchSort> {
+        SearchSort::parse_list("key").unwrap()
+    }
+
+    #[test]
+    fn test_search_filters() {
+        let repo = create_test_repository();
+        let params = |params: SearchParams| SearchParams {
+            sort: sorted_by_key(),
+            ..params
+        };
+
+        assert_eq!(
+            keys(
+                &repo,
+                params(SearchParams {
+                    labels: vec!["backend".to_string(), "frontend".to_string()],
+                    ..Default::default()
+                })
+            ),
+            vec!["PROJ-1", "PROJ-2", "PROJ-10"]
+        );
+        assert_eq!(
+            keys(
+                &repo,
+                params(SearchParams {
+                    labels: vec!["backend".to_string(), "auth".to_string()],
+                    labels_match: LabelMatch::All,
+                    ..Default::default()
+                })
+            ),
+            vec!["PROJ-1"]
+        );
+        assert_eq!(
+            keys(
+                &repo,
+                params(SearchParams {
+                    components: vec!["UI".to_string()],
+                    sprint: Some("Sprint 2".to_string()),
+                    ..Default::default()
+                })
+            ),
+            vec!["PROJ-2", "PROJ-10"]
+        );
+        assert_eq!(
+            keys(
+                &repo,
+                params(SearchParams {
+                    resolution: Some("Unresolved".to_string()),
+                    status_category: Some("To Do".to_string()),
+                    parent_key: Some("PROJ-9".to_string()),
+                    ..Default::default()
+                })
+            ),
+            vec!["PROJ-1"]
+        );
+        assert_eq!(
+            keys(
+                &repo,
+                params(SearchParams {
+                    query: Some("Login".to_string()),
+                    priority: Some("High".to_string()),
+                    created: DateRange::parse(Some("2024-05-02"), None).unwrap(),
+                    ..Default::default()
+                })
+            ),
+            vec!["PROJ-10"]
+        );
+        assert_eq!(
+            keys(
+                &repo,
+                params(SearchParams {
+                    resolved: DateRange::parse(Some("2024-05-12"), Some("2024-05-12")).unwrap(),
+                    ..Default::default()
+                })
+            ),
+            vec!["PROJ-2"]
+        );
+    }
+
+    #[test]
+    fn test_search_sort_and_count() {
+        let repo = create_test_repository();
+
+        let params = SearchParams {
+            sort: SearchSort::parse_list("updated:asc").unwrap(),
+            limit: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(keys(&repo, params.clone()), vec!["PROJ-2", "PROJ-10"]);
+        assert_eq!(repo.count_matching(&params).unwrap(), 3);
+
+        let params = SearchParams {
+            sort: SearchSort::parse_list("due:desc,key:desc").unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(keys(&repo, params), vec!["PROJ-1", "PROJ-10", "PROJ-2"]);
+    }
+
+    fn facet(facets: &[SearchFacet], field: FacetField) -> Vec<(Option<&str>, usize)> {
+        facets
+            .iter()
+            .find(|facet| facet.field == field)
+            .unwrap()
+            .values
+            .iter()
+            .map(|value| (value.value.as_deref(), value.count))
+            .collect()
+    }
+
+    #[test]
+    fn test_facet_counts() {
+        let repo = create_test_repository();
+
+        let params = SearchParams {
+            limit: Some(1),
+            ..Default::default()
+        };
+        let facets = repo.facet_counts(&params, &FacetField::ALL).unwrap();
+
+        assert_eq!(
+            facet(&facets, FacetField::Priority),
+            vec![(Some("High"), 2), (Some("Low"), 1)]
+        );
+        assert_eq!(
+            facet(&facets, FacetField::Labels),
+            vec![
+                (Some("backend"), 2),
+                (Some("auth"), 1),
+                (Some("frontend"), 1)
+            ]
+        );
+        assert_eq!(facet(&facets, FacetField::Assignee), vec![(None, 3)]);
+        assert_eq!(
+            facet(&facets, FacetField::FixVersions),
+            vec![(Some("1.0"), 1), (Some("1.1"), 1)]
+        );
+
+        let mut merged = repo
+            .facet_counts(
+                &SearchParams {
+                    issue_type: Some("Bug".to_string()),
+                    ..Default::default()
+                },
+                &[FacetField::Components],
+            )
+            .unwrap();
+        merge_facets(
+            &mut merged,
+            repo.facet_counts(&SearchParams::default(), &[FacetField::Components])
+                .unwrap(),
+        );
+        assert_eq!(
+            facet(&merged, FacetField::Components),
+            vec![(Some("API"), 4), (Some("UI"), 3)]
+        );
+    }
+
+    #[test]
+    fn test_find_links_reads_raw_issue_links() {
+        let repo = create_test_repository();
+        repo.conn
+            .lock()
+            .unwrap()
+            .execute_batch(
+                r#"
+                UPDATE issues SET raw_data = '{"fields": {"issuelinks": [
+                    {"type": {"name": "Duplicate", "inward": "is duplicated by", "outward": "duplicates"},
+                     "outwardIssue": {"key": "PROJ-10"}}
+                ]}}'
+                WHERE key IN ('PROJ-1', 'PROJ-11');
+                "#,
+            )
+            .unwrap();
+
+        let links = repo.find_links("100").unwrap();
+
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].key, "PROJ-1");
+        assert_eq!(links[0].linked_key, "PROJ-10");
+        assert_eq!(links[0].relation.as_deref(), Some("duplicates"));
+    }
+}
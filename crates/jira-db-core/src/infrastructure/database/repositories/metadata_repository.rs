@@ -1,4 +1,7 @@
-use crate::domain::entities::{Component, FixVersion, IssueType, Label, Priority, Status};
+use crate::domain::entities::{
+    Component, FieldOption, FixVersion, IssueType, Label, Priority, Status, Workflow,
+    WorkflowTransition,
+};
 use crate::domain::error::{DomainError, DomainResult};
 use crate::domain::repositories::MetadataRepository;
 use chrono::{DateTime, Utc};
@@ -351,4 +354,244 @@ impl MetadataRepository for DuckDbMetadataRepository {
         }
         Ok(fix_versions)
     }
+
+    fn replace_workflows(&self, project_id: &str, workflows: &[Workflow]) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        let now = Utc::now().to_rfc3339();
+
+        debug!(
+            "[SQL] Replacing {} workflows for project {}",
+            workflows.len(),
+            project_id
+        );
+
+        conn.execute(
+            "DELETE FROM workflow_transitions WHERE project_id = ?",
+            duckdb::params![project_id],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to delete workflow transitions: {}", e))
+        })?;
+        conn.execute(
+            "DELETE FROM workflows WHERE project_id = ?",
+            duckdb::params![project_id],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to delete workflows: {}", e)))?;
+
+        for workflow in workflows {
+            let issue_types = serde_json::to_string(&workflow.issue_types)
+                .map_err(|e| DomainError::Repository(e.to_string()))?;
+            let statuses = serde_json::to_string(&workflow.statuses)
+                .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+            conn.execute(
+                r#"
+                INSERT INTO workflows (project_id, name, description, scheme_name, is_default, issue_types, statuses, created_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                duckdb::params![
+                    project_id,
+                    &workflow.name,
+                    &workflow.description,
+                    &workflow.scheme_name,
+                    workflow.is_default,
+                    issue_types,
+                    statuses,
+                    &now,
+                    &now,
+                ],
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to insert workflow: {}", e)))?;
+
+            for transition in &workflow.transitions {
+                conn.execute(
+                    r#"
+                    INSERT INTO workflow_transitions (project_id, workflow_name, transition_id, name, from_status, to_status, transition_type)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                    "#,
+                    duckdb::params![
+                        project_id,
+                        &workflow.name,
+                        &transition.id,
+                        &transition.name,
+                        &transition.from_status,
+                        &transition.to_status,
+                        &transition.transition_type,
+                    ],
+                )
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to insert workflow transition: {}", e))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn find_workflows_by_project(&self, project_id: &str) -> DomainResult<Vec<Workflow>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT name, description, scheme_name, is_default, issue_types::VARCHAR, statuses::VARCHAR FROM workflows WHERE project_id = ? ORDER BY name",
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(duckdb::params![project_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<String>>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, bool>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                ))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let parse_list = |s: Option<String>| -> Vec<String> {
+            s.and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default()
+        };
+
+        let mut workflows = Vec::new();
+        for row in rows {
+            let (name, description, scheme_name, is_default, issue_types, statuses) =
+                row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            workflows.push(Workflow {
+                name,
+                description,
+                scheme_name,
+                is_default,
+                issue_types: parse_list(issue_types),
+                statuses: parse_list(statuses),
+                transitions: Vec::new(),
+            });
+        }
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT workflow_name, transition_id, name, from_status, to_status, transition_type
+                FROM workflow_transitions
+                WHERE project_id = ?
+                ORDER BY workflow_name, TRY_CAST(transition_id AS INTEGER), transition_id
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(duckdb::params![project_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    WorkflowTransition {
+                        id: row.get(1)?,
+                        name: row.get(2)?,
+                        from_status: row.get(3)?,
+                        to_status: row.get(4)?,
+                        transition_type: row.get(5)?,
+                    },
+                ))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        for row in rows {
+            let (workflow_name, transition) =
+                row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            if let Some(workflow) = workflows.iter_mut().find(|w| w.name == workflow_name) {
+                workflow.transitions.push(transition);
+            }
+        }
+
+        Ok(workflows)
+    }
+
+    fn replace_field_options(&self, project_id: &str, options: &[FieldOption]) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        let now = Utc::now().to_rfc3339();
+
+        debug!(
+            "[SQL] Replacing {} field options for project {}",
+            options.len(),
+            project_id
+        );
+
+        conn.execute(
+            "DELETE FROM field_options WHERE project_id = ?",
+            duckdb::params![project_id],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to delete field options: {}", e)))?;
+
+        for option in options {
+            conn.execute(
+                r#"
+                INSERT INTO field_options (project_id, field_id, field_name, option_id, value, parent_option_id, disabled, created_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT (project_id, field_id, option_id) DO UPDATE SET
+                    field_name = excluded.field_name,
+                    value = excluded.value,
+                    parent_option_id = excluded.parent_option_id,
+                    disabled = excluded.disabled,
+                    updated_at = excluded.updated_at
+                "#,
+                duckdb::params![
+                    project_id,
+                    &option.field_id,
+                    &option.field_name,
+                    &option.option_id,
+                    &option.value,
+                    &option.parent_option_id,
+                    option.disabled,
+                    &now,
+                    &now,
+                ],
+            )
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to insert field option: {}", e))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn find_field_options_by_project(&self, project_id: &str) -> DomainResult<Vec<FieldOption>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT field_id, field_name, option_id, value, parent_option_id, disabled
+                FROM field_options
+                WHERE project_id = ?
+                ORDER BY field_id, parent_option_id NULLS FIRST, TRY_CAST(option_id AS INTEGER), option_id
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(duckdb::params![project_id], |row| {
+                Ok(FieldOption {
+                    field_id: row.get(0)?,
+                    field_name: row.get(1)?,
+                    option_id: row.get(2)?,
+                    value: row.get(3)?,
+                    parent_option_id: row.get(4)?,
+                    disabled: row.get(5)?,
+                })
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let mut options = Vec::new();
+        for option in rows {
+            options.push(option.map_err(|e| DomainError::Repository(e.to_string()))?);
+        }
+        Ok(options)
+    }
 }
@@ -1,21 +1,31 @@
 mod change_history_repository;
 mod embeddings_repository;
 mod field_repository;
+mod full_text_repository;
 mod issue_repository;
 mod issue_snapshot_repository;
 mod issues_expanded_repository;
 mod metadata_repository;
+mod pending_operation_repository;
 mod project_repository;
 mod raw_data_repository;
 mod sync_history_repository;
+mod topic_repository;
 
 pub use change_history_repository::DuckDbChangeHistoryRepository;
-pub use embeddings_repository::{EmbeddingsRepository, IssueEmbedding, SemanticSearchResult};
+pub use embeddings_repository::{
+    EmbeddedIssue, EmbeddingNeighbor, EmbeddingsRepository, IssueEmbedding, NeighborFilter,
+    SemanticSearchResult,
+};
 pub use field_repository::DuckDbFieldRepository;
+pub use full_text_repository::FullTextSearchRepository;
+pub(crate) use full_text_repository::{is_cjk, tokenize};
 pub use issue_repository::DuckDbIssueRepository;
 pub use issue_snapshot_repository::DuckDbIssueSnapshotRepository;
 pub use issues_expanded_repository::DuckDbIssuesExpandedRepository;
 pub use metadata_repository::DuckDbMetadataRepository;
+pub use pending_operation_repository::DuckDbPendingOperationRepository;
 pub use project_repository::DuckDbProjectRepository;
-pub use raw_data_repository::{RawDataRepository, SharedRawDataRepository};
+pub use raw_data_repository::{RawDataRepository, RawIssueVersion, SharedRawDataRepository};
 pub use sync_history_repository::DuckDbSyncHistoryRepository;
+pub use topic_repository::DuckDbTopicRepository;
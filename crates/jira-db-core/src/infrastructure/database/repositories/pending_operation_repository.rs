@@ -0,0 +1,202 @@
+use crate::domain::entities::{PendingOperation, PendingOperationKind, PendingOperationStatus};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::PendingOperationRepository;
+use chrono::{DateTime, Utc};
+use duckdb::Connection;
+use std::sync::{Arc, Mutex};
+
+pub struct DuckDbPendingOperationRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl DuckDbPendingOperationRepository {
+    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+        Self { conn }
+    }
+
+    fn parse_datetime(s: Option<String>) -> Option<DateTime<Utc>> {
+        s.and_then(|s| {
+            DateTime::parse_from_rfc3339(&s)
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc))
+        })
+    }
+
+    fn query_operations(
+        conn: &Connection,
+        where_clause: &str,
+        params: &[&dyn duckdb::ToSql],
+    ) -> DomainResult<Vec<PendingOperation>> {
+        let sql = format!(
+            r#"
+            SELECT id, issue_key, payload::VARCHAR,
+                   CASE WHEN base_updated_at IS NOT NULL THEN strftime(base_updated_at::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END,
+                   status, error_message,
+                   strftime(created_at::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00',
+                   CASE WHEN applied_at IS NOT NULL THEN strftime(applied_at::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00' ELSE NULL END
+            FROM pending_operations
+            {}
+            ORDER BY id
+            "#,
+            where_clause
+        );
+
+        let mut stmt = conn
+            .prepare(&sql)
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(params, |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                    row.get::<_, Option<String>>(7)?,
+                ))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let mut operations = Vec::new();
+        for row in rows {
+            let (id, issue_key, payload, base, status, error_message, created, applied) =
+                row.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+            let kind: PendingOperationKind = serde_json::from_str(&payload).map_err(|e| {
+                DomainError::Repository(format!(
+                    "Invalid payload for pending operation {}: {}",
+                    id, e
+                ))
+            })?;
+            let status = PendingOperationStatus::parse(&status).ok_or_else(|| {
+                DomainError::Repository(format!(
+                    "Invalid status for pending operation {}: {}",
+                    id, status
+                ))
+            })?;
+
+            operations.push(PendingOperation {
+                id,
+                issue_key,
+                kind,
+                base_updated_at: Self::parse_datetime(base),
+                status,
+                error_message,
+                created_at: Self::parse_datetime(created).unwrap_or_else(Utc::now),
+                applied_at: Self::parse_datetime(applied),
+            });
+        }
+
+        Ok(operations)
+    }
+}
+
+impl PendingOperationRepository for DuckDbPendingOperationRepository {
+    fn insert(
+        &self,
+        issue_key: &str,
+        kind: &PendingOperationKind,
+        base_updated_at: Option<DateTime<Utc>>,
+    ) -> DomainResult<i64> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let payload = serde_json::to_string(kind).map_err(|e| {
+            DomainError::Repository(format!("Failed to serialize pending operation: {}", e))
+        })?;
+
+        let id: i64 = conn
+            .query_row(
+                r#"
+            INSERT INTO pending_operations (issue_key, operation_type, payload, base_updated_at, status, created_at)
+            VALUES (?, ?, ?, ?, 'pending', ?)
+            RETURNING id
+            "#,
+                duckdb::params![
+                    issue_key,
+                    kind.type_name(),
+                    payload,
+                    base_updated_at.map(|d| d.to_rfc3339()),
+                    Utc::now().to_rfc3339(),
+                ],
+                |row| row.get(0),
+            )
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to insert pending operation: {}", e))
+            })?;
+
+        Ok(id)
+    }
+
+    fn find_by_id(&self, id: i64) -> DomainResult<Option<PendingOperation>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        let mut operations = Self::query_operations(&conn, "WHERE id = ?", &[&id])?;
+        Ok(operations.pop())
+    }
+
+    fn find_by_status(
+        &self,
+        status: Option<PendingOperationStatus>,
+    ) -> DomainResult<Vec<PendingOperation>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        match status {
+            Some(status) => Self::query_operations(&conn, "WHERE status = ?", &[&status.as_str()]),
+            None => Self::query_operations(&conn, "", &[]),
+        }
+    }
+
+    fn update_status(
+        &self,
+        id: i64,
+        status: PendingOperationStatus,
+        error_message: Option<&str>,
+        applied_at: Option<DateTime<Utc>>,
+    ) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        conn.execute(
+            r#"
+            UPDATE pending_operations
+            SET status = ?, error_message = ?, applied_at = ?
+            WHERE id = ?
+            "#,
+            duckdb::params![
+                status.as_str(),
+                error_message,
+                applied_at.map(|d| d.to_rfc3339()),
+                id
+            ],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to update pending operation: {}", e))
+        })?;
+        Ok(())
+    }
+
+    fn requeue(&self, id: i64) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+        conn.execute(
+            r#"
+            UPDATE pending_operations
+            SET status = 'pending', error_message = NULL, base_updated_at = NULL
+            WHERE id = ?
+            "#,
+            duckdb::params![id],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to requeue pending operation: {}", e))
+        })?;
+        Ok(())
+    }
+}
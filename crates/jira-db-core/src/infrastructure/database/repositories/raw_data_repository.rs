@@ -2,12 +2,26 @@
 
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use duckdb::params;
+use serde::Serialize;
 
 use crate::domain::error::{DomainError, DomainResult};
 use crate::infrastructure::database::DbConnection;
 
+/// A recorded version of an issue's raw API payload (without the payload itself)
+#[derive(Debug, Clone, Serialize)]
+pub struct RawIssueVersion {
+    pub issue_key: String,
+    /// Version number per issue, starting at 1
+    pub version: i32,
+    /// SHA-256 of the payload
+    pub content_hash: String,
+    /// `sync_history` ID of the sync run that recorded this version
+    pub sync_run_id: Option<i64>,
+    pub synced_at: DateTime<Utc>,
+}
+
 /// Repository for storing raw JIRA API responses
 pub struct RawDataRepository {
     conn: DbConnection,
@@ -87,6 +101,136 @@ impl RawDataRepository {
         Ok(count)
     }
 
+    /// Append payloads that differ from the latest stored version of each issue.
+    ///
+    /// Identical payloads are skipped, so only changes take up space.
+    /// Returns the number of new versions recorded.
+    pub fn batch_append_issue_raw_versions(
+        &self,
+        items: &[(String, String, String, String)], // (id, issue_key, project_id, raw_data)
+        sync_run_id: Option<i64>,
+    ) -> DomainResult<usize> {
+        if items.is_empty() {
+            return Ok(0);
+        }
+
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let now = Utc::now().to_rfc3339();
+        let mut count = 0;
+
+        for (id, issue_key, project_id, raw_data) in items {
+            count += conn
+                .execute(
+                    r#"
+                    INSERT INTO issue_raw_versions (issue_id, issue_key, project_id, version, content_hash, sync_run_id, raw_data, synced_at)
+                    SELECT ?, ?, ?, COALESCE(latest.version, 0) + 1, new.hash, ?, ?, ?
+                    FROM (SELECT sha256(?) AS hash) new
+                    LEFT JOIN (
+                        SELECT version, content_hash
+                        FROM issue_raw_versions
+                        WHERE issue_id = ?
+                        ORDER BY version DESC
+                        LIMIT 1
+                    ) latest ON true
+                    WHERE latest.content_hash IS DISTINCT FROM new.hash
+                    "#,
+                    params![
+                        id,
+                        issue_key,
+                        project_id,
+                        sync_run_id,
+                        raw_data,
+                        &now,
+                        raw_data,
+                        id
+                    ],
+                )
+                .map_err(|e| {
+                    DomainError::Repository(format!("Failed to append issue raw version: {}", e))
+                })?;
+        }
+
+        Ok(count)
+    }
+
+    /// List the recorded payload versions of an issue, oldest first
+    pub fn find_issue_raw_versions(&self, issue_key: &str) -> DomainResult<Vec<RawIssueVersion>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT issue_key, version, content_hash, sync_run_id,
+                       strftime(synced_at::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00'
+                FROM issue_raw_versions
+                WHERE issue_key = ?
+                ORDER BY version
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare statement: {}", e)))?;
+
+        let rows = stmt
+            .query_map([issue_key], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i32>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<i64>>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let mut versions = Vec::new();
+        for row in rows {
+            let (issue_key, version, content_hash, sync_run_id, synced_at) =
+                row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            versions.push(RawIssueVersion {
+                issue_key,
+                version,
+                content_hash,
+                sync_run_id,
+                synced_at: DateTime::parse_from_rfc3339(&synced_at)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            });
+        }
+        Ok(versions)
+    }
+
+    /// Get the payload of a specific version of an issue
+    pub fn get_issue_raw_version(
+        &self,
+        issue_key: &str,
+        version: i32,
+    ) -> DomainResult<Option<String>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT raw_data::VARCHAR FROM issue_raw_versions WHERE issue_key = ? AND version = ?",
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare statement: {}", e)))?;
+
+        let result = stmt.query_row(params![issue_key, version], |row| row.get::<_, String>(0));
+
+        match result {
+            Ok(data) => Ok(Some(data)),
+            Err(duckdb::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(DomainError::Repository(format!(
+                "Failed to get issue raw version: {}",
+                e
+            ))),
+        }
+    }
+
     /// Get raw data for an issue
     pub fn get_issue_raw_data(&self, issue_key: &str) -> DomainResult<Option<String>> {
         let conn = self.conn.lock().map_err(|e| {
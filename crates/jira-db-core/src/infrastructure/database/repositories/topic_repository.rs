@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+use duckdb::Connection;
+use log::debug;
+use std::sync::{Arc, Mutex};
+
+use crate::domain::entities::{TopicAssignment, TopicCluster};
+use crate::domain::error::{DomainError, DomainResult};
+use crate::domain::repositories::TopicRepository;
+
+pub struct DuckDbTopicRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl DuckDbTopicRepository {
+    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+        Self { conn }
+    }
+
+    fn write(
+        conn: &Connection,
+        project_id: &str,
+        clusters: &[TopicCluster],
+        assignments: &[TopicAssignment],
+    ) -> DomainResult<()> {
+        conn.execute(
+            "DELETE FROM issue_topic_assignments WHERE project_id = ?",
+            duckdb::params![project_id],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to delete topic assignments: {}", e))
+        })?;
+        conn.execute(
+            "DELETE FROM topic_clusters WHERE project_id = ?",
+            duckdb::params![project_id],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to delete topic clusters: {}", e)))?;
+
+        for cluster in clusters {
+            let representatives = serde_json::to_string(&cluster.representatives)
+                .map_err(|e| DomainError::Repository(e.to_string()))?;
+            conn.execute(
+                r#"
+                INSERT INTO topic_clusters (project_id, cluster_id, name, named_by, size, representatives, generated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                duckdb::params![
+                    project_id,
+                    cluster.cluster_id,
+                    &cluster.name,
+                    &cluster.named_by,
+                    cluster.size as i64,
+                    representatives,
+                    cluster.generated_at.to_rfc3339(),
+                ],
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to insert topic cluster: {}", e)))?;
+        }
+
+        for assignment in assignments {
+            conn.execute(
+                r#"
+                INSERT INTO issue_topic_assignments (project_id, issue_id, issue_key, cluster_id, similarity)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+                duckdb::params![
+                    project_id,
+                    &assignment.issue_id,
+                    &assignment.issue_key,
+                    assignment.cluster_id,
+                    assignment.similarity,
+                ],
+            )
+            .map_err(|e| {
+                DomainError::Repository(format!("Failed to insert topic assignment: {}", e))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl TopicRepository for DuckDbTopicRepository {
+    fn replace(
+        &self,
+        project_id: &str,
+        clusters: &[TopicCluster],
+        assignments: &[TopicAssignment],
+    ) -> DomainResult<()> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        debug!(
+            "[SQL] Replacing {} topic clusters ({} assignments) for project {}",
+            clusters.len(),
+            assignments.len(),
+            project_id
+        );
+
+        conn.execute("BEGIN TRANSACTION", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to begin transaction: {}", e)))?;
+        if let Err(e) = Self::write(&conn, project_id, clusters, assignments) {
+            let _ = conn.execute("ROLLBACK", []);
+            return Err(e);
+        }
+        conn.execute("COMMIT", [])
+            .map_err(|e| DomainError::Repository(format!("Failed to commit transaction: {}", e)))?;
+        Ok(())
+    }
+
+    fn find_clusters(&self, project_id: &str) -> DomainResult<Vec<TopicCluster>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT cluster_id, name, named_by, size, representatives::VARCHAR,
+                       strftime(generated_at::TIMESTAMP, '%Y-%m-%dT%H:%M:%S') || '+00:00'
+                FROM topic_clusters
+                WHERE project_id = ?
+                ORDER BY size DESC, cluster_id
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(duckdb::params![project_id], |row| {
+                Ok((
+                    row.get::<_, i32>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, String>(5)?,
+                ))
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        let mut clusters = Vec::new();
+        for row in rows {
+            let (cluster_id, name, named_by, size, representatives, generated_at) =
+                row.map_err(|e| DomainError::Repository(e.to_string()))?;
+            clusters.push(TopicCluster {
+                cluster_id,
+                name,
+                named_by,
+                size: size as usize,
+                representatives: representatives
+                    .and_then(|s| serde_json::from_str(&s).ok())
+                    .unwrap_or_default(),
+                generated_at: DateTime::parse_from_rfc3339(&generated_at)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            });
+        }
+        Ok(clusters)
+    }
+
+    fn find_assignments(&self, project_id: &str) -> DomainResult<Vec<TopicAssignment>> {
+        let conn = self.conn.lock().map_err(|e| {
+            DomainError::Repository(format!("Failed to acquire database lock: {}", e))
+        })?;
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT issue_id, issue_key, cluster_id, similarity
+                FROM issue_topic_assignments
+                WHERE project_id = ?
+                ORDER BY issue_key
+                "#,
+            )
+            .map_err(|e| DomainError::Repository(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(duckdb::params![project_id], |row| {
+                Ok(TopicAssignment {
+                    issue_id: row.get(0)?,
+                    issue_key: row.get(1)?,
+                    cluster_id: row.get(2)?,
+                    similarity: row.get(3)?,
+                })
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to execute query: {}", e)))?;
+
+        rows.collect::<Result<_, _>>()
+            .map_err(|e| DomainError::Repository(e.to_string()))
+    }
+}
@@ -1,3 +1,4 @@
+use super::migrations::{self, MigrationStatus, SchemaKind};
 use crate::domain::error::{DomainError, DomainResult};
 use duckdb::Connection;
 
@@ -6,23 +7,54 @@ pub struct Schema;
 impl Schema {
     /// Initialize the main database schema (processed data)
     pub fn init(conn: &Connection) -> DomainResult<()> {
-        Self::create_projects_table(conn)?;
-        Self::create_issues_table(conn)?;
-        Self::create_sync_history_table(conn)?;
-        Self::create_metadata_tables(conn)?;
-        Self::create_change_history_table(conn)?;
-        Self::create_issue_snapshots_table(conn)?;
-        Self::create_jira_fields_table(conn)?;
-        Self::create_issues_expanded_table(conn)?;
-        Self::create_indexes(conn)?;
-        Self::run_migrations(conn)?;
-        Ok(())
+        Self::migrate(conn, SchemaKind::Main).map(|_| ())
     }
 
     /// Initialize the raw data database schema
     pub fn init_raw(conn: &Connection) -> DomainResult<()> {
-        Self::create_raw_issues_table(conn)?;
-        Self::create_raw_projects_table(conn)?;
+        Self::migrate(conn, SchemaKind::Raw).map(|_| ())
+    }
+
+    /// Create missing tables and apply pending migrations.
+    ///
+    /// Returns the migrations that were applied.
+    pub fn migrate(conn: &Connection, kind: SchemaKind) -> DomainResult<Vec<MigrationStatus>> {
+        migrations::ensure_supported(conn, kind)?;
+        Self::create_tables(conn, kind)?;
+        migrations::migrate(conn, kind)
+    }
+
+    /// Run the same steps as `migrate` in a transaction that is rolled back.
+    ///
+    /// Returns the migrations that would be applied.
+    pub fn dry_run(conn: &Connection, kind: SchemaKind) -> DomainResult<Vec<MigrationStatus>> {
+        migrations::ensure_supported(conn, kind)?;
+        migrations::dry_run(conn, kind, || Self::create_tables(conn, kind))
+    }
+
+    fn create_tables(conn: &Connection, kind: SchemaKind) -> DomainResult<()> {
+        match kind {
+            SchemaKind::Main => {
+                Self::create_projects_table(conn)?;
+                Self::create_issues_table(conn)?;
+                Self::create_sync_history_table(conn)?;
+                Self::create_metadata_tables(conn)?;
+                Self::create_change_history_table(conn)?;
+                Self::create_issue_snapshots_table(conn)?;
+                Self::create_jira_fields_table(conn)?;
+                Self::create_issues_expanded_table(conn)?;
+                Self::create_pending_operations_table(conn)?;
+                Self::create_workflow_tables(conn)?;
+                Self::create_field_options_table(conn)?;
+                Self::create_topic_tables(conn)?;
+                Self::create_indexes(conn)?;
+            }
+            SchemaKind::Raw => {
+                Self::create_raw_issues_table(conn)?;
+                Self::create_raw_issue_versions_table(conn)?;
+                Self::create_raw_projects_table(conn)?;
+            }
+        }
         Ok(())
     }
 
@@ -58,90 +90,53 @@ impl Schema {
         Ok(())
     }
 
-    fn create_raw_projects_table(conn: &Connection) -> DomainResult<()> {
+    fn create_raw_issue_versions_table(conn: &Connection) -> DomainResult<()> {
+        // Append-only: a row is added only when the payload differs from the latest version
         conn.execute(
             r#"
-            CREATE TABLE IF NOT EXISTS project_raw_data (
-                id VARCHAR PRIMARY KEY,
-                project_key VARCHAR NOT NULL,
+            CREATE TABLE IF NOT EXISTS issue_raw_versions (
+                issue_id VARCHAR NOT NULL,
+                issue_key VARCHAR NOT NULL,
+                project_id VARCHAR NOT NULL,
+                version INTEGER NOT NULL,
+                content_hash VARCHAR NOT NULL,
+                sync_run_id BIGINT,
                 raw_data JSON NOT NULL,
-                synced_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+                synced_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (issue_id, version)
             )
             "#,
             [],
         )
         .map_err(|e| {
-            DomainError::Repository(format!("Failed to create project_raw_data table: {}", e))
+            DomainError::Repository(format!("Failed to create issue_raw_versions table: {}", e))
         })?;
 
-        Ok(())
-    }
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_raw_versions_key ON issue_raw_versions(issue_key)",
+            [],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to create index: {}", e)))?;
 
-    /// 既存のデータベースに対してマイグレーションを実行
-    fn run_migrations(conn: &Connection) -> DomainResult<()> {
-        // Migration: issuesテーブルにsprintカラムを追加
-        Self::add_column_if_not_exists(conn, "issues", "sprint", "VARCHAR")?;
-        // Migration: issue_snapshotsテーブルにraw_dataカラムを追加
-        Self::add_column_if_not_exists(conn, "issue_snapshots", "raw_data", "JSON")?;
-        // Migration: issuesテーブルにis_deletedカラムを追加（JIRA上で削除されたissueを論理削除するため）
-        Self::add_column_if_not_exists(conn, "issues", "is_deleted", "BOOLEAN DEFAULT false")?;
-        // Migration: issuesテーブルにdue_dateカラムを追加（期限日）
-        Self::add_column_if_not_exists(conn, "issues", "due_date", "TIMESTAMPTZ")?;
-        // Migration: issuesテーブルにteamカラムを追加（チーム）
-        Self::add_column_if_not_exists(conn, "issues", "team", "VARCHAR")?;
-        // Migration: issues_expandedテーブルにresolved_dateカラムを追加（解決日）
-        Self::add_column_if_not_exists(conn, "issues_expanded", "resolved_date", "TIMESTAMPTZ")?;
-        // Migration: issues_expandedテーブルにdue_dateカラムを追加（期限日）
-        Self::add_column_if_not_exists(conn, "issues_expanded", "due_date", "TIMESTAMPTZ")?;
-        // Migration: issue_snapshotsテーブルにresolved_dateカラムを追加（解決日）
-        Self::add_column_if_not_exists(conn, "issue_snapshots", "resolved_date", "TIMESTAMPTZ")?;
-        // Migration: issue_snapshotsテーブルにdue_dateカラムを追加（期限日）
-        Self::add_column_if_not_exists(conn, "issue_snapshots", "due_date", "TIMESTAMPTZ")?;
-        // Migration: issues_expandedテーブルにenvironmentカラムを追加（環境）
-        Self::add_column_if_not_exists(conn, "issues_expanded", "environment", "TEXT")?;
-        // Migration: issues_expandedテーブルにcreatorカラムを追加（作成者）
-        Self::add_column_if_not_exists(conn, "issues_expanded", "creator", "VARCHAR")?;
-        // Migration: issues_expandedテーブルにaffected_versionsカラムを追加（影響バージョン）
-        Self::add_column_if_not_exists(conn, "issues_expanded", "affected_versions", "JSON")?;
-        // Migration: issues_expandedテーブルにsecurity_levelカラムを追加（セキュリティレベル）
-        Self::add_column_if_not_exists(conn, "issues_expanded", "security_level", "VARCHAR")?;
-        // Migration: issue_snapshotsテーブルにupdated_dateカラムを追加（課題の更新日）
-        Self::add_column_if_not_exists(conn, "issue_snapshots", "updated_date", "TIMESTAMPTZ")?;
         Ok(())
     }
 
-    /// カラムが存在しない場合に追加する
-    fn add_column_if_not_exists(
-        conn: &Connection,
-        table: &str,
-        column: &str,
-        column_type: &str,
-    ) -> DomainResult<()> {
-        // DuckDBでカラムの存在を確認
-        let check_sql = format!(
-            "SELECT COUNT(*) FROM information_schema.columns WHERE table_name = '{}' AND column_name = '{}'",
-            table, column
-        );
-
-        let mut stmt = conn
-            .prepare(&check_sql)
-            .map_err(|e| DomainError::Repository(format!("Failed to prepare statement: {}", e)))?;
-
-        let count: i64 = stmt.query_row([], |row| row.get(0)).map_err(|e| {
-            DomainError::Repository(format!("Failed to check column existence: {}", e))
+    fn create_raw_projects_table(conn: &Connection) -> DomainResult<()> {
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS project_raw_data (
+                id VARCHAR PRIMARY KEY,
+                project_key VARCHAR NOT NULL,
+                raw_data JSON NOT NULL,
+                synced_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to create project_raw_data table: {}", e))
         })?;
 
-        if count == 0 {
-            let alter_sql = format!(
-                "ALTER TABLE {} ADD COLUMN {} {}",
-                table, column, column_type
-            );
-            conn.execute(&alter_sql, []).map_err(|e| {
-                DomainError::Repository(format!("Failed to add column {}.{}: {}", table, column, e))
-            })?;
-            log::info!("Migration: Added column {}.{}", table, column);
-        }
-
         Ok(())
     }
 
@@ -359,6 +354,7 @@ impl Schema {
                 to_value TEXT,
                 to_string TEXT,
                 changed_at TIMESTAMPTZ NOT NULL,
+                source VARCHAR DEFAULT 'changelog',
                 created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
             )
             "#,
@@ -532,6 +528,166 @@ impl Schema {
         Ok(())
     }
 
+    /// Offline write queue (transitions, comments, field edits) replayed by `push`
+    fn create_pending_operations_table(conn: &Connection) -> DomainResult<()> {
+        conn.execute(
+            "CREATE SEQUENCE IF NOT EXISTS pending_operations_id_seq START 1",
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!(
+                "Failed to create pending_operations sequence: {}",
+                e
+            ))
+        })?;
+
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS pending_operations (
+                id INTEGER PRIMARY KEY DEFAULT nextval('pending_operations_id_seq'),
+                issue_key VARCHAR NOT NULL,
+                operation_type VARCHAR NOT NULL,
+                payload JSON NOT NULL,
+                base_updated_at TIMESTAMPTZ,
+                status VARCHAR NOT NULL DEFAULT 'pending',
+                error_message TEXT,
+                created_at TIMESTAMPTZ NOT NULL,
+                applied_at TIMESTAMPTZ
+            )
+            "#,
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to create pending_operations table: {}", e))
+        })?;
+
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_pending_operations_status ON pending_operations(status)",
+            [],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to create index: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn create_workflow_tables(conn: &Connection) -> DomainResult<()> {
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS workflows (
+                project_id VARCHAR NOT NULL,
+                name VARCHAR NOT NULL,
+                description VARCHAR,
+                scheme_name VARCHAR,
+                is_default BOOLEAN NOT NULL DEFAULT false,
+                issue_types JSON,
+                statuses JSON,
+                created_at TIMESTAMPTZ NOT NULL,
+                updated_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (project_id, name)
+            )
+            "#,
+            [],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to create workflows table: {}", e)))?;
+
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS workflow_transitions (
+                project_id VARCHAR NOT NULL,
+                workflow_name VARCHAR NOT NULL,
+                transition_id VARCHAR NOT NULL,
+                name VARCHAR NOT NULL,
+                from_status VARCHAR,
+                to_status VARCHAR NOT NULL,
+                transition_type VARCHAR NOT NULL
+            )
+            "#,
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!(
+                "Failed to create workflow_transitions table: {}",
+                e
+            ))
+        })?;
+
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_workflow_transitions_workflow ON workflow_transitions(project_id, workflow_name)",
+            [],
+        )
+        .map_err(|e| DomainError::Repository(format!("Failed to create index: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn create_field_options_table(conn: &Connection) -> DomainResult<()> {
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS field_options (
+                project_id VARCHAR NOT NULL,
+                field_id VARCHAR NOT NULL,
+                field_name VARCHAR,
+                option_id VARCHAR NOT NULL,
+                value VARCHAR NOT NULL,
+                parent_option_id VARCHAR,
+                disabled BOOLEAN NOT NULL DEFAULT false,
+                created_at TIMESTAMPTZ NOT NULL,
+                updated_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (project_id, field_id, option_id)
+            )
+            "#,
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to create field_options table: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    fn create_topic_tables(conn: &Connection) -> DomainResult<()> {
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS topic_clusters (
+                project_id VARCHAR NOT NULL,
+                cluster_id INTEGER NOT NULL,
+                name VARCHAR NOT NULL,
+                named_by VARCHAR NOT NULL,
+                size INTEGER NOT NULL,
+                representatives JSON,
+                generated_at TIMESTAMPTZ NOT NULL,
+                PRIMARY KEY (project_id, cluster_id)
+            )
+            "#,
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!("Failed to create topic_clusters table: {}", e))
+        })?;
+
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS issue_topic_assignments (
+                project_id VARCHAR NOT NULL,
+                issue_id VARCHAR NOT NULL,
+                issue_key VARCHAR NOT NULL,
+                cluster_id INTEGER NOT NULL,
+                similarity DOUBLE NOT NULL,
+                PRIMARY KEY (project_id, issue_id)
+            )
+            "#,
+            [],
+        )
+        .map_err(|e| {
+            DomainError::Repository(format!(
+                "Failed to create issue_topic_assignments table: {}",
+                e
+            ))
+        })?;
+
+        Ok(())
+    }
+
     fn create_indexes(conn: &Connection) -> DomainResult<()> {
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_issues_project ON issues(project_id)",
@@ -0,0 +1,33 @@
+//! Database fixtures shared by tests
+
+use std::sync::{Arc, Mutex};
+
+use duckdb::Connection;
+
+use super::schema::Schema;
+use super::{DbConnection, EmbeddingsRepository};
+
+/// In-memory database with the main schema and `issue_embeddings`, with `sql` applied.
+///
+/// The embeddings table is created without the VSS extension, so tests need no download.
+pub(crate) fn test_connection(sql: &str) -> DbConnection {
+    let conn = Connection::open_in_memory().unwrap();
+    Schema::init(&conn).unwrap();
+    EmbeddingsRepository::create_table(&conn).unwrap();
+    conn.execute_batch(sql).unwrap();
+    Arc::new(Mutex::new(conn))
+}
+
+/// Store embeddings made by `model` through the repository
+pub(crate) fn store_embeddings(
+    conn: &DbConnection,
+    model: &str,
+    embeddings: &[(&str, &str, Vec<f32>)],
+) {
+    let repository = EmbeddingsRepository::new(conn.clone());
+    for (issue_id, issue_key, embedding) in embeddings {
+        repository
+            .upsert_embedding(issue_id, issue_key, embedding, "", "test", model)
+            .unwrap();
+    }
+}
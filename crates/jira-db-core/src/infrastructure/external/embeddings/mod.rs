@@ -12,6 +12,7 @@ pub use ollama::{OllamaConfig, OllamaEmbeddingClient};
 pub use openai::{EmbeddingConfig, EmbeddingResult, OpenAIEmbeddingClient};
 
 use crate::domain::error::{DomainError, DomainResult};
+use crate::infrastructure::config::EmbeddingsConfig;
 use async_trait::async_trait;
 
 /// Trait for embedding providers
@@ -161,6 +162,16 @@ impl ProviderConfig {
         self.endpoint = Some(endpoint.into());
         self
     }
+
+    /// Provider config from the `embeddings` section of the settings file
+    pub fn from_settings(config: &EmbeddingsConfig) -> DomainResult<Self> {
+        Ok(Self {
+            provider: config.provider.parse()?,
+            api_key: config.get_api_key().cloned(),
+            model: Some(config.model.clone()),
+            endpoint: config.endpoint.clone(),
+        })
+    }
 }
 
 /// Create an embedding provider from configuration
@@ -4,10 +4,11 @@ use log::{debug, info, warn};
 use std::time::Duration;
 use tokio::time::{sleep, timeout};
 
-use crate::application::dto::{CreatedIssueDto, TransitionDto};
+use crate::application::dto::{CreatedIssueDto, IssueFieldUpdate, TransitionDto};
 use crate::application::services::{FetchProgress, JiraService};
 use crate::domain::entities::{
-    Component, FixVersion, Issue, IssueType, JiraField, Label, Priority, Project, Status,
+    Component, FieldOption, FixVersion, Issue, IssueType, JiraField, Label, Priority, Project,
+    Status, Workflow, WorkflowTransition,
 };
 use crate::domain::error::{DomainError, DomainResult};
 use crate::infrastructure::config::JiraConfig;
@@ -131,6 +132,152 @@ impl JiraApiClient {
         })
     }
 
+    /// GET a JSON resource, mapping failures to `Failed to <what>` errors
+    async fn get_json(
+        &self,
+        url: &str,
+        query: &[(&str, &str)],
+        what: &str,
+    ) -> DomainResult<serde_json::Value> {
+        debug!("[JIRA API] GET {} ({})", url, what);
+
+        let response = self
+            .http_client
+            .get(url)
+            .query(query)
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| DomainError::ExternalService(format!("Failed to {}: {}", what, e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Could not read error response".to_string());
+            return Err(DomainError::ExternalService(format!(
+                "Failed to {}: {} - {}",
+                what, status, error_text
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            DomainError::ExternalService(format!("Failed to parse response ({}): {}", what, e))
+        })
+    }
+
+    /// Extract the options of a select-type custom field from a createmeta field entry.
+    ///
+    /// Returns `None` for fields that are not select, multi-select or cascading select fields.
+    fn parse_createmeta_options(field: &serde_json::Value) -> Option<Vec<FieldOption>> {
+        let field_id = field["fieldId"]
+            .as_str()
+            .or_else(|| field["key"].as_str())?;
+        if !field_id.starts_with("customfield_") {
+            return None;
+        }
+        let schema = &field["schema"];
+        let is_select = matches!(
+            (schema["type"].as_str(), schema["items"].as_str()),
+            (Some("option"), _) | (Some("option-with-child"), _) | (Some("array"), Some("option"))
+        );
+        if !is_select {
+            return None;
+        }
+
+        let field_name = field["name"].as_str().map(|s| s.to_string());
+        let option = |value: &serde_json::Value, parent: Option<&str>| {
+            Some(FieldOption {
+                field_id: field_id.to_string(),
+                field_name: field_name.clone(),
+                option_id: value["id"].as_str()?.to_string(),
+                value: value["value"].as_str()?.to_string(),
+                parent_option_id: parent.map(|p| p.to_string()),
+                disabled: value["disabled"].as_bool().unwrap_or(false),
+            })
+        };
+
+        let mut options = Vec::new();
+        for value in field["allowedValues"].as_array().into_iter().flatten() {
+            let Some(parent) = option(value, None) else {
+                continue;
+            };
+            let parent_id = parent.option_id.clone();
+            options.push(parent);
+            for child in value["children"].as_array().into_iter().flatten() {
+                options.extend(option(child, Some(&parent_id)));
+            }
+        }
+        Some(options)
+    }
+
+    /// Fetch a field's options from the custom field context used by the project.
+    /// Unlike createmeta, this includes disabled options (requires admin permission).
+    async fn fetch_context_options(
+        &self,
+        field_id: &str,
+        field_name: Option<&str>,
+        project_id: &str,
+    ) -> DomainResult<Vec<FieldOption>> {
+        let mapping = self
+            .get_json(
+                &format!(
+                    "{}/rest/api/3/field/{}/context/projectmapping",
+                    self.base_url, field_id
+                ),
+                &[("projectId", project_id)],
+                "fetch field context",
+            )
+            .await?;
+        let Some(context_id) = mapping["values"]
+            .as_array()
+            .and_then(|v| v.first())
+            .and_then(|m| m["contextId"].as_str())
+        else {
+            return Ok(Vec::new());
+        };
+
+        let url = format!(
+            "{}/rest/api/3/field/{}/context/{}/option",
+            self.base_url, field_id, context_id
+        );
+        let mut options = Vec::new();
+        let mut start_at = 0usize;
+        loop {
+            let start = start_at.to_string();
+            let page = self
+                .get_json(
+                    &url,
+                    &[("startAt", start.as_str()), ("maxResults", "1000")],
+                    "fetch field options",
+                )
+                .await?;
+            let values = page["values"].as_array().cloned().unwrap_or_default();
+            for value in &values {
+                let (Some(option_id), Some(text)) = (value["id"].as_str(), value["value"].as_str())
+                else {
+                    continue;
+                };
+                options.push(FieldOption {
+                    field_id: field_id.to_string(),
+                    field_name: field_name.map(|s| s.to_string()),
+                    option_id: option_id.to_string(),
+                    value: text.to_string(),
+                    parent_option_id: value["optionId"].as_str().map(|s| s.to_string()),
+                    disabled: value["disabled"].as_bool().unwrap_or(false),
+                });
+            }
+
+            start_at += values.len();
+            if values.is_empty() || page["isLast"].as_bool().unwrap_or(true) {
+                break;
+            }
+        }
+        Ok(options)
+    }
+
     /// Extract sprint name from JIRA fields
     /// Sprint is typically stored in a custom field (customfield_XXXXX)
     /// The format can be either:
@@ -225,7 +372,7 @@ impl JiraApiClient {
     }
 
     /// Parse a single issue from JSON response
-    fn parse_issue(issue_json: &serde_json::Value) -> Option<Issue> {
+    pub(crate) fn parse_issue(issue_json: &serde_json::Value) -> Option<Issue> {
         let id = issue_json["id"].as_str()?;
         let key = issue_json["key"].as_str()?;
         let fields = &issue_json["fields"];
@@ -474,6 +621,55 @@ impl JiraService for JiraApiClient {
         })
     }
 
+    async fn fetch_issue(&self, issue_key: &str) -> DomainResult<Issue> {
+        let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_key);
+
+        debug!("[JIRA API] GET {} (fetching issue {})", url, issue_key);
+
+        let response = self
+            .http_client
+            .get(&url)
+            .query(&[
+                ("fields", "*navigable,created,updated"),
+                ("expand", "changelog"),
+            ])
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| DomainError::ExternalService(format!("Failed to fetch issue: {}", e)))?;
+
+        debug!("[JIRA API] Response status: {}", response.status());
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(DomainError::NotFound(format!(
+                "Issue not found: {}",
+                issue_key
+            )));
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Could not read error response".to_string());
+            return Err(DomainError::ExternalService(format!(
+                "Failed to fetch issue: {} - {}",
+                status, error_text
+            )));
+        }
+
+        let json: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| DomainError::ExternalService(format!("Failed to parse issue: {}", e)))?;
+
+        Self::parse_issue(&json).ok_or_else(|| {
+            DomainError::ExternalService(format!("Failed to parse issue: {}", issue_key))
+        })
+    }
+
     async fn test_connection(&self) -> DomainResult<()> {
         let client = &self.client;
 
@@ -1009,7 +1205,7 @@ impl JiraService for JiraApiClient {
 
     async fn get_issue_transitions(&self, issue_key: &str) -> DomainResult<Vec<TransitionDto>> {
         let url = format!(
-            "{}/rest/api/3/issue/{}/transitions",
+            "{}/rest/api/3/issue/{}/transitions?expand=transitions.fields",
             self.base_url, issue_key
         );
 
@@ -1066,7 +1262,19 @@ impl JiraService for JiraApiClient {
                     .as_str()
                     .map(|s| s.to_string());
 
-                transitions.push(TransitionDto::new(id, name, to_status, to_status_category));
+                let mut transition = TransitionDto::new(id, name, to_status, to_status_category);
+                if let Some(fields) = transition_obj["fields"].as_object() {
+                    transition.required_fields = fields
+                        .iter()
+                        .filter(|(_, field)| {
+                            field["required"].as_bool().unwrap_or(false)
+                                && !field["hasDefaultValue"].as_bool().unwrap_or(false)
+                        })
+                        .map(|(id, field)| field["name"].as_str().unwrap_or(id).to_string())
+                        .collect();
+                }
+
+                transitions.push(transition);
             }
         }
 
@@ -1390,6 +1598,333 @@ impl JiraService for JiraApiClient {
         info!("Updated due date for {}: {}", issue_key, due_date);
         Ok(())
     }
+
+    async fn add_comment(&self, issue_key: &str, body: &str) -> DomainResult<()> {
+        let url = format!("{}/rest/api/3/issue/{}/comment", self.base_url, issue_key);
+
+        let payload = serde_json::json!({
+            "body": {
+                "type": "doc",
+                "version": 1,
+                "content": [
+                    {
+                        "type": "paragraph",
+                        "content": [
+                            {
+                                "type": "text",
+                                "text": body
+                            }
+                        ]
+                    }
+                ]
+            }
+        });
+
+        debug!("[JIRA API] POST {} (adding comment to {})", url, issue_key);
+
+        let response = self
+            .http_client
+            .post(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| DomainError::ExternalService(format!("Failed to add comment: {}", e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(DomainError::ExternalService(format!(
+                "Failed to add comment: {} - {}",
+                status, error_text
+            )));
+        }
+
+        info!("Added comment to {}", issue_key);
+        Ok(())
+    }
+
+    async fn update_issue_fields(
+        &self,
+        issue_key: &str,
+        update: &IssueFieldUpdate,
+    ) -> DomainResult<()> {
+        let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_key);
+
+        let body = update.to_request_body();
+
+        debug!("[JIRA API] PUT {} (updating fields: {})", url, body);
+
+        let response = self
+            .http_client
+            .put(&url)
+            .header("Authorization", &self.auth_header)
+            .header("Content-Type", "application/json")
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                DomainError::ExternalService(format!("Failed to update issue fields: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(DomainError::ExternalService(format!(
+                "Failed to update issue fields: {} - {}",
+                status, error_text
+            )));
+        }
+
+        info!("Updated fields for {}", issue_key);
+        Ok(())
+    }
+
+    async fn fetch_project_workflows(&self, project_id: &str) -> DomainResult<Vec<Workflow>> {
+        // Workflow scheme: issue type ID -> workflow name, plus the default workflow
+        let schemes = self
+            .get_json(
+                &format!("{}/rest/api/3/workflowscheme/project", self.base_url),
+                &[("projectId", project_id)],
+                "fetch workflow scheme",
+            )
+            .await?;
+        let scheme = &schemes["values"][0]["workflowScheme"];
+        let scheme_name = scheme["name"].as_str().map(|s| s.to_string());
+        let default_workflow = scheme["defaultWorkflow"].as_str().map(|s| s.to_string());
+
+        let issue_types = self
+            .get_json(
+                &format!("{}/rest/api/3/issuetype/project", self.base_url),
+                &[("projectId", project_id)],
+                "fetch issue types",
+            )
+            .await?;
+        let issue_type_name = |id: &str| {
+            issue_types
+                .as_array()
+                .and_then(|types| types.iter().find(|t| t["id"].as_str() == Some(id)))
+                .and_then(|t| t["name"].as_str())
+                .map(|s| s.to_string())
+        };
+
+        let mut mappings: Vec<(String, Vec<String>)> = Vec::new();
+        if let Some(default) = &default_workflow {
+            mappings.push((default.clone(), Vec::new()));
+        }
+        if let Some(map) = scheme["issueTypeMappings"].as_object() {
+            for (type_id, workflow_name) in map {
+                let (Some(workflow_name), Some(type_name)) =
+                    (workflow_name.as_str(), issue_type_name(type_id))
+                else {
+                    continue;
+                };
+                match mappings.iter_mut().find(|(name, _)| name == workflow_name) {
+                    Some((_, types)) => types.push(type_name),
+                    None => mappings.push((workflow_name.to_string(), vec![type_name])),
+                }
+            }
+        }
+
+        let mut workflows = Vec::new();
+        for (workflow_name, mut mapped_types) in mappings {
+            let json = self
+                .get_json(
+                    &format!("{}/rest/api/3/workflow/search", self.base_url),
+                    &[
+                        ("workflowName", workflow_name.as_str()),
+                        ("expand", "transitions,statuses"),
+                    ],
+                    "fetch workflow",
+                )
+                .await?;
+            let Some(workflow_obj) = json["values"].as_array().and_then(|v| v.first()) else {
+                warn!("Workflow not found: {}", workflow_name);
+                continue;
+            };
+
+            let status_names: Vec<(String, String)> = workflow_obj["statuses"]
+                .as_array()
+                .map(|statuses| {
+                    statuses
+                        .iter()
+                        .filter_map(|s| {
+                            Some((
+                                s["id"].as_str()?.to_string(),
+                                s["name"].as_str()?.to_string(),
+                            ))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let status_name = |id: &str| {
+                status_names
+                    .iter()
+                    .find(|(status_id, _)| status_id == id)
+                    .map(|(_, name)| name.clone())
+                    .unwrap_or_else(|| id.to_string())
+            };
+
+            let mut transitions = Vec::new();
+            for t in workflow_obj["transitions"].as_array().into_iter().flatten() {
+                let id = t["id"]
+                    .as_str()
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| t["id"].to_string());
+                let name = t["name"].as_str().unwrap_or_default().to_string();
+                let to_status = status_name(t["to"].as_str().unwrap_or_default());
+                let transition_type = t["type"].as_str().unwrap_or("directed").to_string();
+
+                let from: Vec<&str> = t["from"]
+                    .as_array()
+                    .map(|f| f.iter().filter_map(|s| s.as_str()).collect())
+                    .unwrap_or_default();
+                if from.is_empty() {
+                    transitions.push(WorkflowTransition {
+                        id,
+                        name,
+                        from_status: None,
+                        to_status,
+                        transition_type,
+                    });
+                } else {
+                    for from_id in from {
+                        transitions.push(WorkflowTransition {
+                            id: id.clone(),
+                            name: name.clone(),
+                            from_status: Some(status_name(from_id)),
+                            to_status: to_status.clone(),
+                            transition_type: transition_type.clone(),
+                        });
+                    }
+                }
+            }
+
+            mapped_types.sort();
+            workflows.push(Workflow {
+                is_default: default_workflow.as_deref() == Some(workflow_name.as_str()),
+                name: workflow_name,
+                description: workflow_obj["description"].as_str().map(|s| s.to_string()),
+                scheme_name: scheme_name.clone(),
+                issue_types: mapped_types,
+                statuses: status_names.into_iter().map(|(_, name)| name).collect(),
+                transitions,
+            });
+        }
+
+        Ok(workflows)
+    }
+
+    async fn fetch_field_options(
+        &self,
+        project_key: &str,
+        project_id: &str,
+    ) -> DomainResult<Vec<FieldOption>> {
+        let types_url = format!(
+            "{}/rest/api/3/issue/createmeta/{}/issuetypes",
+            self.base_url, project_key
+        );
+        let types = self
+            .get_json(
+                &types_url,
+                &[("maxResults", "200")],
+                "fetch create metadata",
+            )
+            .await?;
+        // Newer responses use "issueTypes", older ones "values"
+        let type_ids: Vec<String> = types["issueTypes"]
+            .as_array()
+            .or_else(|| types["values"].as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|t| t["id"].as_str().map(|s| s.to_string()))
+            .collect();
+
+        // Select fields seen on any create screen, in first-seen order
+        let mut fields: Vec<(String, Option<String>)> = Vec::new();
+        let mut options: Vec<FieldOption> = Vec::new();
+
+        for type_id in type_ids {
+            let url = format!("{}/{}", types_url, type_id);
+            let mut start_at = 0usize;
+            loop {
+                let start = start_at.to_string();
+                let page = self
+                    .get_json(
+                        &url,
+                        &[("startAt", start.as_str()), ("maxResults", "100")],
+                        "fetch create metadata fields",
+                    )
+                    .await?;
+                let entries = page["fields"]
+                    .as_array()
+                    .or_else(|| page["results"].as_array())
+                    .cloned()
+                    .unwrap_or_default();
+
+                for entry in &entries {
+                    let Some(field_options) = Self::parse_createmeta_options(entry) else {
+                        continue;
+                    };
+                    let field_id = entry["fieldId"]
+                        .as_str()
+                        .or_else(|| entry["key"].as_str())
+                        .unwrap_or_default();
+                    if !fields.iter().any(|(id, _)| id == field_id) {
+                        fields.push((
+                            field_id.to_string(),
+                            entry["name"].as_str().map(|s| s.to_string()),
+                        ));
+                    }
+                    for option in field_options {
+                        if !options.iter().any(|o| {
+                            o.field_id == option.field_id && o.option_id == option.option_id
+                        }) {
+                            options.push(option);
+                        }
+                    }
+                }
+
+                start_at += entries.len();
+                let total = page["total"].as_u64().unwrap_or(0) as usize;
+                if entries.is_empty() || start_at >= total {
+                    break;
+                }
+            }
+        }
+
+        // Prefer the field context, which also lists disabled options
+        for (field_id, field_name) in &fields {
+            match self
+                .fetch_context_options(field_id, field_name.as_deref(), project_id)
+                .await
+            {
+                Ok(context_options) if !context_options.is_empty() => {
+                    options.retain(|o| &o.field_id != field_id);
+                    options.extend(context_options);
+                }
+                Ok(_) => {}
+                Err(e) => debug!(
+                    "Using create metadata options for {} (context unavailable: {})",
+                    field_id, e
+                ),
+            }
+        }
+
+        info!(
+            "Fetched {} options for {} select fields",
+            options.len(),
+            fields.len()
+        );
+        Ok(options)
+    }
 }
 
 #[cfg(test)]
@@ -1416,4 +1951,35 @@ mod tests {
         let result = parse_jira_datetime("2024-01-15T10:30:00+0000");
         assert!(result.is_some(), "Should parse without milliseconds");
     }
+
+    #[test]
+    fn test_parse_createmeta_cascading_options() {
+        let field = serde_json::json!({
+            "fieldId": "customfield_10050",
+            "name": "Region",
+            "schema": { "type": "option-with-child" },
+            "allowedValues": [
+                {
+                    "id": "1",
+                    "value": "Asia",
+                    "children": [{ "id": "11", "value": "Japan" }]
+                },
+                { "id": "2", "value": "Europe", "disabled": true }
+            ]
+        });
+
+        let options = JiraApiClient::parse_createmeta_options(&field).unwrap();
+        assert_eq!(options.len(), 3);
+        assert_eq!(options[0].value, "Asia");
+        assert!(options[0].parent_option_id.is_none());
+        assert_eq!(options[1].value, "Japan");
+        assert_eq!(options[1].parent_option_id.as_deref(), Some("1"));
+        assert!(options[2].disabled);
+
+        let summary = serde_json::json!({
+            "fieldId": "summary",
+            "schema": { "type": "string" }
+        });
+        assert!(JiraApiClient::parse_createmeta_options(&summary).is_none());
+    }
 }
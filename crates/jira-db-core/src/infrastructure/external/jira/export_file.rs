@@ -0,0 +1,585 @@
+//! Reader for files exported from JIRA without API access
+//!
+//! Supports the "Export all fields" CSV of the issue navigator, JSON files holding
+//! REST-shaped issues (a search response, a bare array, or an object with an `issues`
+//! array) and the `entities.xml` of a JIRA site backup.
+//! All are turned into the same JSON the REST API returns, so the rest of the pipeline
+//! (raw data, expansion, change history) treats imported issues like synced ones.
+//!
+//! CSV headers only name custom fields, so their values are kept aside until
+//! [`JiraExport::map_custom_fields`] resolves the names to field ids.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use duckdb::Connection;
+use serde_json::{Map, Value, json};
+
+use super::site_backup;
+use crate::domain::entities::JiraField;
+use crate::domain::error::{DomainError, DomainResult};
+
+/// Issue key holding the `Custom field (...)` values of a CSV row by field name
+const CUSTOM_FIELD_COLUMNS: &str = "customFieldColumns";
+
+/// Issues and field definitions read from an export file
+#[derive(Debug, Default)]
+pub struct JiraExport {
+    /// Issues in REST API shape (`id`, `key`, `fields`, optional `changelog`)
+    pub issues: Vec<Value>,
+    /// Field definitions found in the file
+    pub fields: Vec<JiraField>,
+}
+
+impl JiraExport {
+    /// Read an export file, choosing the parser from the extension (`.csv`, `.json`, or
+    /// `.xml` for a site backup's `entities.xml`)
+    pub fn read(path: &Path) -> DomainResult<Self> {
+        if !path.exists() {
+            return Err(DomainError::NotFound(format!(
+                "Import file not found: {}",
+                path.display()
+            )));
+        }
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase());
+        match extension.as_deref() {
+            Some("csv") => Self::read_csv(path),
+            Some("xml") => site_backup::read(path),
+            Some("json") => {
+                let content = std::fs::read_to_string(path).map_err(|e| {
+                    DomainError::Repository(format!("Failed to read {}: {}", path.display(), e))
+                })?;
+                let value: Value = serde_json::from_str(&content).map_err(|e| {
+                    DomainError::Validation(format!("Invalid JSON in {}: {}", path.display(), e))
+                })?;
+                Self::from_json(value)
+            }
+            _ => Err(DomainError::Validation(format!(
+                "Unsupported import file: {} (expected .csv, .json or a site backup's entities.xml)",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Read JIRA's "Export all fields" CSV
+    ///
+    /// The file is parsed by DuckDB without a header row so that repeated columns
+    /// (Labels, Component/s, Sprint, ...) keep their original names.
+    pub fn read_csv(path: &Path) -> DomainResult<Self> {
+        let conn = Connection::open_in_memory().map_err(|e| {
+            DomainError::Repository(format!("Failed to create in-memory database: {}", e))
+        })?;
+        let sql = format!(
+            "SELECT * FROM read_csv('{}', header = false, all_varchar = true, delim = ',', \
+             quote = '\"', escape = '\"', null_padding = true, max_line_size = 16777216)",
+            path.to_string_lossy().replace('\'', "''")
+        );
+        let mut stmt = conn
+            .prepare(&sql)
+            .map_err(|e| DomainError::Repository(format!("Failed to read CSV: {}", e)))?;
+        let rows = stmt
+            .query_map([], |row| {
+                let column_count = row.as_ref().column_count();
+                (0..column_count)
+                    .map(|i| row.get::<_, Option<String>>(i))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(|e| DomainError::Repository(format!("Failed to read CSV: {}", e)))?;
+
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(row.map_err(|e| DomainError::Repository(e.to_string()))?);
+        }
+
+        let mut records = records.into_iter();
+        let headers: Vec<String> = records
+            .next()
+            .ok_or_else(|| DomainError::Validation("CSV file is empty".to_string()))?
+            .into_iter()
+            .map(|h| h.unwrap_or_default().trim().to_string())
+            .collect();
+        if !headers.iter().any(|h| h == "Issue key") {
+            return Err(DomainError::Validation(
+                "CSV has no \"Issue key\" column; use JIRA's \"Export all fields\" CSV".to_string(),
+            ));
+        }
+
+        let records: Vec<Vec<Option<String>>> = records.collect();
+        Ok(Self::from_csv_records(&headers, &records))
+    }
+
+    /// Build REST-shaped issues from CSV header names and records
+    ///
+    /// Custom field columns are not put into `fields` yet, see [`Self::map_custom_fields`].
+    pub fn from_csv_records(headers: &[String], records: &[Vec<Option<String>>]) -> Self {
+        let mut issues = Vec::new();
+
+        for record in records {
+            // Header -> non-empty values, in column order (repeated headers collect several)
+            let mut values: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+            for (header, value) in headers.iter().zip(record) {
+                let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) else {
+                    continue;
+                };
+                values
+                    .entry(header.as_str())
+                    .or_default()
+                    .push(value.to_string());
+            }
+            let first = |name: &str| values.get(name).and_then(|v| v.first()).cloned();
+
+            let Some(key) = first("Issue key") else {
+                continue;
+            };
+            let id = first("Issue id").unwrap_or_else(|| key.clone());
+
+            let mut fields = Map::new();
+            for (header, field) in [
+                ("Summary", "summary"),
+                ("Description", "description"),
+                ("Environment", "environment"),
+            ] {
+                if let Some(value) = first(header) {
+                    fields.insert(field.to_string(), Value::String(value));
+                }
+            }
+
+            for (header, field) in [
+                ("Issue Type", "issuetype"),
+                ("Priority", "priority"),
+                ("Resolution", "resolution"),
+            ] {
+                if let Some(name) = first(header) {
+                    fields.insert(field.to_string(), json!({ "name": name }));
+                }
+            }
+            if let Some(name) = first("Status") {
+                let mut status = json!({ "name": name });
+                if let Some(category) = first("Status Category") {
+                    status["statusCategory"] = json!({ "name": category });
+                }
+                fields.insert("status".to_string(), status);
+            }
+            for (header, field) in [
+                ("Assignee", "assignee"),
+                ("Reporter", "reporter"),
+                ("Creator", "creator"),
+            ] {
+                if let Some(name) = first(header) {
+                    let mut user = json!({ "displayName": name });
+                    if let Some(account_id) = first(&format!("{} Id", header)) {
+                        user["accountId"] = Value::String(account_id);
+                    }
+                    fields.insert(field.to_string(), user);
+                }
+            }
+
+            let mut project = Map::new();
+            if let Some(project_key) = first("Project key") {
+                project.insert("key".to_string(), Value::String(project_key));
+            }
+            if let Some(project_name) = first("Project name") {
+                project.insert("name".to_string(), Value::String(project_name));
+            }
+            if !project.is_empty() {
+                fields.insert("project".to_string(), Value::Object(project));
+            }
+
+            for (header, field) in [
+                ("Created", "created"),
+                ("Updated", "updated"),
+                ("Resolved", "resolutiondate"),
+            ] {
+                if let Some(dt) = first(header).as_deref().and_then(parse_export_datetime) {
+                    fields.insert(field.to_string(), Value::String(dt.to_rfc3339()));
+                }
+            }
+            if let Some(dt) = first("Due Date").as_deref().and_then(parse_export_datetime) {
+                fields.insert(
+                    "duedate".to_string(),
+                    Value::String(dt.format("%Y-%m-%d").to_string()),
+                );
+            }
+
+            if let Some(labels) = values.get("Labels") {
+                fields.insert("labels".to_string(), json!(labels));
+            }
+            for (header, field) in [
+                ("Component/s", "components"),
+                ("Fix Version/s", "fixVersions"),
+                ("Affects Version/s", "versions"),
+            ] {
+                if let Some(names) = values.get(header) {
+                    let items: Vec<Value> = names.iter().map(|n| json!({ "name": n })).collect();
+                    fields.insert(field.to_string(), Value::Array(items));
+                }
+            }
+            if let Some(sprints) = values.get("Sprint") {
+                let items: Vec<Value> = sprints.iter().map(|n| json!({ "name": n })).collect();
+                fields.insert("sprint".to_string(), Value::Array(items));
+            }
+
+            // Newer exports put the parent's key in "Parent", older ones only its id
+            let parent = first("Parent key")
+                .or_else(|| first("Parent").filter(|p| p.contains('-')))
+                .map(|parent_key| json!({ "key": parent_key }))
+                .or_else(|| {
+                    first("Parent id")
+                        .or_else(|| first("Parent"))
+                        .map(|parent_id| json!({ "id": parent_id }))
+                });
+            if let Some(parent) = parent {
+                fields.insert("parent".to_string(), parent);
+            }
+
+            let mut custom_values = Map::new();
+            for (header, header_values) in &values {
+                let Some(name) = header
+                    .strip_prefix("Custom field (")
+                    .and_then(|h| h.strip_suffix(')'))
+                else {
+                    continue;
+                };
+                let value = if header_values.len() > 1 {
+                    json!(header_values)
+                } else {
+                    Value::String(header_values[0].clone())
+                };
+                if name.eq_ignore_ascii_case("team") {
+                    fields.insert("team".to_string(), Value::String(header_values[0].clone()));
+                }
+                custom_values.insert(name.to_string(), value);
+            }
+
+            let mut issue = json!({
+                "id": id,
+                "key": key,
+                "fields": Value::Object(fields),
+            });
+            if !custom_values.is_empty() {
+                issue[CUSTOM_FIELD_COLUMNS] = Value::Object(custom_values);
+            }
+            issues.push(issue);
+        }
+
+        Self {
+            issues,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Move the custom field columns of a CSV issue into `fields`, under the id of the
+    /// custom field in `known` with the column's name
+    ///
+    /// A column that matches no custom field, or several, is stored under a placeholder
+    /// field ([`JiraField::import_placeholder`]) until a fields sync finds the real one.
+    /// Returns the placeholders used, which still have to be registered.
+    /// JSON issues already carry field ids and are left as they are.
+    pub fn map_custom_fields(issue: &mut Value, known: &[JiraField]) -> Vec<JiraField> {
+        let Some(Value::Object(columns)) = issue
+            .as_object_mut()
+            .and_then(|issue| issue.remove(CUSTOM_FIELD_COLUMNS))
+        else {
+            return Vec::new();
+        };
+
+        let mut placeholders = Vec::new();
+        for (name, value) in columns {
+            let id = match JiraField::find_custom_by_name(known, &name) {
+                Some(field) => field.id.clone(),
+                None => {
+                    let placeholder = JiraField::import_placeholder(&name);
+                    let id = placeholder.id.clone();
+                    placeholders.push(placeholder);
+                    id
+                }
+            };
+            issue["fields"][id.as_str()] = value;
+        }
+        placeholders
+    }
+
+    /// Read issues from JSON
+    ///
+    /// Accepts a search response (`{"issues": [...], "names": {...}}`), an object with an
+    /// `issues` array and optional `fields` definitions (as returned by `/rest/api/3/field`),
+    /// or a bare array of issues. Issues must be in REST API shape.
+    pub fn from_json(value: Value) -> DomainResult<Self> {
+        let (issues, field_defs, names) = match value {
+            Value::Array(issues) => (issues, None, None),
+            Value::Object(mut object) => {
+                let issues = match object.remove("issues") {
+                    Some(Value::Array(issues)) => issues,
+                    _ => {
+                        return Err(DomainError::Validation(
+                            "JSON import needs an \"issues\" array or a top-level array"
+                                .to_string(),
+                        ));
+                    }
+                };
+                (issues, object.remove("fields"), object.remove("names"))
+            }
+            _ => {
+                return Err(DomainError::Validation(
+                    "JSON import needs an \"issues\" array or a top-level array".to_string(),
+                ));
+            }
+        };
+
+        let issues: Vec<Value> = issues
+            .into_iter()
+            .filter(|issue| issue["key"].is_string() && issue["fields"].is_object())
+            .collect();
+
+        let mut fields: BTreeMap<String, JiraField> = BTreeMap::new();
+        if let Some(Value::Array(defs)) = field_defs {
+            for def in &defs {
+                if let Some(field) = json_field_definition(def) {
+                    fields.insert(field.id.clone(), field);
+                }
+            }
+        }
+        if let Some(Value::Object(names)) = names {
+            for (id, name) in names {
+                if let Some(name) = name.as_str() {
+                    fields
+                        .entry(id.clone())
+                        .or_insert_with(|| named_field(&id, name));
+                }
+            }
+        }
+        // Custom fields without a definition still get a column, named after their id
+        for issue in &issues {
+            if let Some(issue_fields) = issue["fields"].as_object() {
+                for id in issue_fields.keys() {
+                    if id.starts_with("customfield_") {
+                        fields
+                            .entry(id.clone())
+                            .or_insert_with(|| named_field(id, id));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            issues,
+            fields: fields.into_values().collect(),
+        })
+    }
+}
+
+/// Parse the date formats JIRA uses in CSV exports (interpreted as UTC)
+pub fn parse_export_datetime(s: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    const DATETIME_FORMATS: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y/%m/%d %H:%M",
+        "%d/%b/%y %I:%M %p",
+        "%d/%b/%Y %I:%M %p",
+        "%d/%m/%Y %H:%M",
+        "%d.%m.%Y %H:%M",
+    ];
+    for format in DATETIME_FORMATS {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, format) {
+            return Some(dt.and_utc());
+        }
+    }
+
+    const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%d/%b/%y", "%d/%b/%Y", "%d.%m.%Y"];
+    for format in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(s, format) {
+            return date.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc());
+        }
+    }
+
+    None
+}
+
+fn named_field(id: &str, name: &str) -> JiraField {
+    JiraField {
+        id: id.to_string(),
+        key: id.to_string(),
+        name: name.to_string(),
+        custom: id.starts_with("customfield_"),
+        searchable: true,
+        navigable: true,
+        orderable: true,
+        schema_type: None,
+        schema_items: None,
+        schema_system: None,
+        schema_custom: None,
+        schema_custom_id: None,
+    }
+}
+
+fn json_field_definition(def: &Value) -> Option<JiraField> {
+    let id = def["id"].as_str()?;
+    let schema = &def["schema"];
+    let text = |v: &Value| v.as_str().map(|s| s.to_string());
+    Some(JiraField {
+        id: id.to_string(),
+        key: def["key"].as_str().unwrap_or(id).to_string(),
+        name: def["name"].as_str().unwrap_or(id).to_string(),
+        custom: def["custom"].as_bool().unwrap_or(false),
+        searchable: def["searchable"].as_bool().unwrap_or(true),
+        navigable: def["navigable"].as_bool().unwrap_or(true),
+        orderable: def["orderable"].as_bool().unwrap_or(true),
+        schema_type: text(&schema["type"]),
+        schema_items: text(&schema["items"]),
+        schema_system: text(&schema["system"]),
+        schema_custom: text(&schema["custom"]),
+        schema_custom_id: schema["customId"].as_i64(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(values: &[&str]) -> Vec<Option<String>> {
+        values
+            .iter()
+            .map(|v| (!v.is_empty()).then(|| v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_csv_records_to_rest_issues() {
+        let headers: Vec<String> = [
+            "Summary",
+            "Issue key",
+            "Issue id",
+            "Issue Type",
+            "Status",
+            "Created",
+            "Labels",
+            "Labels",
+            "Component/s",
+            "Sprint",
+            "Sprint",
+            "Custom field (Story Points)",
+            "Parent",
+        ]
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+        let records = vec![record(&[
+            "Login fails",
+            "PROJ-2",
+            "10002",
+            "Bug",
+            "Open",
+            "15/Jan/24 10:30 AM",
+            "backend",
+            "auth",
+            "API",
+            "Sprint 1",
+            "Sprint 2",
+            "3",
+            "PROJ-1",
+        ])];
+
+        let export = JiraExport::from_csv_records(&headers, &records);
+        assert_eq!(export.issues.len(), 1);
+        let issue = &export.issues[0];
+        assert_eq!(issue["key"], "PROJ-2");
+        assert_eq!(issue["id"], "10002");
+        let fields = &issue["fields"];
+        assert_eq!(fields["issuetype"]["name"], "Bug");
+        assert_eq!(fields["labels"], json!(["backend", "auth"]));
+        assert_eq!(fields["components"], json!([{ "name": "API" }]));
+        assert_eq!(fields["sprint"][1]["name"], "Sprint 2");
+        assert_eq!(fields["created"], "2024-01-15T10:30:00+00:00");
+        assert_eq!(fields["parent"]["key"], "PROJ-1");
+        assert!(export.fields.is_empty());
+    }
+
+    #[test]
+    fn test_csv_custom_fields_map_by_name() {
+        let headers: Vec<String> = [
+            "Issue key",
+            "Custom field (Story Points)",
+            "Custom field (Severity)",
+            "Custom field (Sprint Goal)",
+        ]
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+        let records = vec![record(&["PROJ-1", "3", "S2", "Ship it"])];
+        let mut export = JiraExport::from_csv_records(&headers, &records);
+
+        let known = vec![
+            named_field("customfield_10016", "Story Points"),
+            named_field("customfield_10050", "Severity"),
+            named_field("customfield_10051", "Severity"),
+            named_field("summary", "Sprint Goal"),
+        ];
+        let placeholders = JiraExport::map_custom_fields(&mut export.issues[0], &known);
+
+        let names: Vec<&str> = placeholders.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["Severity", "Sprint Goal"]);
+        let issue = &export.issues[0];
+        assert_eq!(issue["fields"]["customfield_10016"], "3");
+        assert!(issue["fields"].get("customfield_10050").is_none());
+        assert_eq!(issue["fields"]["customfield_import_severity"], "S2");
+        assert_eq!(issue["fields"]["customfield_import_sprint_goal"], "Ship it");
+        assert!(issue.get(CUSTOM_FIELD_COLUMNS).is_none());
+
+        // Placeholders are never matched by name, so a re-import reuses the same id
+        let mut known = known;
+        known.extend(placeholders);
+        let mut export = JiraExport::from_csv_records(&headers, &records);
+        let placeholders = JiraExport::map_custom_fields(&mut export.issues[0], &known);
+        assert_eq!(placeholders[0].id, "customfield_import_severity");
+    }
+
+    #[test]
+    fn test_json_search_response() {
+        let value = json!({
+            "issues": [
+                { "id": "1", "key": "PROJ-1", "fields": { "summary": "a", "customfield_10016": 5 } },
+                { "id": "2", "fields": {} }
+            ],
+            "names": { "customfield_10016": "Story Points" }
+        });
+
+        let export = JiraExport::from_json(value).unwrap();
+        assert_eq!(export.issues.len(), 1);
+        assert_eq!(export.fields.len(), 1);
+        assert_eq!(export.fields[0].name, "Story Points");
+        assert!(export.fields[0].custom);
+    }
+
+    #[test]
+    fn test_parse_export_datetime() {
+        let expected = "2024-01-15T10:30:00+00:00";
+        for s in [
+            "2024-01-15 10:30",
+            "15/Jan/24 10:30 AM",
+            "2024-01-15T10:30:00.000+0000",
+            "2024-01-15 10:30:00.0",
+        ] {
+            assert_eq!(
+                parse_export_datetime(s)
+                    .map(|dt| dt.to_rfc3339())
+                    .as_deref(),
+                Some(expected),
+                "{}",
+                s
+            );
+        }
+        assert!(parse_export_datetime("not a date").is_none());
+    }
+}
@@ -1,3 +1,6 @@
 mod client;
+mod export_file;
+mod site_backup;
 
 pub use client::JiraApiClient;
+pub use export_file::{JiraExport, parse_export_datetime};
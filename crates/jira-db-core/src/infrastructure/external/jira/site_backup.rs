@@ -0,0 +1,589 @@
+//! Reader for the `entities.xml` of a JIRA site backup
+//!
+//! A backup zip holds every entity of the site as one flat XML element per row, whose
+//! attributes are the row's columns (long text columns are child elements instead).
+//! Only the entities needed to rebuild REST-shaped issues are kept while streaming through
+//! the file: projects, issues and their lookup tables, users, labels, component and version
+//! associations, custom fields and their values, sub-task links, and change groups, which
+//! become the issues' changelog.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use quick_xml::Reader;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+use serde_json::{Map, Value, json};
+
+use super::export_file::{JiraExport, parse_export_datetime};
+use crate::domain::entities::JiraField;
+use crate::domain::error::{DomainError, DomainResult};
+
+/// Entities whose rows are kept
+const ENTITIES: &[&str] = &[
+    "Project",
+    "Issue",
+    "IssueType",
+    "Priority",
+    "Status",
+    "Resolution",
+    "ApplicationUser",
+    "User",
+    "Label",
+    "Component",
+    "Version",
+    "NodeAssociation",
+    "CustomField",
+    "CustomFieldValue",
+    "CustomFieldOption",
+    "IssueLinkType",
+    "IssueLink",
+    "ChangeGroup",
+    "ChangeItem",
+];
+
+/// `statuscategory` of a status -> (key, name) of the REST `statusCategory`
+const STATUS_CATEGORIES: &[(&str, &str, &str)] = &[
+    ("1", "undefined", "No Category"),
+    ("2", "new", "To Do"),
+    ("3", "done", "Done"),
+    ("4", "indeterminate", "In Progress"),
+];
+
+/// Columns of one entity row
+type Row = HashMap<String, String>;
+
+/// Read `entities.xml` extracted from a site backup zip
+pub(super) fn read(path: &Path) -> DomainResult<JiraExport> {
+    let file = File::open(path).map_err(|e| {
+        DomainError::Repository(format!("Failed to read {}: {}", path.display(), e))
+    })?;
+    from_reader(BufReader::new(file))
+}
+
+/// Build REST-shaped issues and custom field definitions from `entities.xml` content
+pub(super) fn from_reader<R: BufRead>(reader: R) -> DomainResult<JiraExport> {
+    let entities = Entities::read(reader)?;
+    if entities.rows("Issue").is_empty() {
+        return Err(DomainError::Validation(
+            "No issues in the XML file; use the entities.xml of a JIRA site backup".to_string(),
+        ));
+    }
+    Ok(entities.to_export())
+}
+
+fn invalid_xml(e: impl std::fmt::Display) -> DomainError {
+    DomainError::Validation(format!("Invalid site backup XML: {}", e))
+}
+
+/// Non-empty value of a column
+fn attr<'a>(row: &'a Row, column: &str) -> Option<&'a str> {
+    row.get(column)
+        .map(String::as_str)
+        .filter(|v| !v.is_empty())
+}
+
+fn attributes(element: &BytesStart) -> DomainResult<Row> {
+    let mut row = Row::new();
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(invalid_xml)?;
+        let value = attribute.unescape_value().map_err(invalid_xml)?;
+        row.insert(
+            String::from_utf8_lossy(attribute.key.as_ref()).into_owned(),
+            value.into_owned(),
+        );
+    }
+    Ok(row)
+}
+
+/// Kept rows by entity name
+#[derive(Default)]
+struct Entities {
+    rows: HashMap<String, Vec<Row>>,
+}
+
+impl Entities {
+    fn read<R: BufRead>(reader: R) -> DomainResult<Self> {
+        let mut reader = Reader::from_reader(reader);
+        let mut entities = Self::default();
+        let mut buf = Vec::new();
+        // Depth 1 is the root element, entities are its children
+        let mut depth = 0;
+        // Entity read from a start tag, and the child element (column) being read
+        let mut current: Option<(String, Row)> = None;
+        let mut column: Option<(String, String)> = None;
+
+        loop {
+            match reader.read_event_into(&mut buf).map_err(invalid_xml)? {
+                Event::Start(element) => {
+                    depth += 1;
+                    let name = String::from_utf8_lossy(element.name().as_ref()).into_owned();
+                    if depth == 2 && ENTITIES.contains(&name.as_str()) {
+                        current = Some((name, attributes(&element)?));
+                    } else if depth == 3 && current.is_some() {
+                        column = Some((name, String::new()));
+                    }
+                }
+                Event::Empty(element) => {
+                    let name = String::from_utf8_lossy(element.name().as_ref()).into_owned();
+                    if depth == 1 && ENTITIES.contains(&name.as_str()) {
+                        let row = attributes(&element)?;
+                        entities.rows.entry(name).or_default().push(row);
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some((_, value)) = column.as_mut() {
+                        value.push_str(&text.xml_content().map_err(invalid_xml)?);
+                    }
+                }
+                Event::CData(text) => {
+                    if let Some((_, value)) = column.as_mut() {
+                        value.push_str(&text.decode().map_err(invalid_xml)?);
+                    }
+                }
+                Event::GeneralRef(reference) => {
+                    if let Some((_, value)) = column.as_mut() {
+                        if let Some(c) = reference.resolve_char_ref().map_err(invalid_xml)? {
+                            value.push(c);
+                        } else {
+                            let name = reference.decode().map_err(invalid_xml)?;
+                            let resolved = resolve_predefined_entity(&name)
+                                .ok_or_else(|| invalid_xml(format!("unknown entity &{};", name)))?;
+                            value.push_str(resolved);
+                        }
+                    }
+                }
+                Event::End(_) => {
+                    match depth {
+                        2 => {
+                            if let Some((name, row)) = current.take() {
+                                entities.rows.entry(name).or_default().push(row);
+                            }
+                        }
+                        3 => {
+                            if let (Some((_, row)), Some((name, value))) =
+                                (current.as_mut(), column.take())
+                            {
+                                row.insert(name, value);
+                            }
+                        }
+                        _ => {}
+                    }
+                    depth -= 1;
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(entities)
+    }
+
+    fn rows(&self, entity: &str) -> &[Row] {
+        self.rows.get(entity).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Rows of an entity by the value of a column (the first row wins)
+    fn index(&self, entity: &str, column: &str) -> HashMap<&str, &Row> {
+        let mut index = HashMap::new();
+        for row in self.rows(entity) {
+            if let Some(value) = attr(row, column) {
+                index.entry(value).or_insert(row);
+            }
+        }
+        index
+    }
+
+    /// Rows of an entity grouped by the value of a column, in file order
+    fn group(&self, entity: &str, column: &str) -> HashMap<&str, Vec<&Row>> {
+        let mut groups: HashMap<&str, Vec<&Row>> = HashMap::new();
+        for row in self.rows(entity) {
+            if let Some(value) = attr(row, column) {
+                groups.entry(value).or_default().push(row);
+            }
+        }
+        groups
+    }
+
+    /// REST user objects by user key
+    ///
+    /// Issues refer to users by key; `ApplicationUser` maps keys to user names, and `User`
+    /// (of any directory) holds the display name.
+    fn users(&self) -> HashMap<String, Value> {
+        let by_name = self.index("User", "lowerUserName");
+        let user = |key: &str, lower_name: &str| {
+            let row = by_name.get(lower_name);
+            let name = row.and_then(|r| attr(r, "userName")).unwrap_or(lower_name);
+            let display_name = row.and_then(|r| attr(r, "displayName")).unwrap_or(name);
+            json!({ "key": key, "name": name, "displayName": display_name })
+        };
+
+        let mut users: HashMap<String, Value> = by_name
+            .keys()
+            .map(|lower_name| (lower_name.to_string(), user(lower_name, lower_name)))
+            .collect();
+        for row in self.rows("ApplicationUser") {
+            if let Some(key) = attr(row, "userKey") {
+                let lower_name = attr(row, "lowerUserName").unwrap_or(key);
+                users.insert(key.to_string(), user(key, lower_name));
+            }
+        }
+        users
+    }
+
+    /// Parent issue id of each sub-task
+    fn parents(&self) -> HashMap<&str, &str> {
+        let subtask_types: Vec<&str> = self
+            .rows("IssueLinkType")
+            .iter()
+            .filter(|row| attr(row, "style") == Some("jira_subtask"))
+            .filter_map(|row| attr(row, "id"))
+            .collect();
+        self.rows("IssueLink")
+            .iter()
+            .filter(|row| attr(row, "linktype").is_some_and(|t| subtask_types.contains(&t)))
+            .filter_map(|row| Some((attr(row, "destination")?, attr(row, "source")?)))
+            .collect()
+    }
+
+    fn to_export(&self) -> JiraExport {
+        let projects = self.index("Project", "id");
+        let issue_types = self.index("IssueType", "id");
+        let priorities = self.index("Priority", "id");
+        let statuses = self.index("Status", "id");
+        let resolutions = self.index("Resolution", "id");
+        let components = self.index("Component", "id");
+        let versions = self.index("Version", "id");
+        let custom_fields = self.index("CustomField", "id");
+        let options = self.index("CustomFieldOption", "id");
+        let labels = self.group("Label", "issue");
+        let associations = self.group("NodeAssociation", "sourceNodeId");
+        let values = self.group("CustomFieldValue", "issue");
+        let change_groups = self.group("ChangeGroup", "issue");
+        let change_items = self.group("ChangeItem", "group");
+        let users = self.users();
+        let parents = self.parents();
+
+        let user = |key: &str| {
+            users
+                .get(key)
+                .or_else(|| users.get(&key.to_lowercase()))
+                .cloned()
+                .unwrap_or_else(|| json!({ "key": key, "name": key, "displayName": key }))
+        };
+        let datetime =
+            |value: &str| parse_export_datetime(value).map(|dt| Value::String(dt.to_rfc3339()));
+
+        // Older backups store the key on the issue, newer ones only the project and number
+        let keys: HashMap<&str, String> = self
+            .rows("Issue")
+            .iter()
+            .filter_map(|row| {
+                let key = match attr(row, "key") {
+                    Some(key) => key.to_string(),
+                    None => format!(
+                        "{}-{}",
+                        attr(projects.get(attr(row, "project")?)?, "key")?,
+                        attr(row, "number")?
+                    ),
+                };
+                Some((attr(row, "id")?, key))
+            })
+            .collect();
+
+        let mut issues = Vec::new();
+        for row in self.rows("Issue") {
+            let Some((id, key)) = attr(row, "id").and_then(|id| Some((id, keys.get(id)?))) else {
+                continue;
+            };
+
+            let mut fields = Map::new();
+            for column in ["summary", "description", "environment"] {
+                if let Some(value) = attr(row, column) {
+                    fields.insert(column.to_string(), Value::String(value.to_string()));
+                }
+            }
+
+            if let Some(issue_type) = attr(row, "type").and_then(|id| issue_types.get(id)) {
+                fields.insert(
+                    "issuetype".to_string(),
+                    json!({
+                        "id": attr(issue_type, "id"),
+                        "name": attr(issue_type, "name"),
+                        "subtask": attr(issue_type, "style") == Some("jira_subtask"),
+                    }),
+                );
+            }
+            for (column, lookup, field) in [
+                ("priority", &priorities, "priority"),
+                ("resolution", &resolutions, "resolution"),
+            ] {
+                if let Some(value) = attr(row, column).and_then(|id| lookup.get(id)) {
+                    fields.insert(
+                        field.to_string(),
+                        json!({ "id": attr(value, "id"), "name": attr(value, "name") }),
+                    );
+                }
+            }
+            if let Some(status) = attr(row, "status").and_then(|id| statuses.get(id)) {
+                let mut value = json!({ "id": attr(status, "id"), "name": attr(status, "name") });
+                let category = attr(status, "statuscategory")
+                    .and_then(|id| STATUS_CATEGORIES.iter().find(|(c, _, _)| *c == id));
+                if let Some((_, category_key, category_name)) = category {
+                    value["statusCategory"] = json!({ "key": category_key, "name": category_name });
+                }
+                fields.insert("status".to_string(), value);
+            }
+            for column in ["assignee", "reporter", "creator"] {
+                if let Some(key) = attr(row, column) {
+                    fields.insert(column.to_string(), user(key));
+                }
+            }
+
+            if let Some(project) = attr(row, "project").and_then(|id| projects.get(id)) {
+                fields.insert(
+                    "project".to_string(),
+                    json!({
+                        "id": attr(project, "id"),
+                        "key": attr(project, "key"),
+                        "name": attr(project, "name"),
+                    }),
+                );
+            }
+
+            for column in ["created", "updated", "resolutiondate"] {
+                if let Some(value) = attr(row, column).and_then(datetime) {
+                    fields.insert(column.to_string(), value);
+                }
+            }
+            if let Some(dt) = attr(row, "duedate").and_then(parse_export_datetime) {
+                fields.insert(
+                    "duedate".to_string(),
+                    Value::String(dt.format("%Y-%m-%d").to_string()),
+                );
+            }
+
+            // Labels with a field id belong to a custom labels field
+            let issue_labels: Vec<&str> = labels
+                .get(id)
+                .into_iter()
+                .flatten()
+                .filter(|label| attr(label, "fieldid").is_none())
+                .filter_map(|label| attr(label, "label"))
+                .collect();
+            if !issue_labels.is_empty() {
+                fields.insert("labels".to_string(), json!(issue_labels));
+            }
+
+            for (association, lookup, field) in [
+                ("IssueComponent", &components, "components"),
+                ("IssueFixVersion", &versions, "fixVersions"),
+                ("IssueVersion", &versions, "versions"),
+            ] {
+                let items: Vec<Value> = associations
+                    .get(id)
+                    .into_iter()
+                    .flatten()
+                    .filter(|a| attr(a, "sourceNodeEntity") == Some("Issue"))
+                    .filter(|a| attr(a, "associationType") == Some(association))
+                    .filter_map(|a| lookup.get(attr(a, "sinkNodeId")?))
+                    .map(|item| json!({ "id": attr(item, "id"), "name": attr(item, "name") }))
+                    .collect();
+                if !items.is_empty() {
+                    fields.insert(field.to_string(), Value::Array(items));
+                }
+            }
+
+            let mut custom_values: HashMap<&str, Vec<Value>> = HashMap::new();
+            for value in values.get(id).into_iter().flatten() {
+                let Some(field_id) = attr(value, "customfield") else {
+                    continue;
+                };
+                if let Some(value) = custom_field_value(value, &options) {
+                    custom_values.entry(field_id).or_default().push(value);
+                }
+            }
+            for (field_id, mut field_values) in custom_values {
+                let value = if field_values.len() == 1 {
+                    field_values.remove(0)
+                } else {
+                    Value::Array(field_values)
+                };
+                fields.insert(format!("customfield_{}", field_id), value);
+            }
+
+            if let Some(parent_id) = parents.get(id) {
+                let mut parent = json!({ "id": parent_id });
+                if let Some(parent_key) = keys.get(parent_id) {
+                    parent["key"] = Value::String(parent_key.clone());
+                }
+                fields.insert("parent".to_string(), parent);
+            }
+
+            let histories: Vec<Value> = change_groups
+                .get(id)
+                .into_iter()
+                .flatten()
+                .filter_map(|group| {
+                    let group_id = attr(group, "id")?;
+                    let items: Vec<Value> = change_items
+                        .get(group_id)
+                        .into_iter()
+                        .flatten()
+                        .map(|item| {
+                            json!({
+                                "field": attr(item, "field"),
+                                "fieldtype": attr(item, "fieldtype"),
+                                "from": attr(item, "oldvalue"),
+                                "fromString": attr(item, "oldstring"),
+                                "to": attr(item, "newvalue"),
+                                "toString": attr(item, "newstring"),
+                            })
+                        })
+                        .collect();
+                    Some(json!({
+                        "id": group_id,
+                        "author": attr(group, "author").map(user),
+                        "created": attr(group, "created").and_then(datetime),
+                        "items": items,
+                    }))
+                })
+                .collect();
+
+            issues.push(json!({
+                "id": id,
+                "key": key,
+                "fields": Value::Object(fields),
+                "changelog": { "histories": histories },
+            }));
+        }
+
+        let mut fields: Vec<JiraField> = custom_fields
+            .values()
+            .filter_map(|&row| custom_field_definition(row))
+            .collect();
+        fields.sort_by(|a, b| a.id.cmp(&b.id));
+
+        JiraExport { issues, fields }
+    }
+}
+
+/// REST value of one `CustomFieldValue` row
+///
+/// Select fields store the option id in `stringvalue`; it becomes `{"id", "value"}` like
+/// the REST API returns.
+fn custom_field_value(row: &Row, options: &HashMap<&str, &Row>) -> Option<Value> {
+    if let Some(number) = attr(row, "numbervalue") {
+        return number.parse::<f64>().ok().map(|n| json!(n));
+    }
+    if let Some(date) = attr(row, "datevalue") {
+        return parse_export_datetime(date).map(|dt| Value::String(dt.to_rfc3339()));
+    }
+    if let Some(text) = attr(row, "stringvalue") {
+        let option = options
+            .get(text)
+            .filter(|option| attr(option, "customfield") == attr(row, "customfield"));
+        return Some(match option {
+            Some(option) => json!({ "id": text, "value": attr(option, "customvalue") }),
+            None => Value::String(text.to_string()),
+        });
+    }
+    attr(row, "textvalue").map(|text| Value::String(text.to_string()))
+}
+
+fn custom_field_definition(row: &Row) -> Option<JiraField> {
+    let numeric_id = attr(row, "id")?;
+    let id = format!("customfield_{}", numeric_id);
+    Some(JiraField {
+        id: id.clone(),
+        key: id,
+        name: attr(row, "name").unwrap_or(numeric_id).to_string(),
+        custom: true,
+        searchable: true,
+        navigable: true,
+        orderable: true,
+        schema_type: None,
+        schema_items: None,
+        schema_system: None,
+        schema_custom: attr(row, "customfieldtypekey").map(|s| s.to_string()),
+        schema_custom_id: numeric_id.parse().ok(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENTITIES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<entity-engine-xml date="1700000000000">
+    <Project id="10000" name="Project" key="PROJ" counter="2"/>
+    <IssueType id="1" name="Bug" style=""/>
+    <IssueType id="5" name="Sub-task" style="jira_subtask"/>
+    <Status id="3" name="In Progress" statuscategory="4"/>
+    <Priority id="2" name="High"/>
+    <ApplicationUser id="10100" userKey="JIRAUSER10100" lowerUserName="alice"/>
+    <User id="1" directoryId="1" userName="alice" lowerUserName="alice" displayName="Alice &amp; Co"/>
+    <Issue id="20001" project="10000" number="1" reporter="JIRAUSER10100" assignee="bob" type="1" priority="2" status="3" summary="Login fails" created="2024-01-15 10:30:00.0" updated="2024-01-16 09:00:00.0">
+        <description><![CDATA[Steps:
+1. <open> the page]]></description>
+    </Issue>
+    <Issue id="20002" project="10000" number="2" type="5" status="3" summary="Fix it" created="2024-01-16 10:30:00.0"/>
+    <Label id="1" issue="20001" label="backend"/>
+    <Label id="2" issue="20001" fieldid="10030" label="other"/>
+    <Component id="30" project="10000" name="API"/>
+    <NodeAssociation sourceNodeId="20001" sourceNodeEntity="Issue" sinkNodeId="30" sinkNodeEntity="Component" associationType="IssueComponent"/>
+    <CustomField id="10016" customfieldtypekey="com.atlassian.jira.plugin.system.customfieldtypes:float" name="Story Points"/>
+    <CustomField id="10050" customfieldtypekey="com.atlassian.jira.plugin.system.customfieldtypes:select" name="Severity"/>
+    <CustomFieldOption id="40" customfield="10050" customvalue="S2"/>
+    <CustomFieldValue id="1" issue="20001" customfield="10016" numbervalue="3.0"/>
+    <CustomFieldValue id="2" issue="20001" customfield="10050" stringvalue="40"/>
+    <IssueLinkType id="10100" linkname="jira_subtask_link" style="jira_subtask"/>
+    <IssueLink id="1" linktype="10100" source="20001" destination="20002"/>
+    <ChangeGroup id="50" issue="20001" author="JIRAUSER10100" created="2024-01-16 09:00:00.0"/>
+    <ChangeItem id="60" group="50" fieldtype="jira" field="status" oldvalue="1" oldstring="Open" newvalue="3" newstring="In Progress"/>
+</entity-engine-xml>
+"#;
+
+    #[test]
+    fn test_site_backup_to_rest_issues() {
+        let export = from_reader(ENTITIES_XML.as_bytes()).unwrap();
+        assert_eq!(export.issues.len(), 2);
+
+        let issue = &export.issues[0];
+        assert_eq!(issue["key"], "PROJ-1");
+        let fields = &issue["fields"];
+        assert_eq!(fields["description"], "Steps:\n1. <open> the page");
+        assert_eq!(fields["issuetype"]["name"], "Bug");
+        assert_eq!(fields["issuetype"]["subtask"], false);
+        assert_eq!(fields["status"]["statusCategory"]["key"], "indeterminate");
+        assert_eq!(fields["reporter"]["displayName"], "Alice & Co");
+        assert_eq!(fields["assignee"]["displayName"], "bob");
+        assert_eq!(fields["project"]["key"], "PROJ");
+        assert_eq!(fields["created"], "2024-01-15T10:30:00+00:00");
+        assert_eq!(fields["labels"], json!(["backend"]));
+        assert_eq!(fields["components"][0]["name"], "API");
+        assert_eq!(fields["customfield_10016"], 3.0);
+        assert_eq!(fields["customfield_10050"]["value"], "S2");
+
+        let history = &issue["changelog"]["histories"][0];
+        assert_eq!(history["author"]["displayName"], "Alice & Co");
+        assert_eq!(history["items"][0]["toString"], "In Progress");
+
+        let subtask = &export.issues[1];
+        assert_eq!(subtask["fields"]["parent"]["key"], "PROJ-1");
+        assert_eq!(subtask["fields"]["issuetype"]["subtask"], true);
+
+        assert_eq!(export.fields.len(), 2);
+        assert_eq!(export.fields[0].id, "customfield_10016");
+        assert_eq!(export.fields[0].name, "Story Points");
+    }
+
+    #[test]
+    fn test_xml_without_issues_fails() {
+        let xml = r#"<entity-engine-xml><Project id="1" key="PROJ"/></entity-engine-xml>"#;
+        assert!(from_reader(xml.as_bytes()).is_err());
+    }
+}
@@ -12,38 +12,57 @@ pub mod infrastructure;
 pub mod report;
 
 // Re-export commonly used types for convenience
-pub use application::dto::{CreatedIssueDto, SyncResult, TransitionDto};
+pub use application::dto::{
+    CreatedIssueDto, FieldEdit, IssueFieldUpdate, SyncResult, TransitionDto,
+};
 pub use application::services::{FetchProgress, JiraService};
 pub use application::use_cases::{
-    AiTestDataConfig, AiTestDataResult, CreateTestTicketUseCase, CreatedIssueInfo,
-    EmbeddingGenerationConfig, EmbeddingGenerationResult, EmbeddingTiming, ExecuteSqlUseCase,
-    FailedIssueInfo, GenerateAiTestDataUseCase, GenerateEmbeddingsUseCase, GenerateReportUseCase,
-    GenerateSnapshotsUseCase, GenerationStats, GetChangeHistoryUseCase, GetProjectMetadataUseCase,
-    ReportData, ResumableSyncResult, SearchIssuesUseCase, SnapshotGenerationResult, SqlResult,
-    SyncFieldsResult, SyncFieldsUseCase, SyncProjectListUseCase, SyncProjectUseCase,
-    TransitionIssueUseCase, TransitionResult,
+    AiTestDataConfig, AiTestDataResult, BulkEditPreview, BulkEditReport, BulkEditUseCase,
+    ClusterTopicsUseCase, CreateTestTicketUseCase, CreatedIssueInfo, DEFAULT_DUPLICATE_THRESHOLD,
+    DuplicateCluster, DuplicateIssue, DuplicateMatch, DuplicatePair, DuplicateReport,
+    EmbeddingGenerationConfig, EmbeddingGenerationResult, EmbeddingTiming, ExecuteJqlUseCase,
+    ExecuteSqlUseCase, ExportDataUseCase, ExportFormat, ExportResult, ExportSource,
+    FailedIssueInfo, FindDuplicatesUseCase, FindSimilarIssuesUseCase, GenerateAiTestDataUseCase,
+    GenerateEmbeddingsUseCase, GenerateReportUseCase, GenerateSnapshotsUseCase, GenerationStats,
+    GetChangeHistoryUseCase, GetProjectMetadataUseCase, GetWorkflowUseCase, HybridSearchHit,
+    HybridSearchResult, HybridSearchUseCase, ImportProjectUseCase, ImportResult, IssueDuplicates,
+    JqlResult, LabelSuggestion, MoveIssueUseCase, MoveResult, MoveStep, PushOperationResult,
+    PushOutcome, PushPendingOperationsUseCase, PushResult, QueuePendingOperationUseCase,
+    RawDiffUseCase, RawIssueDiff, ReportData, ResumableSyncResult, RunSavedSearchUseCase,
+    SavedSearchResult, SearchIssuesUseCase, SimilarIssue, SimilarIssuesResult,
+    SnapshotGenerationResult, SqlResult, SuggestedValue, SyncFieldsResult, SyncFieldsUseCase,
+    SyncProjectListUseCase, SyncProjectUseCase, TopicReport, TransitionIssueUseCase,
+    TransitionResult, UpdateIssueFieldsUseCase,
 };
 
 pub use domain::entities::{
-    ChangeHistoryItem, Component, FixVersion, Issue, IssueSnapshot, IssueType, JiraField, Label,
-    Priority, Project, Status,
+    ChangeHistoryItem, Component, FieldOption, FixVersion, Issue, IssueLink, IssueSnapshot,
+    IssueType, JiraField, Label, PendingOperation, PendingOperationKind, PendingOperationStatus,
+    Priority, Project, SavedSearch, SavedSearchFilter, SavedSearchParameter,
+    SavedSearchParameterType, SavedSearchQuery, Status, TopicAssignment, TopicCluster, TopicIssue,
+    Workflow, WorkflowGraph, WorkflowTransition, WorkflowViolation,
 };
 pub use domain::error::{DomainError, DomainResult};
 pub use domain::repositories::{
-    ChangeHistoryRepository, IssueRepository, IssueSnapshotRepository, MetadataRepository,
-    ProjectRepository, SearchParams, SyncHistoryRepository,
+    ChangeHistoryRepository, DateRange, FacetField, FacetValue, IssueRepository, IssueSearchHit,
+    IssueSnapshotRepository, LabelMatch, MetadataRepository, PendingOperationRepository,
+    ProjectRepository, SearchFacet, SearchParams, SearchSort, SortField, SyncHistoryRepository,
+    TopicRepository, merge_facets,
 };
 
 pub use infrastructure::config::{
-    DatabaseConfig, EmbeddingsConfig, JiraConfig, JiraEndpoint, LogConfig, ProjectConfig, Settings,
-    SyncCheckpoint, SyncSettings,
+    BulkEditCheckpoint, BulkEditFailure, DatabaseConfig, EmbeddingsConfig, JiraConfig,
+    JiraEndpoint, LogConfig, ProjectConfig, SearchSettings, Settings, SyncCheckpoint, SyncSettings,
 };
 pub use infrastructure::database::{
-    Database, DatabaseFactory, DbConnection, DuckDbChangeHistoryRepository, DuckDbFieldRepository,
+    BlockUsage, ConnectionPool, DEFAULT_READ_POOL_SIZE, Database, DatabaseAccess, DatabaseFactory,
+    DatabaseMaintenance, DbConnection, DuckDbChangeHistoryRepository, DuckDbFieldRepository,
     DuckDbIssueRepository, DuckDbIssueSnapshotRepository, DuckDbIssuesExpandedRepository,
-    DuckDbMetadataRepository, DuckDbProjectRepository, DuckDbSyncHistoryRepository,
-    EmbeddingsRepository, RawDataRepository, SemanticSearchResult, SharedRawDataRepository,
-    checkpoint_connection,
+    DuckDbMetadataRepository, DuckDbPendingOperationRepository, DuckDbProjectRepository,
+    DuckDbSyncHistoryRepository, DuckDbTopicRepository, EmbeddedIssue, EmbeddingNeighbor,
+    EmbeddingsRepository, FederatedDatabase, FullTextSearchRepository, IntegrityFinding,
+    MigrationStatus, NeighborFilter, RawDataRepository, RawIssueVersion, SchemaKind,
+    SemanticSearchResult, SharedRawDataRepository, TableSize, checkpoint_connection,
 };
 pub use infrastructure::external::claude::{
     AiTestDataGenerator, ClaudeCliClient, ClaudeClient, ClaudeConfig, GeneratedIssue,
@@ -54,9 +73,12 @@ pub use infrastructure::external::embeddings::{
     EmbeddingResult, OllamaConfig, OllamaEmbeddingClient, OpenAIEmbeddingClient, ProviderConfig,
     create_provider,
 };
-pub use infrastructure::external::jira::JiraApiClient;
+pub use infrastructure::external::jira::{JiraApiClient, JiraExport};
 
-pub use report::{generate_interactive_report, generate_static_report};
+pub use report::{
+    generate_duplicates_report, generate_interactive_report, generate_static_report,
+    generate_topics_report,
+};
 
 // Re-export external crates for CLI use
 pub use chrono;
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use super::static_report::{get_static_css, get_status_class, html_escape};
+use crate::application::use_cases::{DuplicateIssue, DuplicateReport};
+
+/// Render duplicate candidates as a standalone HTML page for review
+pub fn generate_duplicates_report(report: &DuplicateReport) -> String {
+    let mut html = String::new();
+
+    let since = report
+        .since
+        .map(|since| format!(" / Created since: {}", since.format("%Y-%m-%d %H:%M UTC")))
+        .unwrap_or_default();
+    html.push_str(&format!(
+        r#"<!DOCTYPE html>
+<html lang="ja">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Duplicate Candidates - {}</title>
+    <style>
+{}
+        .similarity {{ font-weight: 600; white-space: nowrap; }}
+    </style>
+</head>
+<body>
+    <div class="container">
+        <header class="header">
+            <h1>Duplicate Candidates: {}</h1>
+            <p class="generated-at">Generated: {}</p>
+            <p class="summary">Threshold: {:.2}{} / Issues scanned: {} / Clusters: {} / Already linked: {}</p>
+        </header>
+"#,
+        html_escape(&report.project_key),
+        get_static_css(),
+        html_escape(&report.project_key),
+        report.generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        report.threshold,
+        since,
+        report.scanned_issues,
+        report.clusters.len(),
+        report.linked_pairs
+    ));
+
+    if report.clusters.is_empty() {
+        html.push_str(
+            r#"
+        <section class="project-section">
+            <p class="issue-count">No duplicate candidates above the threshold.</p>
+        </section>
+"#,
+        );
+    }
+
+    for (index, cluster) in report.clusters.iter().enumerate() {
+        let issues: HashMap<&str, &DuplicateIssue> = cluster
+            .issues
+            .iter()
+            .map(|issue| (issue.key.as_str(), issue))
+            .collect();
+
+        html.push_str(&format!(
+            r#"
+        <section class="project-section">
+            <h2 class="project-title">Cluster {}</h2>
+            <p class="issue-count">{} issues, max similarity {:.3}</p>
+
+            <table class="issue-table">
+                <thead>
+                    <tr>
+                        <th>Key</th>
+                        <th>Summary</th>
+                        <th>Status</th>
+                        <th>Type</th>
+                        <th>Created</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#,
+            index + 1,
+            cluster.issues.len(),
+            cluster.max_similarity()
+        ));
+
+        for issue in &cluster.issues {
+            let status = issue.status.as_deref().unwrap_or("-");
+            html.push_str(&format!(
+                r#"                    <tr>
+                        <td class="issue-key">{}</td>
+                        <td class="issue-summary">{}</td>
+                        <td><span class="status-badge {}">{}</span></td>
+                        <td>{}</td>
+                        <td>{}</td>
+                    </tr>
+"#,
+                html_escape(&issue.key),
+                html_escape(&issue.summary),
+                get_status_class(status),
+                html_escape(status),
+                html_escape(issue.issue_type.as_deref().unwrap_or("-")),
+                issue
+                    .created_date
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| "-".to_string())
+            ));
+        }
+
+        html.push_str(
+            r#"                </tbody>
+            </table>
+
+            <h3 class="table-title">Similar Pairs</h3>
+            <table class="issue-table">
+                <thead>
+                    <tr>
+                        <th>Similarity</th>
+                        <th>Issue</th>
+                        <th>Summary</th>
+                        <th>Issue</th>
+                        <th>Summary</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#,
+        );
+
+        let summary = |key: &str| {
+            issues
+                .get(key)
+                .map(|issue| html_escape(&issue.summary))
+                .unwrap_or_default()
+        };
+        for pair in &cluster.pairs {
+            html.push_str(&format!(
+                r#"                    <tr>
+                        <td class="similarity">{:.3}</td>
+                        <td class="issue-key">{}</td>
+                        <td class="issue-summary">{}</td>
+                        <td class="issue-key">{}</td>
+                        <td class="issue-summary">{}</td>
+                    </tr>
+"#,
+                pair.similarity,
+                html_escape(&pair.left),
+                summary(&pair.left),
+                html_escape(&pair.right),
+                summary(&pair.right)
+            ));
+        }
+
+        html.push_str(
+            r#"                </tbody>
+            </table>
+        </section>
+"#,
+        );
+    }
+
+    html.push_str(
+        r#"
+        <footer class="footer">
+            <p>Generated by jira-db</p>
+        </footer>
+    </div>
+</body>
+</html>
+"#,
+    );
+
+    html
+}
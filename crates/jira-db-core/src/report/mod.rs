@@ -1,5 +1,9 @@
+mod duplicates;
 mod interactive;
 mod static_report;
+mod topics;
 
+pub use duplicates::generate_duplicates_report;
 pub use interactive::generate_interactive_report;
 pub use static_report::generate_static_report;
+pub use topics::generate_topics_report;
@@ -1,3 +1,4 @@
+use super::topics::topics_table;
 use crate::application::use_cases::ReportData;
 
 pub fn generate_static_report(data: &ReportData) -> String {
@@ -158,7 +159,15 @@ pub fn generate_static_report(data: &ReportData) -> String {
             r#"                    </div>
                 </div>
             </div>
+"#,
+        );
+
+        if !project.topics.is_empty() {
+            html.push_str(&topics_table(&project.topics));
+        }
 
+        html.push_str(
+            r#"
             <h3 class="table-title">Issue List</h3>
             <table class="issue-table">
                 <thead>
@@ -231,7 +240,7 @@ pub fn generate_static_report(data: &ReportData) -> String {
     html
 }
 
-fn get_static_css() -> &'static str {
+pub(super) fn get_static_css() -> &'static str {
     r#"
         :root {
             --jira-blue: #0052CC;
@@ -508,7 +517,7 @@ fn get_issue_type_color(issue_type: &str) -> &'static str {
     }
 }
 
-fn get_status_class(status: &str) -> &'static str {
+pub(super) fn get_status_class(status: &str) -> &'static str {
     match status.to_lowercase().as_str() {
         s if s.contains("done")
             || s.contains("complete")
@@ -536,7 +545,7 @@ fn get_priority_class(priority: &str) -> &'static str {
     }
 }
 
-fn html_escape(s: &str) -> String {
+pub(super) fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
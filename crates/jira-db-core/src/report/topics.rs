@@ -0,0 +1,191 @@
+use super::static_report::{get_static_css, html_escape};
+use crate::application::use_cases::{LabelSuggestion, TopicReport};
+use crate::domain::entities::TopicCluster;
+
+/// Render the topics of a project and the label suggestions as a standalone HTML page
+pub fn generate_topics_report(report: &TopicReport) -> String {
+    let mut html = String::new();
+
+    let mut scope = Vec::new();
+    if let Some(from) = report.created_from {
+        scope.push(format!("Created from: {}", from.format("%Y-%m-%d")));
+    }
+    if let Some(to) = report.created_to {
+        scope.push(format!("Created before: {}", to.format("%Y-%m-%d")));
+    }
+    if let Some(issue_type) = &report.issue_type {
+        scope.push(format!("Type: {}", html_escape(issue_type)));
+    }
+    scope.push(format!("Issues clustered: {}", report.scanned_issues));
+    scope.push(format!("Topics: {}", report.topics.len()));
+
+    html.push_str(&format!(
+        r#"<!DOCTYPE html>
+<html lang="ja">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Topics - {}</title>
+    <style>
+{}
+        .similarity {{ font-weight: 600; white-space: nowrap; }}
+    </style>
+</head>
+<body>
+    <div class="container">
+        <header class="header">
+            <h1>Topics: {}</h1>
+            <p class="generated-at">Generated: {}</p>
+            <p class="summary">{}</p>
+        </header>
+
+        <section class="project-section">
+"#,
+        html_escape(&report.project_key),
+        get_static_css(),
+        html_escape(&report.project_key),
+        report.generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        scope.join(" / ")
+    ));
+
+    html.push_str(&topics_table(&report.topics));
+    if report.suggestions.is_empty() {
+        html.push_str(
+            r#"
+            <h3 class="table-title">Label Suggestions</h3>
+            <p class="issue-count">No suggestions: every clustered issue has labels and components, or its neighbours have none.</p>
+"#,
+        );
+    } else {
+        html.push_str(&suggestions_table(&report.suggestions));
+    }
+
+    html.push_str(
+        r#"        </section>
+
+        <footer class="footer">
+            <p>Generated by jira-db</p>
+        </footer>
+    </div>
+</body>
+</html>
+"#,
+    );
+
+    html
+}
+
+/// Topic table for a project section, largest topic first
+pub(super) fn topics_table(topics: &[TopicCluster]) -> String {
+    let mut html = String::from(
+        r#"
+            <h3 class="table-title">Topics</h3>
+            <table class="issue-table">
+                <thead>
+                    <tr>
+                        <th>#</th>
+                        <th>Topic</th>
+                        <th>Issues</th>
+                        <th>Representative Issues</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#,
+    );
+
+    for topic in topics {
+        let representatives: Vec<String> = topic
+            .representatives
+            .iter()
+            .map(|issue| {
+                format!(
+                    r#"<span class="issue-key">{}</span> {}"#,
+                    html_escape(&issue.key),
+                    html_escape(&issue.summary)
+                )
+            })
+            .collect();
+        html.push_str(&format!(
+            r#"                    <tr>
+                        <td>{}</td>
+                        <td class="issue-summary">{}</td>
+                        <td>{}</td>
+                        <td>{}</td>
+                    </tr>
+"#,
+            topic.cluster_id,
+            html_escape(&topic.name),
+            topic.size,
+            representatives.join("<br>")
+        ));
+    }
+
+    html.push_str(
+        r#"                </tbody>
+            </table>
+"#,
+    );
+    html
+}
+
+/// Suggested labels and components, one row per issue and field
+fn suggestions_table(suggestions: &[LabelSuggestion]) -> String {
+    let mut html = String::from(
+        r#"
+            <h3 class="table-title">Label Suggestions</h3>
+            <table class="issue-table">
+                <thead>
+                    <tr>
+                        <th>Key</th>
+                        <th>Summary</th>
+                        <th>Field</th>
+                        <th>Suggested</th>
+                        <th>Based On</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#,
+    );
+
+    for suggestion in suggestions {
+        let values: Vec<String> = suggestion
+            .values
+            .iter()
+            .map(|value| {
+                format!(
+                    r#"{} <span class="similarity">{:.2}</span>"#,
+                    html_escape(&value.value),
+                    value.score
+                )
+            })
+            .collect();
+        let mut neighbors: Vec<&str> = Vec::new();
+        for neighbor in suggestion.values.iter().flat_map(|value| &value.neighbors) {
+            if !neighbors.contains(&neighbor.as_str()) {
+                neighbors.push(neighbor);
+            }
+        }
+        html.push_str(&format!(
+            r#"                    <tr>
+                        <td class="issue-key">{}</td>
+                        <td class="issue-summary">{}</td>
+                        <td>{}</td>
+                        <td>{}</td>
+                        <td>{}</td>
+                    </tr>
+"#,
+            html_escape(&suggestion.issue_key),
+            html_escape(&suggestion.summary),
+            html_escape(&suggestion.field),
+            values.join("<br>"),
+            html_escape(&neighbors.join(", "))
+        ));
+    }
+
+    html.push_str(
+        r#"                </tbody>
+            </table>
+"#,
+    );
+    html
+}
@@ -1,6 +1,7 @@
 //! Configuration for the MCP server
 
 use anyhow::{Context, Result};
+use jira_db_core::{DEFAULT_READ_POOL_SIZE, SearchSettings, Settings};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
@@ -16,6 +17,16 @@ pub struct McpConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub database_path: Option<PathBuf>,
 
+    /// Reader connections per project database, so queries run side by side
+    #[serde(default = "default_read_pool_size")]
+    pub read_pool_size: usize,
+
+    /// Read the replicas published by `jira-db sync` (`data.read.duckdb`, `raw.read.duckdb`)
+    /// instead of the main files, so the server never holds the lock sync needs. Requires
+    /// `database.read_replicas` in the jira-db settings.
+    #[serde(default)]
+    pub read_replica: bool,
+
     /// HTTP server configuration
     #[serde(default)]
     pub http: HttpConfig,
@@ -23,6 +34,32 @@ pub struct McpConfig {
     /// Embedding configuration for vector search
     #[serde(default)]
     pub embedding: EmbeddingConfig,
+
+    /// Rank fusion weights for hybrid search (same format as the jira-db `search` settings)
+    #[serde(default)]
+    pub search: SearchSettings,
+
+    /// Tools that write to JIRA (disabled by default)
+    #[serde(default)]
+    pub write: WriteConfig,
+
+    /// Path to the jira-db settings file holding saved searches
+    /// (defaults to `write.settings_path`, then jira-db's standard settings location)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings_path: Option<PathBuf>,
+}
+
+/// Configuration for tools that modify JIRA issues
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WriteConfig {
+    /// Whether to expose write tools (e.g., update_issue_fields)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Path to the jira-db settings file holding the JIRA credentials
+    /// (defaults to jira-db's standard settings location)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings_path: Option<PathBuf>,
 }
 
 /// HTTP server configuration
@@ -39,6 +76,10 @@ pub struct HttpConfig {
     /// Host to bind to
     #[serde(default = "default_host")]
     pub host: String,
+
+    /// Maximum number of requests processed at the same time
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
 }
 
 impl Default for HttpConfig {
@@ -47,10 +88,19 @@ impl Default for HttpConfig {
             enabled: false,
             port: default_port(),
             host: default_host(),
+            max_concurrent_requests: default_max_concurrent_requests(),
         }
     }
 }
 
+fn default_read_pool_size() -> usize {
+    DEFAULT_READ_POOL_SIZE
+}
+
+fn default_max_concurrent_requests() -> usize {
+    8
+}
+
 fn default_port() -> u16 {
     3000
 }
@@ -125,13 +175,31 @@ impl McpConfig {
         }
     }
 
+    /// jira-db settings file: `settings_path`, else `write.settings_path`, else jira-db's
+    /// standard settings location
+    pub fn jira_settings_path(&self) -> Result<PathBuf> {
+        match self
+            .settings_path
+            .as_ref()
+            .or(self.write.settings_path.as_ref())
+        {
+            Some(path) => Ok(path.clone()),
+            None => Ok(Settings::default_path()?),
+        }
+    }
+
     /// Create a default configuration
     pub fn default_config() -> Self {
         Self {
             database_dir: PathBuf::from("./data"),
             database_path: None,
+            read_pool_size: default_read_pool_size(),
+            read_replica: false,
             http: HttpConfig::default(),
             embedding: EmbeddingConfig::default(),
+            search: SearchSettings::default(),
+            write: WriteConfig::default(),
+            settings_path: None,
         }
     }
 
@@ -163,7 +231,10 @@ mod tests {
         assert_eq!(config.http.port, 3000);
         assert_eq!(config.http.host, "127.0.0.1");
         assert!(!config.http.enabled);
+        assert_eq!(config.http.max_concurrent_requests, 8);
+        assert_eq!(config.read_pool_size, DEFAULT_READ_POOL_SIZE);
         assert_eq!(config.embedding.provider, EmbeddingProvider::OpenAI);
+        assert!(!config.write.enabled);
     }
 
     #[test]
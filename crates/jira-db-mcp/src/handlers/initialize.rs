@@ -36,7 +36,7 @@ pub fn handle_initialize(params: InitializeParams) -> ProtocolResult<InitializeR
         instructions: Some(
             "JIRA Database MCP Server - Query and search JIRA issues stored in a local DuckDB database. \
              Available tools: search_issues, get_issue, get_issue_history, list_projects, \
-             get_project_metadata, get_schema, execute_sql, semantic_search.".to_string()
+             get_project_metadata, get_schema, execute_sql, jql_search, semantic_search.".to_string()
         ),
     };
 
@@ -9,6 +9,8 @@ mod tools;
 pub use initialize::*;
 pub use tools::*;
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use serde_json::Value;
 
 use crate::protocol::{
@@ -22,7 +24,7 @@ use crate::tools::ToolRegistry;
 /// Routes incoming requests to the appropriate handler based on the method.
 pub struct RequestHandler {
     tool_registry: ToolRegistry,
-    initialized: bool,
+    initialized: AtomicBool,
 }
 
 impl RequestHandler {
@@ -30,14 +32,14 @@ impl RequestHandler {
     pub fn new(tool_registry: ToolRegistry) -> Self {
         Self {
             tool_registry,
-            initialized: false,
+            initialized: AtomicBool::new(false),
         }
     }
 
     /// Handle an incoming JSON-RPC request
     ///
     /// Returns None if the request is a notification (no response expected).
-    pub async fn handle(&mut self, request: JsonRpcRequest) -> ProtocolResult<Option<Value>> {
+    pub async fn handle(&self, request: JsonRpcRequest) -> ProtocolResult<Option<Value>> {
         tracing::debug!("Handling method: {}", request.method);
 
         // Handle notifications (no response)
@@ -58,14 +60,14 @@ impl RequestHandler {
 
     /// Handle a notification (no response expected)
     async fn handle_notification(
-        &mut self,
+        &self,
         method: &str,
         _params: Option<Value>,
     ) -> ProtocolResult<()> {
         match method {
             methods::INITIALIZED => {
                 tracing::info!("Client sent initialized notification");
-                self.initialized = true;
+                self.initialized.store(true, Ordering::Relaxed);
                 Ok(())
             }
             methods::SHUTDOWN => {
@@ -8,7 +8,7 @@ use std::sync::Arc;
 use actix_web::{App, HttpServer, web};
 use anyhow::Result;
 
-use jira_db_core::DatabaseFactory;
+use jira_db_core::{DatabaseAccess, DatabaseFactory, Settings};
 
 use crate::config::McpConfig;
 use crate::handlers::RequestHandler;
@@ -23,14 +23,23 @@ use crate::transport::{StdioTransport, Transport};
 /// Uses DatabaseFactory to manage per-project database connections.
 pub struct McpServer {
     db_factory: Arc<DatabaseFactory>,
-    #[allow(dead_code)]
     config: McpConfig,
 }
 
 impl McpServer {
     /// Create a new MCP server instance
     pub fn new(config: McpConfig) -> Result<Self> {
-        let db_factory = DatabaseFactory::with_dir(&config.database_dir);
+        let access = if config.read_replica {
+            DatabaseAccess::ReadReplica
+        } else {
+            DatabaseAccess::ReadWrite
+        };
+        if config.read_replica && config.write.enabled {
+            tracing::warn!("Write tools cannot update the local database in read_replica mode");
+        }
+        let db_factory = DatabaseFactory::with_dir(&config.database_dir)
+            .with_read_pool_size(config.read_pool_size)
+            .with_access(access);
 
         Ok(Self {
             db_factory: Arc::new(db_factory),
@@ -38,18 +47,48 @@ impl McpServer {
         })
     }
 
+    /// Build the tool registry, including write tools when enabled in the config
+    fn tool_registry(&self) -> Result<ToolRegistry> {
+        let mut tool_registry =
+            ToolRegistry::new(self.db_factory.clone(), self.config.search.clone());
+
+        if self.config.write.enabled {
+            let settings_path = match &self.config.write.settings_path {
+                Some(path) => path.clone(),
+                None => Settings::default_path()?,
+            };
+            tracing::info!(
+                "Write tools enabled (JIRA settings: {})",
+                settings_path.display()
+            );
+            tool_registry.register_write_tools(self.db_factory.clone(), settings_path);
+        }
+
+        let saved_searches = self.config.jira_settings_path().and_then(|settings_path| {
+            tool_registry.register_saved_search_tools(self.db_factory.clone(), settings_path)
+        });
+        if let Err(e) = saved_searches {
+            tracing::warn!("Failed to load saved searches: {}", e);
+        }
+
+        Ok(tool_registry)
+    }
+
     /// Run the server over HTTP transport
     pub async fn run_http(self, host: &str, port: u16) -> Result<()> {
         tracing::info!("Starting MCP HTTP server on {}:{}", host, port);
 
-        let tool_registry = ToolRegistry::new(self.db_factory.clone());
-        let state = Arc::new(HttpState::new(tool_registry));
+        let tool_registry = self.tool_registry()?;
+        let max_concurrent_requests = self.config.http.max_concurrent_requests.max(1);
+        let state = Arc::new(HttpState::new(tool_registry, max_concurrent_requests));
 
+        // Tool calls run blocking DuckDB queries, so give each concurrent request a worker
         HttpServer::new(move || {
             App::new()
                 .app_data(web::Data::new(state.clone()))
                 .configure(configure_routes)
         })
+        .workers(max_concurrent_requests)
         .bind((host, port))?
         .run()
         .await?;
@@ -62,8 +101,8 @@ impl McpServer {
         tracing::info!("Starting MCP server over stdio");
 
         let mut transport = StdioTransport::new();
-        let tool_registry = ToolRegistry::new(self.db_factory.clone());
-        let mut handler = RequestHandler::new(tool_registry);
+        let tool_registry = self.tool_registry()?;
+        let handler = RequestHandler::new(tool_registry);
 
         loop {
             match transport.read_request().await {
@@ -1,20 +1,27 @@
 //! Tool implementations
 
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
 use jira_db_core::{
-    DatabaseFactory, DuckDbChangeHistoryRepository, DuckDbIssueRepository,
-    DuckDbMetadataRepository, DuckDbProjectRepository, GetChangeHistoryUseCase,
-    GetProjectMetadataUseCase, IssueRepository, ProjectRepository, RawDataRepository,
-    SearchIssuesUseCase, SearchParams,
+    DEFAULT_DUPLICATE_THRESHOLD, DatabaseFactory, DateRange, DomainError,
+    DuckDbChangeHistoryRepository, DuckDbFieldRepository, DuckDbIssueRepository,
+    DuckDbMetadataRepository, DuckDbProjectRepository, EmbeddingConfig, EmbeddingProvider,
+    EmbeddingsRepository, ExecuteJqlUseCase, FacetField, FieldEdit, FindDuplicatesUseCase,
+    FindSimilarIssuesUseCase, GetChangeHistoryUseCase, GetProjectMetadataUseCase,
+    HybridSearchUseCase, Issue, IssueRepository, JiraApiClient, LabelMatch, OpenAIEmbeddingClient,
+    ProjectRepository, RawDataRepository, RunSavedSearchUseCase, SavedSearch,
+    SavedSearchParameterType, SearchIssuesUseCase, SearchParams, SearchSettings, SearchSort,
+    Settings, UpdateIssueFieldsUseCase, merge_facets,
 };
 use serde_json::Value;
 
 use super::params::*;
 use super::registry::{ToolHandler, build_tool_definition};
-use crate::protocol::{CallToolResult, Tool};
+use crate::protocol::{CallToolResult, Tool, ToolInputSchema};
 
 /// Normalize smart/curly quotes to standard ASCII quotes
 /// This handles cases where OS or browser auto-converts quotes
@@ -43,11 +50,228 @@ fn extract_project_key(issue_key: &str) -> Option<&str> {
 
 pub struct SearchIssuesTool {
     db_factory: Arc<DatabaseFactory>,
+    search_settings: SearchSettings,
+    /// Embeds queries for hybrid search (None without OPENAI_API_KEY)
+    embedding_provider: Option<OpenAIEmbeddingClient>,
 }
 
 impl SearchIssuesTool {
-    pub fn new(db_factory: Arc<DatabaseFactory>) -> Self {
-        Self { db_factory }
+    pub fn new(db_factory: Arc<DatabaseFactory>, search_settings: SearchSettings) -> Self {
+        let embedding_provider = std::env::var("OPENAI_API_KEY")
+            .ok()
+            .and_then(|key| OpenAIEmbeddingClient::new(EmbeddingConfig::new(key)).ok());
+        Self {
+            db_factory,
+            search_settings,
+            embedding_provider,
+        }
+    }
+
+    /// Keyword search ranked by BM25
+    fn lexical_search(
+        &self,
+        projects: &[String],
+        params: &SearchIssuesParams,
+        search: &SearchParams,
+    ) -> Result<SearchIssuesResponse> {
+        let mut all_hits = Vec::new();
+        let mut all_facets = Vec::new();
+        let mut total = 0;
+        for project in projects {
+            let conn = match self.db_factory.get_read_connection(project) {
+                Ok(conn) => conn,
+                Err(e) if params.project.is_some() => return Err(e.into()),
+                Err(_) => continue,
+            };
+            let use_case = SearchIssuesUseCase::new(Arc::new(DuckDbIssueRepository::new(conn)));
+            let project_params = project_search_params(project, search);
+
+            let result = use_case.count(&project_params).and_then(|count| {
+                let facets = use_case.facets(&project_params, facet_fields(params))?;
+                Ok((count, facets, use_case.execute_ranked(project_params)?))
+            });
+            match result {
+                Ok((count, facets, hits)) => {
+                    total += count;
+                    merge_facets(&mut all_facets, facets);
+                    all_hits.extend(hits);
+                }
+                Err(e) if params.project.is_some() => return Err(e.into()),
+                Err(_) => continue,
+            }
+        }
+
+        if search.sorts_by_relevance() {
+            all_hits.sort_by(|a, b| {
+                b.score
+                    .unwrap_or(f64::MIN)
+                    .total_cmp(&a.score.unwrap_or(f64::MIN))
+            });
+        } else {
+            all_hits.sort_by(|a, b| compare_by_sort(&search.sort, &a.issue, &b.issue));
+        }
+        let mut response = page(all_hits, total, search);
+        if params.facets.unwrap_or(false) {
+            response.facets = Some(all_facets.into_iter().map(Into::into).collect());
+        }
+        Ok(response)
+    }
+
+    /// Keyword and semantic rankings fused per project, embedding the query once
+    async fn hybrid_search(
+        &self,
+        projects: &[String],
+        params: &SearchIssuesParams,
+        search: &SearchParams,
+    ) -> Result<SearchIssuesResponse> {
+        let mut use_cases = Vec::new();
+        for project in projects {
+            let conn = match self.db_factory.get_read_connection(project) {
+                Ok(conn) => conn,
+                Err(e) if params.project.is_some() => return Err(e.into()),
+                Err(_) => continue,
+            };
+            let use_case = HybridSearchUseCase::new(
+                Arc::new(DuckDbIssueRepository::new(conn.clone())),
+                Arc::new(EmbeddingsRepository::new(conn)),
+            )
+            .with_settings(self.search_settings.clone())
+            .with_facets(facet_fields(params));
+            use_cases.push((project, use_case));
+        }
+
+        // Only pay for the embedding API call when some project can use it
+        let query = search.query.as_deref().unwrap_or_default().trim();
+        let query_embedding = match &self.embedding_provider {
+            Some(provider)
+                if !query.is_empty()
+                    && search.sorts_by_relevance()
+                    && use_cases
+                        .iter()
+                        .any(|(_, use_case)| use_case.semantic_available()) =>
+            {
+                match provider.embed(query).await {
+                    Ok(embedding) => Some(embedding),
+                    Err(e) => {
+                        tracing::warn!("Failed to embed query, using keyword ranking: {}", e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let mut all_hits = Vec::new();
+        let mut all_facets = Vec::new();
+        let mut total = 0;
+        for (project, use_case) in &use_cases {
+            match use_case.execute_with_embedding(
+                project_search_params(project, search),
+                query_embedding.as_deref(),
+            ) {
+                Ok(result) => {
+                    total += result.total;
+                    merge_facets(&mut all_facets, result.facets);
+                    all_hits.extend(result.hits);
+                }
+                Err(e) if params.project.is_some() => return Err(e.into()),
+                Err(_) => continue,
+            }
+        }
+
+        if search.sorts_by_relevance() {
+            all_hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        } else {
+            all_hits.sort_by(|a, b| compare_by_sort(&search.sort, &a.issue, &b.issue));
+        }
+        let mut response = page(all_hits, total, search);
+        if params.facets.unwrap_or(false) {
+            response.facets = Some(all_facets.into_iter().map(Into::into).collect());
+        }
+        Ok(response)
+    }
+}
+
+/// Search parameters shared by every project database
+fn search_params(params: &SearchIssuesParams) -> Result<SearchParams, DomainError> {
+    let range = |from: &Option<String>, to: &Option<String>| {
+        DateRange::parse(from.as_deref(), to.as_deref())
+    };
+
+    Ok(SearchParams {
+        query: params.query.clone(),
+        project_key: params.project.clone(),
+        status: params.status.clone(),
+        status_category: params.status_category.clone(),
+        assignee: params.assignee.clone(),
+        issue_type: params.issue_type.clone(),
+        priority: params.priority.clone(),
+        resolution: params.resolution.clone(),
+        labels: params.labels.clone().unwrap_or_default(),
+        labels_match: match &params.labels_match {
+            Some(labels_match) => labels_match.parse()?,
+            None => LabelMatch::default(),
+        },
+        components: params.components.clone().unwrap_or_default(),
+        fix_versions: params.fix_versions.clone().unwrap_or_default(),
+        sprint: params.sprint.clone(),
+        parent_key: params.parent.clone(),
+        created: range(&params.created_from, &params.created_to)?,
+        updated: range(&params.updated_from, &params.updated_to)?,
+        due: range(&params.due_from, &params.due_to)?,
+        resolved: range(&params.resolved_from, &params.resolved_to)?,
+        sort: SearchSort::parse_list(params.sort.as_deref().unwrap_or_default())?,
+        limit: Some(params.limit.unwrap_or(20)),
+        offset: params.offset,
+        ..Default::default()
+    })
+}
+
+/// Parameters for one project database: the first `offset + limit` hits, so the
+/// merged results can be paged across projects
+fn project_search_params(project: &str, search: &SearchParams) -> SearchParams {
+    SearchParams {
+        project_key: Some(project.to_string()),
+        limit: search.limit.map(|limit| limit + search.offset.unwrap_or(0)),
+        offset: None,
+        ..search.clone()
+    }
+}
+
+/// Order issues merged from several projects by the requested sort keys
+fn compare_by_sort(sort: &[SearchSort], a: &Issue, b: &Issue) -> std::cmp::Ordering {
+    sort.iter()
+        .map(|key| key.compare(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.key.cmp(&b.key))
+}
+
+/// Apply offset and limit to the merged hits
+fn page<T: Into<SearchHitResponse>>(
+    hits: Vec<T>,
+    total: usize,
+    search: &SearchParams,
+) -> SearchIssuesResponse {
+    let offset = search.offset.unwrap_or(0);
+    SearchIssuesResponse {
+        total,
+        offset,
+        issues: hits
+            .into_iter()
+            .skip(offset)
+            .take(search.limit.unwrap_or(usize::MAX))
+            .map(Into::into)
+            .collect(),
+        facets: None,
+    }
+}
+
+/// Fields to count when the caller asked for facets
+fn facet_fields(params: &SearchIssuesParams) -> &'static [FacetField] {
+    if params.facets.unwrap_or(false) {
+        &FacetField::ALL
+    } else {
+        &[]
     }
 }
 
@@ -56,7 +280,7 @@ impl ToolHandler for SearchIssuesTool {
     fn definition(&self) -> Tool {
         build_tool_definition::<SearchIssuesParams>(
             "search_issues",
-            "Search for JIRA issues by text query, project, status, or assignee. Project key is required to specify which database to search.",
+            "Search for JIRA issues by text query and structured filters (project, status, type, priority, labels, components, fix versions, sprint, parent, resolution, date ranges), with optional sort keys. Text queries are ranked by combining keyword relevance with semantic similarity (when embeddings exist); each result includes a score, per-signal ranks and a snippet with matched terms wrapped in **. The response reports the total number of matches for pagination and, with facets, counts of all matches by status, type, priority, assignee, labels, components and fix versions.",
         )
     }
 
@@ -64,8 +288,8 @@ impl ToolHandler for SearchIssuesTool {
         let params: SearchIssuesParams = serde_json::from_value(arguments)?;
 
         // Project key is required for per-project databases
-        let project_key = match &params.project {
-            Some(key) => key.clone(),
+        let projects = match &params.project {
+            Some(key) => vec![key.clone()],
             None => {
                 // If no project specified, search across all available databases
                 let projects = self.db_factory.list_project_databases()?;
@@ -74,55 +298,20 @@ impl ToolHandler for SearchIssuesTool {
                         "No project databases found. Run 'jira-db sync' first.",
                     ));
                 }
-
-                let mut all_issues = Vec::new();
-                for project in &projects {
-                    if let Ok(conn) = self.db_factory.get_connection(project) {
-                        let repo = DuckDbIssueRepository::new(conn);
-                        let use_case = SearchIssuesUseCase::new(Arc::new(repo));
-
-                        let search_params = SearchParams {
-                            query: params.query.clone(),
-                            project_key: Some(project.clone()),
-                            status: params.status.clone(),
-                            assignee: params.assignee.clone(),
-                            issue_type: None,
-                            priority: None,
-                            team: None,
-                            limit: Some(params.limit.unwrap_or(20)),
-                            offset: params.offset,
-                        };
-
-                        if let Ok(issues) = use_case.execute(search_params) {
-                            all_issues.extend(issues);
-                        }
-                    }
-                }
-
-                let response: Vec<IssueResponse> = all_issues.into_iter().map(Into::into).collect();
-                let json = serde_json::to_string_pretty(&response)?;
-                return Ok(CallToolResult::text(json));
+                projects
             }
         };
 
-        let conn = self.db_factory.get_connection(&project_key)?;
-        let repo = DuckDbIssueRepository::new(conn);
-        let use_case = SearchIssuesUseCase::new(Arc::new(repo));
-
-        let search_params = SearchParams {
-            query: params.query,
-            project_key: Some(project_key),
-            status: params.status,
-            assignee: params.assignee,
-            issue_type: None,
-            priority: None,
-            team: None,
-            limit: Some(params.limit.unwrap_or(20)),
-            offset: params.offset,
+        let search = match search_params(&params) {
+            Ok(search) => search,
+            Err(e) => return Ok(CallToolResult::error(e.to_string())),
         };
 
-        let issues = use_case.execute(search_params)?;
-        let response: Vec<IssueResponse> = issues.into_iter().map(Into::into).collect();
+        let response = if params.hybrid.unwrap_or(true) {
+            self.hybrid_search(&projects, &params, &search).await?
+        } else {
+            self.lexical_search(&projects, &params, &search)?
+        };
         let json = serde_json::to_string_pretty(&response)?;
 
         Ok(CallToolResult::text(json))
@@ -159,7 +348,7 @@ impl ToolHandler for GetIssueTool {
         let project_key = extract_project_key(&params.issue_key)
             .ok_or_else(|| anyhow::anyhow!("Invalid issue key format: {}", params.issue_key))?;
 
-        let conn = self.db_factory.get_connection(project_key)?;
+        let conn = self.db_factory.get_read_connection(project_key)?;
         let repo = DuckDbIssueRepository::new(conn);
 
         let search_params = SearchParams {
@@ -216,7 +405,7 @@ impl ToolHandler for GetIssueHistoryTool {
         let project_key = extract_project_key(&params.issue_key)
             .ok_or_else(|| anyhow::anyhow!("Invalid issue key format: {}", params.issue_key))?;
 
-        let conn = self.db_factory.get_connection(project_key)?;
+        let conn = self.db_factory.get_read_connection(project_key)?;
         let repo = DuckDbChangeHistoryRepository::new(conn);
         let use_case = GetChangeHistoryUseCase::new(Arc::new(repo));
 
@@ -262,7 +451,7 @@ impl ToolHandler for ListProjectsTool {
 
         let mut all_projects = Vec::new();
         for key in &project_keys {
-            if let Ok(conn) = self.db_factory.get_connection(key) {
+            if let Ok(conn) = self.db_factory.get_read_connection(key) {
                 let repo = DuckDbProjectRepository::new(conn);
                 if let Ok(projects) = repo.find_all() {
                     for project in projects {
@@ -306,14 +495,14 @@ impl ToolHandler for GetProjectMetadataTool {
     fn definition(&self) -> Tool {
         build_tool_definition::<GetProjectMetadataParams>(
             "get_project_metadata",
-            "Get metadata for a project including statuses, priorities, issue types, labels, components, and versions",
+            "Get metadata for a project including statuses, priorities, issue types, labels, components, versions, and select field options",
         )
     }
 
     async fn execute(&self, arguments: Value) -> Result<CallToolResult> {
         let params: GetProjectMetadataParams = serde_json::from_value(arguments)?;
 
-        let conn = self.db_factory.get_connection(&params.project_key)?;
+        let conn = self.db_factory.get_read_connection(&params.project_key)?;
 
         let project_repo = DuckDbProjectRepository::new(conn.clone());
         let project = project_repo
@@ -328,6 +517,32 @@ impl ToolHandler for GetProjectMetadataTool {
             None => use_case.execute(&project.id)?,
         };
 
+        // Group select field options by field; child options of cascading fields carry a parent
+        let mut field_options: Vec<Value> = Vec::new();
+        for option in &metadata.field_options {
+            let entry = serde_json::json!({
+                "id": option.option_id,
+                "value": option.value,
+                "parent_id": option.parent_option_id,
+                "disabled": option.disabled,
+            });
+            match field_options
+                .iter_mut()
+                .find(|f| f["field_id"] == option.field_id.as_str())
+            {
+                Some(field) => {
+                    if let Some(options) = field["options"].as_array_mut() {
+                        options.push(entry);
+                    }
+                }
+                None => field_options.push(serde_json::json!({
+                    "field_id": option.field_id,
+                    "field_name": option.field_name,
+                    "options": [entry],
+                })),
+            }
+        }
+
         let result = serde_json::json!({
             "project_key": params.project_key,
             "statuses": metadata.statuses.iter().map(|s| &s.name).collect::<Vec<_>>(),
@@ -336,6 +551,7 @@ impl ToolHandler for GetProjectMetadataTool {
             "labels": metadata.labels.iter().map(|l| &l.name).collect::<Vec<_>>(),
             "components": metadata.components.iter().map(|c| &c.name).collect::<Vec<_>>(),
             "fix_versions": metadata.fix_versions.iter().map(|v| &v.name).collect::<Vec<_>>(),
+            "field_options": field_options,
         });
 
         let json = serde_json::to_string_pretty(&result)?;
@@ -384,7 +600,7 @@ impl ToolHandler for GetSchemaTool {
             }
         };
 
-        let conn = self.db_factory.get_connection(&project_key)?;
+        let conn = self.db_factory.get_read_connection(&project_key)?;
         let conn = conn
             .lock()
             .map_err(|e| anyhow::anyhow!("Failed to lock connection: {}", e))?;
@@ -449,7 +665,7 @@ impl ToolHandler for ExecuteSqlTool {
     fn definition(&self) -> Tool {
         build_tool_definition::<ExecuteSqlParams>(
             "execute_sql",
-            "Execute a read-only SQL query (SELECT statements only) on the JIRA database. Requires project key to specify which database to query.",
+            "Execute a read-only SQL query (SELECT statements only) on the JIRA database. Requires project key to specify which database to query, or '*' to query all projects through the all_issues, all_change_history, ... views (each with a project_key column).",
         )
     }
 
@@ -503,7 +719,12 @@ impl ToolHandler for ExecuteSqlTool {
             }
         }
 
-        let conn = self.db_factory.get_connection(&project_key)?;
+        // "*" queries every project database through the federated union views
+        let conn = if project_key == "*" {
+            self.db_factory.get_all_projects_connection()?
+        } else {
+            self.db_factory.get_read_connection(&project_key)?
+        };
         let conn = conn
             .lock()
             .map_err(|e| anyhow::anyhow!("Failed to lock connection: {}", e))?;
@@ -568,6 +789,91 @@ impl ToolHandler for ExecuteSqlTool {
     }
 }
 
+//=============================================================================
+// JqlSearchTool
+//=============================================================================
+
+pub struct JqlSearchTool {
+    db_factory: Arc<DatabaseFactory>,
+}
+
+impl JqlSearchTool {
+    pub fn new(db_factory: Arc<DatabaseFactory>) -> Self {
+        Self { db_factory }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for JqlSearchTool {
+    fn definition(&self) -> Tool {
+        build_tool_definition::<JqlSearchParams>(
+            "jql_search",
+            "Run a JQL query against the local JIRA database without calling JIRA. Supports =, !=, ~, IN, IS EMPTY, >/< on dates and numbers, relative dates (-7d, startOfWeek()), currentUser(), WAS / CHANGED, AND/OR/NOT and ORDER BY. Custom fields can be referenced by name or cf[10010]. Requires a project key, or '*' for all projects.",
+        )
+    }
+
+    async fn execute(&self, arguments: Value) -> Result<CallToolResult> {
+        let params: JqlSearchParams = serde_json::from_value(arguments)?;
+
+        let project_key = match &params.project {
+            Some(key) => key.clone(),
+            None => {
+                let projects = self.db_factory.list_project_databases()?;
+                return Ok(CallToolResult::error(format!(
+                    "Project key required. Available databases: {:?}",
+                    projects
+                )));
+            }
+        };
+
+        let use_case = if project_key == "*" {
+            ExecuteJqlUseCase::all_projects(&self.db_factory)?
+        } else {
+            ExecuteJqlUseCase::new(self.db_factory.get_read_connection(&project_key)?)
+        };
+        let use_case = match params.current_user {
+            Some(user) => use_case.with_current_user(user),
+            None => use_case,
+        };
+
+        let jql = normalize_quotes(&params.query);
+        let result = match use_case.execute(&jql, params.limit) {
+            Ok(result) => result,
+            // Syntax errors and unknown fields go back to the caller to correct the query
+            Err(DomainError::Validation(message)) => return Ok(CallToolResult::error(message)),
+            Err(e) => return Err(e.into()),
+        };
+
+        let rows: Vec<Value> = result
+            .result
+            .rows
+            .into_iter()
+            .map(|row| {
+                Value::Object(
+                    result
+                        .result
+                        .columns
+                        .iter()
+                        .cloned()
+                        .zip(row)
+                        .collect::<serde_json::Map<_, _>>(),
+                )
+            })
+            .collect();
+
+        let response = serde_json::json!({
+            "project": project_key,
+            "sql": result.sql,
+            "columns": result.result.columns,
+            "rows": rows,
+            "row_count": result.result.row_count
+        });
+
+        let json = serde_json::to_string_pretty(&response)?;
+        Ok(CallToolResult::text(json))
+    }
+}
+
 //=============================================================================
 // SemanticSearchTool
 //=============================================================================
@@ -619,7 +925,7 @@ impl ToolHandler for SemanticSearchTool {
             }
         };
 
-        let conn = self.db_factory.get_connection(&project_key)?;
+        let conn = self.db_factory.get_read_connection(&project_key)?;
 
         // Check if embeddings table exists and has data
         let embeddings_repo = jira_db_core::EmbeddingsRepository::new(conn);
@@ -663,7 +969,6 @@ impl ToolHandler for SemanticSearchTool {
         };
 
         // Embed the query
-        use jira_db_core::EmbeddingProvider;
         let query_embedding = match embedding_client.embed(&params.query).await {
             Ok(embedding) => embedding,
             Err(e) => {
@@ -714,6 +1019,153 @@ impl ToolHandler for SemanticSearchTool {
     }
 }
 
+//=============================================================================
+// FindSimilarIssuesTool
+//=============================================================================
+
+pub struct FindSimilarIssuesTool {
+    db_factory: Arc<DatabaseFactory>,
+}
+
+impl FindSimilarIssuesTool {
+    pub fn new(db_factory: Arc<DatabaseFactory>) -> Self {
+        Self { db_factory }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for FindSimilarIssuesTool {
+    fn definition(&self) -> Tool {
+        build_tool_definition::<FindSimilarIssuesParams>(
+            "find_similar_issues",
+            "Find issues similar to a given issue using its stored embedding (\"more like this\"; requires embeddings to be generated with 'jira-db embeddings'). Searches all projects unless same_project is set.",
+        )
+    }
+
+    async fn execute(&self, arguments: Value) -> Result<CallToolResult> {
+        let params: FindSimilarIssuesParams = serde_json::from_value(arguments)?;
+
+        let created =
+            match DateRange::parse(params.created_from.as_deref(), params.created_to.as_deref()) {
+                Ok(range) => range,
+                Err(e) => return Ok(CallToolResult::error(e.to_string())),
+            };
+
+        let result = FindSimilarIssuesUseCase::connect(
+            &self.db_factory,
+            &params.issue_key,
+            params.same_project.unwrap_or(false),
+        )
+        .and_then(|use_case| {
+            use_case
+                .with_exclude_resolved(params.exclude_resolved.unwrap_or(false))
+                .with_created(created)
+                .execute(&params.issue_key, params.limit.unwrap_or(10))
+        });
+        let result = match result {
+            Ok(result) => result,
+            Err(DomainError::NotFound(msg)) | Err(DomainError::Validation(msg)) => {
+                return Ok(CallToolResult::error(msg));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let similar: Vec<Value> = result
+            .similar
+            .into_iter()
+            .map(|s| {
+                serde_json::json!({
+                    "issue_key": s.issue.key,
+                    "summary": s.issue.summary,
+                    "status": s.issue.status,
+                    "issue_type": s.issue.issue_type,
+                    "resolution": s.issue.resolution,
+                    "created_date": s.issue.created_date,
+                    "similarity": s.similarity,
+                })
+            })
+            .collect();
+
+        let response = serde_json::json!({
+            "issue_key": result.source.key,
+            "summary": result.source.summary,
+            "count": similar.len(),
+            "similar": similar,
+        });
+
+        let json = serde_json::to_string_pretty(&response)?;
+        Ok(CallToolResult::text(json))
+    }
+}
+
+//=============================================================================
+// FindDuplicatesForIssueTool
+//=============================================================================
+
+pub struct FindDuplicatesForIssueTool {
+    db_factory: Arc<DatabaseFactory>,
+}
+
+impl FindDuplicatesForIssueTool {
+    pub fn new(db_factory: Arc<DatabaseFactory>) -> Self {
+        Self { db_factory }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for FindDuplicatesForIssueTool {
+    fn definition(&self) -> Tool {
+        build_tool_definition::<FindDuplicatesForIssueParams>(
+            "find_duplicates_for_issue",
+            "Find likely duplicates of an issue by embedding similarity (requires embeddings to be generated with 'jira-db embeddings'). Issues already linked as duplicates are excluded and listed separately.",
+        )
+    }
+
+    async fn execute(&self, arguments: Value) -> Result<CallToolResult> {
+        let params: FindDuplicatesForIssueParams = serde_json::from_value(arguments)?;
+
+        let project_key = extract_project_key(&params.issue_key)
+            .ok_or_else(|| anyhow::anyhow!("Invalid issue key format: {}", params.issue_key))?;
+
+        let conn = self.db_factory.get_read_connection(project_key)?;
+        let threshold = params.threshold.unwrap_or(DEFAULT_DUPLICATE_THRESHOLD);
+        let use_case = FindDuplicatesUseCase::new(conn).with_threshold(threshold);
+
+        let result = match use_case.for_issue(&params.issue_key, params.limit.unwrap_or(10)) {
+            Ok(result) => result,
+            Err(DomainError::NotFound(msg)) | Err(DomainError::Validation(msg)) => {
+                return Ok(CallToolResult::error(msg));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let duplicates: Vec<Value> = result
+            .duplicates
+            .into_iter()
+            .map(|m| {
+                serde_json::json!({
+                    "issue_key": m.issue.key,
+                    "summary": m.issue.summary,
+                    "status": m.issue.status,
+                    "issue_type": m.issue.issue_type,
+                    "similarity": m.similarity,
+                })
+            })
+            .collect();
+
+        let response = serde_json::json!({
+            "issue_key": result.issue.key,
+            "summary": result.issue.summary,
+            "threshold": threshold,
+            "duplicates": duplicates,
+            "already_linked": result.linked,
+        });
+
+        let json = serde_json::to_string_pretty(&response)?;
+        Ok(CallToolResult::text(json))
+    }
+}
+
 //=============================================================================
 // GetRawIssueDataTool
 //=============================================================================
@@ -745,7 +1197,7 @@ impl ToolHandler for GetRawIssueDataTool {
             .ok_or_else(|| anyhow::anyhow!("Invalid issue key format: {}", params.issue_key))?;
 
         // Get raw connection for this project
-        let raw_conn = match self.db_factory.get_raw_connection(project_key) {
+        let raw_conn = match self.db_factory.get_raw_read_connection(project_key) {
             Ok(conn) => conn,
             Err(_) => {
                 return Ok(CallToolResult::error(format!(
@@ -772,3 +1224,410 @@ impl ToolHandler for GetRawIssueDataTool {
         }
     }
 }
+
+//=============================================================================
+// Saved search tools
+//=============================================================================
+
+/// Load the saved searches from the jira-db settings file (none if it does not exist)
+pub fn load_saved_searches(settings_path: &Path) -> Result<Vec<SavedSearch>> {
+    if !Settings::exists(settings_path) {
+        return Ok(Vec::new());
+    }
+    Ok(Settings::load(settings_path)?.saved_searches)
+}
+
+/// Argument text for a saved search parameter; null means "not given"
+fn argument_text(value: Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Run a saved search, reloading it from the settings file so edits apply without a restart
+fn run_saved_search(
+    db_factory: &DatabaseFactory,
+    settings_path: &Path,
+    name: &str,
+    args: HashMap<String, String>,
+    project: Option<String>,
+    limit: Option<usize>,
+    current_user: Option<String>,
+) -> Result<CallToolResult> {
+    let settings = if Settings::exists(settings_path) {
+        Settings::load(settings_path)?
+    } else {
+        return Ok(CallToolResult::error(format!(
+            "Settings file not found: {}",
+            settings_path.display()
+        )));
+    };
+    let search = match settings.find_saved_search(name) {
+        Some(search) => search.clone(),
+        None => {
+            let names: Vec<&str> = settings
+                .saved_searches
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect();
+            return Ok(CallToolResult::error(format!(
+                "Saved search not found: {}. Available: {:?}",
+                name, names
+            )));
+        }
+    };
+
+    let project = project.or_else(|| search.project.clone());
+    // currentUser() defaults to the account the endpoint is configured with
+    let jira_config = match &project {
+        Some(project_key) => settings.get_jira_config_for_project(project_key),
+        None => settings.get_jira_config(),
+    };
+    let current_user = current_user.or_else(|| jira_config.map(|config| config.username));
+
+    let use_case = RunSavedSearchUseCase::connect(db_factory, &search, project.as_deref())?;
+    let use_case = match current_user {
+        Some(user) => use_case.with_current_user(user),
+        None => use_case,
+    };
+    let result = match use_case.execute(&search, &args, limit) {
+        Ok(result) => result,
+        // Bad arguments go back to the caller to correct
+        Err(DomainError::Validation(message)) => return Ok(CallToolResult::error(message)),
+        Err(e) => return Err(e.into()),
+    };
+
+    let columns = result.result.columns;
+    let rows: Vec<Value> = result
+        .result
+        .rows
+        .into_iter()
+        .map(|row| Value::Object(columns.iter().cloned().zip(row).collect()))
+        .collect();
+
+    let mut response = serde_json::json!({
+        "name": result.name,
+        "type": result.kind,
+        "project": project,
+        "columns": columns,
+        "rows": rows,
+        "row_count": result.result.row_count
+    });
+    if let Some(sql) = result.sql {
+        response["sql"] = Value::String(sql);
+    }
+    if let Some(total) = result.total {
+        response["total"] = total.into();
+    }
+
+    let json = serde_json::to_string_pretty(&response)?;
+    Ok(CallToolResult::text(json))
+}
+
+pub struct ListSavedSearchesTool {
+    settings_path: PathBuf,
+}
+
+impl ListSavedSearchesTool {
+    pub fn new(settings_path: PathBuf) -> Self {
+        Self { settings_path }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for ListSavedSearchesTool {
+    fn definition(&self) -> Tool {
+        build_tool_definition::<ListSavedSearchesParams>(
+            "list_saved_searches",
+            "List saved searches shared with the jira-db CLI and web UI, with their query, owner and typed parameters. Run one with run_saved_search.",
+        )
+    }
+
+    async fn execute(&self, _arguments: Value) -> Result<CallToolResult> {
+        let searches = load_saved_searches(&self.settings_path)?;
+        let json = serde_json::to_string_pretty(&searches)?;
+        Ok(CallToolResult::text(json))
+    }
+}
+
+pub struct RunSavedSearchTool {
+    db_factory: Arc<DatabaseFactory>,
+    settings_path: PathBuf,
+}
+
+impl RunSavedSearchTool {
+    pub fn new(db_factory: Arc<DatabaseFactory>, settings_path: PathBuf) -> Self {
+        Self {
+            db_factory,
+            settings_path,
+        }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for RunSavedSearchTool {
+    fn definition(&self) -> Tool {
+        build_tool_definition::<RunSavedSearchParams>(
+            "run_saved_search",
+            "Run a saved search (SQL, JQL or structured filters) by name with parameter values. Parameters are type-checked; unset optional ones are NULL in SQL, EMPTY in JQL and drop their filter.",
+        )
+    }
+
+    async fn execute(&self, arguments: Value) -> Result<CallToolResult> {
+        let params: RunSavedSearchParams = serde_json::from_value(arguments)?;
+        let args = params
+            .params
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(name, value)| argument_text(value).map(|text| (name, text)))
+            .collect();
+
+        run_saved_search(
+            &self.db_factory,
+            &self.settings_path,
+            &params.name,
+            args,
+            params.project,
+            params.limit,
+            params.current_user,
+        )
+    }
+}
+
+/// One saved search exposed as its own tool (`saved_<name>`), with its parameters as arguments
+pub struct SavedSearchTool {
+    db_factory: Arc<DatabaseFactory>,
+    settings_path: PathBuf,
+    search: SavedSearch,
+}
+
+impl SavedSearchTool {
+    pub fn new(
+        db_factory: Arc<DatabaseFactory>,
+        settings_path: PathBuf,
+        search: SavedSearch,
+    ) -> Self {
+        Self {
+            db_factory,
+            settings_path,
+            search,
+        }
+    }
+
+    pub fn tool_name(search: &SavedSearch) -> String {
+        format!("saved_{}", search.name)
+    }
+}
+
+/// Input schema for a saved search: one property per parameter, plus `project` and `limit`
+fn saved_search_input_schema(search: &SavedSearch) -> ToolInputSchema {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    for param in &search.parameters {
+        let schema_type = match param.param_type {
+            SavedSearchParameterType::Number => "number",
+            SavedSearchParameterType::Boolean => "boolean",
+            SavedSearchParameterType::String | SavedSearchParameterType::Date => "string",
+        };
+        let mut description = param
+            .description
+            .clone()
+            .unwrap_or_else(|| param.name.clone());
+        if param.param_type == SavedSearchParameterType::Date {
+            description.push_str(" (YYYY-MM-DD)");
+        }
+        if let Some(default) = &param.default {
+            description.push_str(&format!(" (default: {})", default));
+        }
+        properties.insert(
+            param.name.clone(),
+            serde_json::json!({ "type": schema_type, "description": description }),
+        );
+        if param.required {
+            required.push(param.name.clone());
+        }
+    }
+    if !properties.contains_key("project") {
+        properties.insert(
+            "project".to_string(),
+            serde_json::json!({
+                "type": "string",
+                "description": "Project key to run against (defaults to the saved project, or all projects)"
+            }),
+        );
+    }
+    if !properties.contains_key("limit") {
+        properties.insert(
+            "limit".to_string(),
+            serde_json::json!({
+                "type": "integer",
+                "description": "Maximum number of rows to return (default: 100)"
+            }),
+        );
+    }
+
+    ToolInputSchema {
+        schema_type: "object".to_string(),
+        properties: Some(Value::Object(properties)),
+        required: (!required.is_empty()).then_some(required),
+    }
+}
+
+#[async_trait]
+impl ToolHandler for SavedSearchTool {
+    fn definition(&self) -> Tool {
+        let mut description = format!(
+            "Saved {} search '{}'",
+            self.search.query.kind().to_uppercase(),
+            self.search.name
+        );
+        if let Some(text) = &self.search.description {
+            description.push_str(&format!(": {}", text));
+        }
+        if let Some(owner) = &self.search.owner {
+            description.push_str(&format!(" (owner: {})", owner));
+        }
+
+        Tool {
+            name: Self::tool_name(&self.search),
+            description: Some(description),
+            input_schema: saved_search_input_schema(&self.search),
+        }
+    }
+
+    async fn execute(&self, arguments: Value) -> Result<CallToolResult> {
+        let mut arguments = match arguments {
+            Value::Object(arguments) => arguments,
+            Value::Null => serde_json::Map::new(),
+            _ => return Ok(CallToolResult::error("Arguments must be an object")),
+        };
+
+        // Parameters named project or limit shadow the built-in arguments
+        let is_param = |name: &str| self.search.parameters.iter().any(|p| p.name == name);
+        let project = if is_param("project") {
+            None
+        } else {
+            arguments.remove("project").and_then(argument_text)
+        };
+        let limit = if is_param("limit") {
+            None
+        } else {
+            arguments.remove("limit").and_then(|v| v.as_u64())
+        };
+        let args = arguments
+            .into_iter()
+            .filter_map(|(name, value)| argument_text(value).map(|text| (name, text)))
+            .collect();
+
+        run_saved_search(
+            &self.db_factory,
+            &self.settings_path,
+            &self.search.name,
+            args,
+            project,
+            limit.map(|l| l as usize),
+            None,
+        )
+    }
+}
+
+//=============================================================================
+// UpdateIssueFieldsTool (write tool, opt-in)
+//=============================================================================
+
+pub struct UpdateIssueFieldsTool {
+    db_factory: Arc<DatabaseFactory>,
+    settings_path: PathBuf,
+}
+
+impl UpdateIssueFieldsTool {
+    pub fn new(db_factory: Arc<DatabaseFactory>, settings_path: PathBuf) -> Self {
+        Self {
+            db_factory,
+            settings_path,
+        }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for UpdateIssueFieldsTool {
+    fn definition(&self) -> Tool {
+        build_tool_definition::<UpdateIssueFieldsParams>(
+            "update_issue_fields",
+            "Edit fields of a JIRA issue (writes to JIRA). Field names and values are validated against the synced field definitions and project metadata. Use dry_run to preview the request.",
+        )
+    }
+
+    async fn execute(&self, arguments: Value) -> Result<CallToolResult> {
+        let params: UpdateIssueFieldsParams = serde_json::from_value(arguments)?;
+
+        let project_key = extract_project_key(&params.issue_key)
+            .ok_or_else(|| anyhow::anyhow!("Invalid issue key format: {}", params.issue_key))?;
+
+        // JIRA credentials come from the jira-db settings file
+        let settings = Settings::load(&self.settings_path)?;
+        let jira_config = match settings.get_jira_config_for_project(project_key) {
+            Some(config) => config,
+            None => {
+                return Ok(CallToolResult::error(format!(
+                    "No JIRA endpoint configured for project {}",
+                    project_key
+                )));
+            }
+        };
+
+        let mut edits = Vec::new();
+        for (field, value) in params.set.unwrap_or_default() {
+            edits.push(FieldEdit::Set { field, value });
+        }
+        for (field, values) in params.add.unwrap_or_default() {
+            for value in values {
+                edits.push(FieldEdit::Add {
+                    field: field.clone(),
+                    value,
+                });
+            }
+        }
+        for (field, values) in params.remove.unwrap_or_default() {
+            for value in values {
+                edits.push(FieldEdit::Remove {
+                    field: field.clone(),
+                    value,
+                });
+            }
+        }
+
+        let conn = self.db_factory.get_connection(project_key)?;
+        let use_case = UpdateIssueFieldsUseCase::new(
+            Arc::new(DuckDbIssueRepository::new(conn.clone())),
+            Arc::new(DuckDbMetadataRepository::new(conn.clone())),
+            Arc::new(DuckDbFieldRepository::new(conn)),
+            Arc::new(JiraApiClient::new(&jira_config)?),
+        );
+
+        let update = match use_case.prepare(&params.issue_key, &edits) {
+            Ok(update) => update,
+            Err(e) => return Ok(CallToolResult::error(e.to_string())),
+        };
+
+        if params.dry_run.unwrap_or(false) {
+            let json = serde_json::to_string_pretty(&serde_json::json!({
+                "dry_run": true,
+                "issue_key": params.issue_key,
+                "payload": update.to_request_body(),
+            }))?;
+            return Ok(CallToolResult::text(json));
+        }
+
+        match use_case.execute(&params.issue_key, &edits).await {
+            Ok(issue) => {
+                let response: IssueResponse = issue.into();
+                let json = serde_json::to_string_pretty(&response)?;
+                Ok(CallToolResult::text(json))
+            }
+            Err(e) => Ok(CallToolResult::error(e.to_string())),
+        }
+    }
+}
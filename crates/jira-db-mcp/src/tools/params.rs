@@ -1,12 +1,16 @@
 //! Tool parameter definitions
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Parameters for searching issues
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct SearchIssuesParams {
     /// Search query text
-    #[schemars(description = "Text to search for in issue summary and description")]
+    #[schemars(
+        description = "Text to search for in issue summary, description, comments and indexed custom fields. Results are ranked by relevance (BM25) when the project has a search index"
+    )]
     pub query: Option<String>,
 
     /// Filter by project key
@@ -21,6 +25,90 @@ pub struct SearchIssuesParams {
     #[schemars(description = "Assignee name to filter by")]
     pub assignee: Option<String>,
 
+    /// Filter by issue type
+    #[schemars(description = "Issue type to filter by (e.g., 'Bug', 'Story')")]
+    pub issue_type: Option<String>,
+
+    /// Filter by priority
+    #[schemars(description = "Priority to filter by (e.g., 'High')")]
+    pub priority: Option<String>,
+
+    /// Filter by labels
+    #[schemars(description = "Labels to filter by, combined according to labels_match")]
+    pub labels: Option<Vec<String>>,
+
+    /// How labels combine
+    #[schemars(
+        description = "'any' (default) matches issues with at least one label, 'all' requires every label"
+    )]
+    pub labels_match: Option<String>,
+
+    /// Filter by components
+    #[schemars(description = "Components to filter by (matches any)")]
+    pub components: Option<Vec<String>>,
+
+    /// Filter by fix versions
+    #[schemars(description = "Fix versions to filter by (matches any)")]
+    pub fix_versions: Option<Vec<String>>,
+
+    /// Filter by sprint
+    #[schemars(description = "Sprint name to filter by")]
+    pub sprint: Option<String>,
+
+    /// Filter by parent or epic
+    #[schemars(description = "Parent issue or epic key to filter by (e.g., 'PROJ-100')")]
+    pub parent: Option<String>,
+
+    /// Filter by resolution
+    #[schemars(
+        description = "Resolution to filter by (e.g., 'Fixed'), or 'Unresolved' for issues without one"
+    )]
+    pub resolution: Option<String>,
+
+    /// Filter by status category
+    #[schemars(description = "Status category to filter by: 'To Do', 'In Progress' or 'Done'")]
+    pub status_category: Option<String>,
+
+    /// Created on or after
+    #[schemars(description = "Only issues created on or after this date (YYYY-MM-DD or RFC 3339)")]
+    pub created_from: Option<String>,
+
+    /// Created on or before
+    #[schemars(
+        description = "Only issues created on or before this date (YYYY-MM-DD or RFC 3339)"
+    )]
+    pub created_to: Option<String>,
+
+    /// Updated on or after
+    #[schemars(description = "Only issues updated on or after this date")]
+    pub updated_from: Option<String>,
+
+    /// Updated on or before
+    #[schemars(description = "Only issues updated on or before this date")]
+    pub updated_to: Option<String>,
+
+    /// Due on or after
+    #[schemars(description = "Only issues due on or after this date")]
+    pub due_from: Option<String>,
+
+    /// Due on or before
+    #[schemars(description = "Only issues due on or before this date")]
+    pub due_to: Option<String>,
+
+    /// Resolved on or after
+    #[schemars(description = "Only issues resolved on or after this date")]
+    pub resolved_from: Option<String>,
+
+    /// Resolved on or before
+    #[schemars(description = "Only issues resolved on or before this date")]
+    pub resolved_to: Option<String>,
+
+    /// Sort order
+    #[schemars(
+        description = "Comma-separated sort keys with optional direction, e.g. 'updated:desc,key'. Fields: relevance, key, summary, created, updated, due, resolved. Default: relevance"
+    )]
+    pub sort: Option<String>,
+
     /// Maximum number of results to return
     #[schemars(description = "Maximum number of results (default: 20)")]
     pub limit: Option<usize>,
@@ -28,6 +116,18 @@ pub struct SearchIssuesParams {
     /// Number of results to skip
     #[schemars(description = "Number of results to skip for pagination")]
     pub offset: Option<usize>,
+
+    /// Fuse keyword and semantic rankings
+    #[schemars(
+        description = "Combine keyword relevance with embedding similarity using reciprocal rank fusion (default: true). Projects without embeddings, or a server without OPENAI_API_KEY, fall back to keyword ranking. Set to false for keyword (BM25) ranking only"
+    )]
+    pub hybrid: Option<bool>,
+
+    /// Count matches per field value
+    #[schemars(
+        description = "Also return counts of all matches (not just this page) by status, issue_type, priority, assignee, labels, components and fix_versions"
+    )]
+    pub facets: Option<bool>,
 }
 
 /// Parameters for getting a specific issue
@@ -76,7 +176,7 @@ pub struct GetProjectMetadataParams {
 
     /// Metadata type to filter
     #[schemars(
-        description = "Type of metadata: 'status', 'priority', 'issue-type', 'label', 'component', 'version', or 'all'"
+        description = "Type of metadata: 'status', 'priority', 'issue-type', 'label', 'component', 'version', 'field-option', or 'all'"
     )]
     pub metadata_type: Option<String>,
 }
@@ -98,8 +198,10 @@ pub struct GetSchemaParams {
 /// Parameters for executing SQL queries
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ExecuteSqlParams {
-    /// Project key (required for per-project databases)
-    #[schemars(description = "Project key to specify which database to query (e.g., 'PROJ')")]
+    /// Project key (required for per-project databases), or "*" for all projects
+    #[schemars(
+        description = "Project key to specify which database to query (e.g., 'PROJ'), or '*' to query all projects via all_issues, all_change_history, ... views"
+    )]
     pub project: Option<String>,
 
     /// SQL query (SELECT only)
@@ -111,6 +213,32 @@ pub struct ExecuteSqlParams {
     pub limit: Option<usize>,
 }
 
+/// Parameters for running JQL against the local database
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct JqlSearchParams {
+    /// JQL query
+    #[schemars(
+        description = "JQL query, e.g. 'project = PROJ AND status WAS \"In Progress\" AFTER -7d ORDER BY updated DESC'"
+    )]
+    pub query: String,
+
+    /// Project key, or "*" for all projects
+    #[schemars(
+        description = "Project key to specify which database to query (e.g., 'PROJ'), or '*' to query all projects"
+    )]
+    pub project: Option<String>,
+
+    /// Maximum number of issues to return
+    #[schemars(description = "Maximum number of issues to return (default: 100)")]
+    pub limit: Option<usize>,
+
+    /// User that currentUser() refers to
+    #[schemars(
+        description = "User that currentUser() refers to (email address, account ID or display name)"
+    )]
+    pub current_user: Option<String>,
+}
+
 /// Parameters for semantic search
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 #[allow(dead_code)]
@@ -128,6 +256,50 @@ pub struct SemanticSearchParams {
     pub limit: Option<usize>,
 }
 
+/// Parameters for finding issues similar to an issue
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct FindSimilarIssuesParams {
+    /// Issue key (e.g., "PROJ-123")
+    #[schemars(description = "The issue key to find similar issues for (e.g., 'PROJ-123')")]
+    pub issue_key: String,
+
+    /// Only return issues of the same project
+    #[schemars(description = "Only return issues of the same project (default: false)")]
+    pub same_project: Option<bool>,
+
+    /// Leave out resolved issues
+    #[schemars(description = "Leave out resolved issues (default: false)")]
+    pub exclude_resolved: Option<bool>,
+
+    /// Created on or after this date
+    #[schemars(description = "Only issues created on or after this date (YYYY-MM-DD)")]
+    pub created_from: Option<String>,
+
+    /// Created on or before this date
+    #[schemars(description = "Only issues created on or before this date (YYYY-MM-DD)")]
+    pub created_to: Option<String>,
+
+    /// Maximum number of results
+    #[schemars(description = "Maximum number of results (default: 10)")]
+    pub limit: Option<usize>,
+}
+
+/// Parameters for finding duplicate candidates of an issue
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct FindDuplicatesForIssueParams {
+    /// Issue key (e.g., "PROJ-123")
+    #[schemars(description = "The issue key to find duplicates for (e.g., 'PROJ-123')")]
+    pub issue_key: String,
+
+    /// Minimum cosine similarity
+    #[schemars(description = "Minimum cosine similarity between 0 and 1 (default: 0.9)")]
+    pub threshold: Option<f64>,
+
+    /// Maximum number of candidates
+    #[schemars(description = "Maximum number of candidates (default: 10)")]
+    pub limit: Option<usize>,
+}
+
 /// Parameters for getting raw issue data
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetRawIssueDataParams {
@@ -136,6 +308,69 @@ pub struct GetRawIssueDataParams {
     pub issue_key: String,
 }
 
+/// Parameters for listing saved searches
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[allow(dead_code)]
+pub struct ListSavedSearchesParams {}
+
+/// Parameters for running a saved search by name
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RunSavedSearchParams {
+    /// Saved search name
+    #[schemars(description = "Name of the saved search (see list_saved_searches)")]
+    pub name: String,
+
+    /// Parameter values
+    #[schemars(
+        description = "Values for the saved search's parameters, keyed by parameter name (e.g., {\"assignee\": \"alice@example.com\"})"
+    )]
+    pub params: Option<HashMap<String, serde_json::Value>>,
+
+    /// Project key
+    #[schemars(
+        description = "Project key to run against (defaults to the saved project, or all projects)"
+    )]
+    pub project: Option<String>,
+
+    /// Maximum number of rows to return
+    #[schemars(description = "Maximum number of rows to return (default: 100)")]
+    pub limit: Option<usize>,
+
+    /// User that currentUser() refers to
+    #[schemars(
+        description = "User that currentUser() refers to in JQL searches (email address, account ID or display name)"
+    )]
+    pub current_user: Option<String>,
+}
+
+/// Parameters for editing issue fields in JIRA
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct UpdateIssueFieldsParams {
+    /// Issue key (e.g., "PROJ-123")
+    #[schemars(description = "The issue key (e.g., 'PROJ-123')")]
+    pub issue_key: String,
+
+    /// Field values to set
+    #[schemars(
+        description = "Field values to set, keyed by field ID or name (e.g., {\"priority\": \"High\"}). An empty value clears the field. List fields take comma-separated values."
+    )]
+    pub set: Option<HashMap<String, String>>,
+
+    /// Values to add to list fields
+    #[schemars(
+        description = "Values to add to list fields, keyed by field ID or name (e.g., {\"labels\": [\"backend\"]})"
+    )]
+    pub add: Option<HashMap<String, Vec<String>>>,
+
+    /// Values to remove from list fields
+    #[schemars(description = "Values to remove from list fields, keyed by field ID or name")]
+    pub remove: Option<HashMap<String, Vec<String>>>,
+
+    /// Validate only
+    #[schemars(description = "Validate and return the JIRA payload without sending it")]
+    pub dry_run: Option<bool>,
+}
+
 /// Issue response for JSON output
 #[derive(Debug, Serialize)]
 pub struct IssueResponse {
@@ -173,6 +408,102 @@ impl From<jira_db_core::Issue> for IssueResponse {
     }
 }
 
+/// One page of search results
+#[derive(Debug, Serialize)]
+pub struct SearchIssuesResponse {
+    /// Issues matching the search across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub issues: Vec<SearchHitResponse>,
+    /// Value counts over all matches, when requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<FacetResponse>>,
+}
+
+/// Counts of one field's values over all matches
+#[derive(Debug, Serialize)]
+pub struct FacetResponse {
+    pub field: String,
+    pub values: Vec<FacetValueResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FacetValueResponse {
+    /// None for issues without a value (e.g. unassigned)
+    pub value: Option<String>,
+    pub count: usize,
+}
+
+impl From<jira_db_core::SearchFacet> for FacetResponse {
+    fn from(mut facet: jira_db_core::SearchFacet) -> Self {
+        facet.truncate(jira_db_core::SearchFacet::DEFAULT_SIZE);
+        Self {
+            field: facet.field.as_str().to_string(),
+            values: facet
+                .values
+                .into_iter()
+                .map(|v| FacetValueResponse {
+                    value: v.value,
+                    count: v.count,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Search result with relevance score and highlighted snippet
+#[derive(Debug, Serialize)]
+pub struct SearchHitResponse {
+    #[serde(flatten)]
+    pub issue: IssueResponse,
+    /// BM25 score, or the fused reciprocal rank score for hybrid search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// Position in the keyword ranking (hybrid search)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lexical_rank: Option<usize>,
+    /// BM25 score of the keyword match (hybrid search)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lexical_score: Option<f64>,
+    /// Position in the embedding similarity ranking (hybrid search)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_rank: Option<usize>,
+    /// Cosine distance to the query, lower is more similar (hybrid search)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_distance: Option<f64>,
+    /// Excerpt around the first match, with matched terms wrapped in `**`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+impl From<jira_db_core::IssueSearchHit> for SearchHitResponse {
+    fn from(hit: jira_db_core::IssueSearchHit) -> Self {
+        Self {
+            issue: hit.issue.into(),
+            score: hit.score,
+            lexical_rank: None,
+            lexical_score: None,
+            semantic_rank: None,
+            semantic_distance: None,
+            snippet: hit.snippet,
+        }
+    }
+}
+
+impl From<jira_db_core::HybridSearchHit> for SearchHitResponse {
+    fn from(hit: jira_db_core::HybridSearchHit) -> Self {
+        Self {
+            issue: hit.issue.into(),
+            score: Some(hit.score),
+            lexical_rank: hit.lexical_rank,
+            lexical_score: hit.lexical_score,
+            semantic_rank: hit.semantic_rank,
+            semantic_distance: hit.semantic_distance,
+            snippet: hit.snippet,
+        }
+    }
+}
+
 /// Project response for JSON output
 #[derive(Debug, Serialize)]
 pub struct ProjectResponse {
@@ -202,6 +533,7 @@ pub struct ChangeHistoryResponse {
     pub to_value: Option<String>,
     pub author: Option<String>,
     pub changed_at: String,
+    pub source: String,
 }
 
 impl From<jira_db_core::ChangeHistoryItem> for ChangeHistoryResponse {
@@ -213,6 +545,7 @@ impl From<jira_db_core::ChangeHistoryItem> for ChangeHistoryResponse {
             to_value: item.to_string,
             author: item.author_display_name,
             changed_at: item.changed_at.to_rfc3339(),
+            source: item.source,
         }
     }
 }
@@ -1,11 +1,12 @@
 //! Tool registry for managing MCP tools
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use jira_db_core::DatabaseFactory;
+use jira_db_core::{DatabaseFactory, SearchSettings};
 use serde_json::Value;
 
 use super::implementations::*;
@@ -28,11 +29,11 @@ pub struct ToolRegistry {
 
 impl ToolRegistry {
     /// Create a new tool registry with all available tools
-    pub fn new(db_factory: Arc<DatabaseFactory>) -> Self {
+    pub fn new(db_factory: Arc<DatabaseFactory>, search_settings: SearchSettings) -> Self {
         let mut tools: HashMap<String, Arc<dyn ToolHandler>> = HashMap::new();
 
         // Register all tools
-        let search_issues = Arc::new(SearchIssuesTool::new(db_factory.clone()));
+        let search_issues = Arc::new(SearchIssuesTool::new(db_factory.clone(), search_settings));
         tools.insert("search_issues".to_string(), search_issues);
 
         let get_issue = Arc::new(GetIssueTool::new(db_factory.clone()));
@@ -53,15 +54,70 @@ impl ToolRegistry {
         let execute_sql = Arc::new(ExecuteSqlTool::new(db_factory.clone()));
         tools.insert("execute_sql".to_string(), execute_sql);
 
+        let jql_search = Arc::new(JqlSearchTool::new(db_factory.clone()));
+        tools.insert("jql_search".to_string(), jql_search);
+
         let semantic_search = Arc::new(SemanticSearchTool::new(db_factory.clone()));
         tools.insert("semantic_search".to_string(), semantic_search);
 
+        let find_similar_issues = Arc::new(FindSimilarIssuesTool::new(db_factory.clone()));
+        tools.insert("find_similar_issues".to_string(), find_similar_issues);
+
+        let find_duplicates_for_issue =
+            Arc::new(FindDuplicatesForIssueTool::new(db_factory.clone()));
+        tools.insert(
+            "find_duplicates_for_issue".to_string(),
+            find_duplicates_for_issue,
+        );
+
         let get_raw_issue_data = Arc::new(GetRawIssueDataTool::new(db_factory.clone()));
         tools.insert("get_raw_issue_data".to_string(), get_raw_issue_data);
 
         Self { tools }
     }
 
+    /// Register tools that modify issues in JIRA (opt-in via `write.enabled`)
+    pub fn register_write_tools(
+        &mut self,
+        db_factory: Arc<DatabaseFactory>,
+        settings_path: PathBuf,
+    ) {
+        let update_issue_fields = Arc::new(UpdateIssueFieldsTool::new(db_factory, settings_path));
+        self.tools
+            .insert("update_issue_fields".to_string(), update_issue_fields);
+    }
+
+    /// Register the saved search tools: `list_saved_searches`, `run_saved_search` and one
+    /// `saved_<name>` tool per saved search in the settings file
+    pub fn register_saved_search_tools(
+        &mut self,
+        db_factory: Arc<DatabaseFactory>,
+        settings_path: PathBuf,
+    ) -> Result<()> {
+        let list_saved_searches = Arc::new(ListSavedSearchesTool::new(settings_path.clone()));
+        self.tools
+            .insert("list_saved_searches".to_string(), list_saved_searches);
+
+        let run_saved_search = Arc::new(RunSavedSearchTool::new(
+            db_factory.clone(),
+            settings_path.clone(),
+        ));
+        self.tools
+            .insert("run_saved_search".to_string(), run_saved_search);
+
+        for search in load_saved_searches(&settings_path)? {
+            let name = SavedSearchTool::tool_name(&search);
+            let tool = Arc::new(SavedSearchTool::new(
+                db_factory.clone(),
+                settings_path.clone(),
+                search,
+            ));
+            self.tools.insert(name, tool);
+        }
+
+        Ok(())
+    }
+
     /// List all available tools
     pub fn list_tools(&self) -> Vec<Tool> {
         self.tools.values().map(|t| t.definition()).collect()
@@ -104,4 +160,53 @@ mod tests {
         assert_eq!(tool.description, Some("Search for JIRA issues".to_string()));
         assert_eq!(tool.input_schema.schema_type, "object");
     }
+
+    #[test]
+    fn test_saved_search_tool_definition() {
+        use jira_db_core::{
+            SavedSearch, SavedSearchParameter, SavedSearchParameterType, SavedSearchQuery,
+        };
+
+        let mut search = SavedSearch::new(
+            "my-bugs",
+            SavedSearchQuery::Jql {
+                jql: "assignee = {{who}} AND points >= {{points}}".into(),
+            },
+        );
+        search.description = Some("Open bugs".into());
+        search.parameters = vec![
+            SavedSearchParameter {
+                name: "who".into(),
+                param_type: SavedSearchParameterType::String,
+                description: None,
+                required: true,
+                default: None,
+            },
+            SavedSearchParameter {
+                name: "points".into(),
+                param_type: SavedSearchParameterType::Number,
+                description: None,
+                required: false,
+                default: Some("3".into()),
+            },
+        ];
+
+        let db_factory = Arc::new(DatabaseFactory::with_dir(std::env::temp_dir()));
+        let tool = SavedSearchTool::new(db_factory, PathBuf::from("settings.json"), search);
+        let definition = tool.definition();
+
+        assert_eq!(definition.name, "saved_my-bugs");
+        assert_eq!(
+            definition.description.as_deref(),
+            Some("Saved JQL search 'my-bugs': Open bugs")
+        );
+        let properties = definition.input_schema.properties.unwrap();
+        assert_eq!(properties["who"]["type"], "string");
+        assert_eq!(properties["points"]["type"], "number");
+        assert!(properties.get("project").is_some());
+        assert_eq!(
+            definition.input_schema.required,
+            Some(vec!["who".to_string()])
+        );
+    }
 }
@@ -7,21 +7,26 @@
 use std::sync::Arc;
 
 use actix_web::{HttpResponse, Responder, web};
+use tokio::sync::Semaphore;
 
 use crate::handlers::RequestHandler;
 use crate::protocol::{JsonRpcRequest, ProtocolError};
 use crate::tools::ToolRegistry;
 
 /// State shared across HTTP handlers
+///
+/// Requests are handled concurrently; the semaphore caps how many run at the same time.
 pub struct HttpState {
-    handler: tokio::sync::Mutex<RequestHandler>,
+    handler: RequestHandler,
+    limiter: Semaphore,
 }
 
 impl HttpState {
-    /// Create new HTTP state with the given tool registry
-    pub fn new(tool_registry: ToolRegistry) -> Self {
+    /// Create new HTTP state with the given tool registry and concurrency cap
+    pub fn new(tool_registry: ToolRegistry, max_concurrent_requests: usize) -> Self {
         Self {
-            handler: tokio::sync::Mutex::new(RequestHandler::new(tool_registry)),
+            handler: RequestHandler::new(tool_registry),
+            limiter: Semaphore::new(max_concurrent_requests.max(1)),
         }
     }
 }
@@ -40,9 +45,17 @@ pub async fn handle_mcp_raw(state: web::Data<Arc<HttpState>>, body: web::Bytes)
     };
 
     let id = request.id.clone();
-    let mut handler = state.handler.lock().await;
+    let _permit = match state.limiter.acquire().await {
+        Ok(permit) => permit,
+        Err(e) => {
+            let error_response = ProtocolError::Internal(e.to_string()).to_error_response(id);
+            return HttpResponse::Ok()
+                .content_type("application/json")
+                .json(error_response);
+        }
+    };
 
-    match handler.handle(request).await {
+    match state.handler.handle(request).await {
         Ok(Some(response)) => HttpResponse::Ok()
             .content_type("application/json")
             .json(response),
@@ -43,9 +43,12 @@ pub use types::*;
 // Re-export service modules
 pub use services::config;
 pub use services::embeddings;
+pub use services::export;
 pub use services::issues;
+pub use services::jql;
 pub use services::metadata;
 pub use services::projects;
 pub use services::reports;
+pub use services::saved;
 pub use services::sql;
 pub use services::sync;
@@ -140,6 +140,7 @@ pub fn update(
                 settings.sync = Some(jira_db_core::SyncSettings {
                     incremental_sync_enabled: sync.incremental_sync_enabled,
                     incremental_sync_margin_minutes: sync.incremental_sync_margin_minutes as u32,
+                    ..settings.get_sync_settings()
                 });
             }
         })
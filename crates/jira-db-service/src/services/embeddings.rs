@@ -3,10 +3,12 @@
 use std::sync::Arc;
 
 use jira_db_core::{
-    DuckDbIssueRepository, EmbeddingGenerationConfig, EmbeddingProviderType, EmbeddingsRepository,
+    DateRange, DomainError, DuckDbIssueRepository, EmbeddingGenerationConfig,
+    EmbeddingProviderType, EmbeddingsRepository, FindSimilarIssuesUseCase,
     GenerateEmbeddingsUseCase, ProviderConfig, SearchIssuesUseCase, SearchParams, create_provider,
 };
 
+use super::issues::convert_issue;
 use crate::error::{ServiceError, ServiceResult};
 use crate::state::AppState;
 use crate::types::*;
@@ -86,7 +88,7 @@ pub async fn search(
     request: SemanticSearchRequest,
 ) -> ServiceResult<SemanticSearchResponse> {
     let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
-    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
+    let db = state.get_read_db().ok_or(ServiceError::NotInitialized)?;
 
     // Get embedding config from settings
     let embedding_config = settings.embeddings.as_ref().ok_or_else(|| {
@@ -136,14 +138,8 @@ pub async fn search(
         // Search for the issue by key
         let params = SearchParams {
             query: Some(sr.issue_key.clone()),
-            project_key: None,
-            status: None,
-            assignee: None,
-            issue_type: None,
-            priority: None,
-            team: None,
             limit: Some(1),
-            offset: None,
+            ..Default::default()
         };
 
         if let Ok(issues) = search_use_case.execute(params) {
@@ -175,3 +171,45 @@ pub async fn search(
 
     Ok(SemanticSearchResponse { results })
 }
+
+/// Issues similar to an issue, from its stored embedding ("more like this")
+pub fn similar(
+    state: &AppState,
+    request: EmbeddingsSimilarRequest,
+) -> ServiceResult<EmbeddingsSimilarResponse> {
+    let factory = state.get_db_factory().ok_or(ServiceError::NotInitialized)?;
+
+    let created = DateRange::parse(
+        request.created_from.as_deref(),
+        request.created_to.as_deref(),
+    )
+    .map_err(|e| ServiceError::InvalidRequest(e.to_string()))?;
+    let result = FindSimilarIssuesUseCase::connect(
+        &factory,
+        &request.issue_key,
+        request.same_project.unwrap_or(false),
+    )
+    .and_then(|use_case| {
+        use_case
+            .with_exclude_resolved(request.exclude_resolved.unwrap_or(false))
+            .with_created(created)
+            .execute(&request.issue_key, request.limit.unwrap_or(10) as usize)
+    })
+    .map_err(|e| match e {
+        DomainError::NotFound(message) => ServiceError::NotFound(message),
+        DomainError::Validation(message) => ServiceError::InvalidRequest(message),
+        other => ServiceError::Database(other.to_string()),
+    })?;
+
+    Ok(EmbeddingsSimilarResponse {
+        source: convert_issue(result.source),
+        results: result
+            .similar
+            .into_iter()
+            .map(|similar| SimilarIssueResult {
+                issue: convert_issue(similar.issue),
+                similarity: similar.similarity,
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,67 @@
+//! Export service
+
+use std::path::Path;
+
+use uuid::Uuid;
+
+use jira_db_core::{ExportDataUseCase, ExportFormat, ExportSource};
+
+use crate::error::{ServiceError, ServiceResult};
+use crate::state::AppState;
+use crate::types::*;
+
+/// Export a table or query result to `out`
+pub fn export_to_file(
+    state: &AppState,
+    request: ExportDataRequest,
+    out: &Path,
+) -> ServiceResult<ExportDataResponse> {
+    let format = request
+        .format
+        .parse::<ExportFormat>()
+        .map_err(|e| ServiceError::InvalidRequest(e.to_string()))?;
+    let source = match (request.table, request.query) {
+        (Some(table), None) => ExportSource::Table(table),
+        (None, Some(query)) => ExportSource::Query(query),
+        _ => {
+            return Err(ServiceError::InvalidRequest(
+                "Exactly one of table or query is required".to_string(),
+            ));
+        }
+    };
+
+    let db = if request.all_projects.unwrap_or(false) {
+        let factory = state.get_db_factory().ok_or(ServiceError::NotInitialized)?;
+        factory.get_all_projects_connection()?
+    } else {
+        state.get_read_db().ok_or(ServiceError::NotInitialized)?
+    };
+
+    let result = ExportDataUseCase::new(db).execute(&source, format, out)?;
+
+    Ok(ExportDataResponse {
+        path: result.path.to_string_lossy().to_string(),
+        format: result.format.to_string(),
+        row_count: result.row_count as i64,
+    })
+}
+
+/// Export to a fresh file in the system temp directory, for streaming to a client
+///
+/// The caller is responsible for removing the file; it is removed here if the export fails.
+pub fn export_to_temp_file(
+    state: &AppState,
+    request: ExportDataRequest,
+) -> ServiceResult<ExportDataResponse> {
+    let extension = request
+        .format
+        .parse::<ExportFormat>()
+        .map(|f| f.extension())
+        .map_err(|e| ServiceError::InvalidRequest(e.to_string()))?;
+    let out = std::env::temp_dir().join(format!("jira-db-export-{}.{}", Uuid::new_v4(), extension));
+    let result = export_to_file(state, request, &out);
+    if result.is_err() {
+        let _ = std::fs::remove_file(&out);
+    }
+    result
+}
@@ -3,8 +3,11 @@
 use std::sync::Arc;
 
 use jira_db_core::{
-    ChangeHistoryRepository, DuckDbChangeHistoryRepository, DuckDbIssueRepository,
-    SearchIssuesUseCase, SearchParams,
+    ChangeHistoryRepository, DateRange, DomainError, DuckDbChangeHistoryRepository,
+    DuckDbFieldRepository, DuckDbIssueRepository, DuckDbMetadataRepository, EmbeddingsRepository,
+    FacetField, FieldEdit, HybridSearchUseCase, IssueRepository, JiraApiClient, LabelMatch,
+    ProviderConfig, SearchFacet, SearchIssuesUseCase, SearchParams, SearchSort,
+    UpdateIssueFieldsUseCase, create_provider,
 };
 
 use crate::error::{ServiceError, ServiceResult};
@@ -12,7 +15,7 @@ use crate::state::AppState;
 use crate::types::*;
 
 /// Convert core Issue to API Issue type
-fn convert_issue(i: jira_db_core::Issue) -> Issue {
+pub(crate) fn convert_issue(i: jira_db_core::Issue) -> Issue {
     Issue {
         id: i.id,
         key: i.key.clone(),
@@ -33,58 +36,169 @@ fn convert_issue(i: jira_db_core::Issue) -> Issue {
     }
 }
 
-/// Search issues with filters
-pub fn search(state: &AppState, request: IssueSearchRequest) -> ServiceResult<IssueSearchResponse> {
-    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
+/// Search issues with filters.
+///
+/// Text queries fuse keyword and embedding rankings unless `hybrid` is false;
+/// without embeddings or a configured provider the keyword ranking is used alone.
+pub async fn search(
+    state: &AppState,
+    request: IssueSearchRequest,
+) -> ServiceResult<IssueSearchResponse> {
+    let db = state.get_read_db().ok_or(ServiceError::NotInitialized)?;
 
-    let issue_repo = Arc::new(DuckDbIssueRepository::new(db));
-    let use_case = SearchIssuesUseCase::new(issue_repo);
+    let issue_repo = Arc::new(DuckDbIssueRepository::new(db.clone()));
 
-    let params = SearchParams {
+    let hybrid = request.hybrid.unwrap_or(true);
+    let facet_fields: &[FacetField] = if request.facets.unwrap_or(false) {
+        &FacetField::ALL
+    } else {
+        &[]
+    };
+    let params = search_params(request)?;
+
+    let (issues, matches, total, facets): (Vec<_>, Vec<_>, usize, _) = if hybrid {
+        let settings = state.get_settings();
+        let provider = settings
+            .as_ref()
+            .and_then(|s| s.embeddings.as_ref())
+            .and_then(|config| {
+                ProviderConfig::from_settings(config)
+                    .and_then(create_provider)
+                    .map_err(|e| tracing::warn!("Hybrid search without embeddings: {}", e))
+                    .ok()
+            });
+        let use_case =
+            HybridSearchUseCase::new(issue_repo, Arc::new(EmbeddingsRepository::new(db)))
+                .with_settings(
+                    settings
+                        .map(|s| s.get_search_settings())
+                        .unwrap_or_default(),
+                )
+                .with_facets(facet_fields);
+
+        let result = use_case.execute(params, provider.as_deref()).await?;
+        let (issues, matches) = result
+            .hits
+            .into_iter()
+            .map(|hit| {
+                let search_match = IssueSearchMatch {
+                    key: hit.issue.key.clone(),
+                    score: Some(hit.score),
+                    lexical_rank: hit.lexical_rank.map(|r| r as i32),
+                    lexical_score: hit.lexical_score,
+                    semantic_rank: hit.semantic_rank.map(|r| r as i32),
+                    semantic_distance: hit.semantic_distance,
+                    snippet: hit.snippet,
+                };
+                (convert_issue(hit.issue), search_match)
+            })
+            .unzip();
+        (issues, matches, result.total, result.facets)
+    } else {
+        let use_case = SearchIssuesUseCase::new(issue_repo);
+        let total = use_case.count(&params)?;
+        let facets = use_case.facets(&params, facet_fields)?;
+        let (issues, matches) = use_case
+            .execute_ranked(params)?
+            .into_iter()
+            .map(|hit| {
+                let search_match = IssueSearchMatch {
+                    key: hit.issue.key.clone(),
+                    score: hit.score,
+                    lexical_rank: None,
+                    lexical_score: None,
+                    semantic_rank: None,
+                    semantic_distance: None,
+                    snippet: hit.snippet,
+                };
+                (convert_issue(hit.issue), search_match)
+            })
+            .unzip();
+        (issues, matches, total, facets)
+    };
+
+    Ok(IssueSearchResponse {
+        total: total as i32,
+        issues,
+        matches,
+        facets: (!facet_fields.is_empty()).then(|| facets.into_iter().map(convert_facet).collect()),
+    })
+}
+
+/// Convert core facet counts to the API type, keeping the most frequent values
+fn convert_facet(mut facet: SearchFacet) -> IssueSearchFacet {
+    facet.truncate(SearchFacet::DEFAULT_SIZE);
+    IssueSearchFacet {
+        field: facet.field.as_str().to_string(),
+        values: facet
+            .values
+            .into_iter()
+            .map(|v| IssueFacetValue {
+                value: v.value,
+                count: v.count as i32,
+            })
+            .collect(),
+    }
+}
+
+/// Convert the API request into core search params
+fn search_params(request: IssueSearchRequest) -> ServiceResult<SearchParams> {
+    let invalid = |e: DomainError| ServiceError::InvalidRequest(e.to_string());
+    let range = |from: Option<String>, to: Option<String>| {
+        DateRange::parse(from.as_deref(), to.as_deref()).map_err(invalid)
+    };
+
+    let sort = request
+        .sort
+        .unwrap_or_default()
+        .into_iter()
+        .map(|sort| {
+            let spec = match sort.descending {
+                Some(true) => format!("{}:desc", sort.field),
+                Some(false) => format!("{}:asc", sort.field),
+                None => sort.field,
+            };
+            spec.parse::<SearchSort>().map_err(invalid)
+        })
+        .collect::<ServiceResult<Vec<_>>>()?;
+
+    Ok(SearchParams {
         query: request.query,
         project_key: request.project,
         status: request.status,
+        status_category: request.status_category,
         assignee: request.assignee,
         issue_type: request.issue_type,
         priority: request.priority,
         team: request.team,
+        resolution: request.resolution,
+        labels: request.labels.unwrap_or_default(),
+        labels_match: match request.labels_match {
+            Some(labels_match) => labels_match.parse().map_err(invalid)?,
+            None => LabelMatch::default(),
+        },
+        components: request.components.unwrap_or_default(),
+        fix_versions: request.fix_versions.unwrap_or_default(),
+        sprint: request.sprint,
+        parent_key: request.parent_key,
+        keys: Vec::new(),
+        created: range(request.created_from, request.created_to)?,
+        updated: range(request.updated_from, request.updated_to)?,
+        due: range(request.due_from, request.due_to)?,
+        resolved: range(request.resolved_from, request.resolved_to)?,
+        sort,
         limit: request.limit.map(|l| l as usize),
         offset: request.offset.map(|o| o as usize),
-    };
-
-    let issues = use_case.execute(params)?;
-    let total = issues.len() as i32;
-
-    let issues = issues.into_iter().map(convert_issue).collect();
-
-    Ok(IssueSearchResponse { issues, total })
+    })
 }
 
 /// Get issue by key
 pub fn get(state: &AppState, request: IssueGetRequest) -> ServiceResult<IssueGetResponse> {
-    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
-
-    let issue_repo = Arc::new(DuckDbIssueRepository::new(db));
-    let use_case = SearchIssuesUseCase::new(issue_repo);
-
-    // Use search with the exact key as query
-    let params = SearchParams {
-        query: Some(request.key.clone()),
-        project_key: None,
-        status: None,
-        assignee: None,
-        issue_type: None,
-        priority: None,
-        team: None,
-        limit: Some(1),
-        offset: None,
-    };
-
-    let issues = use_case.execute(params)?;
+    let db = state.get_read_db().ok_or(ServiceError::NotInitialized)?;
 
-    let issue = issues
-        .into_iter()
-        .find(|i| i.key == request.key)
+    // Look the key up directly; a text search would rank other issues that mention it
+    let issue = DuckDbIssueRepository::new(db)
+        .find_by_key(&request.key)?
         .ok_or_else(|| ServiceError::NotFound("Issue not found".to_string()))?;
 
     Ok(IssueGetResponse {
@@ -97,7 +211,7 @@ pub fn history(
     state: &AppState,
     request: IssueHistoryRequest,
 ) -> ServiceResult<IssueHistoryResponse> {
-    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
+    let db = state.get_read_db().ok_or(ServiceError::NotInitialized)?;
 
     let history_repo = DuckDbChangeHistoryRepository::new(db);
 
@@ -124,8 +238,84 @@ pub fn history(
             to_value: h.to_value,
             to_string: h.to_string,
             changed_at: h.changed_at,
+            source: h.source,
         })
         .collect();
 
     Ok(IssueHistoryResponse { history })
 }
+
+/// Map edit errors so validation problems surface as invalid requests
+fn map_update_error(e: DomainError) -> ServiceError {
+    match e {
+        DomainError::Validation(msg) => ServiceError::InvalidRequest(msg),
+        DomainError::NotFound(msg) => ServiceError::NotFound(msg),
+        DomainError::ExternalService(msg) => ServiceError::JiraApi(msg),
+        other => other.into(),
+    }
+}
+
+/// Edit issue fields in JIRA and refresh the local row
+pub async fn update(
+    state: &AppState,
+    request: IssueUpdateRequest,
+) -> ServiceResult<IssueUpdateResponse> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
+
+    let project_key = request.key.split('-').next().unwrap_or("");
+    let jira_config = settings
+        .get_jira_config_for_project(project_key)
+        .ok_or_else(|| ServiceError::Config("JIRA endpoint not configured".to_string()))?;
+    let jira_client =
+        JiraApiClient::new(&jira_config).map_err(|e| ServiceError::JiraApi(e.to_string()))?;
+
+    let mut edits = Vec::new();
+    for v in request.set.unwrap_or_default() {
+        edits.push(FieldEdit::Set {
+            field: v.field,
+            value: v.value,
+        });
+    }
+    for v in request.add.unwrap_or_default() {
+        edits.push(FieldEdit::Add {
+            field: v.field,
+            value: v.value,
+        });
+    }
+    for v in request.remove.unwrap_or_default() {
+        edits.push(FieldEdit::Remove {
+            field: v.field,
+            value: v.value,
+        });
+    }
+
+    let use_case = UpdateIssueFieldsUseCase::new(
+        Arc::new(DuckDbIssueRepository::new(db.clone())),
+        Arc::new(DuckDbMetadataRepository::new(db.clone())),
+        Arc::new(DuckDbFieldRepository::new(db)),
+        Arc::new(jira_client),
+    );
+
+    let update = use_case
+        .prepare(&request.key, &edits)
+        .map_err(map_update_error)?;
+    let payload = update.to_request_body().to_string();
+
+    if request.dry_run.unwrap_or(false) {
+        return Ok(IssueUpdateResponse {
+            issue: None,
+            payload,
+        });
+    }
+
+    let issue = use_case
+        .execute(&request.key, &edits)
+        .await
+        .map_err(map_update_error)?;
+
+    Ok(IssueUpdateResponse {
+        issue: Some(convert_issue(issue)),
+        payload,
+    })
+}
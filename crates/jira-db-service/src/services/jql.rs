@@ -0,0 +1,61 @@
+//! JQL service
+
+use std::time::Instant;
+
+use jira_db_core::{DomainError, ExecuteJqlUseCase};
+
+use crate::error::{ServiceError, ServiceResult};
+use crate::state::AppState;
+use crate::types::*;
+
+/// Run a JQL query against the local database
+pub fn execute(state: &AppState, request: JqlExecuteRequest) -> ServiceResult<JqlExecuteResponse> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+
+    let (use_case, jira_config) = if request.all_projects.unwrap_or(false) {
+        let factory = state.get_db_factory().ok_or(ServiceError::NotInitialized)?;
+        (
+            ExecuteJqlUseCase::all_projects(&factory)?,
+            settings.get_jira_config(),
+        )
+    } else if let Some(project_key) = &request.project_key {
+        let factory = state.get_db_factory().ok_or(ServiceError::NotInitialized)?;
+        (
+            ExecuteJqlUseCase::new(factory.get_read_connection(project_key)?),
+            settings.get_jira_config_for_project(project_key),
+        )
+    } else {
+        let db = state.get_read_db().ok_or(ServiceError::NotInitialized)?;
+        (ExecuteJqlUseCase::new(db), settings.get_jira_config())
+    };
+    // currentUser() refers to the account the endpoint is configured with
+    let use_case = match jira_config {
+        Some(config) => use_case.with_current_user(config.username),
+        None => use_case,
+    };
+
+    let start = Instant::now();
+    let result = use_case
+        .execute(&request.query, request.limit.map(|l| l as usize))
+        .map_err(|e| match e {
+            DomainError::Validation(message) => ServiceError::InvalidRequest(message),
+            other => ServiceError::Database(other.to_string()),
+        })?;
+    let execution_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let columns = result.result.columns;
+    let rows = result
+        .result
+        .rows
+        .into_iter()
+        .map(|row| serde_json::Value::Object(columns.iter().cloned().zip(row).collect()))
+        .collect();
+
+    Ok(JqlExecuteResponse {
+        sql: result.sql,
+        columns,
+        rows,
+        row_count: result.result.row_count as i32,
+        execution_time_ms,
+    })
+}
@@ -11,7 +11,7 @@ use crate::types::*;
 /// Get project metadata
 pub fn get(state: &AppState, request: MetadataGetRequest) -> ServiceResult<MetadataGetResponse> {
     let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
-    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
+    let db = state.get_read_db().ok_or(ServiceError::NotInitialized)?;
 
     // Find project ID from key
     let project = settings
@@ -87,6 +87,18 @@ pub fn get(state: &AppState, request: MetadataGetRequest) -> ServiceResult<Metad
                     release_date: fv.release_date,
                 })
                 .collect(),
+            field_options: metadata
+                .field_options
+                .into_iter()
+                .map(|o| FieldOption {
+                    field_id: o.field_id,
+                    field_name: o.field_name,
+                    option_id: o.option_id,
+                    value: o.value,
+                    parent_option_id: o.parent_option_id,
+                    disabled: o.disabled,
+                })
+                .collect(),
         },
     })
 }
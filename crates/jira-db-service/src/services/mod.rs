@@ -4,9 +4,12 @@
 
 pub mod config;
 pub mod embeddings;
+pub mod export;
 pub mod issues;
+pub mod jql;
 pub mod metadata;
 pub mod projects;
 pub mod reports;
+pub mod saved;
 pub mod sql;
 pub mod sync;
@@ -143,6 +143,7 @@ pub async fn initialize(
                         endpoint: Some(endpoint_name.clone()),
                         sync_checkpoint: None,
                         snapshot_checkpoint: None,
+                        bulk_edit_checkpoint: None,
                     });
                 }
             }
@@ -0,0 +1,237 @@
+//! Saved search service
+//!
+//! Saved searches live in the settings file, so the CLI and MCP server see the same ones.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use jira_db_core::{
+    DomainError, ExecuteSqlUseCase, RunSavedSearchUseCase, SavedSearchFilter,
+    SavedSearchParameterType, SavedSearchQuery,
+};
+
+use crate::error::{ServiceError, ServiceResult};
+use crate::state::AppState;
+use crate::types::*;
+
+fn invalid(e: DomainError) -> ServiceError {
+    ServiceError::InvalidRequest(e.to_string())
+}
+
+/// Parameter values from a JSON object; non-string values use their JSON text
+fn parameter_values(params: Option<serde_json::Value>) -> ServiceResult<HashMap<String, String>> {
+    let object = match params {
+        None | Some(serde_json::Value::Null) => return Ok(HashMap::new()),
+        Some(serde_json::Value::Object(object)) => object,
+        Some(_) => {
+            return Err(ServiceError::InvalidRequest(
+                "params must be an object keyed by parameter name".to_string(),
+            ));
+        }
+    };
+
+    Ok(object
+        .into_iter()
+        .filter_map(|(name, value)| match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(text) => Some((name, text)),
+            other => Some((name, other.to_string())),
+        })
+        .collect())
+}
+
+/// List saved searches
+pub fn list(state: &AppState) -> ServiceResult<SavedSearchListResponse> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+
+    Ok(SavedSearchListResponse {
+        searches: settings
+            .saved_searches
+            .into_iter()
+            .map(convert_saved_search)
+            .collect(),
+    })
+}
+
+/// Create or replace a saved search
+pub fn save(
+    state: &AppState,
+    request: SavedSearchSaveRequest,
+) -> ServiceResult<SavedSearchSaveResponse> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+
+    let query = match request.search_type.as_str() {
+        "sql" => {
+            let sql = request.query.ok_or_else(|| {
+                ServiceError::InvalidRequest("query is required for SQL searches".to_string())
+            })?;
+            ExecuteSqlUseCase::validate_read_only(&sql).map_err(invalid)?;
+            SavedSearchQuery::Sql { sql }
+        }
+        "jql" => SavedSearchQuery::Jql {
+            jql: request.query.ok_or_else(|| {
+                ServiceError::InvalidRequest("query is required for JQL searches".to_string())
+            })?,
+        },
+        "filter" => {
+            let filter = request.filter.ok_or_else(|| {
+                ServiceError::InvalidRequest("filter is required for filter searches".to_string())
+            })?;
+            SavedSearchQuery::Filter {
+                filter: serde_json::from_value::<SavedSearchFilter>(filter)
+                    .map_err(|e| ServiceError::InvalidRequest(format!("Invalid filter: {}", e)))?,
+            }
+        }
+        other => {
+            return Err(ServiceError::InvalidRequest(format!(
+                "Invalid saved search type '{}': expected sql, jql or filter",
+                other
+            )));
+        }
+    };
+
+    let mut search = jira_db_core::SavedSearch::new(&request.name, query);
+    search.description = request.description;
+    search.project = request.project;
+    search.owner = request
+        .owner
+        .or_else(|| settings.get_jira_config().map(|config| config.username));
+    search.parameters = request
+        .parameters
+        .unwrap_or_default()
+        .into_iter()
+        .map(|param| {
+            Ok(jira_db_core::SavedSearchParameter {
+                param_type: param.param_type.parse::<SavedSearchParameterType>()?,
+                name: param.name,
+                description: param.description,
+                required: param.required,
+                default: param.default,
+            })
+        })
+        .collect::<Result<_, DomainError>>()
+        .map_err(invalid)?;
+    search.validate().map_err(invalid)?;
+
+    let mut result = Ok(());
+    let updated = state
+        .update_settings(|settings| result = settings.upsert_saved_search(search))
+        .map_err(|e| ServiceError::Config(e.to_string()))?;
+    result.map_err(invalid)?;
+
+    let search = updated
+        .find_saved_search(&request.name)
+        .cloned()
+        .ok_or_else(|| ServiceError::Internal("Saved search was not stored".to_string()))?;
+    Ok(SavedSearchSaveResponse {
+        search: convert_saved_search(search),
+    })
+}
+
+/// Delete a saved search
+pub fn delete(
+    state: &AppState,
+    request: SavedSearchDeleteRequest,
+) -> ServiceResult<SavedSearchDeleteResponse> {
+    let mut removed = false;
+    state
+        .update_settings(|settings| removed = settings.remove_saved_search(&request.name))
+        .map_err(|e| ServiceError::Config(e.to_string()))?;
+
+    if !removed {
+        return Err(ServiceError::NotFound(format!(
+            "Saved search not found: {}",
+            request.name
+        )));
+    }
+    Ok(SavedSearchDeleteResponse { success: true })
+}
+
+/// Run a saved search with parameter values
+pub fn run(
+    state: &AppState,
+    request: SavedSearchRunRequest,
+) -> ServiceResult<SavedSearchRunResponse> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+    let factory = state.get_db_factory().ok_or(ServiceError::NotInitialized)?;
+
+    let search = settings
+        .find_saved_search(&request.name)
+        .cloned()
+        .ok_or_else(|| {
+            ServiceError::NotFound(format!("Saved search not found: {}", request.name))
+        })?;
+
+    let args = parameter_values(request.params)?;
+    let project = request.project.or_else(|| search.project.clone());
+    let jira_config = match &project {
+        Some(project_key) => settings.get_jira_config_for_project(project_key),
+        None => settings.get_jira_config(),
+    };
+    let use_case = RunSavedSearchUseCase::connect(&factory, &search, project.as_deref())?;
+    // currentUser() refers to the account the endpoint is configured with
+    let use_case = match jira_config {
+        Some(config) => use_case.with_current_user(config.username),
+        None => use_case,
+    };
+
+    let start = Instant::now();
+    let result = use_case
+        .execute(&search, &args, request.limit.map(|l| l as usize))
+        .map_err(|e| match e {
+            DomainError::Validation(message) => ServiceError::InvalidRequest(message),
+            other => ServiceError::Database(other.to_string()),
+        })?;
+    let execution_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let columns = result.result.columns;
+    let rows = result
+        .result
+        .rows
+        .into_iter()
+        .map(|row| serde_json::Value::Object(columns.iter().cloned().zip(row).collect()))
+        .collect();
+
+    Ok(SavedSearchRunResponse {
+        name: result.name,
+        search_type: result.kind,
+        sql: result.sql,
+        total: result.total.map(|t| t as i32),
+        columns,
+        rows,
+        row_count: result.result.row_count as i32,
+        execution_time_ms,
+    })
+}
+
+fn convert_saved_search(search: jira_db_core::SavedSearch) -> SavedSearch {
+    let search_type = search.query.kind().to_string();
+    let (query, filter) = match search.query {
+        SavedSearchQuery::Sql { sql } => (Some(sql), None),
+        SavedSearchQuery::Jql { jql } => (Some(jql), None),
+        SavedSearchQuery::Filter { filter } => (None, serde_json::to_value(filter).ok()),
+    };
+
+    SavedSearch {
+        name: search.name,
+        description: search.description,
+        owner: search.owner,
+        project: search.project,
+        search_type,
+        query,
+        filter,
+        parameters: search
+            .parameters
+            .into_iter()
+            .map(|param| SavedSearchParameter {
+                name: param.name,
+                param_type: param.param_type.as_str().to_string(),
+                description: param.description,
+                required: param.required,
+                default: param.default,
+            })
+            .collect(),
+        created_at: search.created_at,
+        updated_at: search.updated_at,
+    }
+}
@@ -1,23 +1,23 @@
 //! SQL service
 
-use std::fs;
-use std::path::PathBuf;
 use std::time::Instant;
 
 use chrono::Utc;
-use uuid::Uuid;
 
-use jira_db_core::ExecuteSqlUseCase;
+use jira_db_core::{ExecuteSqlUseCase, SavedSearchQuery};
 
 use crate::error::{ServiceError, ServiceResult};
 use crate::state::AppState;
 use crate::types::*;
 
-const SAVED_QUERIES_FILE: &str = "./data/saved_queries.json";
-
 /// Execute a SQL query (read-only)
 pub fn execute(state: &AppState, request: SqlExecuteRequest) -> ServiceResult<SqlExecuteResponse> {
-    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
+    let db = if request.all_projects.unwrap_or(false) {
+        let factory = state.get_db_factory().ok_or(ServiceError::NotInitialized)?;
+        factory.get_all_projects_connection()?
+    } else {
+        state.get_read_db().ok_or(ServiceError::NotInitialized)?
+    };
 
     let start = Instant::now();
 
@@ -60,7 +60,7 @@ pub fn get_schema(
     state: &AppState,
     request: SqlGetSchemaRequest,
 ) -> ServiceResult<SqlGetSchemaResponse> {
-    let db = state.get_db().ok_or(ServiceError::NotInitialized)?;
+    let db = state.get_read_db().ok_or(ServiceError::NotInitialized)?;
 
     let conn = db
         .lock()
@@ -118,94 +118,115 @@ pub fn get_schema(
     }
 }
 
-/// Load saved queries from file
-fn load_saved_queries() -> Vec<SavedQuery> {
-    let path = PathBuf::from(SAVED_QUERIES_FILE);
-    if path.exists() {
-        fs::read_to_string(&path)
-            .ok()
-            .and_then(|content| serde_json::from_str(&content).ok())
-            .unwrap_or_default()
-    } else {
-        Vec::new()
+/// A SQL saved search as a query of the SQL screen, identified by its name
+fn convert_saved_query(search: jira_db_core::SavedSearch) -> Option<SavedQuery> {
+    match search.query {
+        SavedSearchQuery::Sql { sql } => Some(SavedQuery {
+            id: search.name.clone(),
+            name: search.name,
+            query: sql,
+            description: search.description,
+            created_at: search.created_at,
+            updated_at: search.updated_at,
+        }),
+        _ => None,
     }
 }
 
-/// Save queries to file
-fn save_queries_to_file(queries: &[SavedQuery]) -> ServiceResult<()> {
-    let path = PathBuf::from(SAVED_QUERIES_FILE);
-
-    // Ensure parent directory exists
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| ServiceError::Io(format!("Failed to create data directory: {}", e)))?;
-    }
-
-    let json = serde_json::to_string_pretty(queries)
-        .map_err(|e| ServiceError::Internal(format!("Failed to serialize queries: {}", e)))?;
-    fs::write(&path, json)
-        .map_err(|e| ServiceError::Io(format!("Failed to save queries: {}", e)))?;
-    Ok(())
+/// Find the SQL saved search a query id refers to
+fn find_saved_query(state: &AppState, id: &str) -> ServiceResult<jira_db_core::SavedSearch> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+    settings
+        .find_saved_search(id)
+        .filter(|search| matches!(search.query, SavedSearchQuery::Sql { .. }))
+        .cloned()
+        .ok_or_else(|| ServiceError::NotFound(format!("Query with id {} not found", id)))
 }
 
-/// List saved queries
-pub fn query_list(_request: SqlQueryListRequest) -> ServiceResult<SqlQueryListResponse> {
-    let queries = load_saved_queries();
-    Ok(SqlQueryListResponse { queries })
+/// List saved queries (the SQL saved searches)
+pub fn query_list(
+    state: &AppState,
+    _request: SqlQueryListRequest,
+) -> ServiceResult<SqlQueryListResponse> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+
+    Ok(SqlQueryListResponse {
+        queries: settings
+            .saved_searches
+            .into_iter()
+            .filter_map(convert_saved_query)
+            .collect(),
+    })
 }
 
-/// Save a query
-pub fn query_save(request: SqlQuerySaveRequest) -> ServiceResult<SqlQuerySaveResponse> {
-    let mut queries = load_saved_queries();
-    let now = Utc::now();
-
-    let query = if let Some(id) = request.id {
-        // Update existing query
-        if let Some(existing) = queries.iter_mut().find(|q| q.id == id) {
-            existing.name = request.name;
-            existing.query = request.query;
-            existing.description = request.description;
-            existing.updated_at = now;
-            existing.clone()
-        } else {
-            return Err(ServiceError::NotFound(format!(
-                "Query with id {} not found",
-                id
-            )));
+/// Save a query as a SQL saved search
+///
+/// The name becomes the saved search name. Updating a query keeps its parameters,
+/// and a new name moves it.
+pub fn query_save(
+    state: &AppState,
+    request: SqlQuerySaveRequest,
+) -> ServiceResult<SqlQuerySaveResponse> {
+    let settings = state.get_settings().ok_or(ServiceError::NotInitialized)?;
+    ExecuteSqlUseCase::validate_read_only(&request.query)
+        .map_err(|e| ServiceError::InvalidRequest(e.to_string()))?;
+
+    let name = jira_db_core::SavedSearch::name_from_title(&request.name);
+    let mut search = match &request.id {
+        Some(id) => find_saved_query(state, id)?,
+        None => {
+            let mut search =
+                jira_db_core::SavedSearch::new(&name, SavedSearchQuery::Sql { sql: String::new() });
+            search.owner = settings.get_jira_config().map(|config| config.username);
+            search
         }
-    } else {
-        // Create new query
-        let new_query = SavedQuery {
-            id: Uuid::new_v4().to_string(),
-            name: request.name,
-            query: request.query,
-            description: request.description,
-            created_at: now,
-            updated_at: now,
-        };
-        queries.push(new_query.clone());
-        new_query
     };
+    if request.id.as_deref() != Some(name.as_str()) && settings.find_saved_search(&name).is_some() {
+        return Err(ServiceError::InvalidRequest(format!(
+            "A saved search named '{}' already exists",
+            name
+        )));
+    }
 
-    save_queries_to_file(&queries)?;
-
+    search.name = name.clone();
+    search.query = SavedSearchQuery::Sql { sql: request.query };
+    search.description = request.description;
+    search.updated_at = Utc::now();
+    search
+        .validate()
+        .map_err(|e| ServiceError::InvalidRequest(e.to_string()))?;
+
+    let renamed = request.id.as_deref().filter(|id| *id != name);
+    let mut result = Ok(());
+    let updated = state
+        .update_settings(|settings| {
+            result = settings.upsert_saved_search(search);
+            if let (Ok(()), Some(previous)) = (&result, renamed) {
+                settings.remove_saved_search(previous);
+            }
+        })
+        .map_err(|e| ServiceError::Config(e.to_string()))?;
+    result.map_err(|e| ServiceError::InvalidRequest(e.to_string()))?;
+
+    let query = updated
+        .find_saved_search(&name)
+        .cloned()
+        .and_then(convert_saved_query)
+        .ok_or_else(|| ServiceError::Internal("Saved query was not stored".to_string()))?;
     Ok(SqlQuerySaveResponse { query })
 }
 
 /// Delete a saved query
-pub fn query_delete(request: SqlQueryDeleteRequest) -> ServiceResult<SqlQueryDeleteResponse> {
-    let mut queries = load_saved_queries();
-    let initial_len = queries.len();
-    queries.retain(|q| q.id != request.id);
-
-    if queries.len() == initial_len {
-        return Err(ServiceError::NotFound(format!(
-            "Query with id {} not found",
-            request.id
-        )));
-    }
-
-    save_queries_to_file(&queries)?;
+pub fn query_delete(
+    state: &AppState,
+    request: SqlQueryDeleteRequest,
+) -> ServiceResult<SqlQueryDeleteResponse> {
+    find_saved_query(state, &request.id)?;
+    state
+        .update_settings(|settings| {
+            settings.remove_saved_search(&request.id);
+        })
+        .map_err(|e| ServiceError::Config(e.to_string()))?;
 
     Ok(SqlQueryDeleteResponse { success: true })
 }
@@ -6,8 +6,8 @@ use std::sync::Arc;
 use jira_db_core::{
     DuckDbChangeHistoryRepository, DuckDbFieldRepository, DuckDbIssueRepository,
     DuckDbIssueSnapshotRepository, DuckDbIssuesExpandedRepository, DuckDbMetadataRepository,
-    DuckDbSyncHistoryRepository, JiraApiClient, JiraConfig, Settings, SyncCheckpoint,
-    SyncFieldsUseCase, SyncProjectUseCase,
+    DuckDbSyncHistoryRepository, FullTextSearchRepository, JiraApiClient, JiraConfig, Settings,
+    SyncCheckpoint, SyncFieldsUseCase, SyncProjectUseCase,
 };
 
 use crate::error::{ServiceError, ServiceResult};
@@ -42,6 +42,7 @@ pub async fn execute(
 
     // Create repositories for fields expansion
     let field_repo = Arc::new(DuckDbFieldRepository::new(db.clone()));
+    let search_repo = FullTextSearchRepository::new(db.clone());
     let expanded_repo = Arc::new(DuckDbIssuesExpandedRepository::new(db));
 
     // Get projects to sync with their endpoint information
@@ -55,7 +56,7 @@ pub async fn execute(
                 key: p.key.clone(),
                 id: p.id.clone(),
                 checkpoint: p.sync_checkpoint.clone(),
-                endpoint_name: p.endpoint.clone(),
+                endpoint_name: settings.endpoint_name_for_project(p),
             })
             .collect()
     } else {
@@ -67,7 +68,7 @@ pub async fn execute(
                 key: p.key.clone(),
                 id: p.id.clone(),
                 checkpoint: p.sync_checkpoint.clone(),
-                endpoint_name: p.endpoint.clone(),
+                endpoint_name: settings.endpoint_name_for_project(p),
             })
             .collect()
     };
@@ -78,7 +79,7 @@ pub async fn execute(
         ));
     }
 
-    // Group projects by endpoint
+    // Group projects by endpoint (projects without one use the active endpoint)
     let mut projects_by_endpoint: HashMap<Option<String>, Vec<&ProjectSyncInfo>> = HashMap::new();
     for project in &projects_to_sync {
         projects_by_endpoint
@@ -119,6 +120,7 @@ pub async fn execute(
                 for project in projects {
                     results.push(SyncResult {
                         project_key: project.key.clone(),
+                        endpoint: endpoint_name.clone(),
                         issue_count: 0,
                         metadata_updated: false,
                         duration: 0.0,
@@ -146,6 +148,7 @@ pub async fn execute(
                 for project in projects {
                     results.push(SyncResult {
                         project_key: project.key.clone(),
+                        endpoint: endpoint_name.clone(),
                         issue_count: 0,
                         metadata_updated: false,
                         duration: 0.0,
@@ -271,6 +274,7 @@ pub async fn execute(
 
                     results.push(SyncResult {
                         project_key: sync_result.project_key,
+                        endpoint: endpoint_name.clone(),
                         issue_count: sync_result.issues_synced as i32,
                         metadata_updated: true,
                         duration,
@@ -281,6 +285,7 @@ pub async fn execute(
                 Err(e) => {
                     results.push(SyncResult {
                         project_key: project.key.clone(),
+                        endpoint: endpoint_name.clone(),
                         issue_count: 0,
                         metadata_updated: false,
                         duration,
@@ -309,6 +314,14 @@ pub async fn execute(
         })
         .map_err(|e| ServiceError::Config(e.to_string()))?;
 
+    // Refresh the full-text search index over the synced issues
+    if results.iter().any(|r| r.success) {
+        match search_repo.rebuild(&settings.get_sync_settings().search_fields) {
+            Ok(count) => tracing::info!("Indexed {} issues for search", count),
+            Err(e) => tracing::warn!("Failed to refresh search index: {}", e),
+        }
+    }
+
     Ok(SyncExecuteResponse { results })
 }
 
@@ -3,9 +3,12 @@
 //! Provides a shared state container for settings and database connection.
 
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-use jira_db_core::{Database, DbConnection, Settings, checkpoint_connection};
+use jira_db_core::{
+    ConnectionPool, Database, DatabaseAccess, DatabaseFactory, DbConnection, Settings,
+    checkpoint_connection,
+};
 
 /// Default database filename
 const DEFAULT_DB_FILENAME: &str = "jira.duckdb";
@@ -16,8 +19,12 @@ pub struct AppState {
     pub settings_path: Mutex<Option<PathBuf>>,
     /// Loaded settings
     pub settings: Mutex<Option<Settings>>,
-    /// Database connection
+    /// Database connection (the single writer, used by sync)
     pub db: Mutex<Option<DbConnection>>,
+    /// Reader connections cloned from `db`, so queries do not wait on each other
+    pub read_pool: Mutex<Option<Arc<ConnectionPool>>>,
+    /// Per-project database factory (cross-project queries)
+    pub db_factory: Mutex<Option<Arc<DatabaseFactory>>>,
 }
 
 impl Default for AppState {
@@ -26,6 +33,8 @@ impl Default for AppState {
             settings_path: Mutex::new(None),
             settings: Mutex::new(None),
             db: Mutex::new(None),
+            read_pool: Mutex::new(None),
+            db_factory: Mutex::new(None),
         }
     }
 }
@@ -46,6 +55,19 @@ fn ensure_db_file_path(path: PathBuf) -> PathBuf {
     path
 }
 
+/// Factory for cross-project queries over the per-project databases
+///
+/// With `database.read_replicas` the replicas published by `jira-db sync` are read, so the
+/// server never holds the lock a sync in another process needs.
+fn query_factory(settings: &Settings) -> DatabaseFactory {
+    let access = if settings.database.read_replicas {
+        DatabaseAccess::ReadReplica
+    } else {
+        DatabaseAccess::ReadWrite
+    };
+    DatabaseFactory::new(settings).with_access(access)
+}
+
 impl AppState {
     /// Create a new AppState
     pub fn new() -> Self {
@@ -55,7 +77,12 @@ impl AppState {
     /// Initialize the application state with a settings file
     pub fn initialize(&self, settings_path: PathBuf) -> anyhow::Result<()> {
         // Load and resolve paths relative to settings file location
-        let settings = Settings::load_and_resolve(&settings_path)?;
+        let mut settings = Settings::load_and_resolve(&settings_path)?;
+
+        // The SQL screen used to keep its queries in a separate file
+        if let Err(e) = settings.migrate_legacy_saved_queries(&settings_path) {
+            tracing::warn!("Failed to migrate saved queries: {}", e);
+        }
 
         tracing::info!(
             "Loaded settings, database_dir: {:?}",
@@ -72,7 +99,10 @@ impl AppState {
 
         // Store state
         *self.settings_path.lock().unwrap() = Some(settings_path);
+        let read_pool = ConnectionPool::new(db.connection(), settings.database.read_pool_size)?;
         *self.db.lock().unwrap() = Some(db.connection());
+        *self.read_pool.lock().unwrap() = Some(Arc::new(read_pool));
+        *self.db_factory.lock().unwrap() = Some(Arc::new(query_factory(&settings)));
         *self.settings.lock().unwrap() = Some(settings);
 
         Ok(())
@@ -110,7 +140,10 @@ impl AppState {
 
         // Store state
         *self.settings_path.lock().unwrap() = Some(settings_path);
+        let read_pool = ConnectionPool::new(db.connection(), settings.database.read_pool_size)?;
         *self.db.lock().unwrap() = Some(db.connection());
+        *self.read_pool.lock().unwrap() = Some(Arc::new(read_pool));
+        *self.db_factory.lock().unwrap() = Some(Arc::new(query_factory(&settings)));
         *self.settings.lock().unwrap() = Some(settings);
 
         Ok(())
@@ -156,6 +189,19 @@ impl AppState {
         self.db.lock().unwrap().clone()
     }
 
+    /// Get a reader connection for queries (falls back to the writer)
+    pub fn get_read_db(&self) -> Option<DbConnection> {
+        match *self.read_pool.lock().unwrap() {
+            Some(ref pool) => Some(pool.reader()),
+            None => self.get_db(),
+        }
+    }
+
+    /// Get the per-project database factory
+    pub fn get_db_factory(&self) -> Option<Arc<DatabaseFactory>> {
+        self.db_factory.lock().unwrap().clone()
+    }
+
     /// Check if initialized
     #[allow(dead_code)]
     pub fn is_initialized(&self) -> bool {
@@ -175,5 +221,12 @@ impl AppState {
                 }
             }
         }
+        let closed = match *self.db_factory.lock().unwrap() {
+            Some(ref factory) => factory.close_all(),
+            None => Ok(()),
+        };
+        if let Err(e) = closed {
+            tracing::warn!("Failed to close project databases: {}", e);
+        }
     }
 }
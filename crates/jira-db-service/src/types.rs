@@ -65,6 +65,7 @@ pub struct ChangeHistoryItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to_string: Option<String>,
     pub changed_at: DateTime<Utc>,
+    pub source: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -124,6 +125,20 @@ pub struct FixVersion {
     pub release_date: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldOption {
+    pub field_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_name: Option<String>,
+    pub option_id: String,
+    pub value: String,
+    /// Parent option ID (child options of cascading select fields)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_option_id: Option<String>,
+    pub disabled: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectMetadata {
@@ -134,12 +149,16 @@ pub struct ProjectMetadata {
     pub labels: Vec<Label>,
     pub components: Vec<Component>,
     pub fix_versions: Vec<FixVersion>,
+    pub field_options: Vec<FieldOption>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncResult {
     pub project_key: String,
+    /// Name of the JIRA endpoint the project was synced from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
     pub issue_count: i32,
     pub metadata_updated: bool,
     pub duration: f64,
@@ -173,6 +192,13 @@ pub struct SemanticSearchResult {
     pub score: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarIssueResult {
+    pub issue: Issue,
+    pub similarity: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportResult {
@@ -270,6 +296,50 @@ pub struct ProjectConfig {
     pub endpoint: Option<String>,
 }
 
+// ============================================================
+// Saved Search Types
+// ============================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchParameter {
+    pub name: String,
+    /// "string", "number", "date" or "boolean"
+    #[serde(rename = "type")]
+    pub param_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub required: bool,
+    /// Used when no value is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearch {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Project to run against; all projects when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// "sql", "jql" or "filter"
+    #[serde(rename = "type")]
+    pub search_type: String,
+    /// SQL or JQL text with {{param}} placeholders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Structured filters, with the same snake_case keys as `jira-db saved add --filter`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    pub parameters: Vec<SavedSearchParameter>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 // ============================================================
 // SQL Types
 // ============================================================
@@ -480,9 +550,73 @@ pub struct IssueSearchRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels_match: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_versions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Vec<IssueSearchSort>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hybrid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchSort {
+    pub field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descending: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchMatch {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lexical_rank: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lexical_score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_rank: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_distance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -490,6 +624,24 @@ pub struct IssueSearchRequest {
 pub struct IssueSearchResponse {
     pub issues: Vec<Issue>,
     pub total: i32,
+    pub matches: Vec<IssueSearchMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<IssueSearchFacet>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchFacet {
+    pub field: String,
+    pub values: Vec<IssueFacetValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueFacetValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    pub count: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -520,6 +672,35 @@ pub struct IssueHistoryResponse {
     pub history: Vec<ChangeHistoryItem>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldValue {
+    pub field: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUpdateRequest {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set: Option<Vec<FieldValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add: Option<Vec<FieldValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<Vec<FieldValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUpdateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Issue>,
+    pub payload: String,
+}
+
 // -- Metadata --
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -574,6 +755,29 @@ pub struct SemanticSearchResponse {
     pub results: Vec<SemanticSearchResult>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddingsSimilarRequest {
+    pub issue_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub same_project: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_resolved: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddingsSimilarResponse {
+    pub source: Issue,
+    pub results: Vec<SimilarIssueResult>,
+}
+
 // -- Reports --
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -593,11 +797,41 @@ pub struct ReportGenerateResponse {
     pub result: ReportResult,
 }
 
+// -- JQL --
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlExecuteRequest {
+    pub query: String,
+    /// Project database to query (defaults to the current one)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_key: Option<String>,
+    /// Query across all synced projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlExecuteResponse {
+    /// SQL the query was compiled to
+    pub sql: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<serde_json::Value>,
+    pub row_count: i32,
+    pub execution_time_ms: f64,
+}
+
 // -- SQL --
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SqlExecuteRequest {
+    /// Query across all synced projects (all_issues, all_change_history, ... views)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_projects: Option<bool>,
     pub query: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
@@ -663,3 +897,115 @@ pub struct SqlQueryDeleteRequest {
 pub struct SqlQueryDeleteResponse {
     pub success: bool,
 }
+
+// -- Saved Searches --
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchListRequest {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchListResponse {
+    pub searches: Vec<SavedSearch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchSaveRequest {
+    pub name: String,
+    /// "sql", "jql" or "filter"
+    #[serde(rename = "type")]
+    pub search_type: String,
+    /// SQL or JQL text (for "sql" and "jql")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Structured filters (for "filter")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Defaults to the JIRA username of the active endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<SavedSearchParameter>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchSaveResponse {
+    pub search: SavedSearch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchDeleteRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchDeleteResponse {
+    pub success: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchRunRequest {
+    pub name: String,
+    /// Parameter values keyed by parameter name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+    /// Project to run against (overrides the saved project)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchRunResponse {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub search_type: String,
+    /// SQL that was executed (for "sql" and "jql")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql: Option<String>,
+    /// Matching issues across all pages (for "filter")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i32>,
+    pub columns: Vec<String>,
+    pub rows: Vec<serde_json::Value>,
+    pub row_count: i32,
+    pub execution_time_ms: f64,
+}
+
+// -- Export --
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDataRequest {
+    /// Export from the per-project databases of all synced projects (adds projectKey column)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_projects: Option<bool>,
+    /// Table or view to export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<String>,
+    /// SELECT query whose result is exported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// parquet, csv, jsonl or xlsx
+    pub format: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDataResponse {
+    pub path: String,
+    pub format: String,
+    pub row_count: i64,
+}
@@ -4,10 +4,12 @@ use std::sync::Arc;
 use tauri::State;
 
 use jira_db_core::{
-    DuckDbIssueRepository, EmbeddingGenerationConfig, EmbeddingProviderType, EmbeddingsRepository,
-    GenerateEmbeddingsUseCase, ProviderConfig, SearchIssuesUseCase, SearchParams, create_provider,
+    DateRange, DuckDbIssueRepository, EmbeddingGenerationConfig, EmbeddingProviderType,
+    EmbeddingsRepository, FindSimilarIssuesUseCase, GenerateEmbeddingsUseCase, ProviderConfig,
+    SearchIssuesUseCase, SearchParams, create_provider,
 };
 
+use super::issues::convert_issue;
 use crate::generated::*;
 use crate::state::AppState;
 
@@ -155,14 +157,8 @@ pub async fn embeddings_search(
         // Search for the issue by key
         let params = SearchParams {
             query: Some(sr.issue_key.clone()),
-            project_key: None,
-            status: None,
-            assignee: None,
-            issue_type: None,
-            priority: None,
-            team: None,
             limit: Some(1),
-            offset: None,
+            ..Default::default()
         };
 
         if let Ok(issues) = search_use_case.execute(params) {
@@ -196,3 +192,42 @@ pub async fn embeddings_search(
 
     Ok(SemanticSearchResponse { results })
 }
+
+/// Find issues similar to an issue from its stored embedding ("more like this")
+#[tauri::command]
+pub async fn embeddings_similar(
+    state: State<'_, AppState>,
+    request: EmbeddingsSimilarRequest,
+) -> Result<EmbeddingsSimilarResponse, String> {
+    let factory = state
+        .get_db_factory()
+        .ok_or_else(|| "Database factory not initialized".to_string())?;
+
+    let created = DateRange::parse(
+        request.created_from.as_deref(),
+        request.created_to.as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
+    let result = FindSimilarIssuesUseCase::connect(
+        &factory,
+        &request.issue_key,
+        request.same_project.unwrap_or(false),
+    )
+    .map_err(|e| e.to_string())?
+    .with_exclude_resolved(request.exclude_resolved.unwrap_or(false))
+    .with_created(created)
+    .execute(&request.issue_key, request.limit.unwrap_or(10) as usize)
+    .map_err(|e| e.to_string())?;
+
+    Ok(EmbeddingsSimilarResponse {
+        source: convert_issue(result.source),
+        results: result
+            .similar
+            .into_iter()
+            .map(|similar| SimilarIssueResult {
+                issue: convert_issue(similar.issue),
+                similarity: similar.similarity,
+            })
+            .collect(),
+    })
+}
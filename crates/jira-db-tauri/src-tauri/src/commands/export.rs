@@ -0,0 +1,60 @@
+//! Export command handlers
+
+use std::path::PathBuf;
+
+use tauri::State;
+
+use jira_db_core::{ExportDataUseCase, ExportFormat, ExportSource};
+
+use crate::generated::*;
+use crate::state::AppState;
+
+/// Export a table or query result to Parquet, CSV, JSON Lines or Excel
+#[tauri::command]
+pub async fn export_data(
+    state: State<'_, AppState>,
+    request: ExportDataRequest,
+) -> Result<ExportDataResponse, String> {
+    let format: ExportFormat = request.format.parse().map_err(|e| format!("{}", e))?;
+    let source = match (request.table, request.query) {
+        (Some(table), None) => ExportSource::Table(table),
+        (None, Some(query)) => ExportSource::Query(query),
+        _ => return Err("Exactly one of table or query is required".to_string()),
+    };
+    let out = request
+        .output_path
+        .map(PathBuf::from)
+        .ok_or_else(|| "outputPath is required".to_string())?;
+
+    let db = if request.all_projects.unwrap_or(false) {
+        let settings = state
+            .get_settings()
+            .ok_or_else(|| "Settings not initialized".to_string())?;
+        let factory = state
+            .get_db_factory()
+            .ok_or_else(|| "Database factory not initialized".to_string())?;
+        let projects: Vec<String> = settings
+            .sync_enabled_projects()
+            .iter()
+            .map(|p| p.key.clone())
+            .collect();
+        factory
+            .get_federated_connection(&projects)
+            .map_err(|e| e.to_string())?
+    } else {
+        let project_key = request.project_key.as_deref().unwrap_or("");
+        state
+            .get_db(project_key)
+            .ok_or_else(|| format!("Database not initialized for project {}", project_key))?
+    };
+
+    let result = ExportDataUseCase::new(db)
+        .execute(&source, format, &out)
+        .map_err(|e| e.to_string())?;
+
+    Ok(ExportDataResponse {
+        path: result.path.to_string_lossy().to_string(),
+        format: result.format.to_string(),
+        row_count: result.row_count as i64,
+    })
+}
@@ -4,8 +4,12 @@ use std::sync::Arc;
 use tauri::State;
 
 use jira_db_core::{
-    ChangeHistoryRepository, DuckDbChangeHistoryRepository, DuckDbIssueRepository,
-    SearchIssuesUseCase, SearchParams,
+    ChangeHistoryRepository, DateRange, DomainError, DuckDbChangeHistoryRepository,
+    DuckDbFieldRepository, DuckDbIssueRepository, DuckDbMetadataRepository, EmbeddingProvider,
+    EmbeddingsRepository, FacetField, FieldEdit, HybridSearchHit, HybridSearchUseCase,
+    IssueRepository, IssueSearchHit, JiraApiClient, LabelMatch, ProviderConfig, SearchFacet,
+    SearchIssuesUseCase, SearchParams, SearchSort, UpdateIssueFieldsUseCase, create_provider,
+    merge_facets,
 };
 
 use crate::generated::*;
@@ -17,7 +21,7 @@ fn extract_project_key(issue_key: &str) -> Option<&str> {
 }
 
 /// Convert core Issue to generated Issue type
-fn convert_issue(i: jira_db_core::Issue) -> Issue {
+pub(crate) fn convert_issue(i: jira_db_core::Issue) -> Issue {
     Issue {
         id: i.id,
         key: i.key.clone(),
@@ -41,6 +45,136 @@ fn convert_issue(i: jira_db_core::Issue) -> Issue {
     }
 }
 
+/// Issue and match details of a keyword search hit
+fn lexical_match(hit: IssueSearchHit) -> (jira_db_core::Issue, IssueSearchMatch) {
+    let search_match = IssueSearchMatch {
+        key: hit.issue.key.clone(),
+        score: hit.score,
+        lexical_rank: None,
+        lexical_score: None,
+        semantic_rank: None,
+        semantic_distance: None,
+        snippet: hit.snippet,
+    };
+    (hit.issue, search_match)
+}
+
+/// Issue and match details of a hybrid search hit
+fn hybrid_match(hit: HybridSearchHit) -> (jira_db_core::Issue, IssueSearchMatch) {
+    let search_match = IssueSearchMatch {
+        key: hit.issue.key.clone(),
+        score: Some(hit.score),
+        lexical_rank: hit.lexical_rank.map(|r| r as i32),
+        lexical_score: hit.lexical_score,
+        semantic_rank: hit.semantic_rank.map(|r| r as i32),
+        semantic_distance: hit.semantic_distance,
+        snippet: hit.snippet,
+    };
+    (hit.issue, search_match)
+}
+
+/// Convert core facet counts to the generated type, keeping the most frequent values
+fn convert_facet(mut facet: SearchFacet) -> IssueSearchFacet {
+    facet.truncate(SearchFacet::DEFAULT_SIZE);
+    IssueSearchFacet {
+        field: facet.field.as_str().to_string(),
+        values: facet
+            .values
+            .into_iter()
+            .map(|v| IssueFacetValue {
+                value: v.value,
+                count: v.count as i32,
+            })
+            .collect(),
+    }
+}
+
+/// Convert the request filters into core search params
+fn search_params(request: &IssueSearchRequest) -> Result<SearchParams, String> {
+    let range = |from: &Option<String>, to: &Option<String>| {
+        DateRange::parse(from.as_deref(), to.as_deref()).map_err(|e| e.to_string())
+    };
+
+    let sort = request
+        .sort
+        .iter()
+        .flatten()
+        .map(|sort| {
+            let spec = match sort.descending {
+                Some(true) => format!("{}:desc", sort.field),
+                Some(false) => format!("{}:asc", sort.field),
+                None => sort.field.clone(),
+            };
+            spec.parse::<SearchSort>().map_err(|e| e.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SearchParams {
+        query: request.query.clone(),
+        project_key: request.project.clone(),
+        status: request.status.clone(),
+        status_category: request.status_category.clone(),
+        assignee: request.assignee.clone(),
+        issue_type: request.issue_type.clone(),
+        priority: request.priority.clone(),
+        team: request.team.clone(),
+        resolution: request.resolution.clone(),
+        labels: request.labels.clone().unwrap_or_default(),
+        labels_match: match &request.labels_match {
+            Some(labels_match) => labels_match
+                .parse()
+                .map_err(|e: DomainError| e.to_string())?,
+            None => LabelMatch::default(),
+        },
+        components: request.components.clone().unwrap_or_default(),
+        fix_versions: request.fix_versions.clone().unwrap_or_default(),
+        sprint: request.sprint.clone(),
+        parent_key: request.parent_key.clone(),
+        keys: Vec::new(),
+        created: range(&request.created_from, &request.created_to)?,
+        updated: range(&request.updated_from, &request.updated_to)?,
+        due: range(&request.due_from, &request.due_to)?,
+        resolved: range(&request.resolved_from, &request.resolved_to)?,
+        sort,
+        limit: request.limit.map(|l| l as usize),
+        offset: request.offset.map(|o| o as usize),
+    })
+}
+
+/// Embed a search query once for all projects, when any of them has embeddings
+async fn embed_query(
+    settings: Option<&jira_db_core::Settings>,
+    query: &str,
+    projects: &[String],
+    state: &AppState,
+) -> Option<Vec<f32>> {
+    let config = settings?.embeddings.as_ref()?;
+    let has_embeddings = projects.iter().any(|project_key| {
+        state
+            .get_db(project_key)
+            .and_then(|db| EmbeddingsRepository::new(db).count().ok())
+            .is_some_and(|count| count > 0)
+    });
+    if !has_embeddings {
+        return None;
+    }
+
+    let provider = match ProviderConfig::from_settings(config).and_then(create_provider) {
+        Ok(provider) => provider,
+        Err(e) => {
+            tracing::warn!("[issues_search] No embedding provider: {}", e);
+            return None;
+        }
+    };
+    match provider.embed(query).await {
+        Ok(embedding) => Some(embedding),
+        Err(e) => {
+            tracing::warn!("[issues_search] Failed to embed query: {}", e);
+            None
+        }
+    }
+}
+
 /// Search issues with filters
 #[tauri::command]
 pub async fn issues_search(
@@ -72,70 +206,132 @@ pub async fn issues_search(
         return Ok(IssueSearchResponse {
             issues: vec![],
             total: 0,
+            matches: vec![],
+            facets: None,
         });
     }
 
+    let params = search_params(&request)?;
+    let facet_fields: &[FacetField] = if request.facets.unwrap_or(false) {
+        &FacetField::ALL
+    } else {
+        &[]
+    };
+
+    // Fuse keyword and embedding rankings unless the caller asked for keywords only
+    let hybrid = request.hybrid.unwrap_or(true);
+    let settings = state.get_settings();
+    let query_embedding = match &request.query {
+        Some(query) if hybrid && params.sorts_by_relevance() && !query.trim().is_empty() => {
+            embed_query(settings.as_ref(), query, &projects_to_search, &state).await
+        }
+        _ => None,
+    };
+    let search_settings = settings
+        .map(|s| s.get_search_settings())
+        .unwrap_or_default();
+
+    // Take the first `offset + limit` hits of every project, then page the merged list
+    let offset = params.offset.unwrap_or(0);
+    let limit = params.limit;
+    let project_limit = limit.map(|l| offset + l);
+
     // Search across all projects
-    let mut all_issues = Vec::new();
+    let mut all_matches = Vec::new();
+    let mut all_facets = Vec::new();
+    let mut total = 0;
     for project_key in &projects_to_search {
-        match state.get_db(project_key) {
-            Some(db) => {
-                let issue_repo = Arc::new(DuckDbIssueRepository::new(db));
-                let use_case = SearchIssuesUseCase::new(issue_repo);
-
-                let params = SearchParams {
-                    query: request.query.clone(),
-                    project_key: Some(project_key.clone()),
-                    status: request.status.clone(),
-                    assignee: request.assignee.clone(),
-                    issue_type: request.issue_type.clone(),
-                    priority: request.priority.clone(),
-                    team: request.team.clone(),
-                    limit: request.limit.map(|l| l as usize),
-                    offset: None, // Apply offset after combining results
-                };
-
-                match use_case.execute(params) {
-                    Ok(issues) => {
-                        tracing::debug!(
-                            "[issues_search] Found {} issues for project {}",
-                            issues.len(),
-                            project_key
-                        );
-                        all_issues.extend(issues);
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            "[issues_search] Failed to search issues for project {}: {}",
-                            project_key,
-                            e
-                        );
-                    }
-                }
+        let Some(db) = state.get_db(project_key) else {
+            tracing::warn!(
+                "[issues_search] No database connection for project {}",
+                project_key
+            );
+            continue;
+        };
+
+        let project_params = SearchParams {
+            project_key: Some(project_key.clone()),
+            limit: project_limit,
+            offset: None,
+            ..params.clone()
+        };
+
+        let issue_repo = Arc::new(DuckDbIssueRepository::new(db.clone()));
+        let result = if hybrid {
+            HybridSearchUseCase::new(issue_repo, Arc::new(EmbeddingsRepository::new(db)))
+                .with_settings(search_settings.clone())
+                .with_facets(facet_fields)
+                .execute_with_embedding(project_params, query_embedding.as_deref())
+                .map(|result| {
+                    let matches = result
+                        .hits
+                        .into_iter()
+                        .map(hybrid_match)
+                        .collect::<Vec<_>>();
+                    (matches, result.total, result.facets)
+                })
+        } else {
+            let use_case = SearchIssuesUseCase::new(issue_repo);
+            use_case.count(&project_params).and_then(|total| {
+                let facets = use_case.facets(&project_params, facet_fields)?;
+                let matches = use_case
+                    .execute_ranked(project_params)?
+                    .into_iter()
+                    .map(lexical_match)
+                    .collect::<Vec<_>>();
+                Ok((matches, total, facets))
+            })
+        };
+
+        match result {
+            Ok((matches, project_total, facets)) => {
+                merge_facets(&mut all_facets, facets);
+                tracing::debug!(
+                    "[issues_search] Found {} issues for project {}",
+                    project_total,
+                    project_key
+                );
+                all_matches.extend(matches);
+                total += project_total;
             }
-            None => {
+            Err(e) => {
                 tracing::warn!(
-                    "[issues_search] No database connection for project {}",
-                    project_key
+                    "[issues_search] Failed to search issues for project {}: {}",
+                    project_key,
+                    e
                 );
             }
         }
     }
 
+    if params.sorts_by_relevance() {
+        // Best matches first: fused score for hybrid search, BM25 otherwise
+        all_matches.sort_by(|(_, a), (_, b)| {
+            b.score
+                .unwrap_or(f64::MIN)
+                .total_cmp(&a.score.unwrap_or(f64::MIN))
+        });
+    } else {
+        all_matches.sort_by(|(a, _), (b, _)| {
+            params
+                .sort
+                .iter()
+                .map(|sort| sort.compare(a, b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.key.cmp(&b.key))
+        });
+    }
+
     // Apply limit and offset to combined results
-    let total = all_issues.len() as i32;
-    let offset = request.offset.unwrap_or(0) as usize;
-    let limit = request
-        .limit
-        .map(|l| l as usize)
-        .unwrap_or(all_issues.len());
-
-    let issues: Vec<Issue> = all_issues
+    let limit = limit.unwrap_or(all_matches.len());
+    let total = total as i32;
+
+    let (issues, matches): (Vec<Issue>, Vec<IssueSearchMatch>) = all_matches
         .into_iter()
         .skip(offset)
         .take(limit)
-        .map(convert_issue)
-        .collect();
+        .map(|(issue, search_match)| (convert_issue(issue), search_match))
+        .unzip();
 
     tracing::info!(
         "[issues_search] Returning {} issues (total: {}, offset: {}, limit: {})",
@@ -145,7 +341,13 @@ pub async fn issues_search(
         limit
     );
 
-    Ok(IssueSearchResponse { issues, total })
+    Ok(IssueSearchResponse {
+        issues,
+        total,
+        matches,
+        facets: (!facet_fields.is_empty())
+            .then(|| all_facets.into_iter().map(convert_facet).collect()),
+    })
 }
 
 /// Get issue by key
@@ -162,27 +364,10 @@ pub async fn issues_get(
         .get_db(project_key)
         .ok_or_else(|| format!("Database not initialized for project {}", project_key))?;
 
-    let issue_repo = Arc::new(DuckDbIssueRepository::new(db));
-    let use_case = SearchIssuesUseCase::new(issue_repo);
-
-    // Use search with the exact key as query
-    let params = SearchParams {
-        query: Some(request.key.clone()),
-        project_key: Some(project_key.to_string()),
-        status: None,
-        assignee: None,
-        issue_type: None,
-        priority: None,
-        team: None,
-        limit: Some(1),
-        offset: None,
-    };
-
-    let issues = use_case.execute(params).map_err(|e| e.to_string())?;
-
-    let issue = issues
-        .into_iter()
-        .find(|i| i.key == request.key)
+    // Look the key up directly; a text search would rank other issues that mention it
+    let issue = DuckDbIssueRepository::new(db)
+        .find_by_key(&request.key)
+        .map_err(|e| e.to_string())?
         .ok_or("Issue not found")?;
 
     Ok(IssueGetResponse {
@@ -230,8 +415,82 @@ pub async fn issues_history(
             to_value: h.to_value,
             to_string: h.to_string,
             changed_at: h.changed_at,
+            source: h.source,
         })
         .collect();
 
     Ok(IssueHistoryResponse { history })
 }
+
+/// Edit issue fields in JIRA and refresh the local row
+#[tauri::command]
+pub async fn issues_update(
+    state: State<'_, AppState>,
+    request: IssueUpdateRequest,
+) -> Result<IssueUpdateResponse, String> {
+    // Extract project key from issue key (e.g., "PROJ-123" -> "PROJ")
+    let project_key = extract_project_key(&request.key)
+        .ok_or_else(|| format!("Invalid issue key format: {}", request.key))?;
+
+    let settings = state.get_settings().ok_or("Settings not initialized")?;
+    let db = state
+        .get_db(project_key)
+        .ok_or_else(|| format!("Database not initialized for project {}", project_key))?;
+
+    // Each project writes to its own endpoint (falls back to the active one)
+    let jira_config = settings
+        .get_jira_config_for_project(project_key)
+        .ok_or("No JIRA endpoint configured")?;
+    let jira_client = Arc::new(JiraApiClient::new(&jira_config).map_err(|e| e.to_string())?);
+
+    let mut edits = Vec::new();
+    for v in request.set.unwrap_or_default() {
+        edits.push(FieldEdit::Set {
+            field: v.field,
+            value: v.value,
+        });
+    }
+    for v in request.add.unwrap_or_default() {
+        edits.push(FieldEdit::Add {
+            field: v.field,
+            value: v.value,
+        });
+    }
+    for v in request.remove.unwrap_or_default() {
+        edits.push(FieldEdit::Remove {
+            field: v.field,
+            value: v.value,
+        });
+    }
+
+    let use_case = UpdateIssueFieldsUseCase::new(
+        Arc::new(DuckDbIssueRepository::new(db.clone())),
+        Arc::new(DuckDbMetadataRepository::new(db.clone())),
+        Arc::new(DuckDbFieldRepository::new(db)),
+        jira_client,
+    );
+
+    let update = use_case
+        .prepare(&request.key, &edits)
+        .map_err(|e| e.to_string())?;
+    let payload = update.to_request_body().to_string();
+
+    if request.dry_run.unwrap_or(false) {
+        return Ok(IssueUpdateResponse {
+            issue: None,
+            payload,
+        });
+    }
+
+    let issue = use_case
+        .execute(&request.key, &edits)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!("[issues_update] Updated {}", request.key);
+
+    Ok(IssueUpdateResponse {
+        issue: Some(convert_issue(issue)),
+        payload,
+    })
+}
@@ -0,0 +1,64 @@
+//! JQL command handlers
+
+use std::time::Instant;
+
+use tauri::State;
+
+use jira_db_core::ExecuteJqlUseCase;
+
+use super::sql::federated_connection;
+use crate::generated::*;
+use crate::state::AppState;
+
+/// Run a JQL query against the local database
+#[tauri::command]
+pub async fn jql_execute(
+    state: State<'_, AppState>,
+    request: JqlExecuteRequest,
+) -> Result<JqlExecuteResponse, String> {
+    let start = Instant::now();
+
+    let project_key = request.project_key.as_deref().unwrap_or("");
+    let db = if request.all_projects.unwrap_or(false) {
+        federated_connection(&state)?
+    } else {
+        state
+            .get_db(project_key)
+            .ok_or_else(|| format!("Database not initialized for project {}", project_key))?
+    };
+
+    // currentUser() refers to the account the endpoint is configured with
+    let jira_config = state.get_settings().and_then(|settings| {
+        if request.all_projects.unwrap_or(false) {
+            settings.get_jira_config()
+        } else {
+            settings.get_jira_config_for_project(project_key)
+        }
+    });
+    let use_case = match jira_config {
+        Some(config) => ExecuteJqlUseCase::new(db).with_current_user(config.username),
+        None => ExecuteJqlUseCase::new(db),
+    };
+
+    let result = use_case
+        .execute(&request.query, request.limit.map(|l| l as usize))
+        .map_err(|e| e.to_string())?;
+
+    let execution_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let columns = result.result.columns;
+    let rows = result
+        .result
+        .rows
+        .into_iter()
+        .map(|row| serde_json::Value::Object(columns.iter().cloned().zip(row).collect()))
+        .collect();
+
+    Ok(JqlExecuteResponse {
+        sql: result.sql,
+        columns,
+        rows,
+        row_count: result.result.row_count as i32,
+        execution_time_ms,
+    })
+}
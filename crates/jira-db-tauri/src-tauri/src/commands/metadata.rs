@@ -98,6 +98,18 @@ pub async fn metadata_get(
                     release_date: fv.release_date,
                 })
                 .collect(),
+            field_options: metadata
+                .field_options
+                .into_iter()
+                .map(|o| FieldOption {
+                    field_id: o.field_id,
+                    field_name: o.field_name,
+                    option_id: o.option_id,
+                    value: o.value,
+                    parent_option_id: o.parent_option_id,
+                    disabled: o.disabled,
+                })
+                .collect(),
         },
     })
 }
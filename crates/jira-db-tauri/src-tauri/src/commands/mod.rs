@@ -4,10 +4,13 @@ pub mod config;
 pub mod database;
 pub mod debug;
 pub mod embeddings;
+pub mod export;
 pub mod fields;
 pub mod issues;
+pub mod jql;
 pub mod metadata;
 pub mod projects;
 pub mod reports;
+pub mod saved;
 pub mod sql;
 pub mod sync;
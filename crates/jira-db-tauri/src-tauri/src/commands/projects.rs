@@ -68,6 +68,7 @@ pub async fn projects_initialize(
                         endpoint: None,
                         sync_checkpoint: None,
                         snapshot_checkpoint: None,
+                        bulk_edit_checkpoint: None,
                     });
                 }
             }
@@ -5,8 +5,8 @@ use std::sync::Arc;
 use tauri::State;
 
 use jira_db_core::{
-    DuckDbChangeHistoryRepository, DuckDbIssueRepository, GenerateReportUseCase,
-    generate_interactive_report, generate_static_report,
+    DuckDbChangeHistoryRepository, DuckDbIssueRepository, DuckDbTopicRepository,
+    GenerateReportUseCase, generate_interactive_report, generate_static_report,
 };
 
 use crate::generated::*;
@@ -39,10 +39,12 @@ pub async fn reports_generate(
 
     // Create repositories
     let issue_repo = Arc::new(DuckDbIssueRepository::new(db.clone()));
-    let change_history_repo = Arc::new(DuckDbChangeHistoryRepository::new(db));
+    let change_history_repo = Arc::new(DuckDbChangeHistoryRepository::new(db.clone()));
+    let topic_repo = Arc::new(DuckDbTopicRepository::new(db));
 
     // Create use case
-    let use_case = GenerateReportUseCase::new(issue_repo, change_history_repo);
+    let use_case = GenerateReportUseCase::new(issue_repo, change_history_repo)
+        .with_topic_repository(topic_repo);
 
     // Build project keys (single project)
     let project_keys: Vec<(&str, &str, &str)> = vec![(
@@ -0,0 +1,240 @@
+//! Saved search command handlers
+//!
+//! Saved searches live in the settings file, so the CLI and MCP server see the same ones.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use tauri::State;
+
+use jira_db_core::{
+    ExecuteSqlUseCase, RunSavedSearchUseCase, SavedSearchFilter, SavedSearchParameterType,
+    SavedSearchQuery,
+};
+
+use crate::generated::*;
+use crate::state::AppState;
+
+/// List saved searches
+#[tauri::command]
+pub async fn saved_list(
+    state: State<'_, AppState>,
+    _request: SavedSearchListRequest,
+) -> Result<SavedSearchListResponse, String> {
+    let settings = state
+        .get_settings()
+        .ok_or_else(|| "Settings not initialized".to_string())?;
+
+    Ok(SavedSearchListResponse {
+        searches: settings
+            .saved_searches
+            .into_iter()
+            .map(convert_saved_search)
+            .collect(),
+    })
+}
+
+/// Create or replace a saved search
+#[tauri::command]
+pub async fn saved_save(
+    state: State<'_, AppState>,
+    request: SavedSearchSaveRequest,
+) -> Result<SavedSearchSaveResponse, String> {
+    let settings = state
+        .get_settings()
+        .ok_or_else(|| "Settings not initialized".to_string())?;
+
+    let query = match request.r#type.as_str() {
+        "sql" => {
+            let sql = request
+                .query
+                .ok_or_else(|| "query is required for SQL searches".to_string())?;
+            ExecuteSqlUseCase::validate_read_only(&sql).map_err(|e| e.to_string())?;
+            SavedSearchQuery::Sql { sql }
+        }
+        "jql" => SavedSearchQuery::Jql {
+            jql: request
+                .query
+                .ok_or_else(|| "query is required for JQL searches".to_string())?,
+        },
+        "filter" => {
+            let filter = request
+                .filter
+                .ok_or_else(|| "filter is required for filter searches".to_string())?;
+            SavedSearchQuery::Filter {
+                filter: serde_json::from_value::<SavedSearchFilter>(filter)
+                    .map_err(|e| format!("Invalid filter: {}", e))?,
+            }
+        }
+        other => {
+            return Err(format!(
+                "Invalid saved search type '{}': expected sql, jql or filter",
+                other
+            ));
+        }
+    };
+
+    let mut search = jira_db_core::SavedSearch::new(&request.name, query);
+    search.description = request.description;
+    search.project = request.project;
+    search.owner = request
+        .owner
+        .or_else(|| settings.get_jira_config().map(|config| config.username));
+    search.parameters = request
+        .parameters
+        .unwrap_or_default()
+        .into_iter()
+        .map(|param| {
+            Ok(jira_db_core::SavedSearchParameter {
+                param_type: param
+                    .r#type
+                    .parse::<SavedSearchParameterType>()
+                    .map_err(|e| e.to_string())?,
+                name: param.name,
+                description: param.description,
+                required: param.required,
+                default: param.default,
+            })
+        })
+        .collect::<Result<_, String>>()?;
+    search.validate().map_err(|e| e.to_string())?;
+
+    let mut result = Ok(());
+    let updated = state
+        .update_settings(|settings| result = settings.upsert_saved_search(search))
+        .map_err(|e| e.to_string())?;
+    result.map_err(|e| e.to_string())?;
+
+    let search = updated
+        .find_saved_search(&request.name)
+        .cloned()
+        .ok_or_else(|| "Saved search was not stored".to_string())?;
+    Ok(SavedSearchSaveResponse {
+        search: convert_saved_search(search),
+    })
+}
+
+/// Delete a saved search
+#[tauri::command]
+pub async fn saved_delete(
+    state: State<'_, AppState>,
+    request: SavedSearchDeleteRequest,
+) -> Result<SavedSearchDeleteResponse, String> {
+    let mut removed = false;
+    state
+        .update_settings(|settings| removed = settings.remove_saved_search(&request.name))
+        .map_err(|e| e.to_string())?;
+
+    if !removed {
+        return Err(format!("Saved search not found: {}", request.name));
+    }
+    Ok(SavedSearchDeleteResponse { success: true })
+}
+
+/// Run a saved search with parameter values
+#[tauri::command]
+pub async fn saved_run(
+    state: State<'_, AppState>,
+    request: SavedSearchRunRequest,
+) -> Result<SavedSearchRunResponse, String> {
+    let settings = state
+        .get_settings()
+        .ok_or_else(|| "Settings not initialized".to_string())?;
+    let factory = state
+        .get_db_factory()
+        .ok_or_else(|| "Database factory not initialized".to_string())?;
+
+    let search = settings
+        .find_saved_search(&request.name)
+        .cloned()
+        .ok_or_else(|| format!("Saved search not found: {}", request.name))?;
+
+    let args = parameter_values(request.params)?;
+    let project = request.project.or_else(|| search.project.clone());
+    let jira_config = match &project {
+        Some(project_key) => settings.get_jira_config_for_project(project_key),
+        None => settings.get_jira_config(),
+    };
+    let use_case = RunSavedSearchUseCase::connect(&factory, &search, project.as_deref())
+        .map_err(|e| e.to_string())?;
+    // currentUser() refers to the account the endpoint is configured with
+    let use_case = match jira_config {
+        Some(config) => use_case.with_current_user(config.username),
+        None => use_case,
+    };
+
+    let start = Instant::now();
+    let result = use_case
+        .execute(&search, &args, request.limit.map(|l| l as usize))
+        .map_err(|e| e.to_string())?;
+    let execution_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let columns = result.result.columns;
+    let rows = result
+        .result
+        .rows
+        .into_iter()
+        .map(|row| serde_json::Value::Object(columns.iter().cloned().zip(row).collect()))
+        .collect();
+
+    Ok(SavedSearchRunResponse {
+        name: result.name,
+        r#type: result.kind,
+        sql: result.sql,
+        total: result.total.map(|t| t as i32),
+        columns,
+        rows,
+        row_count: result.result.row_count as i32,
+        execution_time_ms,
+    })
+}
+
+/// Parameter values from a JSON object; non-string values use their JSON text
+fn parameter_values(params: Option<serde_json::Value>) -> Result<HashMap<String, String>, String> {
+    let object = match params {
+        None | Some(serde_json::Value::Null) => return Ok(HashMap::new()),
+        Some(serde_json::Value::Object(object)) => object,
+        Some(_) => return Err("params must be an object keyed by parameter name".to_string()),
+    };
+
+    Ok(object
+        .into_iter()
+        .filter_map(|(name, value)| match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(text) => Some((name, text)),
+            other => Some((name, other.to_string())),
+        })
+        .collect())
+}
+
+fn convert_saved_search(search: jira_db_core::SavedSearch) -> SavedSearch {
+    let search_type = search.query.kind().to_string();
+    let (query, filter) = match search.query {
+        SavedSearchQuery::Sql { sql } => (Some(sql), None),
+        SavedSearchQuery::Jql { jql } => (Some(jql), None),
+        SavedSearchQuery::Filter { filter } => (None, serde_json::to_value(filter).ok()),
+    };
+
+    SavedSearch {
+        name: search.name,
+        description: search.description,
+        owner: search.owner,
+        project: search.project,
+        r#type: search_type,
+        query,
+        filter,
+        parameters: search
+            .parameters
+            .into_iter()
+            .map(|param| SavedSearchParameter {
+                name: param.name,
+                r#type: param.param_type.as_str().to_string(),
+                description: param.description,
+                required: param.required,
+                default: param.default,
+            })
+            .collect(),
+        created_at: search.created_at,
+        updated_at: search.updated_at,
+    }
+}
@@ -1,5 +1,6 @@
 //! Sync command handlers
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, State};
 
@@ -7,8 +8,8 @@ use chrono::Duration;
 use jira_db_core::{
     DuckDbChangeHistoryRepository, DuckDbFieldRepository, DuckDbIssueRepository,
     DuckDbIssueSnapshotRepository, DuckDbIssuesExpandedRepository, DuckDbMetadataRepository,
-    DuckDbSyncHistoryRepository, JiraApiClient, RawDataRepository, Settings, SyncCheckpoint,
-    SyncFieldsUseCase, SyncProjectUseCase,
+    DuckDbSyncHistoryRepository, FullTextSearchRepository, JiraApiClient, RawDataRepository,
+    Settings, SyncCheckpoint, SyncFieldsUseCase, SyncProjectUseCase,
     chrono::{self, DateTime, Utc},
 };
 use serde::{Deserialize, Serialize};
@@ -28,6 +29,9 @@ use crate::{log_debug, log_info, log_warn};
 pub struct SyncResultExtended {
     #[serde(rename = "projectKey")]
     pub project_key: String,
+    /// Name of the JIRA endpoint the project was synced from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
     #[serde(rename = "issueCount")]
     pub issue_count: i32,
     #[serde(rename = "metadataUpdated")]
@@ -97,12 +101,6 @@ pub async fn sync_execute(
         .get_db_factory()
         .ok_or("Database factory not initialized")?;
 
-    // Create JIRA config and client from active endpoint
-    let jira_config = settings
-        .get_jira_config()
-        .ok_or("No JIRA endpoint configured")?;
-    let jira_client = Arc::new(JiraApiClient::new(&jira_config).map_err(|e| e.to_string())?);
-
     // Get sync settings for incremental sync
     let sync_settings = settings.get_sync_settings();
     let force_full_sync = request.force.unwrap_or(false);
@@ -114,6 +112,7 @@ pub async fn sync_execute(
         String,
         Option<SyncCheckpoint>,
         Option<DateTime<Utc>>,
+        Option<String>,
     )> = if let Some(ref project_key) = request.project_key {
         settings
             .projects
@@ -125,6 +124,7 @@ pub async fn sync_execute(
                     p.id.clone(),
                     p.sync_checkpoint.clone(),
                     p.last_synced,
+                    settings.endpoint_name_for_project(p),
                 )
             })
             .collect()
@@ -139,6 +139,7 @@ pub async fn sync_execute(
                     p.id.clone(),
                     p.sync_checkpoint.clone(),
                     p.last_synced,
+                    settings.endpoint_name_for_project(p),
                 )
             })
             .collect()
@@ -158,9 +159,53 @@ pub async fn sync_execute(
     let mut results = Vec::new();
     let mut total_fields_synced = 0i32;
 
-    for (key, id, existing_checkpoint, last_synced) in &projects_to_sync {
+    // JIRA clients are created once per endpoint and shared by its projects
+    let mut jira_clients: HashMap<Option<String>, Arc<JiraApiClient>> = HashMap::new();
+
+    for (key, id, existing_checkpoint, last_synced, endpoint_name) in &projects_to_sync {
         let start_time = std::time::Instant::now();
 
+        let jira_client = match jira_clients.get(endpoint_name) {
+            Some(client) => client.clone(),
+            None => {
+                let client = settings
+                    .get_jira_config_for_project(key)
+                    .ok_or_else(|| "No JIRA endpoint configured".to_string())
+                    .and_then(|config| JiraApiClient::new(&config).map_err(|e| e.to_string()));
+                match client {
+                    Ok(client) => {
+                        let client = Arc::new(client);
+                        jira_clients.insert(endpoint_name.clone(), client.clone());
+                        client
+                    }
+                    Err(e) => {
+                        log_warn!(
+                            log,
+                            "[{}] Failed to create JIRA client for endpoint '{}': {}",
+                            key,
+                            endpoint_name.as_deref().unwrap_or("default"),
+                            e
+                        );
+                        results.push(SyncResultExtended {
+                            project_key: key.clone(),
+                            endpoint: endpoint_name.clone(),
+                            issue_count: 0,
+                            metadata_updated: false,
+                            duration: 0.0,
+                            success: false,
+                            error: Some(e),
+                            fields_synced: 0,
+                            columns_added: 0,
+                            issues_expanded: 0,
+                            last_issue_updated_at: None,
+                            failed_checkpoint: None,
+                        });
+                        continue;
+                    }
+                }
+            }
+        };
+
         // Determine the checkpoint to use for sync:
         // 1. If force=true, use None (full sync)
         // 2. If existing checkpoint exists, use it (resume interrupted sync)
@@ -343,6 +388,25 @@ pub async fn sync_execute(
                         sync_result.issues_synced,
                         duration
                     );
+                    let indexed = db_factory.get_connection(key).and_then(|conn| {
+                        FullTextSearchRepository::new(conn).rebuild(&sync_settings.search_fields)
+                    });
+                    match indexed {
+                        Ok(count) => {
+                            log_info!(log, "[{}] Indexed {} issues for search", key, count)
+                        }
+                        Err(e) => {
+                            log_warn!(log, "[{}] Failed to refresh search index: {}", key, e)
+                        }
+                    }
+                    // Let servers in other processes pick up the new data
+                    match db_factory.publish_read_replica(key) {
+                        Ok(true) => log_info!(log, "[{}] Published read replica", key),
+                        Ok(false) => {}
+                        Err(e) => {
+                            log_warn!(log, "[{}] Failed to publish read replica: {}", key, e)
+                        }
+                    }
                 } else {
                     log_warn!(
                         log,
@@ -354,6 +418,7 @@ pub async fn sync_execute(
 
                 results.push(SyncResultExtended {
                     project_key: sync_result.project_key,
+                    endpoint: endpoint_name.clone(),
                     issue_count: sync_result.issues_synced as i32,
                     metadata_updated: true,
                     duration,
@@ -375,6 +440,7 @@ pub async fn sync_execute(
                 log_warn!(log, "[{}] Sync failed: {}", key, e);
                 results.push(SyncResultExtended {
                     project_key: key.clone(),
+                    endpoint: endpoint_name.clone(),
                     issue_count: 0,
                     metadata_updated: false,
                     duration,
@@ -425,7 +491,7 @@ pub async fn sync_execute(
     );
 
     // Close database connections after sync to free resources
-    for (key, _, _, _) in &projects_to_sync {
+    for (key, _, _, _, _) in &projects_to_sync {
         if let Err(e) = state.close_db(key) {
             log_warn!(log, "Failed to close database for {}: {}", key, e);
         }
@@ -146,6 +146,15 @@ pub async fn issues_history(
     todo!("Implement issues_history")
 }
 
+/// Edit issue fields in JIRA
+#[tauri::command]
+pub async fn issues_update(
+    state: State<'_, AppState>,
+    request: IssueUpdateRequest,
+) -> Result<IssueUpdateResponse, String> {
+    todo!("Implement issues_update")
+}
+
 // ============================================================
 // Metadata Commands
 // ============================================================
@@ -181,6 +190,15 @@ pub async fn embeddings_search(
     todo!("Implement embeddings_search")
 }
 
+/// Find issues similar to an issue from its stored embedding
+#[tauri::command]
+pub async fn embeddings_similar(
+    state: State<'_, AppState>,
+    request: EmbeddingsSimilarRequest,
+) -> Result<EmbeddingsSimilarResponse, String> {
+    todo!("Implement embeddings_similar")
+}
+
 // ============================================================
 // Reports Commands
 // ============================================================
@@ -194,6 +212,19 @@ pub async fn reports_generate(
     todo!("Implement reports_generate")
 }
 
+// ============================================================
+// Jql Commands
+// ============================================================
+
+/// Run a JQL query against the local database
+#[tauri::command]
+pub async fn jql_execute(
+    state: State<'_, AppState>,
+    request: JqlExecuteRequest,
+) -> Result<JqlExecuteResponse, String> {
+    todo!("Implement jql_execute")
+}
+
 // ============================================================
 // Sql Commands
 // ============================================================
@@ -243,6 +274,46 @@ pub async fn sql_delete_query(
     todo!("Implement sql_delete_query")
 }
 
+// ============================================================
+// Saved Commands
+// ============================================================
+
+/// List saved searches
+#[tauri::command]
+pub async fn saved_list(
+    state: State<'_, AppState>,
+    request: SavedSearchListRequest,
+) -> Result<SavedSearchListResponse, String> {
+    todo!("Implement saved_list")
+}
+
+/// Create or replace a saved search
+#[tauri::command]
+pub async fn saved_save(
+    state: State<'_, AppState>,
+    request: SavedSearchSaveRequest,
+) -> Result<SavedSearchSaveResponse, String> {
+    todo!("Implement saved_save")
+}
+
+/// Delete a saved search
+#[tauri::command]
+pub async fn saved_delete(
+    state: State<'_, AppState>,
+    request: SavedSearchDeleteRequest,
+) -> Result<SavedSearchDeleteResponse, String> {
+    todo!("Implement saved_delete")
+}
+
+/// Run a saved search with parameter values
+#[tauri::command]
+pub async fn saved_run(
+    state: State<'_, AppState>,
+    request: SavedSearchRunRequest,
+) -> Result<SavedSearchRunResponse, String> {
+    todo!("Implement saved_run")
+}
+
 // ============================================================
 // Debug Commands
 // ============================================================
@@ -340,15 +411,22 @@ macro_rules! register_commands {
             issues_search,
             issues_get,
             issues_history,
+            issues_update,
             metadata_get,
             embeddings_generate,
             embeddings_search,
+            embeddings_similar,
             reports_generate,
+            jql_execute,
             sql_execute,
             sql_get_schema,
             sql_list_queries,
             sql_save_query,
             sql_delete_query,
+            saved_list,
+            saved_save,
+            saved_delete,
+            saved_run,
             debug_status,
             debug_get_issue_types,
             debug_create_issues,
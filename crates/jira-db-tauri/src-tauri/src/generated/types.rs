@@ -83,6 +83,7 @@ pub struct ChangeHistoryItem {
     pub to_string: Option<String>,
     #[serde(rename = "changedAt")]
     pub changed_at: DateTime<Utc>,
+    pub source: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -145,6 +146,23 @@ pub struct FixVersion {
     pub release_date: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldOption {
+    #[serde(rename = "fieldId")]
+    pub field_id: String,
+    #[serde(rename = "fieldName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_name: Option<String>,
+    #[serde(rename = "optionId")]
+    pub option_id: String,
+    pub value: String,
+    #[serde(rename = "parentOptionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_option_id: Option<String>,
+    pub disabled: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectMetadata {
@@ -158,6 +176,8 @@ pub struct ProjectMetadata {
     pub components: Vec<Component>,
     #[serde(rename = "fixVersions")]
     pub fix_versions: Vec<FixVersion>,
+    #[serde(rename = "fieldOptions")]
+    pub field_options: Vec<FieldOption>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -165,6 +185,8 @@ pub struct ProjectMetadata {
 pub struct SyncResult {
     #[serde(rename = "projectKey")]
     pub project_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
     #[serde(rename = "issueCount")]
     pub issue_count: i32,
     #[serde(rename = "metadataUpdated")]
@@ -203,6 +225,13 @@ pub struct SemanticSearchResult {
     pub score: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarIssueResult {
+    pub issue: Issue,
+    pub similarity: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportResult {
@@ -212,6 +241,42 @@ pub struct ReportResult {
     pub issue_count: i32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearch {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(rename = "type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    pub parameters: Vec<SavedSearchParameter>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SavedQuery {
@@ -510,10 +575,90 @@ pub struct IssueSearchRequest {
     pub issue_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
+    #[serde(rename = "statusCategory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    #[serde(rename = "labelsMatch")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels_match: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<String>>,
+    #[serde(rename = "fixVersions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_versions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sprint: Option<String>,
+    #[serde(rename = "parentKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_key: Option<String>,
+    #[serde(rename = "createdFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<String>,
+    #[serde(rename = "createdTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_to: Option<String>,
+    #[serde(rename = "updatedFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_from: Option<String>,
+    #[serde(rename = "updatedTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_to: Option<String>,
+    #[serde(rename = "dueFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_from: Option<String>,
+    #[serde(rename = "dueTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_to: Option<String>,
+    #[serde(rename = "resolvedFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_from: Option<String>,
+    #[serde(rename = "resolvedTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Vec<IssueSearchSort>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hybrid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchSort {
+    pub field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descending: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchMatch {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(rename = "lexicalRank")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lexical_rank: Option<i32>,
+    #[serde(rename = "lexicalScore")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lexical_score: Option<f64>,
+    #[serde(rename = "semanticRank")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_rank: Option<i32>,
+    #[serde(rename = "semanticDistance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub semantic_distance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -521,6 +666,24 @@ pub struct IssueSearchRequest {
 pub struct IssueSearchResponse {
     pub issues: Vec<Issue>,
     pub total: i32,
+    pub matches: Vec<IssueSearchMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<IssueSearchFacet>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchFacet {
+    pub field: String,
+    pub values: Vec<IssueFacetValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueFacetValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    pub count: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -551,6 +714,35 @@ pub struct IssueHistoryResponse {
     pub history: Vec<ChangeHistoryItem>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldValue {
+    pub field: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUpdateRequest {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set: Option<Vec<FieldValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add: Option<Vec<FieldValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<Vec<FieldValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUpdateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Issue>,
+    pub payload: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MetadataGetRequest {
@@ -605,6 +797,34 @@ pub struct SemanticSearchResponse {
     pub results: Vec<SemanticSearchResult>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddingsSimilarRequest {
+    #[serde(rename = "issueKey")]
+    pub issue_key: String,
+    #[serde(rename = "sameProject")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub same_project: Option<bool>,
+    #[serde(rename = "excludeResolved")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_resolved: Option<bool>,
+    #[serde(rename = "createdFrom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<String>,
+    #[serde(rename = "createdTo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddingsSimilarResponse {
+    pub source: Issue,
+    pub results: Vec<SimilarIssueResult>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportGenerateRequest {
@@ -624,6 +844,34 @@ pub struct ReportGenerateResponse {
     pub result: ReportResult,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlExecuteRequest {
+    pub query: String,
+    #[serde(rename = "projectKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_key: Option<String>,
+    /// If true, query across all synced projects instead of just one
+    #[serde(rename = "allProjects")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JqlExecuteResponse {
+    /// SQL the query was compiled to
+    pub sql: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<serde_json::Value>,
+    #[serde(rename = "rowCount")]
+    pub row_count: i32,
+    #[serde(rename = "executionTimeMs")]
+    pub execution_time_ms: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SqlExecuteRequest {
@@ -709,6 +957,117 @@ pub struct SqlQueryDeleteResponse {
     pub success: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchListRequest {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchListResponse {
+    pub searches: Vec<SavedSearch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchSaveRequest {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<SavedSearchParameter>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchSaveResponse {
+    pub search: SavedSearch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchDeleteRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchDeleteResponse {
+    pub success: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchRunRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchRunResponse {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i32>,
+    pub columns: Vec<String>,
+    pub rows: Vec<serde_json::Value>,
+    #[serde(rename = "rowCount")]
+    pub row_count: i32,
+    #[serde(rename = "executionTimeMs")]
+    pub execution_time_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDataRequest {
+    #[serde(rename = "projectKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_key: Option<String>,
+    /// If true, export from all synced projects (adds a project_key column)
+    #[serde(rename = "allProjects")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_projects: Option<bool>,
+    /// Table or view to export (e.g. issues_readable)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<String>,
+    /// SELECT query whose result is exported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// parquet, csv, jsonl or xlsx
+    pub format: String,
+    /// Destination file (desktop app only)
+    #[serde(rename = "outputPath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDataResponse {
+    pub path: String,
+    pub format: String,
+    #[serde(rename = "rowCount")]
+    pub row_count: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatedIssue {
@@ -196,11 +196,13 @@ pub fn run() {
             commands::issues::issues_search,
             commands::issues::issues_get,
             commands::issues::issues_history,
+            commands::issues::issues_update,
             // Metadata
             commands::metadata::metadata_get,
             // Embeddings
             commands::embeddings::embeddings_generate,
             commands::embeddings::embeddings_search,
+            commands::embeddings::embeddings_similar,
             // Fields
             commands::fields::fields_sync,
             commands::fields::fields_expand,
@@ -208,12 +210,21 @@ pub fn run() {
             commands::fields::fields_list,
             // Reports
             commands::reports::reports_generate,
+            // JQL
+            commands::jql::jql_execute,
             // SQL
             commands::sql::sql_execute,
             commands::sql::sql_get_schema,
             commands::sql::sql_list_queries,
             commands::sql::sql_save_query,
             commands::sql::sql_delete_query,
+            // Saved searches
+            commands::saved::saved_list,
+            commands::saved::saved_save,
+            commands::saved::saved_delete,
+            commands::saved::saved_run,
+            // Export
+            commands::export::export_data,
             // Database management
             commands::database::database_close,
             commands::database::database_status,
@@ -29,7 +29,12 @@ impl AppState {
     /// Initialize the application state with a settings file
     pub fn initialize(&self, settings_path: PathBuf) -> anyhow::Result<()> {
         // Load and resolve paths relative to settings file location
-        let settings = Settings::load_and_resolve(&settings_path)?;
+        let mut settings = Settings::load_and_resolve(&settings_path)?;
+
+        // The SQL screen used to keep its queries in a separate file
+        if let Err(e) = settings.migrate_legacy_saved_queries(&settings_path) {
+            tracing::warn!("Failed to migrate saved queries: {}", e);
+        }
 
         tracing::info!(
             "Loaded settings, database_dir: {:?}",
@@ -28,6 +28,10 @@ pub struct ServerConfig {
     /// Port to bind to
     #[serde(default = "default_port")]
     pub port: u16,
+
+    /// Maximum number of API requests processed at the same time
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
 }
 
 /// Application settings
@@ -50,6 +54,10 @@ fn default_port() -> u16 {
     8080
 }
 
+fn default_max_concurrent_requests() -> usize {
+    8
+}
+
 fn default_settings_path() -> String {
     "./data/settings.json".to_string()
 }
@@ -63,6 +71,7 @@ impl Default for ServerConfig {
         Self {
             host: default_host(),
             port: default_port(),
+            max_concurrent_requests: default_max_concurrent_requests(),
         }
     }
 }
@@ -2,9 +2,15 @@
 //!
 //! Each handler wraps the corresponding service function.
 
+use std::path::PathBuf;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
-use actix_web::{HttpResponse, web};
+use actix_web::body::{BodySize, BoxBody, MessageBody};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::web::Bytes;
+use actix_web::{HttpRequest, HttpResponse, web};
 
 use jira_db_service::{self as service, AppState};
 
@@ -104,7 +110,7 @@ pub async fn issues_search(
     state: web::Data<Arc<AppState>>,
     request: web::Json<service::IssueSearchRequest>,
 ) -> Result<HttpResponse> {
-    let response = service::issues::search(&state, request.into_inner())?;
+    let response = service::issues::search(&state, request.into_inner()).await?;
     Ok(HttpResponse::Ok().json(response))
 }
 
@@ -124,6 +130,14 @@ pub async fn issues_history(
     Ok(HttpResponse::Ok().json(response))
 }
 
+pub async fn issues_update(
+    state: web::Data<Arc<AppState>>,
+    request: web::Json<service::IssueUpdateRequest>,
+) -> Result<HttpResponse> {
+    let response = service::issues::update(&state, request.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
 // ============================================================
 // Metadata Handlers
 // ============================================================
@@ -156,6 +170,14 @@ pub async fn embeddings_search(
     Ok(HttpResponse::Ok().json(response))
 }
 
+pub async fn embeddings_similar(
+    state: web::Data<Arc<AppState>>,
+    request: web::Json<service::EmbeddingsSimilarRequest>,
+) -> Result<HttpResponse> {
+    let response = service::embeddings::similar(&state, request.into_inner())?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
 // ============================================================
 // Reports Handlers
 // ============================================================
@@ -168,6 +190,18 @@ pub async fn reports_generate(
     Ok(HttpResponse::Ok().json(response))
 }
 
+// ============================================================
+// JQL Handlers
+// ============================================================
+
+pub async fn jql_execute(
+    state: web::Data<Arc<AppState>>,
+    request: web::Json<service::JqlExecuteRequest>,
+) -> Result<HttpResponse> {
+    let response = service::jql::execute(&state, request.into_inner())?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
 // ============================================================
 // SQL Handlers
 // ============================================================
@@ -189,25 +223,127 @@ pub async fn sql_get_schema(
 }
 
 pub async fn sql_query_list(
-    _state: web::Data<Arc<AppState>>,
+    state: web::Data<Arc<AppState>>,
     request: web::Json<service::SqlQueryListRequest>,
 ) -> Result<HttpResponse> {
-    let response = service::sql::query_list(request.into_inner())?;
+    let response = service::sql::query_list(&state, request.into_inner())?;
     Ok(HttpResponse::Ok().json(response))
 }
 
 pub async fn sql_query_save(
-    _state: web::Data<Arc<AppState>>,
+    state: web::Data<Arc<AppState>>,
     request: web::Json<service::SqlQuerySaveRequest>,
 ) -> Result<HttpResponse> {
-    let response = service::sql::query_save(request.into_inner())?;
+    let response = service::sql::query_save(&state, request.into_inner())?;
     Ok(HttpResponse::Ok().json(response))
 }
 
 pub async fn sql_query_delete(
-    _state: web::Data<Arc<AppState>>,
+    state: web::Data<Arc<AppState>>,
     request: web::Json<service::SqlQueryDeleteRequest>,
 ) -> Result<HttpResponse> {
-    let response = service::sql::query_delete(request.into_inner())?;
+    let response = service::sql::query_delete(&state, request.into_inner())?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+// ============================================================
+// Saved Search Handlers
+// ============================================================
+
+pub async fn saved_list(
+    state: web::Data<Arc<AppState>>,
+    _request: web::Json<service::SavedSearchListRequest>,
+) -> Result<HttpResponse> {
+    let response = service::saved::list(&state)?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn saved_save(
+    state: web::Data<Arc<AppState>>,
+    request: web::Json<service::SavedSearchSaveRequest>,
+) -> Result<HttpResponse> {
+    let response = service::saved::save(&state, request.into_inner())?;
     Ok(HttpResponse::Ok().json(response))
 }
+
+pub async fn saved_delete(
+    state: web::Data<Arc<AppState>>,
+    request: web::Json<service::SavedSearchDeleteRequest>,
+) -> Result<HttpResponse> {
+    let response = service::saved::delete(&state, request.into_inner())?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn saved_run(
+    state: web::Data<Arc<AppState>>,
+    request: web::Json<service::SavedSearchRunRequest>,
+) -> Result<HttpResponse> {
+    let response = service::saved::run(&state, request.into_inner())?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+// ============================================================
+// Export Handlers
+// ============================================================
+
+/// Export a table or query result and stream the file as an attachment
+pub async fn export_download(
+    state: web::Data<Arc<AppState>>,
+    request: web::Json<service::ExportDataRequest>,
+    http_request: HttpRequest,
+) -> Result<HttpResponse> {
+    let response = service::export::export_to_temp_file(&state, request.into_inner())?;
+
+    let temp_file = TempFileGuard(PathBuf::from(&response.path));
+
+    let file = actix_files::NamedFile::open(&temp_file.0)
+        .map_err(|e| ApiError::internal(format!("Failed to open export file: {}", e)))?;
+
+    let file = file.set_content_disposition(ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(format!(
+            "export.{}",
+            response.format
+        ))],
+    });
+    Ok(file
+        .into_response(&http_request)
+        .map_body(|_, body| TempFileBody {
+            body,
+            _temp_file: temp_file,
+        })
+        .map_into_boxed_body())
+}
+
+/// Removes the exported temp file when dropped
+struct TempFileGuard(PathBuf);
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Response body that keeps the temp file until the body has been streamed
+///
+/// Fields drop in order, so the file handle inside `body` is closed before the
+/// guard removes the file (Windows refuses to delete open files).
+struct TempFileBody {
+    body: BoxBody,
+    _temp_file: TempFileGuard,
+}
+
+impl MessageBody for TempFileBody {
+    type Error = Box<dyn std::error::Error>;
+
+    fn size(&self) -> BodySize {
+        self.body.size()
+    }
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<std::result::Result<Bytes, Self::Error>>> {
+        Pin::new(&mut self.get_mut().body).poll_next(cx)
+    }
+}
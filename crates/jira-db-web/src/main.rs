@@ -7,8 +7,10 @@ use std::sync::Arc;
 
 use actix_cors::Cors;
 use actix_files::Files;
+use actix_web::dev::Service;
 use actix_web::{App, HttpServer, middleware, web};
 use clap::Parser;
+use tokio::sync::Semaphore;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 mod config;
@@ -34,6 +36,10 @@ struct Args {
     #[arg(short, long)]
     port: Option<u16>,
 
+    /// Maximum number of API requests processed at the same time (overrides config file)
+    #[arg(long)]
+    max_concurrent_requests: Option<usize>,
+
     /// Path to settings.json (overrides config file)
     #[arg(long)]
     settings: Option<String>,
@@ -63,6 +69,10 @@ async fn main() -> std::io::Result<()> {
     // Apply CLI overrides
     let host = args.host.unwrap_or(config.server.host);
     let port = args.port.unwrap_or(config.server.port);
+    let max_concurrent_requests = args
+        .max_concurrent_requests
+        .unwrap_or(config.server.max_concurrent_requests)
+        .max(1);
     let settings_path_str = args.settings.unwrap_or(config.app.settings_path);
     let static_dir = args.static_dir.unwrap_or(config.app.static_dir);
 
@@ -93,6 +103,11 @@ async fn main() -> std::io::Result<()> {
 
     tracing::info!("Starting JiraDb Web Server on http://{}", bind_addr);
     tracing::info!("Static files directory: {}", static_dir);
+    tracing::info!("Max concurrent API requests: {}", max_concurrent_requests);
+
+    // Service calls run blocking DuckDB queries on the worker threads, so there is one worker
+    // per concurrent request and the semaphore caps requests across all workers
+    let limiter = Arc::new(Semaphore::new(max_concurrent_requests));
 
     HttpServer::new(move || {
         let cors = Cors::default()
@@ -108,6 +123,19 @@ async fn main() -> std::io::Result<()> {
             // API routes
             .service(
                 web::scope("/api")
+                    .wrap_fn({
+                        let limiter = limiter.clone();
+                        move |req, srv| {
+                            let limiter = limiter.clone();
+                            let response = srv.call(req);
+                            async move {
+                                let _permit = limiter.acquire_owned().await.map_err(|e| {
+                                    actix_web::error::ErrorServiceUnavailable(e.to_string())
+                                })?;
+                                response.await
+                            }
+                        }
+                    })
                     // Config
                     .route("/config.get", web::post().to(handlers::config_get))
                     .route("/config.update", web::post().to(handlers::config_update))
@@ -136,6 +164,7 @@ async fn main() -> std::io::Result<()> {
                     .route("/issues.search", web::post().to(handlers::issues_search))
                     .route("/issues.get", web::post().to(handlers::issues_get))
                     .route("/issues.history", web::post().to(handlers::issues_history))
+                    .route("/issues.update", web::post().to(handlers::issues_update))
                     // Metadata
                     .route("/metadata.get", web::post().to(handlers::metadata_get))
                     // Embeddings
@@ -147,11 +176,17 @@ async fn main() -> std::io::Result<()> {
                         "/embeddings.search",
                         web::post().to(handlers::embeddings_search),
                     )
+                    .route(
+                        "/embeddings.similar",
+                        web::post().to(handlers::embeddings_similar),
+                    )
                     // Reports
                     .route(
                         "/reports.generate",
                         web::post().to(handlers::reports_generate),
                     )
+                    // JQL
+                    .route("/jql.execute", web::post().to(handlers::jql_execute))
                     // SQL
                     .route("/sql.execute", web::post().to(handlers::sql_execute))
                     .route("/sql.get-schema", web::post().to(handlers::sql_get_schema))
@@ -163,6 +198,16 @@ async fn main() -> std::io::Result<()> {
                     .route(
                         "/sql.delete-query",
                         web::post().to(handlers::sql_query_delete),
+                    )
+                    // Saved searches
+                    .route("/saved.list", web::post().to(handlers::saved_list))
+                    .route("/saved.save", web::post().to(handlers::saved_save))
+                    .route("/saved.delete", web::post().to(handlers::saved_delete))
+                    .route("/saved.run", web::post().to(handlers::saved_run))
+                    // Export
+                    .route(
+                        "/export.download",
+                        web::post().to(handlers::export_download),
                     ),
             );
 
@@ -184,6 +229,7 @@ async fn main() -> std::io::Result<()> {
 
         app
     })
+    .workers(max_concurrent_requests)
     .bind(&bind_addr)?
     .run()
     .await
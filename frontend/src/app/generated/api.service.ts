@@ -29,12 +29,19 @@ import {
   DebugTransitionIssueResponse,
   EmbeddingsGenerateRequest,
   EmbeddingsGenerateResponse,
+  EmbeddingsSimilarRequest,
+  EmbeddingsSimilarResponse,
+  ExportDataRequest,
   IssueGetRequest,
   IssueGetResponse,
   IssueHistoryRequest,
   IssueHistoryResponse,
+  IssueUpdateRequest,
+  IssueUpdateResponse,
   IssueSearchRequest,
   IssueSearchResponse,
+  JqlExecuteRequest,
+  JqlExecuteResponse,
   MetadataGetRequest,
   MetadataGetResponse,
   ProjectDisableRequest,
@@ -47,6 +54,14 @@ import {
   ProjectListResponse,
   ReportGenerateRequest,
   ReportGenerateResponse,
+  SavedSearchDeleteRequest,
+  SavedSearchDeleteResponse,
+  SavedSearchListRequest,
+  SavedSearchListResponse,
+  SavedSearchRunRequest,
+  SavedSearchRunResponse,
+  SavedSearchSaveRequest,
+  SavedSearchSaveResponse,
   SemanticSearchRequest,
   SemanticSearchResponse,
   SqlExecuteRequest,
@@ -147,6 +162,11 @@ export class ApiService {
     return this.http.post<IssueHistoryResponse>(`${this.baseUrl}/issues.history`, request);
   }
 
+  /** Edit issue fields in JIRA */
+  issuesUpdate(request: IssueUpdateRequest): Observable<IssueUpdateResponse> {
+    return this.http.post<IssueUpdateResponse>(`${this.baseUrl}/issues.update`, request);
+  }
+
   // ----------------------------------------
   // Metadata
   // ----------------------------------------
@@ -170,6 +190,11 @@ export class ApiService {
     return this.http.post<SemanticSearchResponse>(`${this.baseUrl}/embeddings.search`, request);
   }
 
+  /** Find issues similar to an issue from its stored embedding */
+  embeddingsSimilar(request: EmbeddingsSimilarRequest): Observable<EmbeddingsSimilarResponse> {
+    return this.http.post<EmbeddingsSimilarResponse>(`${this.baseUrl}/embeddings.similar`, request);
+  }
+
   // ----------------------------------------
   // Reports
   // ----------------------------------------
@@ -179,6 +204,15 @@ export class ApiService {
     return this.http.post<ReportGenerateResponse>(`${this.baseUrl}/reports.generate`, request);
   }
 
+  // ----------------------------------------
+  // Jql
+  // ----------------------------------------
+
+  /** Run a JQL query against the local database */
+  jqlExecute(request: JqlExecuteRequest): Observable<JqlExecuteResponse> {
+    return this.http.post<JqlExecuteResponse>(`${this.baseUrl}/jql.execute`, request);
+  }
+
   // ----------------------------------------
   // Sql
   // ----------------------------------------
@@ -208,6 +242,39 @@ export class ApiService {
     return this.http.post<SqlQueryDeleteResponse>(`${this.baseUrl}/sql.delete-query`, request);
   }
 
+  // ----------------------------------------
+  // Saved
+  // ----------------------------------------
+
+  /** List saved searches */
+  savedList(request: SavedSearchListRequest): Observable<SavedSearchListResponse> {
+    return this.http.post<SavedSearchListResponse>(`${this.baseUrl}/saved.list`, request);
+  }
+
+  /** Create or replace a saved search */
+  savedSave(request: SavedSearchSaveRequest): Observable<SavedSearchSaveResponse> {
+    return this.http.post<SavedSearchSaveResponse>(`${this.baseUrl}/saved.save`, request);
+  }
+
+  /** Delete a saved search */
+  savedDelete(request: SavedSearchDeleteRequest): Observable<SavedSearchDeleteResponse> {
+    return this.http.post<SavedSearchDeleteResponse>(`${this.baseUrl}/saved.delete`, request);
+  }
+
+  /** Run a saved search with parameter values */
+  savedRun(request: SavedSearchRunRequest): Observable<SavedSearchRunResponse> {
+    return this.http.post<SavedSearchRunResponse>(`${this.baseUrl}/saved.run`, request);
+  }
+
+  // ----------------------------------------
+  // Export
+  // ----------------------------------------
+
+  /** Export a table or query result and download the file */
+  exportDownload(request: ExportDataRequest): Observable<Blob> {
+    return this.http.post(`${this.baseUrl}/export.download`, request, { responseType: 'blob' });
+  }
+
   // ----------------------------------------
   // Debug
   // ----------------------------------------
@@ -47,6 +47,8 @@ export interface ChangeHistoryItem {
   toValue?: string;
   toString?: string;
   changedAt: string;
+  /** "changelog" or "sync-diff" (derived by comparing payloads during sync) */
+  source: string;
 }
 
 export interface Status {
@@ -85,6 +87,15 @@ export interface FixVersion {
   releaseDate?: string;
 }
 
+export interface FieldOption {
+  fieldId: string;
+  fieldName?: string;
+  optionId: string;
+  value: string;
+  parentOptionId?: string;
+  disabled: boolean;
+}
+
 export interface ProjectMetadata {
   projectKey: string;
   statuses: Status[];
@@ -93,10 +104,12 @@ export interface ProjectMetadata {
   labels: Label[];
   components: Component[];
   fixVersions: FixVersion[];
+  fieldOptions: FieldOption[];
 }
 
 export interface SyncResult {
   projectKey: string;
+  endpoint?: string;
   issueCount: number;
   metadataUpdated: boolean;
   duration: number;
@@ -123,11 +136,44 @@ export interface SemanticSearchResult {
   score: number;
 }
 
+export interface SimilarIssueResult {
+  issue: Issue;
+  /** Cosine similarity to the source issue (1 = same direction) */
+  similarity: number;
+}
+
 export interface ReportResult {
   outputPath: string;
   issueCount: number;
 }
 
+export interface SavedSearchParameter {
+  name: string;
+  /** "string", "number", "date" or "boolean" */
+  type: string;
+  description?: string;
+  required: boolean;
+  /** Used when no value is given */
+  default?: string;
+}
+
+export interface SavedSearch {
+  name: string;
+  description?: string;
+  owner?: string;
+  /** Project to run against; all projects when unset */
+  project?: string;
+  /** "sql", "jql" or "filter" */
+  type: string;
+  /** SQL or JQL text with {{param}} placeholders */
+  query?: string;
+  /** Structured filters, with the same snake_case keys as `jira-db saved add --filter` */
+  filter?: unknown;
+  parameters: SavedSearchParameter[];
+  createdAt: string;
+  updatedAt: string;
+}
+
 export interface SavedQuery {
   id: string;
   name: string;
@@ -303,13 +349,70 @@ export interface IssueSearchRequest {
   priority?: string;
   issueType?: string;
   team?: string;
+  statusCategory?: string;
+  resolution?: string;
+  labels?: string[];
+  /** "any" (default) or "all" */
+  labelsMatch?: string;
+  components?: string[];
+  fixVersions?: string[];
+  sprint?: string;
+  parentKey?: string;
+  /** Date ranges accept YYYY-MM-DD or RFC 3339; "to" bounds include the whole day */
+  createdFrom?: string;
+  createdTo?: string;
+  updatedFrom?: string;
+  updatedTo?: string;
+  dueFrom?: string;
+  dueTo?: string;
+  resolvedFrom?: string;
+  resolvedTo?: string;
+  /** Sort keys in priority order; relevance when omitted */
+  sort?: IssueSearchSort[];
   limit?: number;
   offset?: number;
+  hybrid?: boolean;
+  /** Count matches by status, type, priority, assignee, labels, components and fix versions */
+  facets?: boolean;
+}
+
+export interface IssueSearchSort {
+  /** "relevance", "key", "summary", "created", "updated", "due" or "resolved" */
+  field: string;
+  /** Defaults to descending for relevance and dates, ascending otherwise */
+  descending?: boolean;
+}
+
+export interface IssueSearchMatch {
+  key: string;
+  score?: number;
+  lexicalRank?: number;
+  lexicalScore?: number;
+  semanticRank?: number;
+  semanticDistance?: number;
+  snippet?: string;
 }
 
 export interface IssueSearchResponse {
   issues: Issue[];
+  /** Matching issues across all pages */
   total: number;
+  matches: IssueSearchMatch[];
+  /** Present when facets were requested; counted over all matches, not just this page */
+  facets?: IssueSearchFacet[];
+}
+
+export interface IssueSearchFacet {
+  /** "status", "issue_type", "priority", "assignee", "labels", "components" or "fix_versions" */
+  field: string;
+  /** Most frequent values first */
+  values: IssueFacetValue[];
+}
+
+export interface IssueFacetValue {
+  /** Omitted for issues without a value (e.g. unassigned) */
+  value?: string;
+  count: number;
 }
 
 export interface IssueGetRequest {
@@ -330,6 +433,24 @@ export interface IssueHistoryResponse {
   history: ChangeHistoryItem[];
 }
 
+export interface FieldValue {
+  field: string;
+  value: string;
+}
+
+export interface IssueUpdateRequest {
+  key: string;
+  set?: FieldValue[];
+  add?: FieldValue[];
+  remove?: FieldValue[];
+  dryRun?: boolean;
+}
+
+export interface IssueUpdateResponse {
+  issue?: Issue;
+  payload: string;
+}
+
 export interface MetadataGetRequest {
   projectKey: string;
   type?: string;
@@ -360,6 +481,24 @@ export interface SemanticSearchResponse {
   results: SemanticSearchResult[];
 }
 
+export interface EmbeddingsSimilarRequest {
+  issueKey: string;
+  /** Only return issues of the same project */
+  sameProject?: boolean;
+  /** Leave out resolved issues */
+  excludeResolved?: boolean;
+  /** Created on or after this date (YYYY-MM-DD or RFC 3339) */
+  createdFrom?: string;
+  /** Created on or before this date (YYYY-MM-DD or RFC 3339) */
+  createdTo?: string;
+  limit?: number;
+}
+
+export interface EmbeddingsSimilarResponse {
+  source: Issue;
+  results: SimilarIssueResult[];
+}
+
 export interface ReportGenerateRequest {
   interactive?: boolean;
   outputPath?: string;
@@ -370,6 +509,23 @@ export interface ReportGenerateResponse {
   result: ReportResult;
 }
 
+export interface JqlExecuteRequest {
+  query: string;
+  projectKey?: string;
+  /** If true, query across all synced projects instead of just one */
+  allProjects?: boolean;
+  limit?: number;
+}
+
+export interface JqlExecuteResponse {
+  /** SQL the query was compiled to */
+  sql: string;
+  columns: string[];
+  rows: unknown[];
+  rowCount: number;
+  executionTimeMs: number;
+}
+
 export interface SqlExecuteRequest {
   projectKey?: string;
   /** If true, query across all synced projects instead of just one */
@@ -422,6 +578,82 @@ export interface SqlQueryDeleteResponse {
   success: boolean;
 }
 
+export interface SavedSearchListRequest {
+}
+
+export interface SavedSearchListResponse {
+  searches: SavedSearch[];
+}
+
+export interface SavedSearchSaveRequest {
+  name: string;
+  /** "sql", "jql" or "filter" */
+  type: string;
+  /** SQL or JQL text (for "sql" and "jql") */
+  query?: string;
+  /** Structured filters (for "filter") */
+  filter?: unknown;
+  description?: string;
+  /** Defaults to the JIRA username of the active endpoint */
+  owner?: string;
+  project?: string;
+  parameters?: SavedSearchParameter[];
+}
+
+export interface SavedSearchSaveResponse {
+  search: SavedSearch;
+}
+
+export interface SavedSearchDeleteRequest {
+  name: string;
+}
+
+export interface SavedSearchDeleteResponse {
+  success: boolean;
+}
+
+export interface SavedSearchRunRequest {
+  name: string;
+  /** Parameter values keyed by parameter name */
+  params?: unknown;
+  /** Project to run against (overrides the saved project) */
+  project?: string;
+  limit?: number;
+}
+
+export interface SavedSearchRunResponse {
+  name: string;
+  type: string;
+  /** SQL that was executed (for "sql" and "jql") */
+  sql?: string;
+  /** Matching issues across all pages (for "filter") */
+  total?: number;
+  columns: string[];
+  rows: unknown[];
+  rowCount: number;
+  executionTimeMs: number;
+}
+
+export interface ExportDataRequest {
+  projectKey?: string;
+  /** If true, export from all synced projects (adds a project_key column) */
+  allProjects?: boolean;
+  /** Table or view to export (e.g. issues_readable) */
+  table?: string;
+  /** SELECT query whose result is exported */
+  query?: string;
+  /** parquet, csv, jsonl or xlsx */
+  format: string;
+  /** Destination file (desktop app only) */
+  outputPath?: string;
+}
+
+export interface ExportDataResponse {
+  path: string;
+  format: string;
+  rowCount: number;
+}
+
 export interface CreatedIssue {
   key: string;
   id: string;
@@ -29,12 +29,20 @@ import {
   DebugTransitionIssueResponse,
   EmbeddingsGenerateRequest,
   EmbeddingsGenerateResponse,
+  EmbeddingsSimilarRequest,
+  EmbeddingsSimilarResponse,
+  ExportDataRequest,
+  ExportDataResponse,
   IssueGetRequest,
   IssueGetResponse,
   IssueHistoryRequest,
   IssueHistoryResponse,
+  IssueUpdateRequest,
+  IssueUpdateResponse,
   IssueSearchRequest,
   IssueSearchResponse,
+  JqlExecuteRequest,
+  JqlExecuteResponse,
   MetadataGetRequest,
   MetadataGetResponse,
   ProjectDisableRequest,
@@ -47,6 +55,14 @@ import {
   ProjectListResponse,
   ReportGenerateRequest,
   ReportGenerateResponse,
+  SavedSearchDeleteRequest,
+  SavedSearchDeleteResponse,
+  SavedSearchListRequest,
+  SavedSearchListResponse,
+  SavedSearchRunRequest,
+  SavedSearchRunResponse,
+  SavedSearchSaveRequest,
+  SavedSearchSaveResponse,
   SemanticSearchRequest,
   SemanticSearchResponse,
   SqlExecuteRequest,
@@ -143,6 +159,11 @@ export class TauriApiService {
     return from(invoke<IssueHistoryResponse>('issues_history', { request }));
   }
 
+  /** Edit issue fields in JIRA */
+  issuesUpdate(request: IssueUpdateRequest): Observable<IssueUpdateResponse> {
+    return from(invoke<IssueUpdateResponse>('issues_update', { request }));
+  }
+
   // ----------------------------------------
   // Metadata
   // ----------------------------------------
@@ -166,6 +187,11 @@ export class TauriApiService {
     return from(invoke<SemanticSearchResponse>('embeddings_search', { request }));
   }
 
+  /** Find issues similar to an issue from its stored embedding */
+  embeddingsSimilar(request: EmbeddingsSimilarRequest): Observable<EmbeddingsSimilarResponse> {
+    return from(invoke<EmbeddingsSimilarResponse>('embeddings_similar', { request }));
+  }
+
   // ----------------------------------------
   // Reports
   // ----------------------------------------
@@ -175,6 +201,15 @@ export class TauriApiService {
     return from(invoke<ReportGenerateResponse>('reports_generate', { request }));
   }
 
+  // ----------------------------------------
+  // Jql
+  // ----------------------------------------
+
+  /** Run a JQL query against the local database */
+  jqlExecute(request: JqlExecuteRequest): Observable<JqlExecuteResponse> {
+    return from(invoke<JqlExecuteResponse>('jql_execute', { request }));
+  }
+
   // ----------------------------------------
   // Sql
   // ----------------------------------------
@@ -204,6 +239,39 @@ export class TauriApiService {
     return from(invoke<SqlQueryDeleteResponse>('sql_delete_query', { request }));
   }
 
+  // ----------------------------------------
+  // Saved
+  // ----------------------------------------
+
+  /** List saved searches */
+  savedList(request: SavedSearchListRequest): Observable<SavedSearchListResponse> {
+    return from(invoke<SavedSearchListResponse>('saved_list', { request }));
+  }
+
+  /** Create or replace a saved search */
+  savedSave(request: SavedSearchSaveRequest): Observable<SavedSearchSaveResponse> {
+    return from(invoke<SavedSearchSaveResponse>('saved_save', { request }));
+  }
+
+  /** Delete a saved search */
+  savedDelete(request: SavedSearchDeleteRequest): Observable<SavedSearchDeleteResponse> {
+    return from(invoke<SavedSearchDeleteResponse>('saved_delete', { request }));
+  }
+
+  /** Run a saved search with parameter values */
+  savedRun(request: SavedSearchRunRequest): Observable<SavedSearchRunResponse> {
+    return from(invoke<SavedSearchRunResponse>('saved_run', { request }));
+  }
+
+  // ----------------------------------------
+  // Export
+  // ----------------------------------------
+
+  /** Export a table or query result to a file */
+  exportData(request: ExportDataRequest): Observable<ExportDataResponse> {
+    return from(invoke<ExportDataResponse>('export_data', { request }));
+  }
+
   // ----------------------------------------
   // Debug
   // ----------------------------------------
@@ -12,7 +12,7 @@ describe('IssuesComponent', () => {
   const mockApiService = {
     projectsList: jest.fn().mockReturnValue(of({ projects: [] })),
     metadataGet: jest.fn().mockReturnValue(of({ metadata: { statuses: [], issueTypes: [] } })),
-    issuesSearch: jest.fn().mockReturnValue(of({ issues: [], total: 0 })),
+    issuesSearch: jest.fn().mockReturnValue(of({ issues: [], total: 0, matches: [] })),
   };
 
   beforeEach(async () => {